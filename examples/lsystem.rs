@@ -0,0 +1,30 @@
+use loempia::drawing::Drawing;
+use loempia::lsystem::LSystem;
+use loempia::{Error, Plot};
+
+/// Draw a fractal plant beside a Koch snowflake, in millimeters on a Roland DXY, and save a
+/// preview to the path given as argument.
+fn main() -> Result<(), Error> {
+    let output = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "lsystem.svg".to_string());
+
+    let mut drawing = Drawing::new();
+    drawing.scale(40.0, 40.0);
+
+    drawing.push();
+    drawing.translate(100.0, 250.0);
+    drawing.set_heading(270.0);
+    let plant = LSystem::new("X", &[('X', "F+[[X]-X]-F[-FX]+X"), ('F', "FF")]);
+    plant.draw(&mut drawing, 5, 1.8, 25.0);
+    drawing.pop();
+
+    drawing.translate(200.0, 90.0);
+    let snowflake = LSystem::new("F--F--F", &[('F', "F+F--F+F")]);
+    snowflake.draw(&mut drawing, 4, 1.5, 60.0);
+    drawing.circle(60.0, 35.0, 80.0);
+
+    let plot = Plot::new(drawing.into_paths()?);
+    svg::save(output, &plot.preview())?;
+    Ok(())
+}
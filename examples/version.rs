@@ -1,11 +1,14 @@
-use loempia::{Command, Driver, Error};
-use std::path::Path;
+use loempia::discovery::discover;
+use loempia::{Backend, Command, Driver, Error};
 
 fn main() -> Result<(), Error> {
-    let path = Path::new("/dev/ttyACM0");
+    let device = discover()?
+        .into_iter()
+        .find(|device| device.backend == Backend::Ebb)
+        .ok_or_else(|| Error::ProbeError("No EBB found.".to_string()))?;
 
-    let mut driver = Driver::open(path)?;
+    let mut driver = Driver::open(&device.path)?;
 
-    driver.execute_command(Command::V)?;
+    println!("{}", driver.query(Command::V)?);
     Ok(())
 }
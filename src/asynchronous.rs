@@ -0,0 +1,449 @@
+//! Asynchronous variants of the EBB and Roland DXY drivers.
+//!
+//! The drivers work on top of any `AsyncRead + AsyncWrite` transport, like the `SerialStream`
+//! of tokio-serial, and use the same `Command`s as the blocking drivers. A single runtime can
+//! supervise many plotters concurrently:
+//!
+//! ```no_run
+//! # async fn example(plot: loempia::Plot) -> Result<(), loempia::Error> {
+//! use loempia::asynchronous::{Ebb, RolandDxy};
+//! use loempia::config::SerialSettings;
+//! use std::path::Path;
+//!
+//! let mut axidraw = Ebb::open(Path::new("/dev/ttyACM0"))?;
+//! let mut dxy = RolandDxy::open(Path::new("/dev/ttyUSB0"), &SerialSettings::default())?;
+//!
+//! let (axidraw, dxy) = tokio::join!(axidraw.plot(&plot), dxy.plot(&plot));
+//! axidraw?;
+//! dxy?;
+//! # Ok(())
+//! # }
+//! ```
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio_serial::{DataBits, SerialPortBuilderExt, SerialStream, StopBits};
+use tracing::{debug, info, info_span, trace_span, Instrument};
+
+use crate::config::{Flow, Parity, SerialSettings};
+use crate::plotter::Status;
+use crate::point::{Coordinate, Relative};
+use crate::{
+    expects_ok_line, parse_status, plot_to_commands_with, query_value, roland_dxy, Command, Error,
+    PenSettings, Plot, DEFAULT_MOVE_DURATION, DEFAULT_TIMEOUT,
+};
+
+/// Wait at most `timeout` for `future`, failing like a blocking serial port does.
+async fn with_timeout<T>(
+    timeout: Duration,
+    future: impl std::future::Future<Output = io::Result<T>>,
+) -> io::Result<T> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .unwrap_or_else(|_| Err(io::Error::new(io::ErrorKind::TimedOut, "timed out")))
+}
+
+/// Asynchronous driver for the EBB. See `Driver` for the blocking variant.
+pub struct Ebb<T> {
+    transport: BufReader<T>,
+    pen: PenSettings,
+    move_duration: u32,
+    timeout: Duration,
+}
+
+impl Ebb<SerialStream> {
+    /// Open the serial device at `path`.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let port = tokio_serial::new(path.to_string_lossy(), 9600)
+            .open_native_async()
+            .map_err(io::Error::from)?;
+
+        Ok(Self::new(port))
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> Ebb<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport: BufReader::new(transport),
+            pen: PenSettings::default(),
+            move_duration: DEFAULT_MOVE_DURATION,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Fail reads and writes that take longer than `timeout`.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Make every `SM` command sent by `plot()` and `move_by()` take `duration` milliseconds.
+    pub fn set_move_duration(&mut self, duration: u32) {
+        self.move_duration = duration;
+    }
+
+    /// Configure the pen servo of the device and use the delays of `pen` for all pen moves.
+    pub async fn set_pen_settings(&mut self, pen: PenSettings) -> Result<(), Error> {
+        for command in pen.configuration() {
+            self.execute_command(command).await?;
+        }
+        self.pen = pen;
+
+        Ok(())
+    }
+
+    pub async fn execute_command(&mut self, cmd: Command) -> Result<(), Error> {
+        let span = trace_span!("command", command = %cmd);
+        async {
+            self.write_command(&cmd).await?;
+            self.read_response(&cmd).await?;
+
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Execute a command that queries the device, like `V` or `QT`, and return the value.
+    pub async fn query(&mut self, cmd: Command) -> Result<String, Error> {
+        let span = trace_span!("query", command = %cmd);
+        async {
+            self.write_command(&cmd).await?;
+            let mut response = self.read_response(&cmd).await?;
+            if expects_ok_line(&cmd, &response) {
+                response.push_str(&self.read_response(&cmd).await?);
+            }
+
+            Ok(query_value(&response))
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn write_command(&mut self, cmd: &Command) -> Result<(), Error> {
+        let line = format!("{}\r", cmd);
+        debug!("Writing command: {:?}", line);
+
+        with_timeout(self.timeout, self.transport.write_all(line.as_bytes()))
+            .await
+            .map_err(|err| Error::CommandError(cmd.to_string(), err))
+    }
+
+    /// Read a single line of response to the given command.
+    async fn read_response(&mut self, cmd: &Command) -> Result<String, Error> {
+        let mut response = vec![];
+        while !response.ends_with(b"\r\n") {
+            let length = with_timeout(
+                self.timeout,
+                self.transport.read_until(b'\n', &mut response),
+            )
+            .await
+            .map_err(|err| Error::ResponseError(cmd.to_string(), err))?;
+
+            if length == 0 {
+                return Err(Error::ResponseError(
+                    cmd.to_string(),
+                    io::ErrorKind::UnexpectedEof.into(),
+                ));
+            }
+        }
+
+        let response = String::from_utf8_lossy(&response).to_string();
+        debug!("Response {:?}", response);
+        if response.starts_with('!') {
+            return Err(Error::ErrorResponse(cmd.clone(), response));
+        }
+
+        Ok(response)
+    }
+
+    /// Plot all paths of the `Plot`.
+    pub async fn plot(&mut self, plot: &Plot) -> Result<(), Error> {
+        let commands = plot_to_commands_with(plot, &self.pen, self.move_duration)?;
+        let span = info_span!("plot", commands = commands.len());
+        async {
+            for command in commands {
+                self.execute_command(command).await?;
+            }
+            info!("Plot finished");
+
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    pub async fn pen_up(&mut self) -> Result<(), Error> {
+        self.execute_command(self.pen.command(1)).await
+    }
+
+    pub async fn pen_down(&mut self) -> Result<(), Error> {
+        self.execute_command(self.pen.command(0)).await
+    }
+
+    /// Move the pen relative to its current position.
+    pub async fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+        self.execute_command(Command::step_move(&delta, self.move_duration))
+            .await
+    }
+
+    /// Raise the pen and move to home.
+    pub async fn home(&mut self) -> Result<(), Error> {
+        self.pen_up().await?;
+        self.execute_command(Command::HM {
+            step_frequency: 1000,
+            position_1: None,
+            position_2: None,
+        })
+        .await
+    }
+
+    /// Make the current position the new home.
+    pub async fn set_origin(&mut self) -> Result<(), Error> {
+        self.execute_command(Command::CS).await
+    }
+
+    /// Query the position and pen state of the plotter.
+    pub async fn status(&mut self) -> Result<Status, Error> {
+        let steps = self.query(Command::QS).await?;
+        let pen = self.query(Command::QP).await?;
+
+        parse_status(&steps, &pen)
+    }
+
+    /// Disable the motors, so the carriage can be moved by hand.
+    pub async fn disable_motors(&mut self) -> Result<(), Error> {
+        self.execute_command(Command::EM {
+            enable_1: 0,
+            enable_2: 0,
+        })
+        .await
+    }
+
+    /// Query the firmware version.
+    pub async fn version(&mut self) -> Result<String, Error> {
+        self.query(Command::V).await
+    }
+}
+
+/// Asynchronous driver for the Roland DXY. See `roland_dxy::Driver` for the blocking variant.
+pub struct RolandDxy<T> {
+    transport: BufReader<T>,
+    timeout: Duration,
+    travel: (i32, i32),
+}
+
+impl RolandDxy<SerialStream> {
+    /// Open the serial device at `path` and configure it with `settings`.
+    pub fn open(path: &Path, settings: &SerialSettings) -> Result<Self, Error> {
+        let data_bits = match settings.data_bits {
+            5 => DataBits::Five,
+            6 => DataBits::Six,
+            7 => DataBits::Seven,
+            8 => DataBits::Eight,
+            other => {
+                return Err(Error::ConfigError(format!(
+                    "Invalid number of data bits: {}.",
+                    other
+                )))
+            }
+        };
+
+        let port = tokio_serial::new(path.to_string_lossy(), settings.baud_rate as u32)
+            .data_bits(data_bits)
+            .parity(match settings.parity {
+                Parity::None => tokio_serial::Parity::None,
+                Parity::Odd => tokio_serial::Parity::Odd,
+                Parity::Even => tokio_serial::Parity::Even,
+            })
+            .stop_bits(if settings.stop_bits == 2 {
+                StopBits::Two
+            } else {
+                StopBits::One
+            })
+            .flow_control(match settings.flow_control {
+                Flow::None => tokio_serial::FlowControl::None,
+                Flow::Software => tokio_serial::FlowControl::Software,
+                Flow::Hardware => tokio_serial::FlowControl::Hardware,
+            })
+            .open_native_async()
+            .map_err(io::Error::from)?;
+
+        Ok(Self::new(port))
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> RolandDxy<T> {
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport: BufReader::new(transport),
+            timeout: DEFAULT_TIMEOUT,
+            travel: roland_dxy::default_travel(),
+        }
+    }
+
+    /// Fail reads and writes that take longer than `timeout`.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Scale plots to fit `travel`, the width and height of the area the pen can reach, instead of
+    /// the default plotting area.
+    pub fn set_travel(&mut self, travel: (i32, i32)) {
+        self.travel = travel;
+    }
+
+    async fn write(&mut self, instruction: &str) -> Result<(), Error> {
+        debug!("Writing instruction: {:?}", instruction);
+
+        with_timeout(
+            self.timeout,
+            self.transport.write_all(instruction.as_bytes()),
+        )
+        .await
+        .map_err(|err| Error::CommandError(instruction.to_string(), err))
+    }
+
+    /// Send an output instruction, like `OI;`, and return the response of the plotter.
+    pub async fn query(&mut self, instruction: &str) -> Result<String, Error> {
+        self.write(instruction).await?;
+
+        let mut response = vec![];
+        let length = with_timeout(
+            self.timeout,
+            self.transport.read_until(b'\r', &mut response),
+        )
+        .await
+        .map_err(|err| Error::ResponseError(instruction.to_string(), err))?;
+        if length == 0 || !response.ends_with(b"\r") {
+            return Err(Error::ResponseError(
+                instruction.to_string(),
+                io::ErrorKind::UnexpectedEof.into(),
+            ));
+        }
+        response.pop();
+
+        let response = String::from_utf8_lossy(&response).to_string();
+        debug!("Response {:?}", response);
+
+        Ok(response)
+    }
+
+    /// Send a single command.
+    pub async fn execute_command(&mut self, command: &roland_dxy::Command) -> Result<(), Error> {
+        self.write(&command.to_string())
+            .instrument(trace_span!("command", %command))
+            .await
+    }
+
+    /// Plot all paths of the `Plot`.
+    pub async fn plot(&mut self, plot: &Plot) -> Result<(), Error> {
+        let commands = roland_dxy::plot_to_commands_with(plot, self.travel)?;
+        let span = info_span!("plot", commands = commands.len());
+        async {
+            for command in commands {
+                self.execute_command(&command).await?;
+            }
+            info!("Plot finished");
+
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    pub async fn pen_up(&mut self) -> Result<(), Error> {
+        self.execute_command(&roland_dxy::Command::PU(None)).await
+    }
+
+    pub async fn pen_down(&mut self) -> Result<(), Error> {
+        self.execute_command(&roland_dxy::Command::PD(None)).await
+    }
+
+    /// Move the pen relative to its current position.
+    pub async fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+        self.execute_command(&roland_dxy::Command::PR(Some(delta)))
+            .await
+    }
+
+    /// Raise the pen and move to home.
+    pub async fn home(&mut self) -> Result<(), Error> {
+        self.pen_up().await?;
+        self.execute_command(&roland_dxy::Command::PA(Some(Coordinate::new(0, 0))))
+            .await
+    }
+
+    /// Query the position and pen state of the plotter.
+    pub async fn status(&mut self) -> Result<Status, Error> {
+        let response = self.query("OA;").await?;
+        roland_dxy::parse_status(&response)
+    }
+
+    /// Query the model of the plotter.
+    pub async fn version(&mut self) -> Result<String, Error> {
+        self.query("OI;").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{duplex, AsyncReadExt, DuplexStream};
+
+    /// Play an EBB: answer every command with "OK", except `V`. Returns the commands received.
+    async fn fake_ebb(mut device: DuplexStream) -> Vec<String> {
+        let mut commands = vec![];
+        let mut received = String::new();
+        let mut buffer = [0; 256];
+
+        loop {
+            let length = device.read(&mut buffer).await.unwrap();
+            if length == 0 {
+                break;
+            }
+            received.push_str(std::str::from_utf8(&buffer[..length]).unwrap());
+
+            while let Some(end) = received.find('\r') {
+                let command: String = received.drain(..=end).collect();
+                let response = match command.as_str() {
+                    "V\r" => "EBBv13_and_above EB Firmware Version 2.8.1\r\n",
+                    _ => "OK\r\n",
+                };
+                device.write_all(response.as_bytes()).await.unwrap();
+                commands.push(command.trim_end().to_string());
+            }
+        }
+
+        commands
+    }
+
+    #[tokio::test]
+    async fn plot_on_ebb() {
+        let (driver, device) = duplex(64);
+        let device = tokio::spawn(fake_ebb(device));
+        let plot = Plot::from_path(vec![
+            Coordinate::new(0, 0),
+            Coordinate::new(1000, 0),
+            Coordinate::new(0, 0),
+        ])
+        .unwrap();
+
+        let mut ebb = Ebb::new(driver);
+        ebb.plot(&plot).await.unwrap();
+        assert_eq!(
+            ebb.version().await.unwrap(),
+            "EBBv13_and_above EB Firmware Version 2.8.1"
+        );
+
+        drop(ebb);
+        let mut expected: Vec<String> = crate::plot_to_commands(&plot)
+            .unwrap()
+            .iter()
+            .map(|command| command.to_string())
+            .collect();
+        expected.push("V".to_string());
+        assert_eq!(device.await.unwrap(), expected);
+    }
+}
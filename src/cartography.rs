@@ -0,0 +1,523 @@
+//! Decorations of a map: a scale bar, a north arrow, a graticule or grid, a frame and markers.
+//! Every decoration is added to the `Plot` of a `geo::Map` as a `Layer` of its own.
+//!
+//! Decorations are placed inside the area of the map, so give the map a margin to keep the frame
+//! off the edge of the page.
+use std::str::FromStr;
+
+use crate::geo::Map;
+use crate::point::{Absolute, Coordinate};
+use crate::projection;
+use crate::text::{self, Point};
+use crate::{Error, Layer, Path, Plot};
+
+/// Distance between the decorations and the frame, in millimeters.
+const PADDING_MM: f64 = 5.0;
+
+/// Height of digits and capitals, in millimeters.
+pub(crate) const TEXT_HEIGHT_MM: f64 = 3.0;
+
+/// Length of the north arrow, in millimeters.
+const NORTH_ARROW_MM: f64 = 12.0;
+
+/// Size of the start, finish and waypoint markers, in millimeters.
+const MARKER_MM: f64 = 2.5;
+
+/// Number of segments every line of the graticule is made of.
+const GRATICULE_SEGMENTS: usize = 64;
+
+/// The most lines a grid has in each direction.
+const MAX_GRID_LINES: usize = 100;
+
+const METERS_PER_MILE: f64 = 1609.344;
+
+/// Clip a line to a rectangle of left, top, right and bottom with the algorithm of Liang and
+/// Barsky. The parts inside become separate lines.
+pub(crate) fn clip(
+    line: &[Point],
+    (left, top, right, bottom): (f64, f64, f64, f64),
+) -> Vec<Vec<Point>> {
+    let mut parts: Vec<Vec<Point>> = vec![];
+    let mut current: Vec<Point> = vec![];
+
+    for segment in line.windows(2) {
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        let (mut t0, mut t1) = (0.0f64, 1.0f64);
+        let inside = [
+            (-dx, x0 - left),
+            (dx, right - x0),
+            (-dy, y0 - top),
+            (dy, bottom - y0),
+        ]
+        .into_iter()
+        .all(|(p, q)| {
+            if p == 0.0 {
+                return q >= 0.0;
+            }
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            t0 <= t1
+        });
+
+        if !inside {
+            if current.len() > 1 {
+                parts.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            continue;
+        }
+
+        let start = (x0 + t0 * dx, y0 + t0 * dy);
+        let end = (x0 + t1 * dx, y0 + t1 * dy);
+        if current.is_empty() {
+            current.push(start);
+        }
+        current.push(end);
+        if t1 < 1.0 {
+            parts.push(std::mem::take(&mut current));
+        }
+    }
+    if current.len() > 1 {
+        parts.push(current);
+    }
+
+    parts
+}
+
+/// Round the points of lines to `Path`s.
+pub(crate) fn to_paths(lines: Vec<Vec<Point>>) -> Vec<Path> {
+    lines
+        .into_iter()
+        .map(|line| {
+            line.into_iter()
+                .map(|(x, y)| Coordinate::new(x.round() as i32, y.round() as i32))
+                .collect()
+        })
+        .collect()
+}
+
+/// A regular polygon around `centre`, closed.
+pub(crate) fn polygon(centre: Point, radius: f64, corners: usize, rotation: f64) -> Vec<Point> {
+    (0..=corners)
+        .map(|corner| {
+            let angle = rotation + corner as f64 / corners as f64 * std::f64::consts::TAU;
+            (
+                centre.0 + radius * angle.cos(),
+                centre.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Units of the scale bar.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Units {
+    /// Meters or kilometers.
+    #[default]
+    Metric,
+
+    /// Miles.
+    Imperial,
+}
+
+/// Lines of the map's coordinate system.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Grid {
+    /// Parallels and meridians at a round number of degrees.
+    Graticule,
+
+    /// Lines of the projection at a round distance, the UTM grid when projected with UTM.
+    Projected,
+}
+
+/// A decoration, as named on the command line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Decoration {
+    ScaleBar(Units),
+    NorthArrow,
+    Grid(Grid),
+    Frame,
+    Markers,
+}
+
+impl FromStr for Decoration {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scale-bar" | "scale-bar-km" => Ok(Decoration::ScaleBar(Units::Metric)),
+            "scale-bar-mi" => Ok(Decoration::ScaleBar(Units::Imperial)),
+            "north-arrow" => Ok(Decoration::NorthArrow),
+            "graticule" => Ok(Decoration::Grid(Grid::Graticule)),
+            "grid" | "utm-grid" => Ok(Decoration::Grid(Grid::Projected)),
+            "frame" => Ok(Decoration::Frame),
+            "markers" => Ok(Decoration::Markers),
+            _ => Err(format!(
+                "Unknown decoration {:?}, expected \"scale-bar\", \"scale-bar-mi\", \
+                 \"north-arrow\", \"graticule\", \"grid\", \"frame\" or \"markers\".",
+                s
+            )),
+        }
+    }
+}
+
+/// A scale bar at the bottom left of the map, divided in four, labelled with its length.
+pub fn scale_bar(map: &Map, units: Units) -> (Layer, Vec<Path>) {
+    let (meters, label) = match units {
+        Units::Metric if map.scale_bar.meters >= 1000.0 => (
+            map.scale_bar.meters,
+            format!("{} km", text::number(map.scale_bar.meters / 1000.0)),
+        ),
+        Units::Metric => (
+            map.scale_bar.meters,
+            format!("{} m", text::number(map.scale_bar.meters)),
+        ),
+        Units::Imperial => {
+            let miles = projection::round_distance(map.width / 4.0 / METERS_PER_MILE);
+            (
+                miles * METERS_PER_MILE,
+                format!("{} mi", text::number(miles)),
+            )
+        }
+    };
+
+    let mm = map.units_per_mm;
+    let (left, _, _, bottom) = map.area();
+    let (x, y) = (left + PADDING_MM * mm, bottom - PADDING_MM * mm);
+    let length = meters * map.units_per_meter;
+
+    let mut lines = vec![vec![(x, y), (x + length, y)]];
+    for tick in 0..=4 {
+        let height = if tick % 4 == 0 { 2.0 } else { 1.0 } * mm;
+        let tick_x = x + length * tick as f64 / 4.0;
+        lines.push(vec![(tick_x, y), (tick_x, y - height)]);
+    }
+    let height = TEXT_HEIGHT_MM * mm;
+    lines.extend(text::strokes(
+        &label,
+        (x + length + 2.0 * mm, y - height),
+        height,
+    ));
+
+    (layer("scale bar"), to_paths(lines))
+}
+
+/// An arrow pointing to true north at the top right of the map, with an "N" above it.
+pub fn north_arrow(map: &Map) -> (Layer, Vec<Path>) {
+    let mm = map.units_per_mm;
+    let length = NORTH_ARROW_MM * mm;
+    let height = TEXT_HEIGHT_MM * mm;
+    let (_, top, right, _) = map.area();
+    let centre = (
+        right - PADDING_MM * mm - height,
+        top + PADDING_MM * mm + height + 1.0 * mm + length / 2.0,
+    );
+
+    // Grid north and true north differ away from the central meridian of transverse Mercator.
+    let (lat, lon) = map.centre;
+    let from = map.to_plot((lat, lon));
+    let to = map.to_plot((lat + 0.01, lon));
+    let norm = (to.0 - from.0).hypot(to.1 - from.1);
+    let (dx, dy) = if norm > 0.0 {
+        ((to.0 - from.0) / norm, (to.1 - from.1) / norm)
+    } else {
+        (0.0, -1.0)
+    };
+
+    let tip = (centre.0 + dx * length / 2.0, centre.1 + dy * length / 2.0);
+    let tail = (centre.0 - dx * length / 2.0, centre.1 - dy * length / 2.0);
+    let head = length / 4.0;
+    let side = |sign: f64| {
+        (
+            tip.0 - dx * head + sign * dy * head / 2.0,
+            tip.1 - dy * head - sign * dx * head / 2.0,
+        )
+    };
+
+    let mut lines = vec![vec![tail, tip], vec![side(1.0), tip, side(-1.0)]];
+    let top_of_text = tip.1.min(centre.1 - length / 2.0) - 1.0 * mm - height;
+    lines.extend(text::strokes(
+        "N",
+        (centre.0 - text::width("N", height) / 2.0, top_of_text),
+        height,
+    ));
+
+    (layer("north arrow"), to_paths(lines))
+}
+
+/// Parallels and meridians at a round number of degrees, clipped to the area of the map.
+pub fn graticule(map: &Map) -> (Layer, Vec<Path>) {
+    let area = map.area();
+    let (lat, lon) = (f64::from(map.centre.0), f64::from(map.centre.1));
+
+    // Estimate how many degrees the area spans, from the scale at the centre.
+    let from = map.to_plot(map.centre);
+    let to = map.to_plot(((lat + 0.01) as f32, (lon + 0.01) as f32));
+    let per_degree = (
+        ((to.0 - from.0) / 0.01).abs().max(f64::EPSILON),
+        ((to.1 - from.1) / 0.01).abs().max(f64::EPSILON),
+    );
+    let span_lon = (area.2 - area.0) / per_degree.0;
+    let span_lat = (area.3 - area.1) / per_degree.1;
+    let interval = projection::round_distance(span_lon.max(span_lat) / 4.0);
+    if interval <= 0.0 {
+        return (layer("graticule"), vec![]);
+    }
+
+    // Twice the span, to cover the corners when the centre isn't in the middle of the page.
+    let range = |centre: f64, span: f64, min: f64, max: f64| {
+        let start = ((centre - span).max(min) / interval).floor() as i64;
+        let end = ((centre + span).min(max) / interval).ceil() as i64;
+        let count = (end - start).clamp(0, MAX_GRID_LINES as i64);
+        (
+            start,
+            start + count,
+            (centre - span).max(min),
+            (centre + span).min(max),
+        )
+    };
+    let (lat_start, lat_end, lat_min, lat_max) = range(lat, span_lat, -90.0, 90.0);
+    let (lon_start, lon_end, lon_min, lon_max) = range(lon, span_lon, -180.0, 180.0);
+
+    let sample = |from: f64, to: f64| {
+        (0..=GRATICULE_SEGMENTS)
+            .map(move |step| from + (to - from) * step as f64 / GRATICULE_SEGMENTS as f64)
+    };
+    let mut lines = vec![];
+    for index in lat_start..=lat_end {
+        let parallel = index as f64 * interval;
+        let line: Vec<Point> = sample(lon_min, lon_max)
+            .map(|lon| map.to_plot((parallel as f32, lon as f32)))
+            .collect();
+        lines.extend(clip(&line, area));
+    }
+    for index in lon_start..=lon_end {
+        let meridian = index as f64 * interval;
+        let line: Vec<Point> = sample(lat_min, lat_max)
+            .map(|lat| map.to_plot((lat as f32, meridian as f32)))
+            .collect();
+        lines.extend(clip(&line, area));
+    }
+
+    (layer("graticule"), to_paths(lines))
+}
+
+/// Lines of constant easting and northing at a round distance, clipped to the area of the map.
+pub fn grid(map: &Map) -> (Layer, Vec<Path>) {
+    let area = map.area();
+    let top_left = map.plot_to_projected((area.0, area.1));
+    let bottom_right = map.plot_to_projected((area.2, area.3));
+    let interval = projection::round_distance((bottom_right.0 - top_left.0).abs() / 4.0);
+    if interval <= 0.0 {
+        return (layer("grid"), vec![]);
+    }
+
+    let indices = |from: f64, to: f64| {
+        let start = (from.min(to) / interval).ceil() as i64;
+        let end = (from.max(to) / interval).floor() as i64;
+        start..=end.min(start + MAX_GRID_LINES as i64)
+    };
+    let mut lines = vec![];
+    for index in indices(top_left.0, bottom_right.0) {
+        let easting = index as f64 * interval;
+        let line = [
+            map.projected_to_plot((easting, top_left.1)),
+            map.projected_to_plot((easting, bottom_right.1)),
+        ];
+        lines.extend(clip(&line, area));
+    }
+    for index in indices(bottom_right.1, top_left.1) {
+        let northing = index as f64 * interval;
+        let line = [
+            map.projected_to_plot((top_left.0, northing)),
+            map.projected_to_plot((bottom_right.0, northing)),
+        ];
+        lines.extend(clip(&line, area));
+    }
+
+    (layer("grid"), to_paths(lines))
+}
+
+/// A neat line around the area of the map.
+pub fn frame(map: &Map) -> (Layer, Vec<Path>) {
+    let (left, top, right, bottom) = map.area();
+    let line = vec![
+        (left, top),
+        (right, top),
+        (right, bottom),
+        (left, bottom),
+        (left, top),
+    ];
+
+    (layer("frame"), to_paths(vec![line]))
+}
+
+/// A triangle at the start of the first path, a square at the end of the last path and a circle
+/// at every waypoint inside the area of the map.
+pub fn markers(map: &Map) -> (Layer, Vec<Path>) {
+    let radius = MARKER_MM * map.units_per_mm / 2.0;
+    let paths = &map.plot.paths().paths;
+    let point =
+        |coordinate: &Coordinate<Absolute>| (f64::from(coordinate.x), f64::from(coordinate.y));
+
+    let mut lines = vec![];
+    if let Some(start) = paths.first().and_then(|path| path.first()) {
+        lines.push(polygon(
+            point(start),
+            radius,
+            3,
+            -std::f64::consts::FRAC_PI_2,
+        ));
+    }
+    if let Some(finish) = paths.last().and_then(|path| path.last()) {
+        lines.push(polygon(
+            point(finish),
+            radius,
+            4,
+            std::f64::consts::FRAC_PI_4,
+        ));
+    }
+
+    let (left, top, right, bottom) = map.area();
+    lines.extend(
+        map.waypoints
+            .iter()
+            .map(|(_, position)| map.to_plot(*position))
+            .filter(|(x, y)| (left..=right).contains(x) && (top..=bottom).contains(y))
+            .map(|centre| polygon(centre, radius, 12, 0.0)),
+    );
+
+    (layer("markers"), to_paths(lines))
+}
+
+pub(crate) fn layer(name: &str) -> Layer {
+    Layer {
+        name: Some(name.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Add the decorations to the plot of `map`, each as a `Layer`.
+pub fn decorate(map: Map, decorations: &[Decoration]) -> Result<Plot, Error> {
+    let layers: Vec<(Layer, Vec<Path>)> = decorations
+        .iter()
+        .map(|decoration| match decoration {
+            Decoration::ScaleBar(units) => scale_bar(&map, *units),
+            Decoration::NorthArrow => north_arrow(&map),
+            Decoration::Grid(Grid::Graticule) => graticule(&map),
+            Decoration::Grid(Grid::Projected) => grid(&map),
+            Decoration::Frame => frame(&map),
+            Decoration::Markers => markers(&map),
+        })
+        .collect();
+
+    let mut paths = map.plot.into_paths();
+    for (layer, layer_paths) in layers {
+        paths.add_layer(layer, layer_paths);
+    }
+
+    Ok(Plot::new(paths))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{self, Feature};
+
+    #[test]
+    fn clip_lines() {
+        let area = (0.0, 0.0, 10.0, 10.0);
+        assert_eq!(
+            clip(&[(-5.0, 5.0), (5.0, 5.0), (15.0, 5.0)], area),
+            vec![vec![(0.0, 5.0), (5.0, 5.0), (10.0, 5.0)]]
+        );
+        assert_eq!(
+            clip(&[(5.0, 5.0), (5.0, 15.0), (8.0, 5.0)], area),
+            vec![vec![(5.0, 5.0), (5.0, 10.0)], vec![(6.5, 10.0), (8.0, 5.0)]]
+        );
+        assert!(clip(&[(20.0, 20.0), (30.0, 30.0)], area).is_empty());
+    }
+
+    #[test]
+    fn decorate_map() {
+        let features = vec![
+            Feature {
+                lines: vec![vec![(47.0, 11.0), (47.01, 11.02)]],
+                ..Default::default()
+            },
+            Feature {
+                points: vec![(47.005, 11.01)],
+                ..Default::default()
+            },
+        ];
+        let options = geo::Options {
+            margin: 10.0,
+            ..Default::default()
+        };
+        let map = geo::project(features, &options).unwrap();
+        let (left, top, right, bottom) = map.area();
+        let plot = decorate(
+            map,
+            &[
+                Decoration::Frame,
+                Decoration::ScaleBar(Units::Metric),
+                Decoration::NorthArrow,
+                Decoration::Grid(Grid::Graticule),
+                Decoration::Grid(Grid::Projected),
+                Decoration::Markers,
+            ],
+        )
+        .unwrap();
+
+        let paths = plot.paths();
+        let names: Vec<_> = paths
+            .layers
+            .iter()
+            .filter_map(|layer| layer.name.as_deref())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "frame",
+                "scale bar",
+                "north arrow",
+                "graticule",
+                "grid",
+                "markers"
+            ]
+        );
+
+        // Everything but the markers on the ends of the track stays inside the frame.
+        for (index, path) in paths.paths.iter().enumerate() {
+            let layer = paths
+                .layer_of(index)
+                .and_then(|layer| layer.name.as_deref());
+            if layer == Some("markers") {
+                continue;
+            }
+            for point in path {
+                assert!(
+                    (left as i32..=right as i32).contains(&point.x)
+                        && (top as i32..=bottom as i32).contains(&point.y),
+                    "{:?} of {:?} is outside the frame",
+                    point,
+                    layer
+                );
+            }
+        }
+
+        // A triangle, a square and a circle.
+        let markers = paths.layers.last().unwrap();
+        let lengths: Vec<_> = markers
+            .paths
+            .iter()
+            .map(|index| paths.paths[*index].len())
+            .collect();
+        assert_eq!(lengths, [4, 5, 13]);
+    }
+}
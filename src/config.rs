@@ -0,0 +1,588 @@
+//! Settings of plotters, shared by every tool, read from a TOML file.
+//!
+//! The file describes named machines. All settings except `backend` are optional:
+//!
+//! ```toml
+//! default = "axidraw"
+//!
+//! [machines.axidraw]
+//! backend = "ebb"
+//! serial_number = "ABC123"
+//! travel = [30300, 21000]
+//! move_duration = 1000
+//!
+//! [machines.axidraw.pen]
+//! up_position = 16000
+//! down_position = 12000
+//! down_delay = 150
+//!
+//! [machines.dxy]
+//! backend = "roland-dxy"
+//! device = "/dev/ttyUSB0"
+//! timeout = 10.0
+//!
+//! [machines.dxy.serial]
+//! baud_rate = 9600
+//! data_bits = 7
+//! parity = "even"
+//! stop_bits = 1
+//!
+//! [machines.grbl]
+//! backend = "grbl"
+//! steps_per_mm = 10.0
+//!
+//! [machines.grbl.gcode]
+//! feed_rate = 2000.0
+//! pen_up = "G0 Z5"
+//! pen_down = "G1 Z0 F500"
+//! ```
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serial_core::{BaudRate, CharSize, FlowControl, PortSettings, StopBits};
+
+use crate::discovery::{self, Device};
+use crate::plotter::Plotter;
+use crate::preview::{
+    Simulation, EBB_PAPER, EBB_STEPS_PER_INCH, GRBL_PLOTTING_AREA_MM, ROLAND_DXY_PLOTTING_AREA,
+    ROLAND_DXY_SPEED, ROLAND_DXY_UNITS_PER_INCH,
+};
+use crate::{
+    gcode, roland_dxy, Backend, Driver, Error, PenSettings, Plot, DEFAULT_MOVE_DURATION,
+    DEFAULT_TIMEOUT,
+};
+
+/// Parity of a serial port.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Parity {
+    None,
+    Odd,
+    Even,
+}
+
+/// Flow control of a serial port.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Flow {
+    None,
+    Software,
+    Hardware,
+}
+
+/// Settings of the serial port of HP-GL and GRBL plotters. The EBB is an USB device and ignores
+/// these.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct SerialSettings {
+    pub baud_rate: usize,
+    pub data_bits: u8,
+    pub parity: Parity,
+    pub stop_bits: u8,
+    pub flow_control: Flow,
+}
+
+impl Default for SerialSettings {
+    /// The settings of `roland_dxy::default_port_settings()`: 9600 baud, 7 data bits, even parity
+    /// and 1 stop bit.
+    fn default() -> Self {
+        Self {
+            baud_rate: 9600,
+            data_bits: 7,
+            parity: Parity::Even,
+            stop_bits: 1,
+            flow_control: Flow::None,
+        }
+    }
+}
+
+impl SerialSettings {
+    /// The settings of `gcode::default_port_settings()`: 115200 baud, 8 data bits, no parity and
+    /// 1 stop bit.
+    pub fn grbl() -> Self {
+        Self {
+            baud_rate: 115200,
+            data_bits: 8,
+            parity: Parity::None,
+            ..Default::default()
+        }
+    }
+
+    /// Convert into the settings used to configure a serial port.
+    pub fn port_settings(&self) -> Result<PortSettings, Error> {
+        let char_size = match self.data_bits {
+            5 => CharSize::Bits5,
+            6 => CharSize::Bits6,
+            7 => CharSize::Bits7,
+            8 => CharSize::Bits8,
+            other => {
+                return Err(Error::ConfigError(format!(
+                    "Invalid number of data bits: {}.",
+                    other
+                )))
+            }
+        };
+
+        let stop_bits = match self.stop_bits {
+            1 => StopBits::Stop1,
+            2 => StopBits::Stop2,
+            other => {
+                return Err(Error::ConfigError(format!(
+                    "Invalid number of stop bits: {}.",
+                    other
+                )))
+            }
+        };
+
+        Ok(PortSettings {
+            baud_rate: BaudRate::from_speed(self.baud_rate),
+            char_size,
+            parity: match self.parity {
+                Parity::None => serial_core::Parity::ParityNone,
+                Parity::Odd => serial_core::Parity::ParityOdd,
+                Parity::Even => serial_core::Parity::ParityEven,
+            },
+            stop_bits,
+            flow_control: match self.flow_control {
+                Flow::None => FlowControl::FlowNone,
+                Flow::Software => FlowControl::FlowSoftware,
+                Flow::Hardware => FlowControl::FlowHardware,
+            },
+        })
+    }
+}
+
+/// The `serial` section of a machine. Settings that are omitted default to those of the backend.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct SerialSection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baud_rate: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_bits: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parity: Option<Parity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_bits: Option<u8>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flow_control: Option<Flow>,
+}
+
+impl SerialSection {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The settings of this section, with the omitted ones taken from `defaults`.
+    pub fn merge(&self, defaults: SerialSettings) -> SerialSettings {
+        SerialSettings {
+            baud_rate: self.baud_rate.unwrap_or(defaults.baud_rate),
+            data_bits: self.data_bits.unwrap_or(defaults.data_bits),
+            parity: self.parity.unwrap_or(defaults.parity),
+            stop_bits: self.stop_bits.unwrap_or(defaults.stop_bits),
+            flow_control: self.flow_control.unwrap_or(defaults.flow_control),
+        }
+    }
+}
+
+/// Settings of a single plotter.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Machine {
+    pub backend: Backend,
+
+    /// Path to the serial device. When omitted, the plotter is discovered.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<PathBuf>,
+
+    /// Serial number of the USB device, used to discover the plotter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub serial_number: Option<String>,
+
+    /// Nickname of the EBB, used to discover the plotter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+
+    /// Seconds to wait for the plotter to respond.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<f64>,
+
+    /// Settings of the serial port. Omitted settings are taken from `SerialSettings::grbl()` for
+    /// GRBL machines and from `SerialSettings::default()` for HP-GL plotters.
+    #[serde(default, skip_serializing_if = "SerialSection::is_empty")]
+    pub serial: SerialSection,
+
+    /// Width and height of the area the pen can reach, in the unit of the device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub travel: Option<(i32, i32)>,
+
+    /// Resolution of the plotter, in the unit of the device per millimeter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steps_per_mm: Option<f64>,
+
+    /// Duration of every `SM` command of the EBB in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub move_duration: Option<u32>,
+
+    /// Number of commands the EBB driver sends ahead of their responses. Defaults to one more
+    /// than the depth of the motion FIFO. 1 disables pipelining.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline_depth: Option<usize>,
+
+    /// Speed of HP-GL plotters in plotter units per millisecond, used to estimate plotting time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+
+    /// Configuration of the pen servo of the EBB.
+    #[serde(default)]
+    pub pen: PenSettings,
+
+    /// The G-code GRBL machines use to plot. Its `units_per_mm` is replaced by `steps_per_mm`.
+    #[serde(default)]
+    pub gcode: gcode::Options,
+}
+
+impl Machine {
+    /// Create a machine that uses the default settings of `backend`.
+    pub fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            device: None,
+            serial_number: None,
+            nickname: None,
+            timeout: None,
+            serial: SerialSection::default(),
+            travel: None,
+            steps_per_mm: None,
+            move_duration: None,
+            pipeline_depth: None,
+            speed: None,
+            pen: PenSettings::default(),
+            gcode: gcode::Options::default(),
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+            .map(Duration::from_secs_f64)
+            .unwrap_or(DEFAULT_TIMEOUT)
+    }
+
+    pub fn travel(&self) -> (i32, i32) {
+        self.travel.unwrap_or(match self.backend {
+            Backend::Ebb => (EBB_PAPER.0 as i32, EBB_PAPER.1 as i32),
+            Backend::RolandDxy => (
+                ROLAND_DXY_PLOTTING_AREA.0 as i32,
+                ROLAND_DXY_PLOTTING_AREA.1 as i32,
+            ),
+            Backend::Grbl => (
+                (GRBL_PLOTTING_AREA_MM.0 * self.steps_per_mm()) as i32,
+                (GRBL_PLOTTING_AREA_MM.1 * self.steps_per_mm()) as i32,
+            ),
+        })
+    }
+
+    pub fn steps_per_mm(&self) -> f64 {
+        self.steps_per_mm.unwrap_or(match self.backend {
+            Backend::Ebb => EBB_STEPS_PER_INCH / 25.4,
+            Backend::RolandDxy => ROLAND_DXY_UNITS_PER_INCH / 25.4,
+            Backend::Grbl => self.gcode.units_per_mm,
+        })
+    }
+
+    /// The serial port settings, which default to those of the backend.
+    pub fn serial_settings(&self) -> SerialSettings {
+        self.serial.merge(match self.backend {
+            Backend::Grbl => SerialSettings::grbl(),
+            _ => SerialSettings::default(),
+        })
+    }
+
+    /// The G-code options of a GRBL machine, in the units of `steps_per_mm`.
+    pub fn gcode_options(&self) -> gcode::Options {
+        gcode::Options {
+            units_per_mm: self.steps_per_mm(),
+            ..self.gcode.clone()
+        }
+    }
+
+    pub fn move_duration(&self) -> u32 {
+        self.move_duration.unwrap_or(DEFAULT_MOVE_DURATION)
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed.unwrap_or(ROLAND_DXY_SPEED)
+    }
+
+    /// Whether a discovered plotter matches the backend, serial number and nickname.
+    pub fn matches(&self, device: &Device) -> bool {
+        self.backend == device.backend
+            && self.device.as_ref().is_none_or(|path| *path == device.path)
+            && self
+                .serial_number
+                .as_ref()
+                .is_none_or(|serial| device.serial_number.as_ref() == Some(serial))
+            && self
+                .nickname
+                .as_ref()
+                .is_none_or(|nickname| device.nickname.as_ref() == Some(nickname))
+    }
+
+    /// The path of the serial device of this machine: `device` when set, otherwise the first
+    /// plotter discovered that matches.
+    pub fn find_device(&self) -> Result<PathBuf, Error> {
+        if let Some(device) = &self.device {
+            return Ok(device.clone());
+        }
+
+        discovery::discover()?
+            .into_iter()
+            .find(|device| self.matches(device))
+            .map(|device| device.path)
+            .ok_or_else(|| Error::ProbeError("No matching plotter found.".to_string()))
+    }
+
+    /// Open the EBB of this machine and configure its pen and move duration.
+    pub fn open_ebb(&self) -> Result<Driver, Error> {
+        if self.backend != Backend::Ebb {
+            return Err(Error::UnsupportedError(format!(
+                "{:?} is not an EBB.",
+                self.backend
+            )));
+        }
+
+        let mut driver = Driver::open_with_timeout(&self.find_device()?, self.timeout())?;
+        driver.set_move_duration(self.move_duration());
+        if let Some(depth) = self.pipeline_depth {
+            driver.set_pipeline_depth(depth);
+        }
+        driver.set_pen_settings(self.pen)?;
+
+        Ok(driver)
+    }
+
+    /// Open the plotter of this machine.
+    pub fn open(&self) -> Result<Box<dyn Plotter>, Error> {
+        match self.backend {
+            Backend::Ebb => Ok(Box::new(self.open_ebb()?)),
+            Backend::RolandDxy => {
+                let mut driver = roland_dxy::Driver::open_with_timeout(
+                    &self.find_device()?,
+                    self.serial_settings().port_settings()?,
+                    self.timeout(),
+                )?;
+                driver.set_travel(self.travel());
+                Ok(Box::new(driver))
+            }
+            Backend::Grbl => Ok(Box::new(gcode::Driver::open_with_timeout(
+                &self.find_device()?,
+                self.serial_settings().port_settings()?,
+                self.timeout(),
+                self.gcode_options(),
+            )?)),
+        }
+    }
+
+    /// Simulate the commands that the driver of this machine sends to plot the given `Plot`.
+    pub fn simulate(&self, plot: &Plot) -> Result<Simulation, Error> {
+        let mut simulation = match self.backend {
+            Backend::Ebb => Simulation::from_ebb_commands(&crate::plot_to_commands_with(
+                plot,
+                &self.pen,
+                self.move_duration(),
+            )?),
+            Backend::RolandDxy => {
+                let mut simulation = Simulation::from_hpgl_commands(
+                    &roland_dxy::plot_to_commands_with(plot, self.travel())?,
+                );
+                // The simulation assumes the default speed.
+                for movement in simulation.moves.iter_mut() {
+                    movement.duration *= ROLAND_DXY_SPEED / self.speed();
+                }
+                simulation
+            }
+            Backend::Grbl => Simulation::grbl(plot, &self.gcode_options())?,
+        };
+
+        let (width, height) = self.travel();
+        simulation.outline = (width as f64, height as f64);
+
+        Ok(simulation)
+    }
+}
+
+/// The contents of a configuration file.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Name of the machine to use when none is selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+
+    #[serde(default)]
+    pub machines: BTreeMap<String, Machine>,
+}
+
+impl Config {
+    /// Location of the configuration file: `$XDG_CONFIG_HOME/loempia/config.toml`, which defaults
+    /// to `~/.config/loempia/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+        Some(config_home.join("loempia").join("config.toml"))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        toml::from_str(text).map_err(|err| Error::ConfigError(err.to_string()))
+    }
+
+    /// Read the configuration file at `path`.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?).map_err(|err| {
+            Error::ConfigError(format!("Failed to read {}: {}", path.display(), err))
+        })
+    }
+
+    /// Read the configuration file at the default location. An empty configuration is returned
+    /// when the file doesn't exist.
+    pub fn read_default() -> Result<Self, Error> {
+        match Self::default_path() {
+            Some(path) if path.exists() => Self::read(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Write the configuration to `path`, creating its directory when needed.
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        let text =
+            toml::to_string_pretty(self).map_err(|err| Error::ConfigError(err.to_string()))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, text)?;
+
+        Ok(())
+    }
+
+    /// The machine with the given name, or the default machine when `name` is `None`.
+    pub fn machine(&self, name: Option<&str>) -> Result<Option<&Machine>, Error> {
+        let Some(name) = name.or(self.default.as_deref()) else {
+            return Ok(None);
+        };
+
+        self.machines
+            .get(name)
+            .map(Some)
+            .ok_or_else(|| Error::ConfigError(format!("Unknown machine {:?}.", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            r#"
+            default = "dxy"
+
+            [machines.axidraw]
+            backend = "ebb"
+            serial_number = "ABC123"
+            travel = [1000, 2000]
+
+            [machines.axidraw.pen]
+            down_delay = 150
+
+            [machines.dxy]
+            backend = "roland-dxy"
+            device = "/dev/ttyUSB0"
+
+            [machines.dxy.serial]
+            baud_rate = 4800
+            parity = "none"
+
+            [machines.grbl]
+            backend = "grbl"
+            steps_per_mm = 10.0
+
+            [machines.grbl.gcode]
+            pen_up = "G0 Z5"
+
+            [machines.slow-grbl]
+            backend = "grbl"
+
+            [machines.slow-grbl.serial]
+            baud_rate = 9600
+            data_bits = 7
+            parity = "even"
+
+            [machines.fast-grbl]
+            backend = "grbl"
+
+            [machines.fast-grbl.serial]
+            baud_rate = 250000
+            "#,
+        )
+        .unwrap();
+
+        let dxy = config.machine(None).unwrap().unwrap();
+        assert_eq!(dxy.backend, Backend::RolandDxy);
+        assert_eq!(dxy.device, Some(PathBuf::from("/dev/ttyUSB0")));
+        assert_eq!(dxy.serial_settings().baud_rate, 4800);
+        assert_eq!(dxy.serial_settings().data_bits, 7);
+        assert_eq!(dxy.timeout(), DEFAULT_TIMEOUT);
+
+        let axidraw = config.machine(Some("axidraw")).unwrap().unwrap();
+        assert_eq!(axidraw.travel(), (1000, 2000));
+        assert_eq!(axidraw.pen.down_delay, 150);
+        assert_eq!(axidraw.pen.up_position, PenSettings::default().up_position);
+        assert_eq!(axidraw.move_duration(), DEFAULT_MOVE_DURATION);
+
+        let grbl = config.machine(Some("grbl")).unwrap().unwrap();
+        assert_eq!(grbl.serial_settings(), SerialSettings::grbl());
+        assert_eq!(grbl.travel(), (4200, 2970));
+        assert_eq!(grbl.gcode_options().units_per_mm, 10.0);
+        assert_eq!(grbl.gcode_options().pen_down, "M3 S1000");
+
+        // Settings that equal the defaults of another backend are kept.
+        let slow_grbl = config.machine(Some("slow-grbl")).unwrap().unwrap();
+        assert_eq!(slow_grbl.serial_settings(), SerialSettings::default());
+
+        // Omitted settings are taken from the backend.
+        let fast_grbl = config.machine(Some("fast-grbl")).unwrap().unwrap();
+        assert_eq!(
+            fast_grbl.serial_settings(),
+            SerialSettings {
+                baud_rate: 250000,
+                ..SerialSettings::grbl()
+            }
+        );
+
+        assert!(config.machine(Some("unknown")).is_err());
+        assert_eq!(
+            Config::parse(&toml::to_string(&config).unwrap()).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn default_serial_settings_match_roland_dxy() {
+        assert_eq!(
+            SerialSettings::default().port_settings().unwrap(),
+            roland_dxy::default_port_settings()
+        );
+        assert_eq!(
+            SerialSettings::grbl().port_settings().unwrap(),
+            gcode::default_port_settings()
+        );
+    }
+}
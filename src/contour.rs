@@ -0,0 +1,319 @@
+//! Contour lines of a `dem::Grid`, traced by marching squares.
+//!
+//! The lines are smoothed, projected onto a `geo::Map` and clipped to its area, so they line up
+//! with the tracks of the map. Every `index`th contour is an index contour, in a layer of its own.
+use std::collections::HashMap;
+
+use crate::cartography;
+use crate::dem::Grid;
+use crate::geo::Map;
+use crate::text::Point;
+use crate::{Error, Layer, Path};
+
+/// How contour lines are traced.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    /// Difference in elevation between contour lines, in meters.
+    pub interval: f64,
+
+    /// Every how many contour lines an index contour is drawn.
+    pub index: usize,
+
+    /// Number of rounds of Chaikin's corner cutting.
+    pub smoothing: usize,
+}
+
+impl Default for Options {
+    /// Contour lines every 10 m, an index contour every 50 m.
+    fn default() -> Self {
+        Self {
+            interval: 10.0,
+            index: 5,
+            smoothing: 2,
+        }
+    }
+}
+
+/// Edges of the grid, between two neighbouring cells. Horizontal edges are even, vertical ones
+/// odd.
+type Edge = usize;
+
+/// Trace the contour lines of `level`, in cells from the centre of the top left cell. Squares with
+/// a cell without data are skipped.
+pub fn trace(grid: &Grid, level: f64) -> Vec<Vec<Point>> {
+    let horizontal = |column: usize, row: usize| (row * grid.columns + column) * 2;
+    let vertical = |column: usize, row: usize| (row * grid.columns + column) * 2 + 1;
+
+    // Segments between two edges, within a square of four cells.
+    let mut segments: Vec<(Edge, Edge)> = vec![];
+    for row in 0..grid.rows.saturating_sub(1) {
+        for column in 0..grid.columns.saturating_sub(1) {
+            let (Some(top_left), Some(top_right), Some(bottom_right), Some(bottom_left)) = (
+                grid.value(column, row),
+                grid.value(column + 1, row),
+                grid.value(column + 1, row + 1),
+                grid.value(column, row + 1),
+            ) else {
+                continue;
+            };
+
+            // Edges clockwise from the top, with the corners at their ends.
+            let edges = [
+                (horizontal(column, row), top_left, top_right),
+                (vertical(column + 1, row), top_right, bottom_right),
+                (horizontal(column, row + 1), bottom_left, bottom_right),
+                (vertical(column, row), top_left, bottom_left),
+            ];
+            let crossed: Vec<Edge> = edges
+                .iter()
+                .filter(|(_, a, b)| (*a >= level) != (*b >= level))
+                .map(|(edge, _, _)| *edge)
+                .collect();
+
+            match crossed.as_slice() {
+                [a, b] => segments.push((*a, *b)),
+                [top, right, bottom, left] => {
+                    // A saddle: the centre decides which corners are connected.
+                    let centre = (top_left + top_right + bottom_right + bottom_left) / 4.0;
+                    if (top_left >= level) != (centre >= level) {
+                        segments.push((*top, *left));
+                        segments.push((*right, *bottom));
+                    } else {
+                        segments.push((*top, *right));
+                        segments.push((*bottom, *left));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Where the contour crosses an edge, interpolated between its cells.
+    let point = |edge: Edge| -> Point {
+        let cell = edge / 2;
+        let (column, row) = (cell % grid.columns, cell / grid.columns);
+        let (next_column, next_row) = if edge.is_multiple_of(2) {
+            (column + 1, row)
+        } else {
+            (column, row + 1)
+        };
+        let a = grid.value(column, row).unwrap_or(level);
+        let b = grid.value(next_column, next_row).unwrap_or(level);
+        let t = if a == b { 0.5 } else { (level - a) / (b - a) };
+        (
+            column as f64 + t * (next_column - column) as f64,
+            row as f64 + t * (next_row - row) as f64,
+        )
+    };
+
+    join(&segments)
+        .into_iter()
+        .map(|edges| edges.into_iter().map(point).collect())
+        .collect()
+}
+
+/// Join segments that share an edge into lines. Closed lines end at their first edge.
+fn join(segments: &[(Edge, Edge)]) -> Vec<Vec<Edge>> {
+    let mut at: HashMap<Edge, Vec<usize>> = HashMap::new();
+    for (index, (a, b)) in segments.iter().enumerate() {
+        at.entry(*a).or_default().push(index);
+        at.entry(*b).or_default().push(index);
+    }
+
+    let mut used = vec![false; segments.len()];
+    // The other segment at `edge` that isn't used yet, and the edge at its other end.
+    let next = |edge: Edge, used: &mut Vec<bool>| {
+        let index = *at[&edge].iter().find(|index| !used[**index])?;
+        used[index] = true;
+        let (a, b) = segments[index];
+        Some(if a == edge { b } else { a })
+    };
+
+    let mut lines = vec![];
+    for index in 0..segments.len() {
+        if used[index] {
+            continue;
+        }
+        used[index] = true;
+        let (start, end) = segments[index];
+        let mut line = vec![start, end];
+        while let Some(edge) = next(*line.last().unwrap(), &mut used) {
+            line.push(edge);
+        }
+        if line.first() != line.last() {
+            let mut before = vec![];
+            while let Some(edge) = next(*before.last().unwrap_or(&start), &mut used) {
+                before.push(edge);
+            }
+            before.reverse();
+            before.extend(line);
+            line = before;
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Smooth a line by cutting its corners, a quarter from each end of every segment. Open lines
+/// keep their ends.
+pub fn smooth(line: &[Point], rounds: usize) -> Vec<Point> {
+    let mut line = line.to_vec();
+    for _ in 0..rounds {
+        if line.len() < 3 {
+            break;
+        }
+        let closed = line.first() == line.last();
+        let mut smoothed = if closed { vec![] } else { vec![line[0]] };
+        for pair in line.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            smoothed.push((0.75 * x0 + 0.25 * x1, 0.75 * y0 + 0.25 * y1));
+            smoothed.push((0.25 * x0 + 0.75 * x1, 0.25 * y0 + 0.75 * y1));
+        }
+        if closed {
+            smoothed.push(smoothed[0]);
+        } else {
+            smoothed.push(line[line.len() - 1]);
+        }
+        line = smoothed;
+    }
+    line
+}
+
+/// Trace the contour lines of `grid`, projected onto `map` and clipped to its area. Returns a
+/// layer for the contours and one for the index contours. Fails when the interval isn't positive.
+pub fn contours(
+    grid: &Grid,
+    map: &Map,
+    options: &Options,
+) -> Result<Vec<(Layer, Vec<Path>)>, Error> {
+    if !(options.interval > 0.0 && options.interval.is_finite()) {
+        return Err(Error::DemError(format!(
+            "Invalid contour interval {}, expected more than 0 m.",
+            options.interval
+        )));
+    }
+
+    let mut contours = vec![];
+    let mut index_contours = vec![];
+    if let Some((min, max)) = grid.range() {
+        let first = (min / options.interval).ceil() as i64;
+        let last = (max / options.interval).floor() as i64;
+        for step in first..=last {
+            let level = step as f64 * options.interval;
+            let lines = if step % options.index.max(1) as i64 == 0 {
+                &mut index_contours
+            } else {
+                &mut contours
+            };
+            for line in trace(grid, level) {
+                let projected: Vec<Point> = smooth(&line, options.smoothing)
+                    .into_iter()
+                    .map(|point| {
+                        let (x, y) = grid.position(point);
+                        if grid.projected {
+                            map.projected_to_plot((x, y))
+                        } else {
+                            map.to_plot((y as f32, x as f32))
+                        }
+                    })
+                    .collect();
+                lines.extend(cartography::clip(&projected, map.area()));
+            }
+        }
+    }
+
+    Ok(vec![
+        (
+            cartography::layer("contours"),
+            cartography::to_paths(contours),
+        ),
+        (
+            cartography::layer("index contours"),
+            cartography::to_paths(index_contours),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dem;
+    use crate::geo::{self, Feature};
+
+    /// A cone 95 m high in the middle of a grid of 11 x 11 cells.
+    fn cone() -> Grid {
+        let mut text =
+            "ncols 11\nnrows 11\nxllcorner 11.0\nyllcorner 47.0\ncellsize 0.001\n".to_string();
+        for row in 0..11 {
+            for column in 0..11 {
+                let distance = ((column as f64 - 5.0).powi(2) + (row as f64 - 5.0).powi(2)).sqrt();
+                text += &format!("{} ", (95.0 - distance * 20.0).max(0.0));
+            }
+            text += "\n";
+        }
+        dem::parse(&text).unwrap()
+    }
+
+    #[test]
+    fn trace_rings() {
+        let grid = cone();
+        let lines = trace(&grid, 50.0);
+        assert_eq!(lines.len(), 1);
+        let ring = &lines[0];
+        assert_eq!(ring.first(), ring.last());
+        for (x, y) in ring {
+            let radius = ((x - 5.0).powi(2) + (y - 5.0).powi(2)).sqrt();
+            assert!((2.0..=2.6).contains(&radius), "{}", radius);
+        }
+
+        // Squares with a cell without data are skipped.
+        let mut grid = grid;
+        grid.values[5 * 11 + 5] = None;
+        assert!(trace(&grid, 90.0).is_empty());
+    }
+
+    #[test]
+    fn smooth_lines() {
+        let line = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0)];
+        assert_eq!(
+            smooth(&line, 1),
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (3.0, 0.0),
+                (4.0, 1.0),
+                (4.0, 3.0),
+                (4.0, 4.0)
+            ]
+        );
+        let square = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0), (0.0, 0.0)];
+        let smoothed = smooth(&square, 1);
+        assert_eq!(smoothed.len(), 9);
+        assert_eq!(smoothed.first(), smoothed.last());
+    }
+
+    #[test]
+    fn project_contours() {
+        let features = vec![Feature {
+            lines: vec![vec![(46.99, 10.99), (47.02, 11.02)]],
+            ..Default::default()
+        }];
+        let map = geo::project(features, &geo::Options::default()).unwrap();
+        let layers = contours(&cone(), &map, &Options::default()).unwrap();
+
+        assert_eq!(layers[0].0.name.as_deref(), Some("contours"));
+        assert_eq!(layers[1].0.name.as_deref(), Some("index contours"));
+        // 10, 20, 30, 40, 60, 70, 80 and 90 m, and 50 m as index contour.
+        assert_eq!(layers[0].1.len(), 8);
+        assert_eq!(layers[1].1.len(), 1);
+
+        for interval in [0.0, -10.0, f64::NAN] {
+            let options = Options {
+                interval,
+                ..Default::default()
+            };
+            assert!(contours(&cone(), &map, &options).is_err());
+        }
+    }
+}
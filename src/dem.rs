@@ -0,0 +1,165 @@
+//! Digital elevation models in the ESRI ASCII grid format, like:
+//!
+//! ```text
+//! ncols 3
+//! nrows 2
+//! xllcorner 11.0
+//! yllcorner 47.0
+//! cellsize 0.001
+//! NODATA_value -9999
+//! 810 820 830
+//! 800 -9999 820
+//! ```
+//!
+//! Rows go from north to south. Coordinates are longitude and latitude, unless they are out of
+//! their range: then they are projected coordinates in meters, in the projection of the map.
+use std::collections::BTreeMap;
+use std::fs;
+use std::path;
+
+use crate::Error;
+
+/// Elevations on a regular grid.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid {
+    pub columns: usize,
+    pub rows: usize,
+
+    /// Coordinates of the centre of the top left cell.
+    pub origin: (f64, f64),
+
+    /// Size of a cell, in degrees or meters.
+    pub cell_size: f64,
+
+    /// Elevation in meters of every cell, row by row. `None` where there is no data.
+    pub values: Vec<Option<f64>>,
+
+    /// The coordinates are projected, instead of longitude and latitude.
+    pub projected: bool,
+}
+
+impl Grid {
+    /// Elevation of a cell, `None` outside of the grid or where there is no data.
+    pub fn value(&self, column: usize, row: usize) -> Option<f64> {
+        if column < self.columns && row < self.rows {
+            self.values[row * self.columns + column]
+        } else {
+            None
+        }
+    }
+
+    /// Coordinates of a position on the grid, in cells from the centre of the top left cell.
+    pub fn position(&self, (column, row): (f64, f64)) -> (f64, f64) {
+        (
+            self.origin.0 + column * self.cell_size,
+            self.origin.1 - row * self.cell_size,
+        )
+    }
+
+    /// The lowest and highest elevation, `None` without data.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.values.iter().flatten().fold(None, |range, value| {
+            let (min, max) = range.unwrap_or((*value, *value));
+            Some((min.min(*value), max.max(*value)))
+        })
+    }
+}
+
+/// Parse a DEM in the ESRI ASCII grid format.
+pub fn parse(text: &str) -> Result<Grid, Error> {
+    let mut words = text.split_whitespace().peekable();
+    let mut header = BTreeMap::new();
+    while let Some(word) = words.next_if(|word| word.starts_with(|c: char| c.is_alphabetic())) {
+        let value = words
+            .next()
+            .and_then(|value| value.parse::<f64>().ok())
+            .ok_or_else(|| Error::DemError(format!("Invalid value of {:?}.", word)))?;
+        header.insert(word.to_lowercase(), value);
+    }
+    let field = |name: &str| {
+        header
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::DemError(format!("Missing {:?} in the header.", name)))
+    };
+
+    let columns = field("ncols")? as usize;
+    let rows = field("nrows")? as usize;
+    let cell_size = field("cellsize")?;
+    // Corners are of the bottom left cell, move to the centre of the top left cell.
+    let left = match header.get("xllcenter") {
+        Some(x) => *x,
+        None => field("xllcorner")? + cell_size / 2.0,
+    };
+    let bottom = match header.get("yllcenter") {
+        Some(y) => *y,
+        None => field("yllcorner")? + cell_size / 2.0,
+    };
+    let top = bottom + (rows.max(1) - 1) as f64 * cell_size;
+    let nodata = header.get("nodata_value").copied();
+
+    let values = words
+        .map(|word| {
+            let value: f64 = word
+                .parse()
+                .map_err(|_| Error::DemError(format!("Invalid elevation {:?}.", word)))?;
+            Ok((Some(value) != nodata).then_some(value))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    if values.len() != columns * rows {
+        return Err(Error::DemError(format!(
+            "Expected {} x {} elevations, found {}.",
+            columns,
+            rows,
+            values.len()
+        )));
+    }
+
+    let right = left + columns as f64 * cell_size;
+    Ok(Grid {
+        columns,
+        rows,
+        origin: (left, top),
+        cell_size,
+        values,
+        projected: !(-180.0..=180.0).contains(&left)
+            || !(-180.0..=180.0).contains(&right)
+            || !(-90.0..=90.0).contains(&bottom)
+            || !(-90.0..=90.0).contains(&top),
+    })
+}
+
+/// Read the DEM in the ESRI ASCII grid file at the given location.
+pub fn read(path: &path::Path) -> Result<Grid, Error> {
+    parse(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ascii_grid() {
+        let grid = parse(
+            "ncols 3\nnrows 2\nxllcorner 11.0\nyllcorner 47.0\ncellsize 0.5\n\
+             NODATA_value -9999\n810 820 830\n800 -9999 820\n",
+        )
+        .unwrap();
+
+        assert_eq!((grid.columns, grid.rows), (3, 2));
+        assert_eq!(grid.origin, (11.25, 47.75));
+        assert_eq!(grid.value(2, 0), Some(830.0));
+        assert_eq!(grid.value(1, 1), None);
+        assert_eq!(grid.position((2.0, 1.0)), (12.25, 47.25));
+        assert_eq!(grid.range(), Some((800.0, 830.0)));
+        assert!(!grid.projected);
+
+        let grid =
+            parse("ncols 2\nnrows 1\nxllcenter 500000\nyllcenter 5200000\ncellsize 25\n1 2\n")
+                .unwrap();
+        assert_eq!(grid.origin, (500000.0, 5200000.0));
+        assert!(grid.projected);
+
+        assert!(parse("ncols 2\nnrows 2\nxllcorner 0\nyllcorner 0\ncellsize 1\n1 2 3\n").is_err());
+    }
+}
@@ -0,0 +1,273 @@
+//! Find plotters connected to this machine.
+//!
+//! Serial ports are enumerated from `/dev`. For USB devices, the vendor and product ID are read
+//! from sysfs. Every candidate port is probed with a command that identifies the device: `V`
+//! for the EBB, `OI;` or `ESC.A` for HP-GL plotters and a soft reset followed by `$I` for GRBL.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::plotter::Plotter;
+use crate::{gcode, roland_dxy};
+use crate::{Backend, Command, Driver, Error};
+
+/// USB vendor and product ID of the EiBotBoard.
+pub const EBB_USB_ID: UsbId = UsbId {
+    vendor: 0x04D8,
+    product: 0xFD92,
+};
+
+/// How long to wait for a device to respond to a probe.
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long to wait for GRBL to respond to a probe. Opening the port resets most GRBL boards,
+/// which takes a while.
+const GRBL_PROBE_TIMEOUT: Duration = Duration::from_millis(2500);
+
+/// Vendor and product ID of an USB device.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UsbId {
+    pub vendor: u16,
+    pub product: u16,
+}
+
+/// A serial port that might have a plotter connected.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Port {
+    pub path: PathBuf,
+
+    /// IDs of the USB device providing the port, if any.
+    pub usb_id: Option<UsbId>,
+
+    /// Serial number of the USB device providing the port, if any.
+    pub serial_number: Option<String>,
+}
+
+/// A plotter that responded to a probe.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Device {
+    pub path: PathBuf,
+    pub backend: Backend,
+
+    /// Model of the plotter, like "EiBotBoard" or "DXY-1300".
+    pub model: String,
+
+    /// Firmware version as reported by the device.
+    pub firmware: Option<String>,
+
+    /// Nickname of the device as set with `ST`.
+    pub nickname: Option<String>,
+
+    /// Serial number of the USB device, if any.
+    pub serial_number: Option<String>,
+}
+
+/// Read the USB vendor and product ID of the device providing the tty with the given name.
+///
+/// `/sys/class/tty/<name>/device` points to the USB interface. The vendor and product ID are
+/// stored in one of its ancestors.
+fn usb_id(name: &str) -> Option<UsbId> {
+    let device = fs::canonicalize(Path::new("/sys/class/tty").join(name).join("device")).ok()?;
+
+    device.ancestors().find_map(|dir| {
+        let read = |file: &str| -> Option<u16> {
+            let value = fs::read_to_string(dir.join(file)).ok()?;
+            u16::from_str_radix(value.trim(), 16).ok()
+        };
+
+        Some(UsbId {
+            vendor: read("idVendor")?,
+            product: read("idProduct")?,
+        })
+    })
+}
+
+/// Read the serial number of the USB device providing the tty with the given name.
+fn usb_serial_number(name: &str) -> Option<String> {
+    let device = fs::canonicalize(Path::new("/sys/class/tty").join(name).join("device")).ok()?;
+
+    device.ancestors().find_map(|dir| {
+        let serial = fs::read_to_string(dir.join("serial")).ok()?;
+        Some(serial.trim().to_string())
+    })
+}
+
+/// List serial ports under `/dev` that are provided by USB devices: ttyACM* and ttyUSB*.
+pub fn serial_ports() -> Result<Vec<Port>, Error> {
+    let mut ports: Vec<Port> = fs::read_dir("/dev")?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !(name.starts_with("ttyACM") || name.starts_with("ttyUSB")) {
+                return None;
+            }
+
+            Some(Port {
+                path: entry.path(),
+                usb_id: usb_id(&name),
+                serial_number: usb_serial_number(&name),
+            })
+        })
+        .collect();
+
+    ports.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(ports)
+}
+
+/// Guess which backends are worth probing on the given port, most likely first.
+///
+/// The EBB is recognized by its USB ID and shows up as ttyACM*. HP-GL plotters are connected
+/// through an USB to RS-232 adapter, which shows up as ttyUSB*. GRBL runs on Arduinos, which show
+/// up as either. It's probed last, because probing it is slow.
+fn candidate_backends(port: &Port) -> Vec<Backend> {
+    if port.usb_id == Some(EBB_USB_ID) {
+        return vec![Backend::Ebb];
+    }
+
+    let name = port
+        .path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    if name.starts_with("ttyACM") {
+        vec![Backend::Ebb, Backend::RolandDxy, Backend::Grbl]
+    } else {
+        vec![Backend::RolandDxy, Backend::Ebb, Backend::Grbl]
+    }
+}
+
+fn probe_ebb(path: &Path) -> Result<Device, Error> {
+    let mut driver = Driver::open_with_timeout(path, PROBE_TIMEOUT)?;
+
+    // The response looks like "EBBv13_and_above EB Firmware Version 2.8.1".
+    let version = driver.query(Command::V)?;
+    if !version.starts_with("EBB") {
+        return Err(Error::ProbeError(format!(
+            "{} is not an EBB: {:?}.",
+            path.display(),
+            version
+        )));
+    }
+
+    let firmware = version
+        .rsplit(' ')
+        .next()
+        .map(|firmware| firmware.to_string());
+
+    // Firmware older than 2.5.4 doesn't support `QT`.
+    let nickname = driver
+        .query(Command::QT)
+        .ok()
+        .filter(|nickname| !nickname.is_empty());
+
+    Ok(Device {
+        path: path.to_path_buf(),
+        backend: Backend::Ebb,
+        model: "EiBotBoard".to_string(),
+        firmware,
+        nickname,
+        serial_number: None,
+    })
+}
+
+fn probe_roland_dxy(path: &Path) -> Result<Device, Error> {
+    let mut driver = roland_dxy::Driver::open_with_timeout(
+        path,
+        roland_dxy::default_port_settings(),
+        PROBE_TIMEOUT,
+    )?;
+
+    let model = driver.query("OI;").or_else(|_| driver.query("\x1b.A"))?;
+
+    let model = model.trim().to_string();
+    if model.is_empty() {
+        return Err(Error::ProbeError(format!(
+            "{} didn't identify itself.",
+            path.display()
+        )));
+    }
+
+    Ok(Device {
+        path: path.to_path_buf(),
+        backend: Backend::RolandDxy,
+        model,
+        firmware: None,
+        nickname: None,
+        serial_number: None,
+    })
+}
+
+fn probe_grbl(path: &Path) -> Result<Device, Error> {
+    let mut driver = gcode::Driver::open_with_timeout(
+        path,
+        gcode::default_port_settings(),
+        GRBL_PROBE_TIMEOUT,
+        Default::default(),
+    )?;
+
+    // The version looks like "Grbl 1.1h.20190825".
+    let version = driver.version()?;
+
+    Ok(Device {
+        path: path.to_path_buf(),
+        backend: Backend::Grbl,
+        model: "GRBL".to_string(),
+        firmware: version.strip_prefix("Grbl ").map(str::to_string),
+        nickname: None,
+        serial_number: None,
+    })
+}
+
+/// Probe a port for a plotter. Returns `None` when no known plotter responds.
+pub fn probe(port: &Port) -> Option<Device> {
+    let device = candidate_backends(port)
+        .into_iter()
+        .find_map(|backend| match backend {
+            Backend::Ebb => probe_ebb(&port.path).ok(),
+            Backend::RolandDxy => probe_roland_dxy(&port.path).ok(),
+            Backend::Grbl => probe_grbl(&port.path).ok(),
+        })?;
+
+    Some(Device {
+        serial_number: port.serial_number.clone(),
+        ..device
+    })
+}
+
+/// Find all plotters connected to this machine.
+pub fn discover() -> Result<Vec<Device>, Error> {
+    Ok(serial_ports()?.iter().filter_map(probe).collect())
+}
+
+/// Find the plotter with the given nickname.
+pub fn find_by_nickname(nickname: &str) -> Result<Option<Device>, Error> {
+    Ok(discover()?
+        .into_iter()
+        .find(|device| device.nickname.as_deref() == Some(nickname)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probe_ebb_first_when_usb_id_matches() {
+        let port = Port {
+            path: PathBuf::from("/dev/ttyUSB0"),
+            usb_id: Some(EBB_USB_ID),
+            serial_number: None,
+        };
+        assert_eq!(candidate_backends(&port), vec![Backend::Ebb]);
+
+        let port = Port {
+            path: PathBuf::from("/dev/ttyUSB0"),
+            usb_id: None,
+            serial_number: None,
+        };
+        assert_eq!(
+            candidate_backends(&port),
+            vec![Backend::RolandDxy, Backend::Ebb, Backend::Grbl]
+        );
+    }
+}
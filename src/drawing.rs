@@ -0,0 +1,352 @@
+//! A drawing context for generative art: a turtle that walks and turns, shapes like rectangles,
+//! circles and Bézier curves, and a stack of transformations. Everything drawn ends up as
+//! `Paths`.
+//!
+//! Coordinates are in units of the plot, with y down, until they are transformed. To draw in
+//! millimeters on a Roland DXY, start with `drawing.scale(40.0, 40.0)`. Angles are in degrees,
+//! clockwise on the plot.
+use crate::cartography;
+use crate::text::Point;
+use crate::{Error, Paths};
+
+/// Greatest distance between a curve and the lines it is drawn with, in units of the plot.
+const TOLERANCE: f64 = 0.5;
+
+/// An affine transformation: `(x, y)` goes to `(a * x + c * y + e, b * x + d * y + f)`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Transform([f64; 6]);
+
+impl Transform {
+    const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    fn apply(&self, (x, y): Point) -> Point {
+        let [a, b, c, d, e, f] = self.0;
+        (a * x + c * y + e, b * x + d * y + f)
+    }
+
+    /// This transformation after `other`.
+    fn then(&self, other: [f64; 6]) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let [a2, b2, c2, d2, e2, f2] = other;
+        Self([
+            a * a2 + c * b2,
+            b * a2 + d * b2,
+            a * c2 + c * d2,
+            b * c2 + d * d2,
+            a * e2 + c * f2 + e,
+            b * e2 + d * f2 + f,
+        ])
+    }
+
+    /// How much lengths grow, on average.
+    fn scale(&self) -> f64 {
+        let [a, b, c, d, _, _] = self.0;
+        (a * d - b * c).abs().sqrt()
+    }
+}
+
+/// What `push` saves and `pop` restores.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct State {
+    transform: Transform,
+    position: Point,
+    heading: f64,
+    pen_down: bool,
+}
+
+/// A drawing context. The turtle starts at the origin, heading right with its pen down.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Drawing {
+    state: State,
+    stack: Vec<State>,
+
+    /// The path being drawn, in units of the plot.
+    current: Vec<Point>,
+    paths: Vec<Vec<Point>>,
+}
+
+impl Default for Drawing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drawing {
+    pub fn new() -> Self {
+        Self {
+            state: State {
+                transform: Transform::IDENTITY,
+                position: (0.0, 0.0),
+                heading: 0.0,
+                pen_down: true,
+            },
+            stack: vec![],
+            current: vec![],
+            paths: vec![],
+        }
+    }
+
+    /// Where the turtle is.
+    pub fn position(&self) -> Point {
+        self.state.position
+    }
+
+    /// Where the turtle is heading, in degrees clockwise from the x axis.
+    pub fn heading(&self) -> f64 {
+        self.state.heading
+    }
+
+    /// End the path being drawn.
+    fn finish(&mut self) {
+        let path = std::mem::take(&mut self.current);
+        if path.len() > 1 {
+            self.paths.push(path);
+        }
+    }
+
+    /// Add a path of its own, apart from the path of the turtle.
+    fn shape(&mut self, points: impl IntoIterator<Item = Point>) {
+        self.finish();
+        let transform = self.state.transform;
+        self.current = points
+            .into_iter()
+            .map(|point| transform.apply(point))
+            .collect();
+        self.finish();
+    }
+
+    /// Move to `(x, y)` without drawing.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.finish();
+        self.state.position = (x, y);
+    }
+
+    /// Draw a line to `(x, y)`.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        if self.current.is_empty() {
+            self.current
+                .push(self.state.transform.apply(self.state.position));
+        }
+        self.current.push(self.state.transform.apply((x, y)));
+        self.state.position = (x, y);
+    }
+
+    /// Move the turtle `distance` ahead, drawing a line if its pen is down.
+    pub fn forward(&mut self, distance: f64) {
+        let (x, y) = self.state.position;
+        let heading = self.state.heading.to_radians();
+        let (x, y) = (x + distance * heading.cos(), y + distance * heading.sin());
+        if self.state.pen_down {
+            self.line_to(x, y);
+        } else {
+            self.move_to(x, y);
+        }
+    }
+
+    /// Turn the turtle clockwise, or counterclockwise for negative `degrees`.
+    pub fn turn(&mut self, degrees: f64) {
+        self.state.heading = (self.state.heading + degrees).rem_euclid(360.0);
+    }
+
+    /// Head the turtle `degrees` clockwise from the x axis.
+    pub fn set_heading(&mut self, degrees: f64) {
+        self.state.heading = degrees.rem_euclid(360.0);
+    }
+
+    /// Stop drawing while the turtle moves.
+    pub fn pen_up(&mut self) {
+        self.finish();
+        self.state.pen_down = false;
+    }
+
+    /// Draw while the turtle moves.
+    pub fn pen_down(&mut self) {
+        self.state.pen_down = true;
+    }
+
+    /// Draw a Bézier curve from where the turtle is to `end`, like `line_to`.
+    pub fn bezier(&mut self, control1: Point, control2: Point, end: Point) {
+        let start = self.state.position;
+        let transform = self.state.transform;
+        let [p0, p1, p2, p3] = [start, control1, control2, end].map(|point| transform.apply(point));
+        // The number of segments to stay within the tolerance, after Wang.
+        let bend =
+            |a: Point, b: Point, c: Point| (a.0 - 2.0 * b.0 + c.0).hypot(a.1 - 2.0 * b.1 + c.1);
+        let segments = (0.75 * bend(p0, p1, p2).max(bend(p1, p2, p3)) / TOLERANCE)
+            .sqrt()
+            .ceil()
+            .clamp(1.0, 1000.0) as usize;
+
+        if self.current.is_empty() {
+            self.current.push(p0);
+        }
+        for segment in 1..=segments {
+            let t = segment as f64 / segments as f64;
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            self.current.push((
+                a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+                a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+            ));
+        }
+        self.state.position = end;
+    }
+
+    /// Draw lines through `points`, back to the first one. The turtle stays where it is.
+    pub fn polygon(&mut self, points: &[Point]) {
+        self.shape(points.iter().chain(points.first()).copied());
+    }
+
+    /// Draw a rectangle with its top left corner at `(x, y)`.
+    pub fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.polygon(&[
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ]);
+    }
+
+    /// Draw a circle around `(x, y)`.
+    pub fn circle(&mut self, x: f64, y: f64, radius: f64) {
+        // Enough corners to stay within the tolerance.
+        let radius_on_plot = radius.abs() * self.state.transform.scale();
+        let corners = if radius_on_plot > TOLERANCE {
+            (std::f64::consts::PI / (1.0 - TOLERANCE / radius_on_plot).acos()).ceil()
+        } else {
+            0.0
+        };
+        let corners = (corners as usize).clamp(8, 1000);
+        self.shape(cartography::polygon((x, y), radius, corners, 0.0));
+    }
+
+    /// Move the origin to `(x, y)`. Starts a new path, like all transformations.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.transform([1.0, 0.0, 0.0, 1.0, x, y]);
+    }
+
+    /// Rotate clockwise around the origin.
+    pub fn rotate(&mut self, degrees: f64) {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        self.transform([cos, sin, -sin, cos, 0.0, 0.0]);
+    }
+
+    /// Scale from the origin.
+    pub fn scale(&mut self, x: f64, y: f64) {
+        self.transform([x, 0.0, 0.0, y, 0.0, 0.0]);
+    }
+
+    fn transform(&mut self, transform: [f64; 6]) {
+        self.finish();
+        self.state.transform = self.state.transform.then(transform);
+    }
+
+    /// Save the transformation and the turtle, to `pop` them later.
+    pub fn push(&mut self) {
+        self.stack.push(self.state);
+    }
+
+    /// Restore the transformation and the turtle of the last `push`. Does nothing without one.
+    pub fn pop(&mut self) {
+        if let Some(state) = self.stack.pop() {
+            self.finish();
+            self.state = state;
+        }
+    }
+
+    /// Everything drawn, in the order it was drawn. Returns `Err` when nothing was drawn.
+    pub fn into_paths(mut self) -> Result<Paths, Error> {
+        self.finish();
+        Paths::new(cartography::to_paths(self.paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(paths: &Paths) -> Vec<Vec<(i32, i32)>> {
+        paths
+            .paths
+            .iter()
+            .map(|path| path.iter().map(|point| (point.x, point.y)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn walk_turtle() {
+        let mut drawing = Drawing::new();
+        drawing.translate(100.0, 100.0);
+        for _ in 0..4 {
+            drawing.forward(50.0);
+            drawing.turn(90.0);
+        }
+        drawing.pen_up();
+        drawing.forward(100.0);
+        drawing.pen_down();
+        drawing.turn(-90.0);
+        drawing.forward(10.0);
+        let (x, y) = drawing.position();
+        assert_eq!((x.round(), y.round()), (100.0, -10.0));
+        assert_eq!(drawing.heading(), 270.0);
+
+        assert_eq!(
+            points(&drawing.into_paths().unwrap()),
+            vec![
+                vec![(100, 100), (150, 100), (150, 150), (100, 150), (100, 100)],
+                vec![(200, 100), (200, 90)]
+            ]
+        );
+    }
+
+    #[test]
+    fn push_and_pop_transformations() {
+        let mut drawing = Drawing::new();
+        drawing.push();
+        drawing.translate(10.0, 20.0);
+        drawing.rotate(90.0);
+        drawing.scale(2.0, 2.0);
+        drawing.rect(0.0, 0.0, 5.0, 10.0);
+        drawing.forward(5.0);
+        drawing.pop();
+        drawing.line_to(0.0, 10.0);
+        assert_eq!(drawing.position(), (0.0, 10.0));
+
+        assert_eq!(
+            points(&drawing.into_paths().unwrap()),
+            vec![
+                vec![(10, 20), (10, 30), (-10, 30), (-10, 20), (10, 20)],
+                vec![(10, 20), (10, 30)],
+                vec![(0, 0), (0, 10)]
+            ]
+        );
+    }
+
+    #[test]
+    fn draw_curves() {
+        let mut drawing = Drawing::new();
+        drawing.circle(100.0, 100.0, 50.0);
+        drawing.move_to(0.0, 0.0);
+        drawing.bezier((0.0, 100.0), (100.0, 100.0), (100.0, 0.0));
+        let paths = drawing.into_paths().unwrap();
+
+        let circle = &paths.paths[0];
+        assert_eq!(circle.first(), circle.last());
+        for point in circle {
+            let radius = ((point.x - 100) as f64).hypot((point.y - 100) as f64);
+            assert!((49.0..=51.0).contains(&radius), "{}", radius);
+        }
+
+        let curve = &paths.paths[1];
+        assert!(curve.len() > 10);
+        assert_eq!((curve[0].x, curve[0].y), (0, 0));
+        assert_eq!(
+            (curve[curve.len() - 1].x, curve[curve.len() - 1].y),
+            (100, 0)
+        );
+        // The middle of the curve is three quarters of the way to its control points.
+        assert_eq!(curve.iter().map(|point| point.y).max(), Some(75));
+
+        assert!(Drawing::new().into_paths().is_err());
+    }
+}
@@ -0,0 +1,548 @@
+//! Read a `Plot` from a DXF file and write a `Plot` as DXF R12.
+//!
+//! Only the `ENTITIES` section is read. `LINE`, `LWPOLYLINE`, `POLYLINE`, `CIRCLE`, `ARC`,
+//! `ELLIPSE` and `SPLINE` are converted into paths, other entities are skipped. Blocks aren't
+//! expanded. Arcs, bulges and splines are flattened into straight segments. The y-axis of DXF
+//! points up like in CAD, so y is negated on the way in and out to match the y-down `Plot`.
+use std::f64::consts::PI;
+use std::fmt::Write;
+use std::fs;
+use std::path;
+
+use crate::point::Coordinate;
+use crate::{Error, Path, Paths, Plot};
+
+/// Number of straight segments used to approximate a full circle.
+const CIRCLE_SEGMENTS: usize = 64;
+
+/// Number of straight segments per control point used to approximate a spline.
+const SPLINE_SEGMENTS: usize = 8;
+
+type Point = (f64, f64);
+
+/// An entity: its type and its group codes with their values.
+struct Entity {
+    kind: String,
+    groups: Vec<(i32, String)>,
+}
+
+impl Entity {
+    /// The first value of a group code as number.
+    fn number(&self, code: i32) -> Result<Option<f64>, Error> {
+        self.groups
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, value)| parse_number(code, value))
+            .transpose()
+    }
+
+    /// The first value of a group code as number, or `default` when it's missing.
+    fn number_or(&self, code: i32, default: f64) -> Result<f64, Error> {
+        Ok(self.number(code)?.unwrap_or(default))
+    }
+
+    /// All values of a group code as numbers, in order.
+    fn numbers(&self, code: i32) -> Result<Vec<f64>, Error> {
+        self.groups
+            .iter()
+            .filter(|(c, _)| *c == code)
+            .map(|(_, value)| parse_number(code, value))
+            .collect()
+    }
+
+    fn flags(&self) -> Result<u32, Error> {
+        Ok(self.number_or(70, 0.0)? as u32)
+    }
+
+    fn layer(&self) -> String {
+        self.groups
+            .iter()
+            .find(|(code, _)| *code == 8)
+            .map(|(_, layer)| layer.clone())
+            .unwrap_or_else(|| "0".to_string())
+    }
+
+    fn point(&self, x: i32, y: i32) -> Result<Point, Error> {
+        Ok((self.number_or(x, 0.0)?, self.number_or(y, 0.0)?))
+    }
+}
+
+fn parse_number(code: i32, value: &str) -> Result<f64, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::DxfError(format!("Invalid value {:?} of group {}.", value, code)))
+}
+
+/// Split DXF into group codes and values.
+fn groups(text: &str) -> Result<Vec<(i32, String)>, Error> {
+    let lines: Vec<&str> = text.lines().map(str::trim).collect();
+
+    lines
+        .chunks(2)
+        .filter(|pair| pair.len() == 2)
+        .map(|pair| {
+            let code = pair[0]
+                .parse()
+                .map_err(|_| Error::DxfError(format!("Invalid group code {:?}.", pair[0])))?;
+            Ok((code, pair[1].to_string()))
+        })
+        .collect()
+}
+
+/// The entities in the `ENTITIES` section.
+fn entities(text: &str) -> Result<Vec<Entity>, Error> {
+    let mut entities: Vec<Entity> = vec![];
+    let mut section: Option<String> = None;
+    let mut expect_name = false;
+
+    for (code, value) in groups(text)? {
+        if expect_name {
+            expect_name = false;
+            if code == 2 {
+                section = Some(value);
+                continue;
+            }
+        }
+
+        match (code, value.as_str()) {
+            (0, "SECTION") => expect_name = true,
+            (0, "ENDSEC") => section = None,
+            (0, kind) if section.as_deref() == Some("ENTITIES") => entities.push(Entity {
+                kind: kind.to_string(),
+                groups: vec![],
+            }),
+            _ if section.as_deref() == Some("ENTITIES") => {
+                if let Some(entity) = entities.last_mut() {
+                    entity.groups.push((code, value));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(entities)
+}
+
+/// Approximate the arc of a circle from `start` to `end` in radians, counterclockwise when `end`
+/// is larger than `start`. Both ends are included.
+fn arc(center: Point, radius: f64, start: f64, end: f64) -> Vec<Point> {
+    let segments =
+        ((CIRCLE_SEGMENTS as f64 * (end - start).abs() / (2.0 * PI)).ceil() as usize).max(1);
+
+    (0..=segments)
+        .map(|i| {
+            let angle = start + (end - start) * i as f64 / segments as f64;
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Approximate the segment of a polyline from `from` to `to` with the given bulge: the tangent
+/// of a quarter of the included angle, positive for counterclockwise arcs. `from` is not
+/// included.
+fn bulge(from: Point, to: Point, bulge: f64) -> Vec<Point> {
+    if bulge == 0.0 || from == to {
+        return vec![to];
+    }
+
+    let angle = 4.0 * bulge.atan();
+    let chord = (to.0 - from.0).hypot(to.1 - from.1);
+    let radius = chord / (2.0 * (angle / 2.0).sin());
+
+    // The center lies on the perpendicular bisector of the chord.
+    let middle = ((from.0 + to.0) / 2.0, (from.1 + to.1) / 2.0);
+    let distance = radius * (angle / 2.0).cos();
+    let normal = (-(to.1 - from.1) / chord, (to.0 - from.0) / chord);
+    let center = (
+        middle.0 + normal.0 * distance,
+        middle.1 + normal.1 * distance,
+    );
+
+    let start = (from.1 - center.1).atan2(from.0 - center.0);
+    let mut points = arc(center, radius.abs(), start, start + angle);
+    points.remove(0);
+    if let Some(last) = points.last_mut() {
+        *last = to;
+    }
+
+    points
+}
+
+/// Build a polyline from vertices with the bulge of the segment that starts at them.
+fn polyline(vertices: &[(Point, f64)], closed: bool) -> Vec<Point> {
+    let Some(&(first, _)) = vertices.first() else {
+        return vec![];
+    };
+
+    let mut points = vec![first];
+    let mut segments: Vec<(Point, f64, Point)> = vertices
+        .windows(2)
+        .map(|pair| (pair[0].0, pair[0].1, pair[1].0))
+        .collect();
+    if closed && vertices.len() > 1 {
+        let &(last, last_bulge) = vertices.last().unwrap_or(&(first, 0.0));
+        segments.push((last, last_bulge, first));
+    }
+
+    for (from, b, to) in segments {
+        points.extend(bulge(from, to, b));
+    }
+
+    points
+}
+
+/// Evaluate a NURBS curve of `degree` at `t` with the de Boor algorithm.
+fn de_boor(degree: usize, knots: &[f64], points: &[(f64, f64, f64)], t: f64) -> Point {
+    let n = points.len();
+    // The knot span that contains `t`.
+    let span = (degree..n).rev().find(|&i| knots[i] <= t).unwrap_or(degree);
+
+    let mut d: Vec<(f64, f64, f64)> = (0..=degree)
+        .map(|j| {
+            let (x, y, w) = points[span - degree + j];
+            (x * w, y * w, w)
+        })
+        .collect();
+
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = span - degree + j;
+            let denominator = knots[i + degree + 1 - r] - knots[i];
+            let alpha = if denominator == 0.0 {
+                0.0
+            } else {
+                (t - knots[i]) / denominator
+            };
+            d[j] = (
+                (1.0 - alpha) * d[j - 1].0 + alpha * d[j].0,
+                (1.0 - alpha) * d[j - 1].1 + alpha * d[j].1,
+                (1.0 - alpha) * d[j - 1].2 + alpha * d[j].2,
+            );
+        }
+    }
+
+    let (x, y, w) = d[degree];
+    (x / w, y / w)
+}
+
+fn spline(entity: &Entity) -> Result<Vec<Point>, Error> {
+    let degree = entity.number_or(71, 3.0)? as usize;
+    let knots = entity.numbers(40)?;
+    let xs = entity.numbers(10)?;
+    let ys = entity.numbers(20)?;
+    let mut weights = entity.numbers(41)?;
+
+    if xs.is_empty() {
+        // Splines can be defined by fit points only, approximate them with a polyline.
+        let fit = entity
+            .numbers(11)?
+            .into_iter()
+            .zip(entity.numbers(21)?)
+            .collect();
+        return Ok(fit);
+    }
+
+    let n = xs.len().min(ys.len());
+    if knots.len() != n + degree + 1 || n <= degree {
+        return Err(Error::DxfError(format!(
+            "Spline of degree {} with {} control points has {} knots.",
+            degree,
+            n,
+            knots.len()
+        )));
+    }
+    weights.resize(n, 1.0);
+    let points: Vec<(f64, f64, f64)> = (0..n).map(|i| (xs[i], ys[i], weights[i])).collect();
+
+    let (start, end) = (knots[degree], knots[n]);
+    let segments = SPLINE_SEGMENTS * n;
+    Ok((0..=segments)
+        .map(|i| {
+            let t = start + (end - start) * i as f64 / segments as f64;
+            de_boor(degree, &knots, &points, t)
+        })
+        .collect())
+}
+
+fn ellipse(entity: &Entity) -> Result<Vec<Point>, Error> {
+    let center = entity.point(10, 20)?;
+    let major = entity.point(11, 21)?;
+    let ratio = entity.number_or(40, 1.0)?;
+    let start = entity.number_or(41, 0.0)?;
+    let mut end = entity.number_or(42, 2.0 * PI)?;
+    if end <= start {
+        end += 2.0 * PI;
+    }
+
+    let minor = (-major.1 * ratio, major.0 * ratio);
+    let segments = ((CIRCLE_SEGMENTS as f64 * (end - start) / (2.0 * PI)).ceil() as usize).max(1);
+    Ok((0..=segments)
+        .map(|i| {
+            let angle = start + (end - start) * i as f64 / segments as f64;
+            let (cos, sin) = (angle.cos(), angle.sin());
+            (
+                center.0 + major.0 * cos + minor.0 * sin,
+                center.1 + major.1 * cos + minor.1 * sin,
+            )
+        })
+        .collect())
+}
+
+/// Convert the entities into polylines with their layer. A `POLYLINE` takes the `VERTEX`
+/// entities up to the next `SEQEND`.
+fn polylines(entities: &[Entity]) -> Result<Vec<(String, Vec<Point>)>, Error> {
+    let mut polylines = vec![];
+    let mut iter = entities.iter();
+
+    while let Some(entity) = iter.next() {
+        let points = match entity.kind.as_str() {
+            "LINE" => vec![entity.point(10, 20)?, entity.point(11, 21)?],
+            "LWPOLYLINE" => {
+                // Every vertex starts with group 10, a bulge applies to the last vertex.
+                let mut vertices: Vec<(Point, f64)> = vec![];
+                for (code, value) in &entity.groups {
+                    match code {
+                        10 => vertices.push(((parse_number(*code, value)?, 0.0), 0.0)),
+                        20 => {
+                            if let Some(vertex) = vertices.last_mut() {
+                                vertex.0 .1 = parse_number(*code, value)?;
+                            }
+                        }
+                        42 => {
+                            if let Some(vertex) = vertices.last_mut() {
+                                vertex.1 = parse_number(*code, value)?;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                polyline(&vertices, entity.flags()? & 1 != 0)
+            }
+            "POLYLINE" => {
+                let mut vertices = vec![];
+                for vertex in iter.by_ref() {
+                    match vertex.kind.as_str() {
+                        // Skip the control points of spline-fit polylines.
+                        "VERTEX" if vertex.flags()? & 16 == 0 => {
+                            vertices.push((vertex.point(10, 20)?, vertex.number_or(42, 0.0)?))
+                        }
+                        "VERTEX" => {}
+                        _ => break,
+                    }
+                }
+                polyline(&vertices, entity.flags()? & 1 != 0)
+            }
+            "CIRCLE" => arc(
+                entity.point(10, 20)?,
+                entity.number_or(40, 0.0)?,
+                0.0,
+                2.0 * PI,
+            ),
+            "ARC" => {
+                let start = entity.number_or(50, 0.0)?.to_radians();
+                let mut end = entity.number_or(51, 360.0)?.to_radians();
+                if end <= start {
+                    end += 2.0 * PI;
+                }
+                arc(
+                    entity.point(10, 20)?,
+                    entity.number_or(40, 0.0)?,
+                    start,
+                    end,
+                )
+            }
+            "ELLIPSE" => ellipse(entity)?,
+            "SPLINE" => {
+                let mut points = spline(entity)?;
+                if entity.flags()? & 1 != 0 {
+                    if let Some(first) = points.first().copied() {
+                        points.push(first);
+                    }
+                }
+                points
+            }
+            _ => continue,
+        };
+
+        polylines.push((entity.layer(), points));
+    }
+
+    Ok(polylines)
+}
+
+/// The paths of a DXF file with the layer of each path.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Drawing {
+    pub paths: Vec<(String, Path)>,
+}
+
+impl Drawing {
+    /// Names of all layers with paths, in the order they first appear.
+    pub fn layers(&self) -> Vec<&str> {
+        let mut layers: Vec<&str> = vec![];
+        for (layer, _) in &self.paths {
+            if !layers.contains(&layer.as_str()) {
+                layers.push(layer);
+            }
+        }
+
+        layers
+    }
+
+    /// Build a `Plot` from the paths on the given layers, or from all paths.
+    pub fn plot(&self, layers: Option<&[String]>) -> Result<Plot, Error> {
+        let paths = self
+            .paths
+            .iter()
+            .filter(|(layer, _)| layers.is_none_or(|layers| layers.contains(layer)))
+            .map(|(_, path)| path.clone())
+            .collect();
+
+        Ok(Plot::new(Paths::new(paths)?))
+    }
+}
+
+/// Read the paths of DXF with their layers. Every unit is multiplied by `scale` before it's
+/// rounded to the unit of the `Plot`, and y is negated to point down.
+pub fn parse_drawing(text: &str, scale: f64) -> Result<Drawing, Error> {
+    let paths = polylines(&entities(text)?)?
+        .into_iter()
+        .map(|(layer, points)| {
+            let path = points
+                .iter()
+                .map(|(x, y)| {
+                    Coordinate::new((x * scale).round() as i32, (-y * scale).round() as i32)
+                })
+                .collect();
+            (layer, path)
+        })
+        .collect();
+
+    Ok(Drawing { paths })
+}
+
+/// Build a `Plot` from the entities on all layers of DXF.
+pub fn parse(text: &str, scale: f64) -> Result<Plot, Error> {
+    parse_drawing(text, scale)?.plot(None)
+}
+
+/// Build a `Plot` from the DXF file at the given location.
+pub fn read(path: &path::Path, scale: f64) -> Result<Plot, Error> {
+    parse(&fs::read_to_string(path)?, scale)
+}
+
+/// Write a group code and its value.
+fn group(dxf: &mut String, code: i32, value: impl std::fmt::Display) {
+    writeln!(dxf, "{:>3}\n{}", code, value).unwrap();
+}
+
+/// Write every `Path` of the `Plot` as a `POLYLINE` on layer 0 of a DXF R12 file, in the unit of
+/// the `Plot`. y is negated to point up.
+pub fn to_dxf(plot: &Plot) -> String {
+    let mut dxf = String::new();
+    group(&mut dxf, 0, "SECTION");
+    group(&mut dxf, 2, "HEADER");
+    group(&mut dxf, 9, "$ACADVER");
+    group(&mut dxf, 1, "AC1009");
+    group(&mut dxf, 0, "ENDSEC");
+
+    group(&mut dxf, 0, "SECTION");
+    group(&mut dxf, 2, "ENTITIES");
+    for path in &plot.paths().paths {
+        group(&mut dxf, 0, "POLYLINE");
+        group(&mut dxf, 8, 0);
+        // Vertices follow.
+        group(&mut dxf, 66, 1);
+        for (code, value) in [(10, 0.0), (20, 0.0), (30, 0.0)] {
+            group(&mut dxf, code, value);
+        }
+
+        for point in path {
+            group(&mut dxf, 0, "VERTEX");
+            group(&mut dxf, 8, 0);
+            group(&mut dxf, 10, point.x);
+            group(&mut dxf, 20, -point.y);
+            group(&mut dxf, 30, 0);
+        }
+        group(&mut dxf, 0, "SEQEND");
+        group(&mut dxf, 8, 0);
+    }
+    group(&mut dxf, 0, "ENDSEC");
+    group(&mut dxf, 0, "EOF");
+
+    dxf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wrap entities in an `ENTITIES` section.
+    fn dxf(entities: &str) -> String {
+        format!("0\nSECTION\n2\nENTITIES\n{}0\nENDSEC\n0\nEOF\n", entities)
+    }
+
+    #[test]
+    fn parse_entities() {
+        let text = dxf(concat!(
+            "0\nLINE\n8\nCUT\n10\n0\n20\n0\n11\n3\n21\n4\n",
+            "0\nLWPOLYLINE\n8\nDRAW\n90\n2\n70\n1\n10\n0\n20\n0\n42\n1\n10\n10\n20\n0\n",
+            "0\nCIRCLE\n10\n5\n20\n5\n40\n5\n",
+            "0\nARC\n10\n0\n20\n0\n40\n10\n50\n0\n51\n90\n",
+            "0\nTEXT\n1\nIgnored\n",
+        ));
+        let drawing = parse_drawing(&text, 1.0).unwrap();
+
+        assert_eq!(drawing.layers(), vec!["CUT", "DRAW", "0"]);
+        assert_eq!(drawing.paths.len(), 4);
+        assert_eq!(
+            drawing.paths[0].1,
+            vec![Coordinate::new(0, 0), Coordinate::new(3, -4)]
+        );
+
+        // A bulge of 1 is a half circle, counterclockwise from (0, 0) to (10, 0), so below the
+        // chord in DXF and above it in the plot.
+        let half_circle = &drawing.paths[1].1;
+        assert!(half_circle.contains(&Coordinate::new(5, 5)));
+        assert_eq!(half_circle.last(), Some(&Coordinate::new(0, 0)));
+
+        let arc = &drawing.paths[3].1;
+        assert_eq!(arc.first(), Some(&Coordinate::new(10, 0)));
+        assert_eq!(arc.last(), Some(&Coordinate::new(0, -10)));
+
+        let cut = drawing.plot(Some(&["CUT".to_string()])).unwrap();
+        assert_eq!(cut.paths().paths.len(), 1);
+    }
+
+    #[test]
+    fn evaluate_spline() {
+        // A clamped quadratic spline passes through its first and last control point.
+        let text = dxf(concat!(
+            "0\nSPLINE\n71\n2\n72\n6\n73\n3\n",
+            "40\n0\n40\n0\n40\n0\n40\n1\n40\n1\n40\n1\n",
+            "10\n0\n20\n0\n10\n5\n20\n10\n10\n10\n20\n0\n",
+        ));
+        let plot = parse(&text, 1.0).unwrap();
+        let path = &plot.paths().paths[0];
+
+        assert_eq!(path.first(), Some(&Coordinate::new(0, 0)));
+        assert_eq!(path[path.len() / 2], Coordinate::new(5, -5));
+        assert_eq!(path.last(), Some(&Coordinate::new(10, 0)));
+    }
+
+    #[test]
+    fn write_and_read() {
+        let path = vec![
+            Coordinate::new(0, 0),
+            Coordinate::new(100, 0),
+            Coordinate::new(100, 50),
+        ];
+        let plot = Plot::from_path(path.clone()).unwrap();
+
+        let drawing = parse_drawing(&to_dxf(&plot), 1.0).unwrap();
+        assert_eq!(drawing.paths, vec![("0".to_string(), path)]);
+    }
+}
@@ -0,0 +1,593 @@
+//! G-code for GRBL based pen plotters: export a `Plot` as a file with `to_gcode()`, or stream it
+//! to GRBL over a serial port with `Driver`.
+//!
+//! The pen is raised and lowered with configurable G-code, for example `M5` and `M3 S1000` for a
+//! servo on the spindle output, or `G0 Z5` and `G1 Z0 F500` for a Z-axis. See `Options`.
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serial_core::{BaudRate, CharSize, FlowControl, Parity, PortSettings, SerialPort, StopBits};
+use tracing::{debug, info, info_span, trace_span};
+
+use crate::plotter::{Plotter, Status};
+use crate::point::{Coordinate, Relative};
+use crate::traffic::{Connection, TrafficLog, Transport};
+use crate::{Error, Plot, Strokes, DEFAULT_TIMEOUT};
+
+/// Size of the serial receive buffer of GRBL in bytes.
+pub const RX_BUFFER_SIZE: usize = 128;
+
+/// Unit of the coordinates in the G-code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Units {
+    /// `G21`
+    Millimeters,
+
+    /// `G20`
+    Inches,
+}
+
+impl Units {
+    /// The G-code that selects these units.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Units::Millimeters => "G21",
+            Units::Inches => "G20",
+        }
+    }
+
+    /// Number of these units per millimeter.
+    pub fn per_mm(&self) -> f64 {
+        match self {
+            Units::Millimeters => 1.0,
+            Units::Inches => 1.0 / 25.4,
+        }
+    }
+}
+
+/// Options to tweak the G-code created by `to_gcode()` and `Driver`.
+///
+/// `pen_up`, `pen_down`, `header` and `footer` may hold several lines. The header and footer are
+/// templates: `{units}`, `{feed_rate}`, `{pen_up}` and `{pen_down}` are replaced by the G-code
+/// of the units, the feed rate and the pen sequences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Options {
+    /// Number of units of the `Plot` per millimeter.
+    pub units_per_mm: f64,
+
+    /// Unit of the coordinates in the G-code.
+    pub units: Units,
+
+    /// Speed of moves made with the pen down in `units` per minute.
+    pub feed_rate: f64,
+
+    /// Speed of `G0` moves in `units` per minute. Only used to estimate the plotting time, GRBL
+    /// uses its maximum rate.
+    pub travel_rate: f64,
+
+    /// G-code that raises the pen.
+    pub pen_up: String,
+
+    /// G-code that lowers the pen.
+    pub pen_down: String,
+
+    /// G-code before the first stroke.
+    pub header: String,
+
+    /// G-code after the last stroke.
+    pub footer: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            units_per_mm: 1.0,
+            units: Units::Millimeters,
+            feed_rate: 1000.0,
+            travel_rate: 3000.0,
+            pen_up: "M5".to_string(),
+            pen_down: "M3 S1000".to_string(),
+            header: "{units}\nG90\n{pen_up}".to_string(),
+            footer: "G0 X0 Y0".to_string(),
+        }
+    }
+}
+
+impl Options {
+    /// Options for a plotter that moves the pen with a Z-axis: `G0 Z{up}` raises the pen and
+    /// `G1 Z{down}` lowers it at `z_feed_rate`.
+    pub fn z_axis(up: f64, down: f64, z_feed_rate: f64) -> Self {
+        Self {
+            pen_up: format!("G0 Z{}", up),
+            pen_down: format!("G1 Z{} F{}", down, z_feed_rate),
+            ..Default::default()
+        }
+    }
+
+    /// Convert a distance in units of the `Plot` into `units`.
+    fn convert(&self, distance: i32) -> f64 {
+        distance as f64 / self.units_per_mm * self.units.per_mm()
+    }
+
+    /// Convert a distance in `units` into units of the `Plot`.
+    fn convert_back(&self, distance: f64) -> i32 {
+        (distance / self.units.per_mm() * self.units_per_mm).round() as i32
+    }
+
+    /// Fill in the placeholders of a header or footer.
+    fn expand(&self, template: &str) -> String {
+        template
+            .replace("{units}", self.units.code())
+            .replace("{feed_rate}", &self.feed_rate.to_string())
+            .replace("{pen_up}", &self.pen_up)
+            .replace("{pen_down}", &self.pen_down)
+    }
+}
+
+/// Split G-code into trimmed, non-empty lines.
+fn lines(gcode: &str) -> Vec<String> {
+    gcode
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The G-code of a `Plot`: the header, the lines of every stroke and the footer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Program {
+    pub header: Vec<String>,
+    pub strokes: Vec<Vec<String>>,
+    pub footer: Vec<String>,
+}
+
+impl Program {
+    /// Convert a `Plot` into G-code. Pen-up moves use `G0` and pen-down moves use `G1`.
+    pub fn new(plot: &Plot, options: &Options) -> Result<Self, Error> {
+        let strokes = Strokes::try_from(plot.paths())?;
+        let position =
+            |x: i32, y: i32| format!("X{:.3} Y{:.3}", options.convert(x), options.convert(y));
+
+        let strokes = strokes
+            .iter()
+            .map(|stroke| {
+                let mut gcode = vec![format!("G0 {}", position(stroke.start.x, stroke.start.y))];
+                gcode.extend(lines(&options.pen_down));
+
+                let (mut x, mut y) = (stroke.start.x, stroke.start.y);
+                for (i, delta) in stroke.path.iter().enumerate() {
+                    x += delta.x;
+                    y += delta.y;
+
+                    if i == 0 {
+                        gcode.push(format!("G1 {} F{}", position(x, y), options.feed_rate));
+                    } else {
+                        gcode.push(format!("G1 {}", position(x, y)));
+                    }
+                }
+
+                gcode.extend(lines(&options.pen_up));
+                gcode
+            })
+            .collect();
+
+        Ok(Self {
+            header: lines(&options.expand(&options.header)),
+            strokes,
+            footer: lines(&options.expand(&options.footer)),
+        })
+    }
+
+    /// All lines of the program, in order.
+    pub fn lines(&self) -> impl Iterator<Item = &String> {
+        self.header
+            .iter()
+            .chain(self.strokes.iter().flatten())
+            .chain(self.footer.iter())
+    }
+}
+
+/// Convert a `Plot` into G-code. Pen-up moves use `G0` and pen-down moves use `G1`.
+pub fn to_gcode(plot: &Plot, options: &Options) -> Result<String, Error> {
+    Ok(Program::new(plot, options)?
+        .lines()
+        .map(|line| format!("{}\n", line))
+        .collect())
+}
+
+/// The settings of the serial port of GRBL: 115200 baud, 8 data bits, no parity and 1 stop bit.
+pub fn default_port_settings() -> PortSettings {
+    PortSettings {
+        baud_rate: BaudRate::Baud115200,
+        char_size: CharSize::Bits8,
+        parity: Parity::ParityNone,
+        stop_bits: StopBits::Stop1,
+        flow_control: FlowControl::FlowNone,
+    }
+}
+
+/// Build a `Status` from a status report like `<Idle|MPos:1.000,2.000,0.000|FS:0,0>`. The work
+/// position is used when GRBL reports it, otherwise the machine position.
+pub(crate) fn parse_status(response: &str, options: &Options) -> Result<Status, Error> {
+    let invalid = || Error::ConversionError(format!("Failed to parse {:?} as status.", response));
+
+    let fields: Vec<&str> = response
+        .trim()
+        .strip_prefix('<')
+        .and_then(|response| response.strip_suffix('>'))
+        .ok_or_else(invalid)?
+        .split('|')
+        .collect();
+    let position = fields
+        .iter()
+        .find_map(|field| field.strip_prefix("WPos:"))
+        .or_else(|| fields.iter().find_map(|field| field.strip_prefix("MPos:")))
+        .ok_or_else(invalid)?;
+
+    let values: Vec<f64> = position
+        .split(',')
+        .map(|value| value.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid())?;
+    match values[..] {
+        [x, y, ..] => Ok(Status {
+            position: Coordinate::new(options.convert_back(x), options.convert_back(y)),
+            pen_down: false,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+/// Streams G-code to GRBL.
+///
+/// Lines are sent ahead of their responses for as long as they fit in the receive buffer of
+/// GRBL, which keeps its planner full. That's GRBL's character-counting flow control.
+pub struct Driver {
+    file: Connection,
+    options: Options,
+
+    /// Lines that GRBL hasn't acknowledged yet.
+    in_flight: VecDeque<String>,
+
+    /// Whether the pen was lowered last.
+    pen_down: bool,
+}
+
+impl Driver {
+    pub fn open(path: &path::Path, options: Options) -> Result<Self, Error> {
+        Self::open_with_timeout(path, default_port_settings(), DEFAULT_TIMEOUT, options)
+    }
+
+    /// Open GRBL, reset it and fail reads and writes that take longer than `timeout`.
+    pub fn open_with_timeout(
+        path: &path::Path,
+        settings: PortSettings,
+        timeout: Duration,
+        options: Options,
+    ) -> Result<Self, Error> {
+        let mut port = serial::open(path)?;
+        port.set_timeout(timeout)?;
+        port.configure(&settings)?;
+
+        let mut driver = Self::from_transport(port, options);
+        driver.reset()?;
+
+        Ok(driver)
+    }
+
+    /// Create a driver that talks to GRBL through `transport`, for example a `Replay`.
+    pub fn from_transport(transport: impl Transport + 'static, options: Options) -> Self {
+        Self {
+            file: Connection::new(transport),
+            options,
+            in_flight: VecDeque::new(),
+            pen_down: false,
+        }
+    }
+
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Soft-reset GRBL and wait for its welcome message, like "Grbl 1.1h ['$' for help]".
+    pub fn reset(&mut self) -> Result<String, Error> {
+        self.in_flight.clear();
+        self.file
+            .write_all(b"\x18")
+            .map_err(|err| Error::CommandError("soft reset".to_string(), err))?;
+
+        loop {
+            let line = self.read_line("soft reset")?;
+            if line.starts_with("Grbl ") {
+                return Ok(line);
+            }
+        }
+    }
+
+    /// Read a line sent by GRBL. `context` describes what's being waited for.
+    fn read_line(&mut self, context: &str) -> Result<String, Error> {
+        let mut line = String::new();
+        loop {
+            let mut buffer = [0; 1];
+            self.file
+                .read_exact(&mut buffer)
+                .map_err(|err| Error::ResponseError(context.to_string(), err))?;
+
+            match buffer[0] {
+                b'\n' if line.is_empty() => {}
+                b'\n' => break,
+                b'\r' => {}
+                byte => line.push(byte as char),
+            }
+        }
+        debug!("Response {:?}", line);
+
+        Ok(line)
+    }
+
+    /// Wait for the response to the oldest line in flight and return the lines GRBL sent before
+    /// it, like the report of `$I`.
+    fn acknowledge(&mut self) -> Result<Vec<String>, Error> {
+        let line = self.in_flight.pop_front().unwrap_or_default();
+
+        let mut messages = vec![];
+        loop {
+            let response = self.read_line(&line)?;
+            if response == "ok" {
+                return Ok(messages);
+            }
+            if response.starts_with("error:") || response.starts_with("ALARM:") {
+                return Err(Error::GrblError(line, response));
+            }
+            messages.push(response);
+        }
+    }
+
+    /// Send a line of G-code. Blocks until it fits in the receive buffer of GRBL.
+    pub fn send(&mut self, line: &str) -> Result<(), Error> {
+        let _span = trace_span!("line", line).entered();
+        let length = |line: &str| line.len() + 1;
+        while !self.in_flight.is_empty()
+            && self
+                .in_flight
+                .iter()
+                .map(|line| length(line))
+                .sum::<usize>()
+                + length(line)
+                > RX_BUFFER_SIZE
+        {
+            self.acknowledge()?;
+        }
+
+        debug!("Writing line: {:?}", line);
+        self.file
+            .write_all(format!("{}\n", line).as_bytes())
+            .map_err(|err| Error::CommandError(line.to_string(), err))?;
+        self.in_flight.push_back(line.to_string());
+
+        Ok(())
+    }
+
+    /// Wait until GRBL acknowledged all lines sent.
+    pub fn sync(&mut self) -> Result<(), Error> {
+        while !self.in_flight.is_empty() {
+            self.acknowledge()?;
+        }
+
+        Ok(())
+    }
+
+    /// Send a line and return the lines GRBL sent before acknowledging it.
+    pub fn query(&mut self, line: &str) -> Result<Vec<String>, Error> {
+        self.sync()?;
+        self.send(line)?;
+        self.acknowledge()
+    }
+
+    fn send_all(&mut self, lines: &[String]) -> Result<(), Error> {
+        for line in lines {
+            self.send(line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Plotter for Driver {
+    fn plot_with_progress(
+        &mut self,
+        plot: &Plot,
+        progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let program = Program::new(plot, &self.options)?;
+        let total = program.strokes.len();
+        let _span = info_span!("plot", strokes = total).entered();
+
+        self.send_all(&program.header)?;
+        for (index, stroke) in program.strokes.iter().enumerate() {
+            let _span = info_span!("stroke", index, lines = stroke.len()).entered();
+            info!("Plotting stroke {}", index + 1);
+            self.send_all(stroke)?;
+            self.sync()?;
+            self.pen_down = false;
+            progress(index + 1, total)?;
+        }
+        self.send_all(&program.footer)?;
+
+        self.sync()
+    }
+
+    fn log_traffic(&mut self, log: TrafficLog) {
+        self.file.log_traffic(log);
+    }
+
+    fn pen_up(&mut self) -> Result<(), Error> {
+        self.send_all(&lines(&self.options.pen_up))?;
+        self.pen_down = false;
+        self.sync()
+    }
+
+    fn pen_down(&mut self) -> Result<(), Error> {
+        self.send_all(&lines(&self.options.pen_down))?;
+        self.pen_down = true;
+        self.sync()
+    }
+
+    /// Move by a distance in units of the `Plot`, with `G1` at the feed rate when the pen is
+    /// down.
+    fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+        let (x, y) = (self.options.convert(delta.x), self.options.convert(delta.y));
+        let motion = if self.pen_down {
+            format!("G1 X{:.3} Y{:.3} F{}", x, y, self.options.feed_rate)
+        } else {
+            format!("G0 X{:.3} Y{:.3}", x, y)
+        };
+
+        self.send("G91")?;
+        self.send(&motion)?;
+        self.send("G90")?;
+        self.sync()
+    }
+
+    fn home(&mut self) -> Result<(), Error> {
+        self.pen_up()?;
+        self.send("G90")?;
+        self.send("G0 X0 Y0")?;
+        self.sync()
+    }
+
+    fn set_origin(&mut self) -> Result<(), Error> {
+        self.send("G92 X0 Y0")?;
+        self.sync()
+    }
+
+    fn status(&mut self) -> Result<Status, Error> {
+        self.sync()?;
+
+        // `?` is a realtime command: GRBL answers right away and doesn't acknowledge it.
+        self.file
+            .write_all(b"?")
+            .map_err(|err| Error::CommandError("?".to_string(), err))?;
+        loop {
+            let response = self.read_line("?")?;
+            if response.starts_with('<') {
+                return Ok(Status {
+                    pen_down: self.pen_down,
+                    ..parse_status(&response, &self.options)?
+                });
+            }
+        }
+    }
+
+    /// Put GRBL to sleep, which disables the motors. GRBL needs a reset afterwards.
+    fn disable_motors(&mut self) -> Result<(), Error> {
+        self.send("$SLP")?;
+        self.sync()
+    }
+
+    fn version(&mut self) -> Result<String, Error> {
+        // The build info looks like "[VER:1.1h.20190825:]".
+        self.query("$I")?
+            .iter()
+            .find_map(|line| line.strip_prefix("[VER:"))
+            .map(|version| format!("Grbl {}", version.trim_end_matches([']', ':'])))
+            .ok_or_else(|| Error::ConversionError("GRBL didn't report its version.".to_string()))
+    }
+
+    fn y_up(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traffic::Replay;
+
+    fn line() -> Plot {
+        Plot::from_path(vec![Coordinate::new(0, 0), Coordinate::new(100, 50)]).unwrap()
+    }
+
+    #[test]
+    fn z_axis_in_inches() {
+        let options = Options {
+            units_per_mm: 10.0,
+            units: Units::Inches,
+            header: "{units}\n; feed {feed_rate}\n{pen_up}".to_string(),
+            ..Options::z_axis(5.0, 0.0, 500.0)
+        };
+
+        assert_eq!(
+            to_gcode(&line(), &options).unwrap(),
+            "G20\n; feed 1000\nG0 Z5\nG0 X0.000 Y0.000\nG1 Z0 F500\nG1 X0.394 Y0.197 F1000\nG0 Z5\nG0 X0 Y0\n"
+        );
+    }
+
+    #[test]
+    fn parse_status_report() {
+        let options = Options {
+            units_per_mm: 10.0,
+            ..Default::default()
+        };
+        let status = parse_status("<Idle|MPos:1.000,2.500,0.000|FS:0,0>", &options).unwrap();
+        assert_eq!(status.position, Coordinate::new(10, 25));
+        assert!(parse_status("<Idle|FS:0,0>", &options).is_err());
+    }
+
+    #[test]
+    fn stream_with_character_counting() {
+        // Lines are sent before earlier lines are acknowledged, as long as they fit in the
+        // receive buffer.
+        let replay = Replay::parse(
+            r#"
+            0.0 > "G21\n"
+            0.1 > "G90\n"
+            0.2 > "M5\n"
+            0.3 > "G0 X0.000 Y0.000\n"
+            0.4 > "M3 S1000\n"
+            0.5 > "G1 X100.000 Y50.000 F1000\n"
+            0.6 > "M5\n"
+            0.7 < "ok\nok\nok\nok\nok\nok\nok\n"
+            0.8 > "G0 X0 Y0\n"
+            0.9 < "ok\n"
+            "#,
+        )
+        .unwrap();
+        let mut driver = Driver::from_transport(replay, Options::default());
+
+        let mut progress = vec![];
+        driver
+            .plot_with_progress(&line(), &mut |done, total| {
+                progress.push((done, total));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(progress, vec![(1, 1)]);
+    }
+
+    #[test]
+    fn report_errors() {
+        let replay = Replay::parse(
+            r#"
+            0.0 > "G92 X0 Y0\n"
+            0.1 < "error:20\n"
+            "#,
+        )
+        .unwrap();
+        let mut driver = Driver::from_transport(replay, Options::default());
+
+        match driver.set_origin() {
+            Err(Error::GrblError(line, response)) => {
+                assert_eq!(line, "G92 X0 Y0");
+                assert_eq!(response, "error:20");
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+}
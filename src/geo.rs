@@ -0,0 +1,421 @@
+//! Geographic data shared by the GPX, GeoJSON and KML importers: features with their
+//! properties, and how they are projected onto the plot at a scale.
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::point::Coordinate;
+use crate::preview::{ROLAND_DXY_PLOTTING_AREA, ROLAND_DXY_UNITS_PER_INCH};
+use crate::projection::{self, Projection, Scale};
+use crate::{Error, Layer, Path, Paths, Plot};
+
+/// Latitude and longitude in degrees.
+pub type LatLon = (f32, f32);
+
+/// A line of the map, like a track segment or the ring of a polygon.
+pub type Line = Vec<LatLon>;
+
+/// A feature of a map: lines with properties, like a `Placemark` of KML.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Feature {
+    pub properties: BTreeMap<String, String>,
+
+    /// Colour of the lines, like "#ff0000".
+    pub colour: Option<String>,
+
+    pub lines: Vec<Line>,
+
+    /// Points of interest, like waypoints of GPX.
+    pub points: Vec<LatLon>,
+}
+
+impl Feature {
+    pub fn name(&self) -> Option<&str> {
+        self.properties.get("name").map(String::as_str)
+    }
+}
+
+/// Selects features by property, parsed from "key=value".
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Selector {
+    pub key: String,
+    pub value: String,
+}
+
+impl Selector {
+    pub fn matches(&self, feature: &Feature) -> bool {
+        self.matches_properties(&feature.properties)
+    }
+
+    pub fn matches_properties(&self, properties: &BTreeMap<String, String>) -> bool {
+        properties.get(&self.key) == Some(&self.value)
+    }
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid selector {:?}, expected \"key=value\".", s))?;
+
+        Ok(Self {
+            key: key.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+/// How features are projected onto the plot.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    pub projection: Projection,
+    pub scale: Scale,
+
+    /// Number of units of the plot per millimeter on paper.
+    pub units_per_mm: f64,
+
+    /// Width and height of the page in units of the plot, filled by `Scale::Fit`.
+    pub page: (f64, f64),
+
+    /// Space around the map on the page, in millimeters.
+    pub margin: f64,
+
+    /// Only project the features that match.
+    pub selector: Option<Selector>,
+}
+
+impl Default for Options {
+    /// UTM, fit to the plotting area of a Roland DXY.
+    fn default() -> Self {
+        Self {
+            projection: Projection::default(),
+            scale: Scale::default(),
+            units_per_mm: ROLAND_DXY_UNITS_PER_INCH / 25.4,
+            page: ROLAND_DXY_PLOTTING_AREA,
+            margin: 0.0,
+            selector: None,
+        }
+    }
+}
+
+/// A scale bar: a round distance on the ground and its length on the plot.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ScaleBar {
+    pub meters: f64,
+
+    /// Length in units of the plot.
+    pub length: f64,
+}
+
+/// Features projected onto a `Plot`.
+pub struct Map {
+    pub plot: Plot,
+
+    /// The projection, with the UTM zone or standard parallel filled in.
+    pub projection: Projection,
+
+    /// The cartographic scale, like 25 000 for 1:25 000.
+    pub scale: f64,
+
+    /// A scale bar of at most a quarter of the width of the map.
+    pub scale_bar: ScaleBar,
+
+    /// Width of the map on the ground, in meters.
+    pub width: f64,
+
+    /// Width and height of the page, and the margin, in units of the plot.
+    pub page: (f64, f64),
+    pub margin: f64,
+
+    pub units_per_mm: f64,
+
+    /// Units of the plot per meter on the ground.
+    pub units_per_meter: f64,
+
+    /// The centre of the map.
+    pub centre: LatLon,
+
+    /// Named points of the features, like waypoints.
+    pub waypoints: Vec<(Option<String>, LatLon)>,
+
+    /// Projected coordinates of the origin of the plot.
+    origin: (f64, f64),
+
+    /// Units of the plot per projected meter.
+    factor: f64,
+}
+
+impl Map {
+    /// The position of a point on the plot.
+    pub fn to_plot(&self, point: LatLon) -> (f64, f64) {
+        self.projected_to_plot(self.projection.project(point))
+    }
+
+    /// The position of projected coordinates on the plot.
+    pub fn projected_to_plot(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            (x - self.origin.0) * self.factor,
+            (self.origin.1 - y) * self.factor,
+        )
+    }
+
+    /// The projected coordinates of a position on the plot.
+    pub fn plot_to_projected(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (
+            self.origin.0 + x / self.factor,
+            self.origin.1 - y / self.factor,
+        )
+    }
+
+    /// The area of the page inside the margin: left, top, right and bottom.
+    pub fn area(&self) -> (f64, f64, f64, f64) {
+        (
+            self.margin,
+            self.margin,
+            self.page.0 - self.margin,
+            self.page.1 - self.margin,
+        )
+    }
+}
+
+/// Mean radius of the earth in meters.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Distance in meters between two positions along a great circle, by the haversine formula.
+pub fn distance(a: LatLon, b: LatLon) -> f64 {
+    let (lat_a, lon_a) = (f64::from(a.0).to_radians(), f64::from(a.1).to_radians());
+    let (lat_b, lon_b) = (f64::from(b.0).to_radians(), f64::from(b.1).to_radians());
+    let h = ((lat_b - lat_a) / 2.0).sin().powi(2)
+        + lat_a.cos() * lat_b.cos() * ((lon_b - lon_a) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS * h.sqrt().min(1.0).asin()
+}
+
+/// The centre of the bounding box of the points of lines that can be plotted.
+fn centre(lines: &[&Line]) -> LatLon {
+    let points = || {
+        lines
+            .iter()
+            .filter(|line| line.len() > 1)
+            .flat_map(|line| line.iter())
+    };
+    let bounds = |values: Vec<f32>| {
+        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        if min.is_finite() {
+            (min + max) / 2.0
+        } else {
+            0.0
+        }
+    };
+
+    (
+        bounds(points().map(|point| point.0).collect()),
+        bounds(points().map(|point| point.1).collect()),
+    )
+}
+
+/// Project the features that match the selector into a `Plot` with a `Layer` per feature. North
+/// is up. The map is moved so its smallest coordinates are at the margin.
+pub fn project(features: Vec<Feature>, options: &Options) -> Result<Map, Error> {
+    let features: Vec<Feature> = features
+        .into_iter()
+        .filter(|feature| {
+            options
+                .selector
+                .as_ref()
+                .is_none_or(|selector| selector.matches(feature))
+        })
+        .collect();
+
+    let lines: Vec<&Line> = features.iter().flat_map(|feature| &feature.lines).collect();
+    let centre = centre(&lines);
+    let projection = options.projection.resolve(centre);
+    let (_, _, k) = projection.project_with_scale(centre);
+
+    let projected: Vec<Vec<(f64, f64)>> = lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|point| projection.project(*point))
+                .collect()
+        })
+        .collect();
+
+    // Paths of a single point are dropped by `Paths`, so they don't count.
+    let plottable = || projected.iter().filter(|line| line.len() > 1).flatten();
+    let min_x = plottable()
+        .map(|point| point.0)
+        .fold(f64::INFINITY, f64::min);
+    let max_x = plottable()
+        .map(|point| point.0)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = plottable()
+        .map(|point| point.1)
+        .fold(f64::INFINITY, f64::min);
+    let max_y = plottable()
+        .map(|point| point.1)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_x, max_y) = if min_x.is_finite() {
+        (min_x, max_y)
+    } else {
+        (0.0, 0.0)
+    };
+
+    // Size of the map on the ground, in meters.
+    let width = (max_x - min_x).max(0.0) / k;
+    let height = (max_y - min_y).max(0.0) / k;
+    let margin = options.margin * options.units_per_mm;
+    let scale = match options.scale {
+        Scale::Ratio(ratio) => ratio,
+        Scale::Fit => {
+            let page_width = (options.page.0 - 2.0 * margin) / options.units_per_mm / 1000.0;
+            let page_height = (options.page.1 - 2.0 * margin) / options.units_per_mm / 1000.0;
+            let ratio = (width / page_width).max(height / page_height);
+            if ratio > 0.0 && ratio.is_finite() {
+                ratio
+            } else {
+                1.0
+            }
+        }
+    };
+
+    // Units of the plot per meter on the ground, and per projected meter.
+    let units_per_meter = 1000.0 / scale * options.units_per_mm;
+    let factor = units_per_meter / k;
+    let origin = (min_x - margin / factor, max_y + margin / factor);
+
+    let mut paths = projected.into_iter().map(|line| -> Path {
+        line.into_iter()
+            .map(|(x, y)| {
+                Coordinate::new(
+                    ((x - origin.0) * factor).round() as i32,
+                    ((origin.1 - y) * factor).round() as i32,
+                )
+            })
+            .collect()
+    });
+    let waypoints = features
+        .iter()
+        .flat_map(|feature| {
+            let name = feature.name().map(str::to_string);
+            feature
+                .points
+                .iter()
+                .map(move |point| (name.clone(), *point))
+        })
+        .collect();
+
+    let layers = features
+        .into_iter()
+        .map(|feature| {
+            let feature_paths = paths.by_ref().take(feature.lines.len()).collect();
+            let layer = Layer {
+                name: feature.name().map(str::to_string),
+                colour: feature.colour,
+                properties: feature.properties,
+                paths: vec![],
+            };
+            (layer, feature_paths)
+        })
+        .collect();
+
+    let map_width = if width > 0.0 {
+        width
+    } else {
+        options.page.0 / units_per_meter
+    };
+    let meters = projection::round_distance(map_width / 4.0);
+
+    Ok(Map {
+        plot: Plot::new(Paths::with_layers(layers)?),
+        projection,
+        scale,
+        scale_bar: ScaleBar {
+            meters,
+            length: meters * units_per_meter,
+        },
+        width: map_width,
+        page: options.page,
+        margin,
+        units_per_mm: options.units_per_mm,
+        units_per_meter,
+        centre,
+        waypoints,
+        origin,
+        factor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select_features() {
+        let feature = |name: &str, line: Line| Feature {
+            properties: BTreeMap::from([("name".to_string(), name.to_string())]),
+            lines: vec![line],
+            ..Default::default()
+        };
+        let features = vec![
+            feature("a", vec![(47.0, 11.0), (47.001, 11.002)]),
+            feature("b", vec![(47.002, 11.0), (47.003, 11.001)]),
+        ];
+
+        let map = project(features.clone(), &Options::default()).unwrap();
+        assert_eq!(map.plot.paths().paths.len(), 2);
+        assert_eq!(
+            map.plot.paths().layer_of(1).unwrap().name.as_deref(),
+            Some("b")
+        );
+
+        let options = Options {
+            selector: Some("name=b".parse().unwrap()),
+            ..Default::default()
+        };
+        let map = project(features, &options).unwrap();
+        let paths = map.plot.paths();
+        assert_eq!(paths.paths.len(), 1);
+        assert_eq!(paths.layers[0].name.as_deref(), Some("b"));
+
+        // North is up: the path goes from the bottom left to the top right.
+        assert_eq!(paths.paths[0][0].x, 0);
+        assert_eq!(paths.paths[0][1].y, 0);
+    }
+
+    #[test]
+    fn project_at_scale() {
+        let features = vec![Feature {
+            lines: vec![vec![(0.0, 0.0), (0.0, 0.01)]],
+            ..Default::default()
+        }];
+        let options = Options {
+            projection: Projection::WebMercator,
+            scale: Scale::Ratio(25_000.0),
+            units_per_mm: 10.0,
+            ..Default::default()
+        };
+
+        // 0.01° along the equator is 1113 m, or 44.5 mm at 1:25 000.
+        let map = project(features.clone(), &options).unwrap();
+        assert_eq!(map.plot.paths().paths[0][1], Coordinate::new(445, 0));
+        assert_eq!(
+            map.scale_bar,
+            ScaleBar {
+                meters: 200.0,
+                length: 80.0
+            }
+        );
+
+        let options = Options {
+            scale: Scale::Fit,
+            page: (1000.0, 1000.0),
+            ..options
+        };
+        let map = project(features, &options).unwrap();
+        assert_eq!(map.plot.dimensions(), (1000, 0));
+        assert!((map.scale - 11_131.95).abs() < 0.01);
+    }
+}
@@ -0,0 +1,209 @@
+//! Build a `Plot` from the features in a GeoJSON file.
+//!
+//! `LineString`, `MultiLineString`, `Polygon` and `MultiPolygon` geometries are read, also inside
+//! a `GeometryCollection`. Polygons are plotted as their rings. Points become waypoints. Every
+//! feature becomes a `Layer` with its properties. The colour is read from the "stroke" property,
+//! as used by simplestyle.
+use std::fs;
+use std::path;
+
+use serde_json::Value;
+
+use crate::geo::{self, Feature, LatLon, Line};
+use crate::{Error, Plot};
+
+/// Read a position, which is `[longitude, latitude]` optionally followed by the altitude.
+fn position(value: &Value) -> Result<LatLon, Error> {
+    match value.as_array().map(Vec::as_slice) {
+        Some([lon, lat, ..]) => match (lat.as_f64(), lon.as_f64()) {
+            (Some(lat), Some(lon)) => Ok((lat as f32, lon as f32)),
+            _ => Err(Error::GeoJsonError(format!("Invalid position {}.", value))),
+        },
+        _ => Err(Error::GeoJsonError(format!("Invalid position {}.", value))),
+    }
+}
+
+/// Read an array of positions.
+fn line(value: &Value) -> Result<Line, Error> {
+    array(value)?.iter().map(position).collect()
+}
+
+/// Read an array of arrays of positions.
+fn lines(value: &Value) -> Result<Vec<Line>, Error> {
+    array(value)?.iter().map(line).collect()
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>, Error> {
+    value
+        .as_array()
+        .ok_or_else(|| Error::GeoJsonError(format!("Expected an array, got {}.", value)))
+}
+
+/// The lines of a geometry.
+fn geometry(value: &Value) -> Result<Vec<Line>, Error> {
+    let coordinates = || {
+        value
+            .get("coordinates")
+            .ok_or_else(|| Error::GeoJsonError("Geometry without coordinates.".to_string()))
+    };
+
+    match value.get("type").and_then(Value::as_str) {
+        Some("LineString") => Ok(vec![line(coordinates()?)?]),
+        Some("MultiLineString") | Some("Polygon") => lines(coordinates()?),
+        Some("MultiPolygon") => Ok(array(coordinates()?)?
+            .iter()
+            .map(lines)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect()),
+        Some("GeometryCollection") => Ok(array(value.get("geometries").unwrap_or(&Value::Null))?
+            .iter()
+            .map(geometry)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect()),
+        _ => Ok(vec![]),
+    }
+}
+
+/// The points of a geometry.
+fn points(value: &Value) -> Result<Vec<LatLon>, Error> {
+    let coordinates = value.get("coordinates").unwrap_or(&Value::Null);
+    match value.get("type").and_then(Value::as_str) {
+        Some("Point") => Ok(vec![position(coordinates)?]),
+        Some("MultiPoint") => line(coordinates),
+        Some("GeometryCollection") => Ok(array(value.get("geometries").unwrap_or(&Value::Null))?
+            .iter()
+            .map(points)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect()),
+        _ => Ok(vec![]),
+    }
+}
+
+/// The text of a property value. Strings are used without quotes.
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn feature(value: &Value) -> Result<Feature, Error> {
+    let properties = value
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(key, value)| (key.clone(), text(value)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(Feature {
+        colour: value
+            .pointer("/properties/stroke")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        properties,
+        lines: match value.get("geometry") {
+            Some(Value::Null) | None => vec![],
+            Some(geometry_value) => geometry(geometry_value)?,
+        },
+        points: match value.get("geometry") {
+            Some(Value::Null) | None => vec![],
+            Some(geometry_value) => points(geometry_value)?,
+        },
+    })
+}
+
+/// The features of a GeoJSON object. A bare geometry is a feature without properties.
+fn object_features(value: &Value) -> Result<Vec<Feature>, Error> {
+    match value.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => array(value.get("features").unwrap_or(&Value::Null))?
+            .iter()
+            .map(feature)
+            .collect(),
+        Some("Feature") => Ok(vec![feature(value)?]),
+        Some(_) => Ok(vec![Feature {
+            lines: geometry(value)?,
+            points: points(value)?,
+            ..Default::default()
+        }]),
+        None => Err(Error::GeoJsonError("Object without type.".to_string())),
+    }
+}
+
+/// Read the features of a GeoJSON document.
+pub fn features(text: &str) -> Result<Vec<Feature>, Error> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|err| Error::GeoJsonError(err.to_string()))?;
+
+    object_features(&value)
+}
+
+/// Build a `Plot` from all features in a GeoJSON document.
+pub fn parse(text: &str) -> Result<Plot, Error> {
+    parse_with(text, &geo::Options::default())
+}
+
+/// Build a `Plot` from the features in a GeoJSON document, projected with `options`.
+pub fn parse_with(text: &str, options: &geo::Options) -> Result<Plot, Error> {
+    Ok(geo::project(features(text)?, options)?.plot)
+}
+
+/// Build a `Plot` from all features in the GeoJSON file at the given location.
+pub fn read(path: &path::Path) -> Result<Plot, Error> {
+    parse(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_features() {
+        let text = r##"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": {"name": "Trail", "stroke": "#ff0000", "length": 2},
+                    "geometry": {"type": "LineString", "coordinates": [[11.0, 47.0], [11.002, 47.001]]}
+                },
+                {
+                    "type": "Feature",
+                    "properties": {"name": "Lake"},
+                    "geometry": {
+                        "type": "Polygon",
+                        "coordinates": [[[11.0, 47.0], [11.001, 47.0], [11.0, 47.001], [11.0, 47.0]]]
+                    }
+                },
+                {"type": "Feature", "properties": {"name": "Peak"}, "geometry": {"type": "Point", "coordinates": [11.0, 47.0]}}
+            ]
+        }"##;
+
+        let plot = parse(text).unwrap();
+        let paths = plot.paths();
+        assert_eq!(paths.paths.len(), 2);
+        assert_eq!(paths.layers.len(), 3);
+        assert_eq!(paths.layers[0].colour.as_deref(), Some("#ff0000"));
+        assert_eq!(paths.layers[0].properties["length"], "2");
+        assert_eq!(paths.layer_of(1).unwrap().name.as_deref(), Some("Lake"));
+
+        // The map is 152 m wide and 222 m high, so its height fits the page.
+        assert_eq!(plot.dimensions().1, geo::Options::default().page.1 as i32);
+
+        let options = geo::Options {
+            selector: Some("name=Lake".parse().unwrap()),
+            ..Default::default()
+        };
+        let plot = parse_with(text, &options).unwrap();
+        assert_eq!(plot.paths().paths.len(), 1);
+    }
+}
@@ -0,0 +1,199 @@
+//! Build a `Plot` from the tracks in a GPX file.
+//!
+//! Tracks are projected by `geo::project()`. Every track becomes a `Layer`, named after the
+//! track. Waypoints become `Feature`s with a single point. `tracks()` keeps the elevation and
+//! time of every track point, like for an elevation profile.
+use std::collections::BTreeMap;
+use std::fs;
+use std::path;
+
+use roxmltree::{Document, Node};
+
+use crate::geo::{self, Feature, LatLon, Line};
+use crate::{Error, Plot};
+
+/// Build a collection of `Path`s from a "trk" element.
+/// A `Path` is build for every "trkseg" child.
+///
+/// <trk>
+///     <trkseg>
+///         ...
+///     </trkseg>
+///     <trkseg>
+///         ...
+///     </trkseg>
+///     ..
+/// </trk>
+fn track_to_paths(node: &Node) -> Result<Vec<Line>, Error> {
+    node.children()
+        .filter(|child| child.has_tag_name("trkseg"))
+        .map(|child| track_segment_to_path(&child))
+        .collect()
+}
+
+/// Read an attribute of an element as float.
+fn float_attribute(node: &Node, name: &str) -> Result<f32, Error> {
+    let position = || node.document().text_pos_at(node.position());
+    node.attribute(name)
+        .ok_or_else(|| {
+            Error::GpxError(format!(
+                "Element <{}> at line {} is missing attribute \"{}\".",
+                node.tag_name().name(),
+                position(),
+                name
+            ))
+        })?
+        .parse()
+        .map_err(|_| {
+            Error::GpxError(format!(
+                "Failed to parse attribute \"{}\" at line {} as float.",
+                name,
+                position()
+            ))
+        })
+}
+
+/// A "trkpt" element: its position with the optional "ele" and "time" children.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TrackPoint {
+    pub position: LatLon,
+
+    /// Elevation in meters.
+    pub elevation: Option<f32>,
+
+    /// Time as written in the file, like "2021-05-01T10:00:00Z".
+    pub time: Option<String>,
+}
+
+/// A "trk" element with the points of every "trkseg".
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Track {
+    pub properties: BTreeMap<String, String>,
+    pub segments: Vec<Vec<TrackPoint>>,
+}
+
+/// Text of the child of `node` with the given tag name.
+fn child_text<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.has_tag_name(name))
+        .and_then(|child| child.text())
+        .map(str::trim)
+}
+
+/// Build the points of the "trkpt" elements inside an "trgseg" element.
+///
+/// <trksg>
+///     <trkpt lat="1" lon="2"><ele>120.5</ele></trkpt>
+///     <trkpt lat="2" lon="3"></trkpt>
+///     ..
+/// </trksg>
+fn track_segment_to_points(node: &Node) -> Result<Vec<TrackPoint>, Error> {
+    node.children()
+        .filter(|child| child.has_tag_name("trkpt"))
+        .map(|child| {
+            let lat = float_attribute(&child, "lat")?;
+            let lon = float_attribute(&child, "lon")?;
+            let elevation = child_text(&child, "ele")
+                .map(|text| {
+                    text.parse().map_err(|_| {
+                        Error::GpxError(format!(
+                            "Failed to parse elevation \"{}\" at line {} as float.",
+                            text,
+                            node.document().text_pos_at(child.position())
+                        ))
+                    })
+                })
+                .transpose()?;
+
+            Ok(TrackPoint {
+                position: (lat, lon),
+                elevation,
+                time: child_text(&child, "time").map(String::from),
+            })
+        })
+        .collect()
+}
+
+/// Build a Path from the "trkpt" elements inside an "trgseg" element.
+fn track_segment_to_path(node: &Node) -> Result<Line, Error> {
+    Ok(track_segment_to_points(node)?
+        .into_iter()
+        .map(|point| point.position)
+        .collect())
+}
+
+/// Properties of a "trk" or "wpt" element: the text of its "name" child.
+fn properties(node: &Node) -> BTreeMap<String, String> {
+    child_text(node, "name")
+        .map(|name| BTreeMap::from([("name".to_string(), name.to_string())]))
+        .unwrap_or_default()
+}
+
+/// Build a `Feature` from a "trk" element, named after its "name" child.
+fn track_to_feature(node: &Node) -> Result<Feature, Error> {
+    Ok(Feature {
+        properties: properties(node),
+        lines: track_to_paths(node)?,
+        ..Default::default()
+    })
+}
+
+/// Build a `Feature` from a "wpt" element.
+///
+/// <wpt lat="1" lon="2">
+///     <name>Summit</name>
+/// </wpt>
+fn waypoint_to_feature(node: &Node) -> Result<Feature, Error> {
+    Ok(Feature {
+        properties: properties(node),
+        points: vec![(float_attribute(node, "lat")?, float_attribute(node, "lon")?)],
+        ..Default::default()
+    })
+}
+
+/// Read the tracks and waypoints of a GPX document as `Feature`s.
+pub fn features(text: &str) -> Result<Vec<Feature>, Error> {
+    let doc = Document::parse(text).map_err(|err| Error::GpxError(err.to_string()))?;
+
+    doc.descendants()
+        .filter_map(|node| match node.tag_name().name() {
+            "trk" => Some(track_to_feature(&node)),
+            "wpt" => Some(waypoint_to_feature(&node)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Read the tracks of a GPX document with the elevation and time of every point.
+pub fn tracks(text: &str) -> Result<Vec<Track>, Error> {
+    let doc = Document::parse(text).map_err(|err| Error::GpxError(err.to_string()))?;
+
+    doc.descendants()
+        .filter(|node| node.has_tag_name("trk"))
+        .map(|node| {
+            Ok(Track {
+                properties: properties(&node),
+                segments: node
+                    .children()
+                    .filter(|child| child.has_tag_name("trkseg"))
+                    .map(|child| track_segment_to_points(&child))
+                    .collect::<Result<_, Error>>()?,
+            })
+        })
+        .collect()
+}
+
+/// Build a `Plot` from all tracks in a GPX document, with a `Layer` per track.
+pub fn parse(text: &str) -> Result<Plot, Error> {
+    parse_with(text, &geo::Options::default())
+}
+
+/// Build a `Plot` from the tracks in a GPX document, projected with `options`.
+pub fn parse_with(text: &str, options: &geo::Options) -> Result<Plot, Error> {
+    Ok(geo::project(features(text)?, options)?.plot)
+}
+
+/// Build a `Plot` from all tracks in the GPX file at the given location.
+pub fn read(path: &path::Path) -> Result<Plot, Error> {
+    parse(&fs::read_to_string(path)?)
+}
@@ -0,0 +1,588 @@
+//! Render grayscale images, like photos, as plots.
+//!
+//! The image is scaled to fit the size of the plot, keeping its aspect ratio. Dark parts of the
+//! image become dense lines or points:
+//!
+//! - `Halftone`: rows of waves, a sine or a zigzag, with an amplitude following the darkness.
+//! - `Spiral`: a single spiral from the centre, with waves like `Halftone`.
+//! - `Stipple`: points placed by weighted Voronoi stippling, joined into a single path by a short
+//!   tour, known as TSP art.
+//! - `Crosshatch`: hatch lines in up to four directions, one more for every band of darkness.
+use std::f64::consts::{PI, TAU};
+use std::fs;
+use std::io::Cursor;
+use std::path;
+use std::str::FromStr;
+
+use crate::cartography;
+use crate::preview::{ROLAND_DXY_PLOTTING_AREA, ROLAND_DXY_UNITS_PER_INCH};
+use crate::text::Point;
+use crate::{Error, Paths, Plot};
+
+/// Darkness from which the hatch lines in every direction are drawn.
+const HATCH_BANDS: [(f64, f64); 4] = [
+    (0.15, PI / 4.0),
+    (0.4, -PI / 4.0),
+    (0.6, 0.0),
+    (0.8, PI / 2.0),
+];
+
+/// Most samples of the image along its longest side, to place stipple points.
+const STIPPLE_SAMPLES: f64 = 400.0;
+
+/// Most rounds of improving the tour through the stipple points.
+const TOUR_ROUNDS: usize = 8;
+
+/// A grayscale image.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+
+    /// Darkness of every pixel, row by row, from 0 for white to 1 for black.
+    pub darkness: Vec<f64>,
+}
+
+impl Image {
+    /// Decode a PNG image. Colours become gray by their luminance, transparent parts are white.
+    pub fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size().unwrap_or_default()];
+        let info = reader.next_frame(&mut buffer)?;
+
+        let channels = info.color_type.samples();
+        let darkness = buffer[..info.buffer_size()]
+            .chunks_exact(channels)
+            .map(|pixel| {
+                let pixel: Vec<f64> = pixel.iter().map(|value| *value as f64 / 255.0).collect();
+                let (luminance, alpha) = match pixel.as_slice() {
+                    [gray] => (*gray, 1.0),
+                    [gray, alpha] => (*gray, *alpha),
+                    [red, green, blue] => (0.299 * red + 0.587 * green + 0.114 * blue, 1.0),
+                    [red, green, blue, alpha, ..] => {
+                        (0.299 * red + 0.587 * green + 0.114 * blue, *alpha)
+                    }
+                    [] => (1.0, 1.0),
+                };
+                (1.0 - luminance) * alpha
+            })
+            .collect();
+
+        Ok(Self {
+            width: info.width as usize,
+            height: info.height as usize,
+            darkness,
+        })
+    }
+
+    /// Read the PNG image at the given location.
+    pub fn read(path: &path::Path) -> Result<Self, Error> {
+        Self::decode(&fs::read(path)?)
+    }
+
+    /// Darkness of the pixel at a position in pixels, 0 outside of the image.
+    pub fn darkness_at(&self, (x, y): Point) -> f64 {
+        if x < 0.0 || y < 0.0 || x >= self.width as f64 || y >= self.height as f64 {
+            return 0.0;
+        }
+        self.darkness[y as usize * self.width + x as usize]
+    }
+}
+
+/// The shape of the waves of `Renderer::Halftone` and `Renderer::Spiral`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Wave {
+    Sine,
+    Zigzag,
+}
+
+/// How an image is rendered.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Renderer {
+    Halftone(Wave),
+    Spiral,
+    Stipple,
+    Crosshatch,
+}
+
+impl FromStr for Renderer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "halftone" => Ok(Self::Halftone(Wave::Sine)),
+            "zigzag" => Ok(Self::Halftone(Wave::Zigzag)),
+            "spiral" => Ok(Self::Spiral),
+            "stipple" => Ok(Self::Stipple),
+            "crosshatch" => Ok(Self::Crosshatch),
+            _ => Err(format!(
+                "Unknown renderer {:?}, expected \"halftone\", \"zigzag\", \"spiral\", \
+                 \"stipple\" or \"crosshatch\".",
+                s
+            )),
+        }
+    }
+}
+
+/// Size and density of the rendered image.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    /// Largest width and height of the plot, in units of the plot.
+    pub size: (f64, f64),
+
+    /// Distance between rows, turns of the spiral and hatch lines, in units of the plot. It is
+    /// also the length of a wave.
+    pub spacing: f64,
+
+    /// Number of points of `Renderer::Stipple`.
+    pub points: usize,
+
+    /// Rounds of moving the stipple points to the centroid of their Voronoi cell.
+    pub iterations: usize,
+}
+
+impl Default for Options {
+    /// The plotting area of a Roland DXY, with rows 1.5 mm apart.
+    fn default() -> Self {
+        Self {
+            size: ROLAND_DXY_PLOTTING_AREA,
+            spacing: 1.5 * ROLAND_DXY_UNITS_PER_INCH / 25.4,
+            points: 2000,
+            iterations: 10,
+        }
+    }
+}
+
+/// An image scaled to the plot.
+struct Canvas<'a> {
+    image: &'a Image,
+
+    /// Units of the plot per pixel.
+    scale: f64,
+    width: f64,
+    height: f64,
+}
+
+impl<'a> Canvas<'a> {
+    fn new(image: &'a Image, size: (f64, f64)) -> Self {
+        let scale = (size.0 / image.width.max(1) as f64).min(size.1 / image.height.max(1) as f64);
+        Self {
+            image,
+            scale,
+            width: image.width as f64 * scale,
+            height: image.height as f64 * scale,
+        }
+    }
+
+    /// Darkness at a position on the plot.
+    fn darkness(&self, (x, y): Point) -> f64 {
+        self.image.darkness_at((x / self.scale, y / self.scale))
+    }
+
+    fn contains(&self, (x, y): Point) -> bool {
+        (0.0..=self.width).contains(&x) && (0.0..=self.height).contains(&y)
+    }
+}
+
+/// Offset of a wave at `phase`, from -1 to 1.
+fn wave(shape: Wave, phase: f64) -> f64 {
+    match shape {
+        Wave::Sine => phase.sin(),
+        Wave::Zigzag => 1.0 - 4.0 * ((phase / TAU).rem_euclid(1.0) - 0.5).abs(),
+    }
+}
+
+/// Distance between the points of a wave `spacing` long: a zigzag only needs its corners.
+fn wave_step(shape: Wave, spacing: f64) -> f64 {
+    match shape {
+        Wave::Sine => spacing / 12.0,
+        Wave::Zigzag => spacing / 2.0,
+    }
+}
+
+/// Rows of waves, every other row from right to left.
+fn halftone(canvas: &Canvas, shape: Wave, spacing: f64) -> Vec<Vec<Point>> {
+    let step = wave_step(shape, spacing);
+    let amplitude = spacing * 0.45;
+    let rows = (canvas.height / spacing).floor() as usize;
+    (0..rows)
+        .map(|row| {
+            let y = (row as f64 + 0.5) * spacing;
+            let count = (canvas.width / step).floor() as usize;
+            let mut line: Vec<Point> = (0..=count)
+                .map(|index| {
+                    let x = index as f64 * step;
+                    let offset = wave(shape, TAU * x / spacing);
+                    (x, y + amplitude * canvas.darkness((x, y)) * offset)
+                })
+                .collect();
+            if row % 2 == 1 {
+                line.reverse();
+            }
+            line
+        })
+        .collect()
+}
+
+/// A spiral from the centre, with turns `spacing` apart, split where it leaves the image.
+fn spiral(canvas: &Canvas, spacing: f64) -> Vec<Vec<Point>> {
+    let centre = (canvas.width / 2.0, canvas.height / 2.0);
+    let end = centre.0.hypot(centre.1);
+    let step = wave_step(Wave::Sine, spacing);
+    let amplitude = spacing * 0.45;
+
+    let mut lines = vec![];
+    let mut line = vec![];
+    let (mut angle, mut length) = (0.0, 0.0);
+    loop {
+        let radius = spacing * angle / TAU;
+        if radius > end {
+            break;
+        }
+        let direction = (angle.cos(), angle.sin());
+        let on_spiral = (
+            centre.0 + radius * direction.0,
+            centre.1 + radius * direction.1,
+        );
+        let offset =
+            amplitude * canvas.darkness(on_spiral) * wave(Wave::Sine, TAU * length / spacing);
+        let point = (
+            on_spiral.0 + offset * direction.0,
+            on_spiral.1 + offset * direction.1,
+        );
+        if canvas.contains(point) {
+            line.push(point);
+        } else if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        // Advance by about `step` along the spiral, also near the centre.
+        let delta = step / radius.max(spacing);
+        angle += delta;
+        length += step;
+    }
+    lines.push(line);
+    lines
+}
+
+/// Random numbers by xorshift, to place the same points for the same image.
+struct Random(u64);
+
+impl Random {
+    /// A number from 0 up to 1.
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Points in buckets of a grid, to find the nearest point fast.
+struct Buckets {
+    size: f64,
+    columns: usize,
+    rows: usize,
+    buckets: Vec<Vec<usize>>,
+}
+
+impl Buckets {
+    fn new(points: &[Point], width: f64, height: f64) -> Self {
+        let size = (width * height / points.len().max(1) as f64)
+            .sqrt()
+            .max(1.0);
+        let columns = (width / size).ceil().max(1.0) as usize;
+        let rows = (height / size).ceil().max(1.0) as usize;
+        let mut buckets = vec![vec![]; columns * rows];
+        for (index, point) in points.iter().enumerate() {
+            let (column, row) = Self::cell(size, columns, rows, *point);
+            buckets[row * columns + column].push(index);
+        }
+        Self {
+            size,
+            columns,
+            rows,
+            buckets,
+        }
+    }
+
+    fn cell(size: f64, columns: usize, rows: usize, (x, y): Point) -> (usize, usize) {
+        (
+            ((x / size).max(0.0) as usize).min(columns - 1),
+            ((y / size).max(0.0) as usize).min(rows - 1),
+        )
+    }
+
+    /// Index of the point nearest to `target`, searching rings of buckets around it.
+    fn nearest(&self, points: &[Point], target: Point) -> Option<usize> {
+        let (column, row) = Self::cell(self.size, self.columns, self.rows, target);
+        let mut best: Option<(usize, f64)> = None;
+        for ring in 0..=self.columns.max(self.rows) {
+            if best.is_some_and(|(_, distance)| distance <= (ring as f64 - 1.0) * self.size) {
+                break;
+            }
+            let (left, right) = (column as i64 - ring as i64, column as i64 + ring as i64);
+            let (top, bottom) = (row as i64 - ring as i64, row as i64 + ring as i64);
+            for y in top.max(0)..=bottom.min(self.rows as i64 - 1) {
+                for x in left.max(0)..=right.min(self.columns as i64 - 1) {
+                    if x != left && x != right && y != top && y != bottom {
+                        continue;
+                    }
+                    for index in &self.buckets[y as usize * self.columns + x as usize] {
+                        let (px, py) = points[*index];
+                        let distance = (px - target.0).hypot(py - target.1);
+                        if best.is_none_or(|(_, nearest)| distance < nearest) {
+                            best = Some((*index, distance));
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+}
+
+/// Place `count` points by darkness, then move them to the centroid of their Voronoi cell weighted
+/// by darkness, after Secord.
+fn stipple_points(canvas: &Canvas, count: usize, iterations: usize) -> Vec<Point> {
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    let mut points = vec![];
+    for _ in 0..count * 1000 {
+        if points.len() == count {
+            break;
+        }
+        let point = (random.next() * canvas.width, random.next() * canvas.height);
+        if random.next() < canvas.darkness(point) {
+            points.push(point);
+        }
+    }
+
+    let step = canvas.width.max(canvas.height) / STIPPLE_SAMPLES;
+    let samples: Vec<(Point, f64)> = (0..(canvas.height / step) as usize)
+        .flat_map(|row| {
+            (0..(canvas.width / step) as usize)
+                .map(move |column| ((column as f64 + 0.5) * step, (row as f64 + 0.5) * step))
+        })
+        .map(|point| (point, canvas.darkness(point)))
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect();
+
+    for _ in 0..iterations {
+        let buckets = Buckets::new(&points, canvas.width, canvas.height);
+        let mut sums = vec![(0.0, 0.0, 0.0); points.len()];
+        for ((x, y), weight) in &samples {
+            if let Some(index) = buckets.nearest(&points, (*x, *y)) {
+                let sum = &mut sums[index];
+                *sum = (sum.0 + x * weight, sum.1 + y * weight, sum.2 + weight);
+            }
+        }
+        for (point, (x, y, weight)) in points.iter_mut().zip(sums) {
+            if weight > 0.0 {
+                *point = (x / weight, y / weight);
+            }
+        }
+    }
+
+    points
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+/// A short tour through the points: from the nearest neighbour, improved by 2-opt.
+fn tour(mut points: Vec<Point>) -> Vec<Point> {
+    for index in 1..points.len() {
+        let last = points[index - 1];
+        let nearest = (index..points.len())
+            .min_by(|a, b| distance(last, points[*a]).total_cmp(&distance(last, points[*b])))
+            .unwrap();
+        points.swap(index, nearest);
+    }
+
+    for _ in 0..TOUR_ROUNDS {
+        let mut improved = false;
+        for i in 0..points.len().saturating_sub(2) {
+            for j in i + 2..points.len() {
+                let (a, b, c) = (points[i], points[i + 1], points[j]);
+                // Reversing the part from `b` to `c` joins `a` to `c` and `b` to the point after.
+                let gain = match points.get(j + 1) {
+                    Some(d) => distance(a, b) + distance(c, *d) - distance(a, c) - distance(b, *d),
+                    None => distance(a, b) - distance(a, c),
+                };
+                if gain > 1e-9 {
+                    points[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+
+    points
+}
+
+/// Hatch lines `spacing` apart in a direction for every band the darkness exceeds.
+fn crosshatch(canvas: &Canvas, spacing: f64) -> Vec<Vec<Point>> {
+    let step = spacing / 4.0;
+    let centre = (canvas.width / 2.0, canvas.height / 2.0);
+    let reach = centre.0.hypot(centre.1);
+
+    let mut lines = vec![];
+    for (threshold, angle) in HATCH_BANDS {
+        let along = (angle.cos(), angle.sin());
+        let across = (-along.1, along.0);
+        let count = (reach / spacing).ceil() as i64;
+        for (index, offset) in (-count..=count).enumerate() {
+            let offset = offset as f64 * spacing;
+            let mut positions: Vec<f64> = (0..=(2.0 * reach / step) as usize)
+                .map(|index| -reach + index as f64 * step)
+                .collect();
+            if index % 2 == 1 {
+                positions.reverse();
+            }
+
+            let mut line = vec![];
+            for position in positions {
+                let point = (
+                    centre.0 + across.0 * offset + along.0 * position,
+                    centre.1 + across.1 * offset + along.1 * position,
+                );
+                if canvas.contains(point) && canvas.darkness(point) > threshold {
+                    line.push(point);
+                } else if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+            }
+            lines.push(line);
+        }
+    }
+
+    lines.retain(|line| line.len() > 1);
+    lines
+}
+
+/// Render an image as a `Plot`.
+pub fn render(image: &Image, renderer: Renderer, options: &Options) -> Result<Plot, Error> {
+    let canvas = Canvas::new(image, options.size);
+    let spacing = options.spacing.max(1.0);
+    let lines = match renderer {
+        Renderer::Halftone(shape) => halftone(&canvas, shape, spacing),
+        Renderer::Spiral => spiral(&canvas, spacing),
+        Renderer::Stipple => {
+            vec![tour(stipple_points(
+                &canvas,
+                options.points,
+                options.iterations,
+            ))]
+        }
+        Renderer::Crosshatch => crosshatch(&canvas, spacing),
+    };
+
+    Ok(Plot::new(Paths::new(cartography::to_paths(lines))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An image from white at the left to black at the right.
+    fn gradient() -> Image {
+        Image {
+            width: 20,
+            height: 10,
+            darkness: (0..200).map(|index| (index % 20) as f64 / 19.0).collect(),
+        }
+    }
+
+    fn options() -> Options {
+        Options {
+            size: (2000.0, 2000.0),
+            spacing: 100.0,
+            points: 200,
+            iterations: 5,
+        }
+    }
+
+    #[test]
+    fn decode_png() {
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, 2, 1);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer
+            .write_image_data(&[0, 0, 0, 255, 0, 0, 0, 0])
+            .unwrap();
+        writer.finish().unwrap();
+
+        let image = Image::decode(&bytes).unwrap();
+        assert_eq!((image.width, image.height), (2, 1));
+        // Black, and transparent black on white paper.
+        assert_eq!(image.darkness, vec![1.0, 0.0]);
+        assert!(Image::decode(b"not a png").is_err());
+    }
+
+    #[test]
+    fn render_waves() {
+        let plot = render(&gradient(), Renderer::Halftone(Wave::Zigzag), &options()).unwrap();
+        let paths = &plot.paths().paths;
+        // The image is 2000 x 1000 units, with rows 100 units apart.
+        assert_eq!(paths.len(), 10);
+        let amplitude = |x: i32| {
+            paths[0]
+                .iter()
+                .filter(|point| (point.x - x).abs() < 100)
+                .map(|point| (point.y - 50).abs())
+                .max()
+                .unwrap()
+        };
+        assert!(amplitude(100) < amplitude(1900));
+        assert!(amplitude(1900) <= 45);
+
+        let plot = render(&gradient(), Renderer::Spiral, &options()).unwrap();
+        assert!(plot
+            .paths()
+            .paths
+            .iter()
+            .flatten()
+            .all(|point| (0..=2000).contains(&point.x) && (0..=1000).contains(&point.y)));
+    }
+
+    #[test]
+    fn render_stipple_and_hatches() {
+        let plot = render(&gradient(), Renderer::Stipple, &options()).unwrap();
+        let paths = &plot.paths().paths;
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].len(), 200);
+        let dark = paths[0].iter().filter(|point| point.x > 1000).count();
+        assert!(dark > 140, "{}", dark);
+
+        // Only the darkest part gets hatch lines in all four directions.
+        let plot = render(&gradient(), Renderer::Crosshatch, &options()).unwrap();
+        let vertical = plot
+            .paths()
+            .paths
+            .iter()
+            .filter(|path| path.iter().all(|point| point.x == path[0].x))
+            .count();
+        assert!(vertical > 0);
+        assert!(plot
+            .paths()
+            .paths
+            .iter()
+            .flatten()
+            .all(|point| point.x >= 2000 * 15 / 100 - 100));
+
+        let white = Image {
+            width: 2,
+            height: 2,
+            darkness: vec![0.0; 4],
+        };
+        assert!(render(&white, Renderer::Crosshatch, &options()).is_err());
+    }
+}
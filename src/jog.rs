@@ -0,0 +1,304 @@
+//! Move the carriage of a plotter by hand using the keyboard.
+//!
+//! Useful to set up the pen height and the origin of the paper. The terminal is put in raw mode,
+//! so every key press is handled immediately:
+//!
+//! | Key               | Action                                  |
+//! |-------------------|-----------------------------------------|
+//! | arrows or w/a/s/d | Move one step in that direction.        |
+//! | + and -           | Select the next or previous step size.  |
+//! | space             | Toggle the pen.                         |
+//! | o                 | Make the current position the origin.   |
+//! | h                 | Raise the pen and move to home.         |
+//! | m                 | Disable the motors.                     |
+//! | q                 | Quit.                                   |
+//!
+//! The arrows move the carriage the way they point, seen from the user: up moves away from the
+//! user, also on plotters whose y-axis points up.
+use std::io::{self, Read, Write};
+use std::os::unix::io::AsRawFd;
+
+use termios::{cfmakeraw, tcsetattr, Termios, TCSANOW};
+
+use crate::plotter::Plotter;
+use crate::point::Coordinate;
+use crate::Error;
+
+/// A key press that `Jog` responds to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Char(char),
+}
+
+impl Key {
+    /// Decode the bytes a terminal in raw mode sends for key presses. Arrow keys are sent as
+    /// escape sequences like "\x1b[A".
+    pub fn parse(bytes: &[u8]) -> Vec<Key> {
+        let mut keys = vec![];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i..] {
+                [0x1b, b'[', arrow, ..] => {
+                    match arrow {
+                        b'A' => keys.push(Key::Up),
+                        b'B' => keys.push(Key::Down),
+                        b'C' => keys.push(Key::Right),
+                        b'D' => keys.push(Key::Left),
+                        _ => {}
+                    }
+                    i += 3;
+                }
+                [byte, ..] => {
+                    keys.push(Key::Char(byte as char));
+                    i += 1;
+                }
+                [] => break,
+            }
+        }
+
+        keys
+    }
+}
+
+/// State of an interactive jog session.
+pub struct Jog<'a> {
+    plotter: &'a mut dyn Plotter,
+
+    /// Step sizes to choose from, in the unit of the device.
+    step_sizes: Vec<i32>,
+
+    /// Index of the selected step size.
+    step: usize,
+}
+
+impl<'a> Jog<'a> {
+    /// Create a jog session. The smallest step size is selected.
+    pub fn new(plotter: &'a mut dyn Plotter, mut step_sizes: Vec<i32>) -> Self {
+        step_sizes.sort();
+        if step_sizes.is_empty() {
+            step_sizes.push(100);
+        }
+
+        Self {
+            plotter,
+            step_sizes,
+            step: 0,
+        }
+    }
+
+    /// The selected step size.
+    pub fn step_size(&self) -> i32 {
+        self.step_sizes[self.step]
+    }
+
+    /// Respond to a key press. Returns `false` when the session should end.
+    pub fn handle(&mut self, key: Key) -> Result<bool, Error> {
+        let step = self.step_size();
+        // Moving away from the user decreases y, unless the y-axis points up.
+        let up = if self.plotter.y_up() { step } else { -step };
+
+        match key {
+            Key::Up | Key::Char('w') => self.plotter.move_by(Coordinate::new(0, up))?,
+            Key::Down | Key::Char('s') => self.plotter.move_by(Coordinate::new(0, -up))?,
+            Key::Left | Key::Char('a') => self.plotter.move_by(Coordinate::new(-step, 0))?,
+            Key::Right | Key::Char('d') => self.plotter.move_by(Coordinate::new(step, 0))?,
+            Key::Char('+') => self.step = (self.step + 1).min(self.step_sizes.len() - 1),
+            Key::Char('-') => self.step = self.step.saturating_sub(1),
+            Key::Char(' ') => self.plotter.toggle_pen()?,
+            Key::Char('o') => self.plotter.set_origin()?,
+            Key::Char('h') => self.plotter.home()?,
+            Key::Char('m') => self.plotter.disable_motors()?,
+            // Ctrl-C doesn't raise a signal in raw mode.
+            Key::Char('q') | Key::Char('\x03') => return Ok(false),
+            Key::Char(_) => {}
+        }
+
+        Ok(true)
+    }
+
+    /// A line describing the position, pen state and step size.
+    fn status_line(&mut self) -> String {
+        match self.plotter.status() {
+            Ok(status) => format!(
+                "x: {:>7}  y: {:>7}  pen: {:<4}  step: {}",
+                status.position.x,
+                status.position.y,
+                if status.pen_down { "down" } else { "up" },
+                self.step_size()
+            ),
+            Err(err) => format!("step: {}  ({})", self.step_size(), err),
+        }
+    }
+
+    /// Read key presses from the terminal until 'q' is pressed. The terminal is in raw mode while
+    /// this function runs.
+    pub fn run(&mut self) -> Result<(), Error> {
+        let stdin = io::stdin();
+        let fd = stdin.as_raw_fd();
+        let original = Termios::from_fd(fd)?;
+
+        let mut raw = original;
+        cfmakeraw(&mut raw);
+        tcsetattr(fd, TCSANOW, &raw)?;
+
+        let result = self.read_keys(&mut stdin.lock());
+
+        tcsetattr(fd, TCSANOW, &original)?;
+        println!();
+        result
+    }
+
+    fn read_keys(&mut self, input: &mut impl Read) -> Result<(), Error> {
+        let mut stdout = io::stdout();
+        write!(
+            stdout,
+            "arrows/wasd: move, +/-: step size, space: pen, o: origin, h: home, m: motors off, q: quit\r\n"
+        )?;
+
+        loop {
+            write!(stdout, "\r\x1b[K{}", self.status_line())?;
+            stdout.flush()?;
+
+            let mut buffer = [0; 16];
+            let length = input.read(&mut buffer)?;
+            if length == 0 {
+                return Ok(());
+            }
+
+            for key in Key::parse(&buffer[..length]) {
+                match self.handle(key) {
+                    Ok(true) => {}
+                    Ok(false) => return Ok(()),
+                    // Show the error, but keep the session going.
+                    Err(err) => write!(stdout, "\r\x1b[K{}\r\n", err)?,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plotter::Status;
+    use crate::point::Relative;
+    use crate::traffic::TrafficLog;
+    use crate::Plot;
+
+    /// A plotter that records the moves and the pen state.
+    #[derive(Default)]
+    struct Recorder {
+        moves: Vec<Coordinate<Relative>>,
+        pen_down: bool,
+        y_up: bool,
+    }
+
+    impl Plotter for Recorder {
+        fn plot_with_progress(
+            &mut self,
+            _plot: &Plot,
+            _progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn log_traffic(&mut self, _log: TrafficLog) {}
+
+        fn pen_up(&mut self) -> Result<(), Error> {
+            self.pen_down = false;
+            Ok(())
+        }
+
+        fn pen_down(&mut self) -> Result<(), Error> {
+            self.pen_down = true;
+            Ok(())
+        }
+
+        fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+            self.moves.push(delta);
+            Ok(())
+        }
+
+        fn home(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn set_origin(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn status(&mut self) -> Result<Status, Error> {
+            Ok(Status {
+                position: Coordinate::new(0, 0),
+                pen_down: self.pen_down,
+            })
+        }
+
+        fn disable_motors(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn version(&mut self) -> Result<String, Error> {
+            Ok("Recorder".to_string())
+        }
+
+        fn y_up(&self) -> bool {
+            self.y_up
+        }
+    }
+
+    /// Press the keys and return the moves made and whether the pen ended down.
+    fn jog(y_up: bool, keys: &[Key]) -> (Vec<Coordinate<Relative>>, bool) {
+        let mut plotter = Recorder {
+            y_up,
+            ..Default::default()
+        };
+        let mut jog = Jog::new(&mut plotter, vec![100, 10]);
+        for key in keys {
+            assert!(jog.handle(*key).unwrap());
+        }
+        assert!(!jog.handle(Key::Char('q')).unwrap());
+
+        (plotter.moves, plotter.pen_down)
+    }
+
+    #[test]
+    fn handle_keys() {
+        let keys = [
+            Key::Up,
+            Key::Char('+'),
+            Key::Char('d'),
+            Key::Char(' '),
+            Key::Down,
+            Key::Char('-'),
+            Key::Left,
+        ];
+        let moves = vec![
+            Coordinate::new(0, -10),
+            Coordinate::new(100, 0),
+            Coordinate::new(0, 100),
+            Coordinate::new(-10, 0),
+        ];
+        assert_eq!(jog(false, &keys), (moves, true));
+
+        // Up still moves away from the user when the y-axis points up.
+        let (moves, _) = jog(true, &[Key::Up, Key::Char('s')]);
+        assert_eq!(moves, vec![Coordinate::new(0, 10), Coordinate::new(0, -10)]);
+
+        // Pressing space twice raises the pen again.
+        assert!(!jog(false, &[Key::Char(' '), Key::Char(' ')]).1);
+    }
+
+    #[test]
+    fn parse_keys() {
+        assert_eq!(
+            Key::parse(b"\x1b[A\x1b[Dq+"),
+            vec![Key::Up, Key::Left, Key::Char('q'), Key::Char('+')]
+        );
+    }
+}
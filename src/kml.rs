@@ -0,0 +1,202 @@
+//! Build a `Plot` from the placemarks in a KML file, like the ones Google Earth exports.
+//!
+//! The `LineString`s and `LinearRing`s of every `Placemark` are read, also inside `Polygon`s and
+//! `MultiGeometry`s. `Point`s become waypoints. Every placemark becomes a `Layer`. Its properties
+//! are the name, the description and the `ExtendedData`. The colour is read from the `LineStyle`
+//! of the placemark, inline or referenced by `styleUrl`.
+use std::collections::HashMap;
+use std::fs;
+use std::path;
+
+use roxmltree::{Document, Node};
+
+use crate::geo::{self, Feature, LatLon, Line};
+use crate::{Error, Plot};
+
+/// Text of the first descendant with the given tag name.
+fn child_text<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.descendants()
+        .find(|child| child.has_tag_name(name))
+        .and_then(|child| child.text())
+        .map(str::trim)
+}
+
+/// Parse the content of a `coordinates` element: tuples of "longitude,latitude[,altitude]"
+/// separated by whitespace.
+fn coordinates(text: &str) -> Result<Line, Error> {
+    text.split_whitespace()
+        .map(|tuple| {
+            let mut values = tuple.split(',').map(|value| value.parse::<f32>());
+            match (values.next(), values.next()) {
+                (Some(Ok(lon)), Some(Ok(lat))) => Ok::<LatLon, Error>((lat, lon)),
+                _ => Err(Error::KmlError(format!("Invalid coordinates {:?}.", tuple))),
+            }
+        })
+        .collect()
+}
+
+/// Convert a KML colour, which is "aabbggrr", into "#rrggbb".
+fn colour(kml: &str) -> Option<String> {
+    let kml = kml.trim();
+    if kml.len() != 8 || !kml.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(format!("#{}{}{}", &kml[6..8], &kml[4..6], &kml[2..4]).to_lowercase())
+}
+
+/// The line colour of a `Style` element.
+fn style_colour(style: &Node) -> Option<String> {
+    style
+        .descendants()
+        .find(|child| child.has_tag_name("LineStyle"))
+        .and_then(|line_style| child_text(&line_style, "color"))
+        .and_then(colour)
+}
+
+fn placemark(node: &Node, styles: &HashMap<&str, String>) -> Result<Feature, Error> {
+    let mut feature = Feature::default();
+
+    for name in ["name", "description"] {
+        if let Some(text) = node
+            .children()
+            .find(|child| child.has_tag_name(name))
+            .and_then(|child| child.text())
+        {
+            feature
+                .properties
+                .insert(name.to_string(), text.trim().to_string());
+        }
+    }
+
+    // <Data name="..."><value>...</value></Data> and <SimpleData name="...">...</SimpleData>.
+    for data in node
+        .descendants()
+        .filter(|child| child.has_tag_name("Data") || child.has_tag_name("SimpleData"))
+    {
+        let Some(key) = data.attribute("name") else {
+            continue;
+        };
+        let value = if data.has_tag_name("Data") {
+            child_text(&data, "value")
+        } else {
+            data.text().map(str::trim)
+        };
+        feature
+            .properties
+            .insert(key.to_string(), value.unwrap_or_default().to_string());
+    }
+
+    feature.colour = node
+        .children()
+        .find(|child| child.has_tag_name("Style"))
+        .and_then(|style| style_colour(&style))
+        .or_else(|| {
+            let url = child_text(node, "styleUrl")?;
+            styles.get(url.trim_start_matches('#')).cloned()
+        });
+
+    feature.lines = node
+        .descendants()
+        .filter(|child| child.has_tag_name("LineString") || child.has_tag_name("LinearRing"))
+        .filter_map(|geometry| child_text(&geometry, "coordinates"))
+        .map(coordinates)
+        .collect::<Result<_, _>>()?;
+
+    for point in node
+        .descendants()
+        .filter(|child| child.has_tag_name("Point"))
+        .filter_map(|point| child_text(&point, "coordinates"))
+    {
+        feature.points.extend(coordinates(point)?);
+    }
+
+    Ok(feature)
+}
+
+/// Read the placemarks of a KML document as `Feature`s.
+pub fn features(text: &str) -> Result<Vec<Feature>, Error> {
+    let doc = Document::parse(text).map_err(|err| Error::KmlError(err.to_string()))?;
+
+    // Shared styles, by id. A `StyleMap` refers to the style of its "normal" pair.
+    let mut styles: HashMap<&str, String> = doc
+        .descendants()
+        .filter(|node| node.has_tag_name("Style"))
+        .filter_map(|style| Some((style.attribute("id")?, style_colour(&style)?)))
+        .collect();
+    for style_map in doc
+        .descendants()
+        .filter(|node| node.has_tag_name("StyleMap"))
+    {
+        let normal = style_map
+            .descendants()
+            .filter(|node| node.has_tag_name("Pair"))
+            .find(|pair| child_text(pair, "key") == Some("normal"))
+            .and_then(|pair| child_text(&pair, "styleUrl"))
+            .and_then(|url| styles.get(url.trim_start_matches('#')).cloned());
+        if let (Some(id), Some(colour)) = (style_map.attribute("id"), normal) {
+            styles.insert(id, colour);
+        }
+    }
+
+    doc.descendants()
+        .filter(|node| node.has_tag_name("Placemark"))
+        .map(|node| placemark(&node, &styles))
+        .collect()
+}
+
+/// Build a `Plot` from all placemarks in a KML document.
+pub fn parse(text: &str) -> Result<Plot, Error> {
+    parse_with(text, &geo::Options::default())
+}
+
+/// Build a `Plot` from the placemarks in a KML document, projected with `options`.
+pub fn parse_with(text: &str, options: &geo::Options) -> Result<Plot, Error> {
+    Ok(geo::project(features(text)?, options)?.plot)
+}
+
+/// Build a `Plot` from all placemarks in the KML file at the given location.
+pub fn read(path: &path::Path) -> Result<Plot, Error> {
+    parse(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_placemarks() {
+        let text = r##"<?xml version="1.0" encoding="UTF-8"?>
+            <kml xmlns="http://www.opengis.net/kml/2.2">
+              <Document>
+                <Style id="red"><LineStyle><color>ff0000ff</color></LineStyle></Style>
+                <Placemark>
+                  <name>Trail</name>
+                  <styleUrl>#red</styleUrl>
+                  <ExtendedData><Data name="difficulty"><value>hard</value></Data></ExtendedData>
+                  <LineString><coordinates>11.0,47.0,0 11.002,47.001,0</coordinates></LineString>
+                </Placemark>
+                <Placemark>
+                  <name>Lake</name>
+                  <Polygon><outerBoundaryIs><LinearRing>
+                    <coordinates>11.0,47.0 11.001,47.0 11.0,47.001 11.0,47.0</coordinates>
+                  </LinearRing></outerBoundaryIs></Polygon>
+                </Placemark>
+              </Document>
+            </kml>"##;
+
+        let plot = parse(text).unwrap();
+        let paths = plot.paths();
+        assert_eq!(paths.paths.len(), 2);
+        assert_eq!(paths.layers[0].name.as_deref(), Some("Trail"));
+        assert_eq!(paths.layers[0].colour.as_deref(), Some("#ff0000"));
+        assert_eq!(paths.layers[0].properties["difficulty"], "hard");
+
+        let options = geo::Options {
+            selector: Some("difficulty=hard".parse().unwrap()),
+            ..Default::default()
+        };
+        let plot = parse_with(text, &options).unwrap();
+        assert_eq!(plot.paths().paths.len(), 1);
+    }
+}
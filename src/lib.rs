@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
 use std::convert::TryFrom;
 use std::fmt;
 use std::io;
@@ -6,6 +8,7 @@ use std::ops;
 use std::path;
 use std::time::Duration;
 use thiserror::Error;
+use tracing::{debug, info, info_span, trace_span};
 
 use svg::node::element::path::Data;
 use svg::node::element::Path as SVG_Path;
@@ -13,15 +16,62 @@ use svg::Document;
 
 use serial_core::SerialDevice;
 
+pub mod asynchronous;
+pub mod cartography;
+pub mod config;
+pub mod contour;
+pub mod dem;
+pub mod discovery;
+pub mod drawing;
+pub mod dxf;
+pub mod gcode;
+pub mod geo;
+pub mod geojson;
+pub mod gpx;
+pub mod halftone;
+pub mod jog;
+pub mod kml;
+pub mod lsystem;
+pub mod plotter;
 pub mod point;
+pub mod preview;
+pub mod profile;
+pub mod projection;
+pub mod raster;
 pub mod roland_dxy;
+pub mod server;
+pub mod svg_file;
+pub mod text;
+pub mod traffic;
+pub mod vectorize;
+use plotter::{Plotter, Status};
 use point::{Coordinate, Relative};
+use traffic::{Connection, TrafficLog, Transport};
 
 /// A series of connected `Point`s form a `Path`.
 pub type Path = Vec<point::Coordinate<point::Absolute>>;
 
 pub struct Paths {
     pub paths: Vec<Path>,
+
+    /// Groups of paths with metadata, like the features of a GeoJSON file. Not every path has to
+    /// be in a layer.
+    pub layers: Vec<Layer>,
+}
+
+/// Metadata of a group of `Paths`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Layer {
+    pub name: Option<String>,
+
+    /// Colour to draw the paths with, like "#ff0000".
+    pub colour: Option<String>,
+
+    /// All properties of the source, as text.
+    pub properties: BTreeMap<String, String>,
+
+    /// Indices in `Paths::paths` of the paths in this layer.
+    pub paths: Vec<usize>,
 }
 
 impl Paths {
@@ -39,7 +89,43 @@ impl Paths {
         }
 
         //let start = start.ok_or(Error::InvalidPathError("Path is empty".to_string()))?;
-        Ok(Self { paths })
+        Ok(Self {
+            paths,
+            layers: vec![],
+        })
+    }
+
+    /// Create new `Paths` from layers and their paths. The `paths` of the layers are filled in.
+    pub fn with_layers(layers: Vec<(Layer, Vec<Path>)>) -> Result<Self, Error> {
+        let mut with_layers = Self {
+            paths: vec![],
+            layers: vec![],
+        };
+        for (layer, paths) in layers {
+            with_layers.add_layer(layer, paths);
+        }
+
+        Ok(Self {
+            layers: with_layers.layers,
+            ..Self::new(with_layers.paths)?
+        })
+    }
+
+    /// Add `paths` as a new layer. Paths of less than 2 points are dropped.
+    pub fn add_layer(&mut self, mut layer: Layer, paths: Vec<Path>) {
+        layer.paths.clear();
+        for path in paths.into_iter().filter(|path| path.len() > 1) {
+            layer.paths.push(self.paths.len());
+            self.paths.push(path);
+        }
+        self.layers.push(layer);
+    }
+
+    /// The layer the path at `index` is in, if any.
+    pub fn layer_of(&self, index: usize) -> Option<&Layer> {
+        self.layers
+            .iter()
+            .find(|layer| layer.paths.contains(&index))
     }
 }
 
@@ -83,8 +169,21 @@ pub fn get_boundaries(paths: &Paths) -> (i32, i32, i32, i32) {
     )
 }
 
+/// Duration of every `SM` command in milliseconds, unless configured otherwise.
+pub const DEFAULT_MOVE_DURATION: u32 = 1000;
+
+/// Time to wait for the device to respond, unless configured otherwise.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(10000);
+
 impl From<&Coordinate<Relative>> for Command {
     fn from(value: &Coordinate<Relative>) -> Self {
+        Command::step_move(value, DEFAULT_MOVE_DURATION)
+    }
+}
+
+impl Command {
+    /// The `SM` command that moves the pen by `value` in `duration` milliseconds.
+    pub fn step_move(value: &Coordinate<Relative>, duration: u32) -> Self {
         fn movement_on_x_axis(delta_x: i32) -> (i32, i32) {
             (delta_x, -delta_x)
         }
@@ -98,7 +197,7 @@ impl From<&Coordinate<Relative>> for Command {
         let (x2, y2) = movement_on_y_axis(delta_y);
 
         Command::SM {
-            duration: 1000,
+            duration,
             axis_step_1: x1 + x2,
             axis_step_2: Some(y1 + y2),
         }
@@ -156,7 +255,7 @@ impl TryFrom<&Paths> for Strokes {
     }
 }
 
-fn convert_to_series_of_commands(strokes: Strokes) -> Vec<Vec<Command>> {
+fn convert_to_series_of_commands(strokes: Strokes, move_duration: u32) -> Vec<Vec<Command>> {
     strokes
         .iter()
         .map(|stroke| {
@@ -165,28 +264,201 @@ fn convert_to_series_of_commands(strokes: Strokes) -> Vec<Vec<Command>> {
             let start: Coordinate<Relative> = Coordinate::new(stroke.start.x, stroke.start.y);
 
             // Move to first point.
-            cmds.push(Command::from(&start));
+            cmds.push(Command::step_move(&start, move_duration));
             // Lower the pen.
-            cmds.push(Command::Any("SP,1".to_string()));
-
-            let x: Vec<Command> = stroke.path.iter().map(Command::from).collect();
+            cmds.push(Command::SP {
+                value: 0,
+                duration: None,
+            });
+
+            let x: Vec<Command> = stroke
+                .path
+                .iter()
+                .map(|delta| Command::step_move(delta, move_duration))
+                .collect();
             for command in x {
                 cmds.push(command);
             }
 
             // Raise the pen.
-            cmds.push(Command::Any("SP,0".to_string()));
+            cmds.push(Command::SP {
+                value: 1,
+                duration: None,
+            });
 
             // Ugly work around to move to home.
             let home: Coordinate<Relative> = Coordinate::new(-stroke.end.x, -stroke.end.y);
 
             // Move to home.
-            cmds.push(Command::from(&home));
+            cmds.push(Command::step_move(&home, move_duration));
             cmds
         })
         .collect()
 }
 
+/// Build the series of commands that `Driver::plot()` sends to the device, using the default
+/// `PenSettings` and move duration.
+pub fn plot_to_commands(plot: &Plot) -> Result<Vec<Command>, Error> {
+    plot_to_commands_with(plot, &PenSettings::default(), DEFAULT_MOVE_DURATION)
+}
+
+/// Build the series of commands that `Driver::plot()` sends to the device. Every pen move waits
+/// for the delay configured in `pen` and every `SM` takes `move_duration` milliseconds.
+pub fn plot_to_commands_with(
+    plot: &Plot,
+    pen: &PenSettings,
+    move_duration: u32,
+) -> Result<Vec<Command>, Error> {
+    Ok(commands_per_stroke(plot, pen, move_duration)?
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Like `plot_to_commands_with()`, but the commands are grouped per stroke.
+fn commands_per_stroke(
+    plot: &Plot,
+    pen: &PenSettings,
+    move_duration: u32,
+) -> Result<Vec<Vec<Command>>, Error> {
+    let strokes: Strokes = Strokes::try_from(&plot.paths)?;
+
+    let mut commands: Vec<Vec<Command>> = convert_to_series_of_commands(strokes, move_duration)
+        .into_iter()
+        .map(|stroke| {
+            stroke
+                .into_iter()
+                .map(|command| match command {
+                    Command::SP { value, .. } => pen.command(value),
+                    command => command,
+                })
+                .collect()
+        })
+        .collect();
+
+    // The pen may have been left down, raise it before the first travel.
+    if let Some(first) = commands.first_mut() {
+        first.insert(0, pen.command(1));
+    }
+
+    Ok(commands)
+}
+
+/// Whether the response to a query, read so far, is followed by a line containing "OK". That's
+/// the case for all queries except `V`. Some queries, like `QS`, terminate the value with "\n\r"
+/// so the first read already contains both lines.
+pub(crate) fn expects_ok_line(cmd: &Command, response: &str) -> bool {
+    *cmd != Command::V && !response.trim_end().contains(['\r', '\n'])
+}
+
+/// The value of the response to a query: its first line.
+pub(crate) fn query_value(response: &str) -> String {
+    response
+        .split(['\r', '\n'])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Build a `Status` from the values returned by `QS` and `QP`.
+pub(crate) fn parse_status(steps: &str, pen: &str) -> Result<Status, Error> {
+    // The response to `QS` looks like "1024,-512": the position of both motors in steps.
+    let values: Vec<i32> = steps
+        .split(',')
+        .map(|steps| steps.trim().parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::ConversionError(format!("Failed to parse {:?} as position.", steps)))?;
+
+    let (axis_step_1, axis_step_2) = match values[..] {
+        [axis_step_1, axis_step_2] => (axis_step_1, axis_step_2),
+        _ => {
+            return Err(Error::ConversionError(format!(
+                "Failed to parse {:?} as position.",
+                steps
+            )))
+        }
+    };
+
+    // Inverse of `From<&Coordinate<Relative>> for Command`.
+    let position = Coordinate::new(
+        (axis_step_1 - axis_step_2) / 2,
+        -(axis_step_1 + axis_step_2) / 2,
+    );
+
+    // `QP` returns 1 when the pen is up and 0 when it's down.
+    Ok(Status {
+        position,
+        pen_down: pen == "0",
+    })
+}
+
+/// Configuration of the pen servo of the EBB.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PenSettings {
+    /// Servo position of the raised pen, between 1 and 65535. Set with `SC,4`.
+    pub up_position: u16,
+
+    /// Servo position of the lowered pen, between 1 and 65535. Set with `SC,5`.
+    pub down_position: u16,
+
+    /// Rate at which the servo position changes when raising the pen. Set with `SC,11`.
+    pub raise_rate: u16,
+
+    /// Rate at which the servo position changes when lowering the pen. Set with `SC,12`.
+    pub lower_rate: u16,
+
+    /// Time in milliseconds to wait after raising the pen before moving.
+    pub up_delay: u16,
+
+    /// Time in milliseconds to wait after lowering the pen before moving, so the pen touches the
+    /// paper first.
+    pub down_delay: u16,
+}
+
+impl Default for PenSettings {
+    /// The values the EBB uses after a reset, without delays.
+    fn default() -> Self {
+        Self {
+            up_position: 16000,
+            down_position: 12000,
+            raise_rate: 400,
+            lower_rate: 400,
+            up_delay: 0,
+            down_delay: 0,
+        }
+    }
+}
+
+impl PenSettings {
+    /// The `SC` commands that configure the servo.
+    pub fn configuration(&self) -> Vec<Command> {
+        [
+            (4, self.up_position),
+            (5, self.down_position),
+            (11, self.raise_rate),
+            (12, self.lower_rate),
+        ]
+        .into_iter()
+        .map(|(parameter, value)| Command::SC { parameter, value })
+        .collect()
+    }
+
+    /// The `SP` command for `value`, 1 to raise and 0 to lower the pen, including the delay.
+    pub fn command(&self, value: u8) -> Command {
+        let delay = if value == 1 {
+            self.up_delay
+        } else {
+            self.down_delay
+        };
+
+        Command::SP {
+            value,
+            duration: (delay > 0).then_some(delay),
+        }
+    }
+}
+
 pub struct Plot {
     paths: Paths,
 }
@@ -197,6 +469,14 @@ impl Plot {
         Plot { paths }
     }
 
+    pub fn paths(&self) -> &Paths {
+        &self.paths
+    }
+
+    pub fn into_paths(self) -> Paths {
+        self.paths
+    }
+
     pub fn dimensions(&self) -> (i32, i32) {
         let (min_x, min_y, max_x, max_y) = get_boundaries(&self.paths);
 
@@ -209,8 +489,14 @@ impl Plot {
         Ok(Plot::new(paths))
     }
 
-    /// Create SVG preview `Plot` as SVG.
+    /// Create SVG preview `Plot` as SVG, with the outline of the paper of EBB based plotters.
     pub fn preview(&self) -> Document {
+        self.preview_with_outline((30300, 21000))
+    }
+
+    /// Create SVG preview `Plot` as SVG, with a dashed outline of the given width and height.
+    pub fn preview_with_outline(&self, outline: (i32, i32)) -> Document {
+        let (width, height) = outline;
         let (min_x, min_y, max_x, max_y) = get_boundaries(&self.paths);
 
         let strokes: Strokes = (&self.paths).try_into().unwrap();
@@ -242,9 +528,9 @@ impl Plot {
                 "d",
                 Data::new()
                     .move_to((0, 0))
-                    .line_to((0, 21000))
-                    .line_to((30300, 21000))
-                    .line_to((30300, 0))
+                    .line_to((0, height))
+                    .line_to((width, height))
+                    .line_to((width, 0))
                     .line_to((0, 0)),
             );
 
@@ -252,43 +538,254 @@ impl Plot {
     }
 }
 
+/// The command set a plotter understands.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Backend {
+    /// The EiBotBoard of Evil Mad Scientist, as used by the AxiDraw.
+    Ebb,
+
+    /// HP-GL, as used by the Roland DXY.
+    RolandDxy,
+
+    /// G-code, as used by GRBL based plotters.
+    Grbl,
+}
+
 pub struct Driver {
-    file: serial_unix::TTYPort,
+    file: Connection,
+    pen: PenSettings,
+
+    /// Duration of every `SM` command in milliseconds.
+    move_duration: u32,
+
+    /// Maximum number of commands sent before their responses are read.
+    pipeline_depth: Option<usize>,
+}
+
+/// A command sent by `Driver::execute_pipelined()` whose response hasn't been read yet.
+struct InFlight {
+    /// Number of the command, counting from 1.
+    number: usize,
+    command: Command,
+
+    /// Number of the stroke the command ends, if any.
+    stroke: Option<usize>,
 }
 
 impl Driver {
     pub fn open(path: &path::Path) -> Result<Self, Error> {
+        Self::open_with_timeout(path, DEFAULT_TIMEOUT)
+    }
+
+    /// Open the device and fail reads and writes that take longer than `timeout`.
+    pub fn open_with_timeout(path: &path::Path, timeout: Duration) -> Result<Self, Error> {
         let mut port = serial_unix::TTYPort::open(path)?;
-        port.set_timeout(Duration::from_millis(10000))?;
+        port.set_timeout(timeout)?;
+
+        Ok(Self::from_transport(port))
+    }
+
+    /// Create a driver that talks to the device through `transport`, for example a `Replay`.
+    pub fn from_transport(transport: impl Transport + 'static) -> Self {
+        Self {
+            file: Connection::new(transport),
+            pen: PenSettings::default(),
+            move_duration: DEFAULT_MOVE_DURATION,
+            pipeline_depth: None,
+        }
+    }
+
+    /// Make every `SM` command sent by `plot()` and `move_by()` take `duration` milliseconds.
+    pub fn set_move_duration(&mut self, duration: u32) {
+        self.move_duration = duration;
+    }
+
+    /// The pen settings used by this driver.
+    pub fn pen_settings(&self) -> &PenSettings {
+        &self.pen
+    }
 
-        Ok(Self { file: port })
+    /// Send at most `depth` commands before reading their responses while plotting. By default,
+    /// the depth is one more than the depth of the motion FIFO of the EBB, so the next command is
+    /// waiting when a move is queued. A depth of 1 disables pipelining.
+    pub fn set_pipeline_depth(&mut self, depth: usize) {
+        self.pipeline_depth = Some(depth.max(1));
     }
 
-    pub fn plot(&mut self, plot: &Plot) -> Result<(), Error> {
-        let strokes: Strokes = Strokes::try_from(&plot.paths)?;
-        let commands = convert_to_series_of_commands(strokes);
+    /// The number of commands sent before their responses are read while plotting. The depth of
+    /// the motion FIFO is queried once with `QU,3`. Firmware without `QU` has a FIFO of 1.
+    pub fn pipeline_depth(&mut self) -> usize {
+        if let Some(depth) = self.pipeline_depth {
+            return depth;
+        }
+
+        // The response is the depth, possibly preceded by the command: "QU,3,32".
+        let fifo_depth = self
+            .query(Command::QU { parameter: 3 })
+            .ok()
+            .and_then(|response| response.rsplit(',').next()?.trim().parse::<usize>().ok())
+            .filter(|depth| *depth > 0)
+            .unwrap_or(1);
+        debug!("Motion FIFO depth: {}", fifo_depth);
+
+        let depth = fifo_depth + 1;
+        self.pipeline_depth = Some(depth);
+        depth
+    }
 
-        for stroke in commands {
-            self.execute_command(Command::Any("SP,0".to_string()))?;
+    /// Execute commands without waiting for the response to each command before sending the
+    /// next, keeping at most `depth` commands in flight. Responses are matched to the commands in
+    /// order. The pen is only lowered once the responses to all commands before have been read.
+    /// When the device responds with an error, no more commands are sent and the error reports
+    /// which command failed, counting from 1.
+    pub fn execute_pipelined(
+        &mut self,
+        commands: impl IntoIterator<Item = Command>,
+        depth: usize,
+    ) -> Result<(), Error> {
+        self.pipeline(
+            commands.into_iter().map(|command| (command, None)),
+            depth,
+            &mut |_| Ok(()),
+        )
+    }
 
-            for command in stroke {
-                self.execute_command(command)?;
+    /// Like `execute_pipelined()`, but some commands end a stroke: `done` is called with the
+    /// number of the stroke when the response to that command has been read. No more commands
+    /// are sent when `done` returns an error.
+    fn pipeline(
+        &mut self,
+        commands: impl IntoIterator<Item = (Command, Option<usize>)>,
+        depth: usize,
+        done: &mut dyn FnMut(usize) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut in_flight: VecDeque<InFlight> = VecDeque::new();
+        let mut failure = None;
+
+        for (index, (command, stroke)) in commands.into_iter().enumerate() {
+            // Wait before lowering the pen, so `done` is called with the pen raised and can stop
+            // the plot there.
+            let lowers_pen = matches!(command, Command::SP { value: 0, .. });
+            while failure.is_none()
+                && !in_flight.is_empty()
+                && (lowers_pen || in_flight.len() >= depth.max(1))
+            {
+                self.complete(&mut in_flight, &mut failure, done)?;
+            }
+            if failure.is_some() {
+                break;
             }
-            self.execute_command(Command::Any("SP,1".to_string()))?;
+
+            let _span = trace_span!("command", command = %command).entered();
+            self.write_command(&command)?;
+            in_flight.push_back(InFlight {
+                number: index + 1,
+                command,
+                stroke,
+            });
+        }
+
+        // Read the responses to all commands in flight, even after a failure, so the next
+        // response read belongs to the next command.
+        while !in_flight.is_empty() {
+            self.complete(&mut in_flight, &mut failure, done)?;
+        }
+
+        match failure {
+            Some(failure) => Err(failure),
+            None => Ok(()),
+        }
+    }
+
+    /// Read the response to the oldest command in flight, and call `done` when it ends a stroke.
+    /// An error response, or an error of `done`, is stored in `failure`, unless an earlier
+    /// command failed already.
+    fn complete(
+        &mut self,
+        in_flight: &mut VecDeque<InFlight>,
+        failure: &mut Option<Error>,
+        done: &mut dyn FnMut(usize) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let Some(InFlight {
+            number,
+            command,
+            stroke,
+        }) = in_flight.pop_front()
+        else {
+            return Ok(());
+        };
+
+        match self.read_response(&command) {
+            Ok(_) => {
+                if let (Some(stroke), None) = (stroke, &failure) {
+                    if let Err(err) = done(stroke) {
+                        *failure = Some(err);
+                    }
+                }
+                Ok(())
+            }
+            Err(Error::ErrorResponse(command, response)) => {
+                failure.get_or_insert(Error::PipelineError(number, command, response));
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Configure the pen servo of the device and use the delays of `pen` for all pen moves.
+    pub fn set_pen_settings(&mut self, pen: PenSettings) -> Result<(), Error> {
+        for command in pen.configuration() {
+            self.execute_command(command)?;
+        }
+        self.pen = pen;
+
+        Ok(())
+    }
+
+    /// Lower and raise the pen `cycles` times, so the pen settings can be judged.
+    pub fn calibrate_pen(&mut self, cycles: usize) -> Result<(), Error> {
+        for _ in 0..cycles {
+            self.pen_down()?;
+            self.pen_up()?;
         }
 
         Ok(())
     }
 
     pub fn execute_command(&mut self, cmd: Command) -> Result<(), Error> {
+        let _span = trace_span!("command", command = %cmd).entered();
+        self.write_command(&cmd)?;
+        self.read_response(&cmd)?;
+
+        Ok(())
+    }
+
+    /// Execute a command that queries the device, like `V` or `QT`, and return the value.
+    pub fn query(&mut self, cmd: Command) -> Result<String, Error> {
+        let _span = trace_span!("query", command = %cmd).entered();
+        self.write_command(&cmd)?;
+        let mut response = self.read_response(&cmd)?;
+
+        if expects_ok_line(&cmd, &response) {
+            response.push_str(&self.read_response(&cmd)?);
+        }
+
+        Ok(query_value(&response))
+    }
+
+    fn write_command(&mut self, cmd: &Command) -> Result<(), Error> {
         let mut _cmd = cmd.to_string();
         _cmd.push('\r');
-        println!("Writing command: {:?}", _cmd.to_string());
+        debug!("Writing command: {:?}", _cmd);
 
         self.file
             .write_all(_cmd.as_bytes())
-            .map_err(|err| Error::CommandError(cmd.to_string(), err))?;
+            .map_err(|err| Error::CommandError(cmd.to_string(), err))
+    }
 
+    /// Read a single line of response to the given command.
+    fn read_response(&mut self, cmd: &Command) -> Result<String, Error> {
         let mut response = String::new();
 
         loop {
@@ -303,17 +800,95 @@ impl Driver {
             }
         }
 
-        println!("Response {:?}", response);
+        debug!("Response {:?}", response);
         if response.starts_with('!') {
-            return Err(Error::ErrorResponse(cmd, response));
+            return Err(Error::ErrorResponse(cmd.clone(), response));
         }
 
-        Ok(())
+        Ok(response)
+    }
+}
+
+impl Plotter for Driver {
+    fn plot_with_progress(
+        &mut self,
+        plot: &Plot,
+        progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let strokes = commands_per_stroke(plot, &self.pen, self.move_duration)?;
+        let total = strokes.len();
+        let depth = self.pipeline_depth();
+        let _span = info_span!("plot", strokes = total, depth).entered();
+
+        // The commands of the next stroke are sent while the responses of the previous one are
+        // read. Its last command ends the stroke.
+        let commands = strokes.into_iter().enumerate().flat_map(|(index, stroke)| {
+            let last = stroke.len().saturating_sub(1);
+            stroke
+                .into_iter()
+                .enumerate()
+                .map(move |(i, command)| (command, (i == last).then_some(index + 1)))
+        });
+        self.pipeline(commands, depth, &mut |stroke| {
+            info!("Plotted stroke {}", stroke);
+            progress(stroke, total)
+        })
+    }
+
+    fn log_traffic(&mut self, log: TrafficLog) {
+        self.file.log_traffic(log);
+    }
+
+    fn pen_up(&mut self) -> Result<(), Error> {
+        self.execute_command(self.pen.command(1))
+    }
+
+    fn pen_down(&mut self) -> Result<(), Error> {
+        self.execute_command(self.pen.command(0))
+    }
+
+    fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+        self.execute_command(Command::step_move(&delta, self.move_duration))
+    }
+
+    fn home(&mut self) -> Result<(), Error> {
+        self.pen_up()?;
+        self.execute_command(Command::HM {
+            step_frequency: 1000,
+            position_1: None,
+            position_2: None,
+        })
+    }
+
+    fn set_origin(&mut self) -> Result<(), Error> {
+        self.execute_command(Command::CS)
+    }
+
+    fn status(&mut self) -> Result<Status, Error> {
+        let steps = self.query(Command::QS)?;
+        let pen = self.query(Command::QP)?;
+
+        parse_status(&steps, &pen)
+    }
+
+    fn toggle_pen(&mut self) -> Result<(), Error> {
+        self.execute_command(Command::TP { duration: None })
+    }
+
+    fn disable_motors(&mut self) -> Result<(), Error> {
+        self.execute_command(Command::EM {
+            enable_1: 0,
+            enable_2: 0,
+        })
+    }
+
+    fn version(&mut self) -> Result<String, Error> {
+        self.query(Command::V)
     }
 }
 
 /// Command supported by the device.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Command {
     /// Analog value get - Read all analog (ADC) input values.
     A,
@@ -323,6 +898,8 @@ pub enum Command {
         enable: bool,
     },
     Any(String),
+    /// Clear Step position - Zero the step position of both motors.
+    CS,
     EM {
         enable_1: u8,
         enable_2: u8,
@@ -334,14 +911,27 @@ pub enum Command {
         position_2: Option<u32>,
     },
     QC,
+    /// Query Pen - Returns 1 when the pen is up and 0 when it's down.
+    QP,
     QT,
     /// Query Step position
     QS,
+    /// Query Utility - Query the value of `parameter`. `QU,3` returns the depth of the motion
+    /// FIFO.
+    QU {
+        parameter: u8,
+    },
     R,
     // Set EBB nickname tag - This command sets the EBB's "nickname".
     ST {
         name: String,
     },
+    /// Stepper and servo mode Configure - Set `parameter` to `value`. For example, `SC,4` sets
+    /// the servo position of the raised pen.
+    SC {
+        parameter: u8,
+        value: u16,
+    },
     // Stepper Move
     SM {
         // TODO: Create custom type that prevents values over 16777215.
@@ -353,6 +943,12 @@ pub enum Command {
         // TODO: Create custom type that prevents underflow and overflow.
         axis_step_2: Option<i32>,
     },
+    /// Set Pen State - Raise the pen when `value` is 1, lower it when `value` is 0. Then wait
+    /// `duration` milliseconds before executing the next motion command.
+    SP {
+        value: u8,
+        duration: Option<u16>,
+    },
     // Toggle Pen -  This command toggles the state of the pen (up->down and down->up).
     TP {
         duration: Option<u16>,
@@ -365,8 +961,9 @@ impl fmt::Display for Command {
         let cmd: String = match self {
             Command::A => "A".into(),
             Command::AC { channel, enable } => {
-                format!("AC,{},{}", channel, enable.clone() as u8)
+                format!("AC,{},{}", channel, *enable as u8)
             }
+            Command::CS => "CS".into(),
             Command::EM { enable_1, enable_2 } => {
                 format!("EM,{},{}", enable_1, enable_2)
             }
@@ -375,8 +972,10 @@ impl fmt::Display for Command {
                 format!("HM,{}", step_frequency)
             }
             Command::QC => "QC".into(),
+            Command::QP => "QP".into(),
             Command::QT => "QT".into(),
             Command::QS => "QS".into(),
+            Command::QU { parameter } => format!("QU,{}", parameter),
             Command::R => "R".into(),
             Command::ST { name } => {
                 format!("ST,{}", name)
@@ -393,6 +992,11 @@ impl fmt::Display for Command {
                 cmd
             }
 
+            Command::SC { parameter, value } => format!("SC,{},{}", parameter, value),
+            Command::SP { value, duration } => match duration {
+                None => format!("SP,{}", value),
+                Some(duration) => format!("SP,{},{}", value, duration),
+            },
             Command::TP { duration } => match duration {
                 None => "TP".into(),
                 Some(duration) => format!("TP,{}", duration),
@@ -422,11 +1026,60 @@ pub enum Error {
     #[error("Command {0} failed with error: {1}.")]
     ErrorResponse(Command, String),
 
+    /// A pipelined command failed: its number, counting from 1, the command and the response.
+    #[error("Command number {0}, {1}, failed with error: {2}.")]
+    PipelineError(usize, Command, String),
+
     #[error("{0}")]
     ConversionError(String),
 
     #[error("{0}")]
     InvalidPathError(String),
+
+    #[error("{0}")]
+    ProbeError(String),
+
+    #[error("{0}")]
+    UnsupportedError(String),
+
+    #[error("Failed to parse GPX: {0}")]
+    GpxError(String),
+
+    #[error("Failed to parse HP-GL: {0}")]
+    HpglError(String),
+
+    #[error("Failed to parse SVG: {0}")]
+    SvgError(String),
+
+    #[error("Failed to parse DXF: {0}")]
+    DxfError(String),
+
+    #[error("Failed to parse GeoJSON: {0}")]
+    GeoJsonError(String),
+
+    #[error("Failed to parse KML: {0}")]
+    KmlError(String),
+
+    #[error("Failed to parse elevation model: {0}")]
+    DemError(String),
+
+    #[error("{0}")]
+    ConfigError(String),
+
+    #[error("{0}")]
+    JobError(String),
+
+    #[error("GRBL rejected {0:?}: {1}.")]
+    GrblError(String, String),
+
+    #[error("Failed to read session: {0}")]
+    ReplayError(String),
+
+    #[error("Failed to encode PNG: {0}.")]
+    PngError(#[from] png::EncodingError),
+
+    #[error("Failed to decode PNG: {0}.")]
+    PngDecodingError(#[from] png::DecodingError),
 }
 
 #[cfg(test)]
@@ -435,20 +1088,53 @@ mod tests {
 
     #[test]
     fn convert_valid_path_to_stroke() {
-        let path: Path = vec![(0, 0), (1, 0), (1, 1), (0, 1), (0, 0)];
+        let path: Path = vec![
+            Coordinate::new(0, 0),
+            Coordinate::new(1, 0),
+            Coordinate::new(1, 1),
+            Coordinate::new(0, 1),
+            Coordinate::new(0, 0),
+        ];
 
         let vectors = Stroke::try_from(&path).unwrap();
-        let expected = Stroke(vec![(1, 0), (0, 1), (-1, 0), (0, -1)]);
+        let expected = Stroke {
+            start: Coordinate::new(0, 0),
+            path: vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(-1, 0),
+                Coordinate::new(0, -1),
+            ],
+            end: Coordinate::new(0, 0),
+        };
 
         assert_eq!(vectors, expected);
     }
 
     #[test]
     fn convert_valid_stroke_to_series_of_commands() {
-        let stroke = Stroke(vec![(1, 0), (0, 1), (-1, 0), (0, -1)]);
+        let stroke = Stroke {
+            start: Coordinate::new(0, 0),
+            path: vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(-1, 0),
+                Coordinate::new(0, -1),
+            ],
+            end: Coordinate::new(0, 0),
+        };
 
-        let commands = convert_to_series_of_commands(Strokes(vec![stroke]));
+        let commands = convert_to_series_of_commands(Strokes(vec![stroke]), DEFAULT_MOVE_DURATION);
         let expected = vec![vec![
+            Command::SM {
+                duration: 1000,
+                axis_step_1: 0,
+                axis_step_2: Some(0),
+            },
+            Command::SP {
+                value: 0,
+                duration: None,
+            },
             Command::SM {
                 duration: 1000,
                 axis_step_1: 1,
@@ -469,8 +1155,35 @@ mod tests {
                 axis_step_1: 1,
                 axis_step_2: Some(1),
             },
+            Command::SP {
+                value: 1,
+                duration: None,
+            },
+            Command::SM {
+                duration: 1000,
+                axis_step_1: 0,
+                axis_step_2: Some(0),
+            },
         ]];
 
         assert_eq!(commands, expected);
     }
+
+    #[test]
+    fn pen_settings() {
+        let pen = PenSettings {
+            up_delay: 150,
+            ..Default::default()
+        };
+
+        assert_eq!(pen.command(1).to_string(), "SP,1,150");
+        assert_eq!(pen.command(0).to_string(), "SP,0");
+        assert_eq!(
+            pen.configuration()
+                .iter()
+                .map(|command| command.to_string())
+                .collect::<Vec<_>>(),
+            vec!["SC,4,16000", "SC,5,12000", "SC,11,400", "SC,12,400"]
+        );
+    }
 }
@@ -0,0 +1,112 @@
+//! Lindenmayer systems, drawn by the turtle of a `Drawing`. The symbols are:
+//!
+//! - `F` and `G`: move forward, drawing a line,
+//! - `f`: move forward without drawing,
+//! - `+` and `-`: turn counterclockwise and clockwise,
+//! - `|`: turn around,
+//! - `[` and `]`: save and restore the turtle, for branches.
+//!
+//! Other symbols are only rewritten, not drawn.
+use std::collections::BTreeMap;
+
+use crate::drawing::Drawing;
+
+/// An axiom and the rules to rewrite it with.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LSystem {
+    pub axiom: String,
+
+    /// What a symbol is replaced with in every generation. Symbols without a rule stay as they
+    /// are.
+    pub rules: BTreeMap<char, String>,
+}
+
+impl LSystem {
+    pub fn new(axiom: &str, rules: &[(char, &str)]) -> Self {
+        Self {
+            axiom: axiom.to_string(),
+            rules: rules
+                .iter()
+                .map(|(symbol, replacement)| (*symbol, replacement.to_string()))
+                .collect(),
+        }
+    }
+
+    /// The axiom, rewritten `generations` times.
+    pub fn expand(&self, generations: usize) -> String {
+        (0..generations).fold(self.axiom.clone(), |text, _| {
+            text.chars()
+                .map(|symbol| match self.rules.get(&symbol) {
+                    Some(replacement) => replacement.clone(),
+                    None => symbol.to_string(),
+                })
+                .collect()
+        })
+    }
+
+    /// Draw the system after `generations` with the turtle of `drawing`, `step` forward for every
+    /// `F`, turning `angle` degrees for every `+` or `-`.
+    pub fn draw(&self, drawing: &mut Drawing, generations: usize, step: f64, angle: f64) {
+        for symbol in self.expand(generations).chars() {
+            match symbol {
+                'F' | 'G' => {
+                    drawing.pen_down();
+                    drawing.forward(step);
+                }
+                'f' => {
+                    drawing.pen_up();
+                    drawing.forward(step);
+                    drawing.pen_down();
+                }
+                '+' => drawing.turn(-angle),
+                '-' => drawing.turn(angle),
+                '|' => drawing.turn(180.0),
+                '[' => drawing.push(),
+                ']' => drawing.pop(),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_rules() {
+        let algae = LSystem::new("A", &[('A', "AB"), ('B', "A")]);
+        assert_eq!(algae.expand(0), "A");
+        assert_eq!(algae.expand(4), "ABAABABA");
+    }
+
+    #[test]
+    fn draw_branches() {
+        // A Koch curve: a single path of 4 ^ 2 lines.
+        let koch = LSystem::new("F", &[('F', "F+F--F+F")]);
+        let mut drawing = Drawing::new();
+        drawing.translate(0.0, 100.0);
+        koch.draw(&mut drawing, 2, 10.0, 60.0);
+        let paths = drawing.into_paths().unwrap();
+        assert_eq!(paths.paths.len(), 1);
+        assert_eq!(paths.paths[0].len(), 17);
+        let end = paths.paths[0].last().unwrap();
+        assert_eq!((end.x, end.y), (90, 100));
+
+        // A branch ends a path, and the turtle returns to its base.
+        let plant = LSystem::new("F[+F]f[-F]", &[]);
+        let mut drawing = Drawing::new();
+        plant.draw(&mut drawing, 0, 10.0, 90.0);
+        assert_eq!(drawing.position(), (20.0, 0.0));
+        let paths = drawing.into_paths().unwrap();
+        let points: Vec<Vec<(i32, i32)>> = paths
+            .paths
+            .iter()
+            .map(|path| path.iter().map(|point| (point.x, point.y)).collect())
+            .collect();
+        assert_eq!(
+            points,
+            vec![vec![(0, 0), (10, 0), (10, -10)], vec![(20, 0), (20, 10)]]
+        );
+    }
+}
@@ -0,0 +1,884 @@
+use std::fs;
+use std::net::TcpListener;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Parser, Subcommand};
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+use loempia::cartography::{self, Decoration};
+use loempia::config::{Config, Machine};
+use loempia::contour;
+use loempia::discovery::discover;
+use loempia::geo::{self, Selector};
+use loempia::halftone::{self, Image, Renderer};
+use loempia::jog::Jog;
+use loempia::plotter::{self, Plotter};
+use loempia::preview::Options;
+use loempia::profile::{self, Placement, Profile};
+use loempia::projection::{Projection, Scale};
+use loempia::server::{Format, Server};
+use loempia::traffic::{Replay, TrafficLog};
+use loempia::vectorize::{self, Trace};
+use loempia::{
+    dem, dxf, gcode, gpx, raster, roland_dxy, svg_file, Backend, Driver, Error, PenSettings, Plot,
+};
+
+#[derive(Parser, Debug)]
+#[command(name = "loempia", about = "Control pen plotters.")]
+struct Cli {
+    /// Command set of the plotter: "ebb", "roland-dxy" or "grbl". Used to find the plotter when no
+    /// device is given and to simulate plots.
+    #[arg(short, long, global = true)]
+    backend: Option<Backend>,
+
+    /// Path to serial device. When omitted, the first plotter found is used.
+    #[arg(short, long, global = true)]
+    device: Option<PathBuf>,
+
+    /// Nickname of the plotter to use.
+    #[arg(short, long, global = true)]
+    nickname: Option<String>,
+
+    /// Configuration file with machine profiles. Defaults to ~/.config/loempia/config.toml.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Name of the machine in the configuration file to use. Defaults to the machine named by
+    /// "default" in the configuration file. Other options override its settings.
+    #[arg(short, long, global = true)]
+    machine: Option<String>,
+
+    /// Log more details: -v for progress, -vv for every command and -vvv for spans of commands.
+    /// `RUST_LOG` overrides this.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Write all bytes exchanged with the plotter to this file.
+    #[arg(long, global = true)]
+    traffic_log: Option<PathBuf>,
+
+    /// Replay a session written by --traffic-log instead of talking to a plotter. Fails when the
+    /// commands differ from the session.
+    #[arg(long, global = true)]
+    replay: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+/// Options for reading the input file of commands that plot, preview, convert or serve files.
+#[derive(Args, Debug)]
+struct InputOptions {
+    /// Number of units of the plot per user unit when reading SVG or DXF.
+    #[arg(long, default_value_t = 10.0)]
+    scale: f64,
+
+    /// Only read these layers of DXF files. Defaults to all layers.
+    #[arg(long, value_delimiter = ',')]
+    layers: Vec<String>,
+
+    /// Only read the tracks of GPX files, or the features of GeoJSON and KML files, with this
+    /// property, given as "key=value". For example "name=Spitzstein".
+    #[arg(long)]
+    select: Option<Selector>,
+
+    /// Projection of maps: "utm" with the zone of the map, "utm:<zone>", "web-mercator",
+    /// "transverse-mercator:<central meridian>" or "equirectangular".
+    #[arg(long, default_value = "utm")]
+    projection: Projection,
+
+    /// Scale of maps, like "1:25000". "fit" scales maps to the plotting area of the machine.
+    #[arg(long, default_value = "fit")]
+    map_scale: Scale,
+
+    /// Decorations to add to maps: "scale-bar", "scale-bar-mi", "north-arrow", "graticule",
+    /// "grid", "frame" and "markers".
+    #[arg(long, value_delimiter = ',')]
+    decorate: Vec<Decoration>,
+
+    /// Space around maps in millimeters. Defaults to 10 with decorations or a profile, otherwise
+    /// 0.
+    #[arg(long)]
+    margin: Option<f64>,
+
+    /// Draw the elevation profile of GPX tracks "below" or "beside" the map.
+    #[arg(long)]
+    profile: Option<Placement>,
+
+    /// Draw the contour lines of this elevation model, an ESRI ASCII grid, on maps.
+    #[arg(long)]
+    dem: Option<PathBuf>,
+
+    /// Difference in elevation between contour lines in meters. Every fifth is an index contour.
+    #[arg(long, default_value_t = 10.0)]
+    contour_interval: f64,
+
+    /// How PNG images are drawn: "halftone", "zigzag", "spiral", "stipple" or "crosshatch".
+    #[arg(long, default_value = "halftone")]
+    render: Renderer,
+
+    /// Distance between the lines of rendered images in millimeters.
+    #[arg(long, default_value_t = 1.5)]
+    spacing: f64,
+
+    /// Number of points of stippled images.
+    #[arg(long, default_value_t = 2000)]
+    stipples: usize,
+
+    /// Trace PNG images of line art instead of rendering them: "centreline" draws every stroke
+    /// once, "outline" draws its edges.
+    #[arg(long)]
+    trace: Option<Trace>,
+
+    /// Pixels of traced images darker than this, from 0 for white to 1 for black, are ink.
+    #[arg(long, default_value_t = 0.5)]
+    threshold: f64,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Plot a SVG, GPX, GeoJSON, KML, HP-GL, DXF or PNG file.
+    Plot {
+        input: PathBuf,
+        #[command(flatten)]
+        input_options: InputOptions,
+    },
+
+    /// Write a preview of a SVG, GPX, GeoJSON, KML, HP-GL, DXF or PNG file.
+    Preview {
+        input: PathBuf,
+
+        #[command(flatten)]
+        input_options: InputOptions,
+
+        /// Location where the preview is written to. A PNG is written when the extension is
+        /// ".png", otherwise a SVG.
+        #[arg(short, long, default_value = "/tmp/preview.svg")]
+        output: PathBuf,
+
+        /// Simulate the commands sent to the plotter, including pen-up travel.
+        #[arg(long)]
+        simulate: bool,
+
+        /// Animate the order in which the plotter draws, 10 times faster than the plotter.
+        #[arg(long)]
+        animate: bool,
+
+        /// Resolution of PNG previews.
+        #[arg(long, default_value_t = 96.0)]
+        dpi: f64,
+    },
+
+    /// Print the dimensions and estimated plotting time of a SVG, GPX, GeoJSON, KML, HP-GL, DXF or
+    /// PNG file.
+    Info {
+        input: PathBuf,
+        #[command(flatten)]
+        input_options: InputOptions,
+    },
+
+    /// Convert a SVG, GPX, GeoJSON, KML, HP-GL, DXF or PNG file into SVG, HP-GL, G-code or DXF. The
+    /// formats are derived from the file extensions.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+        #[command(flatten)]
+        input_options: InputOptions,
+    },
+
+    /// Raise, lower, toggle or calibrate the pen.
+    Pen {
+        #[command(subcommand)]
+        action: PenAction,
+    },
+
+    /// Control the motors.
+    Motors {
+        #[command(subcommand)]
+        action: MotorsAction,
+    },
+
+    /// Raise the pen and move to home.
+    Home,
+
+    /// Move the carriage interactively using the keyboard.
+    Jog {
+        /// Step sizes to choose from, in the unit of the plotter.
+        #[arg(long, value_delimiter = ',', default_values_t = [10, 100, 1000])]
+        steps: Vec<i32>,
+    },
+
+    /// Print the model and firmware version of the plotter.
+    Version,
+
+    /// List all plotters connected to this machine.
+    Devices,
+
+    /// Plot jobs submitted over HTTP. See the documentation of `loempia::server` for the API.
+    Serve {
+        /// Address to listen on.
+        #[arg(long, default_value = "127.0.0.1:5741")]
+        listen: String,
+
+        /// Listen on this Unix socket instead of a TCP address.
+        #[arg(long)]
+        socket: Option<PathBuf>,
+
+        /// Machines in the configuration file that plot the jobs. Defaults to the selected
+        /// plotter.
+        machines: Vec<String>,
+
+        #[command(flatten)]
+        input_options: InputOptions,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum PenAction {
+    Up,
+    Down,
+    Toggle,
+
+    /// Configure the pen servo of an EBB and lower and raise the pen a few times, to find good
+    /// pen settings. Settings that are omitted are taken from the machine profile.
+    Calibrate {
+        /// Servo position of the raised pen.
+        #[arg(long)]
+        up: Option<u16>,
+
+        /// Servo position of the lowered pen.
+        #[arg(long)]
+        down: Option<u16>,
+
+        /// Rate at which the pen is raised.
+        #[arg(long)]
+        raise_rate: Option<u16>,
+
+        /// Rate at which the pen is lowered.
+        #[arg(long)]
+        lower_rate: Option<u16>,
+
+        /// Milliseconds to wait after raising the pen.
+        #[arg(long)]
+        up_delay: Option<u16>,
+
+        /// Milliseconds to wait after lowering the pen.
+        #[arg(long)]
+        down_delay: Option<u16>,
+
+        /// Number of times to lower and raise the pen.
+        #[arg(long, default_value_t = 3)]
+        cycles: usize,
+
+        /// Store the pen settings in the machine profile.
+        #[arg(long)]
+        save: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum MotorsAction {
+    /// Disable the motors, so the carriage can be moved by hand.
+    Off,
+}
+
+/// Lowercase extension of a file.
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// How maps are projected for `machine`: in its units, on its plotting area.
+fn map_options(input: &InputOptions, machine: &Machine) -> geo::Options {
+    let (width, height) = machine.travel();
+    geo::Options {
+        projection: input.projection,
+        scale: input.map_scale,
+        units_per_mm: machine.steps_per_mm(),
+        page: (f64::from(width), f64::from(height)),
+        margin: input
+            .margin
+            .unwrap_or(if input.decorate.is_empty() && input.profile.is_none() {
+                0.0
+            } else {
+                10.0
+            }),
+        selector: input.select.clone(),
+    }
+}
+
+/// The page of the map and the area of the elevation profile of `--profile`.
+fn profile_layout(input: &InputOptions, machine: &Machine) -> Option<((f64, f64), profile::Area)> {
+    let options = map_options(input, machine);
+    input.profile.map(|placement| {
+        profile::split(
+            options.page,
+            options.margin * options.units_per_mm,
+            options.units_per_mm,
+            placement,
+        )
+    })
+}
+
+/// Read a GPX, GeoJSON or KML file as `geo::Map`. Returns `None` for other formats.
+fn read_map(
+    input: &InputOptions,
+    machine: &Machine,
+    path: &Path,
+) -> Result<Option<geo::Map>, Error> {
+    match Format::from_extension(&extension(path)) {
+        Some(format) if format.is_map() => {
+            let features = format.features(&fs::read_to_string(path)?)?;
+            let mut options = map_options(input, machine);
+            if let Some((page, _)) = profile_layout(input, machine) {
+                options.page = page;
+            }
+            Ok(Some(geo::project(features, &options)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Add the contour lines of `--dem` and the decorations of `--decorate` to a map read from `path`,
+/// and the elevation profile of `--profile` of the tracks selected by `--select`.
+fn draw_map(
+    input: &InputOptions,
+    machine: &Machine,
+    path: &Path,
+    mut map: geo::Map,
+) -> Result<Plot, Error> {
+    if let Some(dem) = &input.dem {
+        let options = contour::Options {
+            interval: input.contour_interval,
+            ..Default::default()
+        };
+        let layers = contour::contours(&dem::read(dem)?, &map, &options)?;
+        let mut paths = map.plot.into_paths();
+        for (layer, layer_paths) in layers {
+            paths.add_layer(layer, layer_paths);
+        }
+        map.plot = Plot::new(paths);
+    }
+
+    let units_per_mm = map.units_per_mm;
+    let plot = cartography::decorate(map, &input.decorate)?;
+    let Some((_, area)) = profile_layout(input, machine) else {
+        return Ok(plot);
+    };
+    if Format::from_extension(&extension(path)) != Some(Format::Gpx) {
+        return Err(Error::UnsupportedError(
+            "Elevation profiles need a GPX file.".to_string(),
+        ));
+    }
+
+    let tracks: Vec<_> = gpx::tracks(&fs::read_to_string(path)?)?
+        .into_iter()
+        .filter(|track| {
+            input
+                .select
+                .as_ref()
+                .is_none_or(|selector| selector.matches_properties(&track.properties))
+        })
+        .collect();
+    let profile = Profile::new(&tracks)?;
+
+    Ok(profile::add(plot, &profile, area, units_per_mm))
+}
+
+/// Render a PNG image with `--render`, or trace it with `--trace`, fit to the plotting area of
+/// `machine`.
+fn render_image(input: &InputOptions, machine: &Machine, path: &Path) -> Result<Plot, Error> {
+    let (width, height) = machine.travel();
+    let size = (f64::from(width), f64::from(height));
+    let image = Image::read(path)?;
+    if let Some(trace) = input.trace {
+        let options = vectorize::Options {
+            trace,
+            threshold: input.threshold,
+            size,
+            ..Default::default()
+        };
+        return vectorize::vectorize(&image, &options);
+    }
+
+    let options = halftone::Options {
+        size,
+        spacing: input.spacing * machine.steps_per_mm(),
+        points: input.stipples,
+        ..Default::default()
+    };
+    halftone::render(&image, input.render, &options)
+}
+
+/// Read a `Plot` from a SVG, GPX, GeoJSON, KML, HP-GL, DXF, JSON or PNG file. Of DXF files, only
+/// the layers given by `--layers` are read. Maps are projected for `machine`, and only the
+/// features selected by `--select` are read. Maps get the contour lines of `--dem`, the
+/// decorations of `--decorate` and the elevation profile of `--profile`. Images are rendered with
+/// `--render` or traced with `--trace`.
+fn read_plot(input: &InputOptions, machine: &Machine, path: &Path) -> Result<Plot, Error> {
+    if let Some(map) = read_map(input, machine, path)? {
+        return draw_map(input, machine, path, map);
+    }
+    if extension(path) == "png" {
+        return render_image(input, machine, path);
+    }
+
+    match Format::from_extension(&extension(path)) {
+        Some(Format::Dxf) if !input.layers.is_empty() => {
+            dxf::parse_drawing(&fs::read_to_string(path)?, input.scale)?.plot(Some(&input.layers))
+        }
+        Some(format) => format.parse_with(
+            &fs::read_to_string(path)?,
+            input.scale,
+            &map_options(input, machine),
+        ),
+        None => Err(Error::UnsupportedError(format!(
+            "Can't read {}: unsupported format {:?}.",
+            path.display(),
+            extension(path)
+        ))),
+    }
+}
+
+/// Read the configuration file given by `--config`, or the one at the default location.
+fn read_config(cli: &Cli) -> Result<Config, Error> {
+    match &cli.config {
+        Some(path) => Config::read(path),
+        None => Config::read_default(),
+    }
+}
+
+/// The machine selected by `--machine`, with its settings overridden by `--backend`, `--device`
+/// and `--nickname`.
+fn selected_machine(cli: &Cli, config: &Config) -> Result<Option<Machine>, Error> {
+    let Some(mut machine) = config.machine(cli.machine.as_deref())?.cloned() else {
+        return Ok(None);
+    };
+
+    if let Some(backend) = cli.backend {
+        machine.backend = backend;
+    }
+    if let Some(device) = &cli.device {
+        machine.device = Some(device.clone());
+    }
+    if let Some(nickname) = &cli.nickname {
+        machine.nickname = Some(nickname.clone());
+    }
+
+    Ok(Some(machine))
+}
+
+/// The machine that previews and estimates are made for: the selected machine, or the default
+/// settings of the backend when no machine is selected.
+fn simulated_machine(cli: &Cli, machine: Option<&Machine>) -> Machine {
+    machine
+        .cloned()
+        .unwrap_or_else(|| Machine::new(cli.backend.unwrap_or(Backend::RolandDxy)))
+}
+
+/// Open the selected plotter and log its traffic to `--traffic-log`.
+fn open_plotter(cli: &Cli, machine: Option<&Machine>) -> Result<Box<dyn Plotter>, Error> {
+    let mut plotter = open_selected_plotter(cli, machine)?;
+    if let Some(path) = &cli.traffic_log {
+        plotter.log_traffic(TrafficLog::create(path)?);
+    }
+
+    Ok(plotter)
+}
+
+/// Open the plotter of the selected machine, the plotter given by `--device`, or the first
+/// plotter found that matches `--backend` and `--nickname`.
+fn open_selected_plotter(cli: &Cli, machine: Option<&Machine>) -> Result<Box<dyn Plotter>, Error> {
+    if let Some(session) = &cli.replay {
+        let backend = machine
+            .map(|machine| machine.backend)
+            .or(cli.backend)
+            .unwrap_or(Backend::Ebb);
+        return Ok(plotter::from_transport(backend, Replay::read(session)?));
+    }
+    if let Some(machine) = machine {
+        return machine.open();
+    }
+    if let (Some(device), Some(backend)) = (&cli.device, cli.backend) {
+        return plotter::open(backend, device);
+    }
+
+    discover()?
+        .into_iter()
+        .find(|device| {
+            cli.backend.is_none_or(|backend| backend == device.backend)
+                && cli.device.as_ref().is_none_or(|path| *path == device.path)
+                && cli
+                    .nickname
+                    .as_ref()
+                    .is_none_or(|nickname| device.nickname.as_ref() == Some(nickname))
+        })
+        .ok_or_else(|| Error::ProbeError("No matching plotter found.".to_string()))?
+        .open()
+}
+
+/// Open the selected EBB and log its traffic to `--traffic-log`. For features that only the EBB
+/// supports.
+fn open_ebb(cli: &Cli, machine: Option<&Machine>) -> Result<Driver, Error> {
+    let mut driver = open_selected_ebb(cli, machine)?;
+    if let Some(path) = &cli.traffic_log {
+        driver.log_traffic(TrafficLog::create(path)?);
+    }
+
+    Ok(driver)
+}
+
+/// Open the EBB of the selected machine, the EBB given by `--device`, or the first EBB found
+/// that matches `--nickname`.
+fn open_selected_ebb(cli: &Cli, machine: Option<&Machine>) -> Result<Driver, Error> {
+    if let Some(session) = &cli.replay {
+        return Ok(Driver::from_transport(Replay::read(session)?));
+    }
+    if let Some(machine) = machine {
+        return machine.open_ebb();
+    }
+    if cli.backend.is_some_and(|backend| backend != Backend::Ebb) {
+        return Err(Error::UnsupportedError(
+            "Only the EBB supports this command.".to_string(),
+        ));
+    }
+    if let Some(device) = &cli.device {
+        return Driver::open(device);
+    }
+
+    let device = discover()?
+        .into_iter()
+        .find(|device| {
+            device.backend == Backend::Ebb
+                && cli
+                    .nickname
+                    .as_ref()
+                    .is_none_or(|nickname| device.nickname.as_ref() == Some(nickname))
+        })
+        .ok_or_else(|| Error::ProbeError("No matching EBB found.".to_string()))?;
+    Driver::open(&device.path)
+}
+
+/// Store the pen settings in the profile of the selected machine.
+fn save_pen_settings(cli: &Cli, mut config: Config, pen: PenSettings) -> Result<(), Error> {
+    let name = cli
+        .machine
+        .clone()
+        .or(config.default.clone())
+        .ok_or_else(|| {
+            Error::ConfigError("Select a machine to store the pen settings in.".to_string())
+        })?;
+    let path = cli
+        .config
+        .clone()
+        .or_else(Config::default_path)
+        .ok_or_else(|| Error::ConfigError("No location for the configuration file.".to_string()))?;
+
+    config
+        .machines
+        .get_mut(&name)
+        .ok_or_else(|| Error::ConfigError(format!("Unknown machine {:?}.", name)))?
+        .pen = pen;
+    config.write(&path)?;
+    println!("Pen settings of {} written to {}.", name, path.display());
+
+    Ok(())
+}
+
+/// Log to stderr at the level selected by `--verbose` or `RUST_LOG`.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn main() -> Result<(), Error> {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    let config = read_config(&cli)?;
+    let machine = selected_machine(&cli, &config)?;
+    let machine = machine.as_ref();
+
+    let simulated = simulated_machine(&cli, machine);
+
+    match &cli.command {
+        Commands::Plot {
+            input,
+            input_options,
+        } => {
+            let plot = read_plot(input_options, &simulated, input)?;
+            open_plotter(&cli, machine)?.plot(&plot)?;
+        }
+        Commands::Preview {
+            input,
+            input_options,
+            output,
+            simulate,
+            animate,
+            dpi,
+        } => {
+            let plot = read_plot(input_options, &simulated, input)?;
+            let options = Options {
+                show_travel: true,
+                number_strokes: true,
+                start_markers: true,
+                ..Default::default()
+            };
+
+            if extension(output) == "png" {
+                let options = raster::Options {
+                    dpi: *dpi,
+                    ..Default::default()
+                };
+                simulated
+                    .simulate(&plot)?
+                    .rasterize(&options)
+                    .save(output)?;
+            } else {
+                let document = if *animate {
+                    simulated.simulate(&plot)?.animate(&options, 10.0)
+                } else if *simulate {
+                    simulated.simulate(&plot)?.render(&options)
+                } else if let Some(machine) = machine {
+                    plot.preview_with_outline(machine.travel())
+                } else {
+                    plot.preview()
+                };
+                svg::save(output, &document)?;
+            }
+            println!("Preview written to {}.", output.display());
+        }
+        Commands::Info {
+            input,
+            input_options,
+        } => {
+            let plot = match read_map(input_options, &simulated, input)? {
+                Some(map) => {
+                    println!("Projection: {}", map.projection);
+                    println!("Scale: 1:{:.0}", map.scale);
+                    println!(
+                        "Scale bar: {} m, {:.0} units",
+                        map.scale_bar.meters, map.scale_bar.length
+                    );
+                    draw_map(input_options, &simulated, input, map)?
+                }
+                None => read_plot(input_options, &simulated, input)?,
+            };
+            let (width, height) = plot.dimensions();
+            let points: usize = plot.paths().paths.iter().map(|path| path.len()).sum();
+            let simulation = simulated.simulate(&plot)?;
+
+            println!("Dimensions: {} x {}", width, height);
+            println!("Paths: {}", plot.paths().paths.len());
+            println!("Points: {}", points);
+            // Waypoints become layers without paths, leave them out.
+            for layer in plot
+                .paths()
+                .layers
+                .iter()
+                .filter(|layer| !layer.paths.is_empty())
+            {
+                println!(
+                    "Layer {}: {} paths{}",
+                    layer.name.as_deref().unwrap_or("(unnamed)"),
+                    layer.paths.len(),
+                    layer
+                        .colour
+                        .as_ref()
+                        .map(|colour| format!(", {}", colour))
+                        .unwrap_or_default()
+                );
+            }
+            println!(
+                "Estimated time on {:?}: {:.0} s",
+                simulated.backend,
+                simulation.duration().as_secs_f64()
+            );
+        }
+        Commands::Convert {
+            input,
+            output,
+            input_options,
+        } => {
+            let plot = read_plot(input_options, &simulated, input)?;
+            match extension(output).as_str() {
+                "svg" => svg::save(output, &svg_file::to_document(&plot))?,
+                "hpgl" | "hpg" | "plt" => {
+                    // Fit the plotting area of the selected machine when it's a Roland DXY.
+                    let travel = machine
+                        .filter(|machine| machine.backend == Backend::RolandDxy)
+                        .map_or_else(
+                            || Machine::new(Backend::RolandDxy).travel(),
+                            Machine::travel,
+                        );
+                    let hpgl: String = roland_dxy::plot_to_commands_with(&plot, travel)?
+                        .iter()
+                        .map(|command| command.to_string())
+                        .collect();
+                    fs::write(output, hpgl)?;
+                }
+                "dxf" => fs::write(output, dxf::to_dxf(&plot))?,
+                "gcode" | "nc" | "ngc" => {
+                    // Use the G-code of the selected machine when it's a GRBL plotter.
+                    let options = machine
+                        .filter(|machine| machine.backend == Backend::Grbl)
+                        .map(Machine::gcode_options)
+                        .unwrap_or_default();
+                    fs::write(output, gcode::to_gcode(&plot, &options)?)?
+                }
+                other => {
+                    return Err(Error::UnsupportedError(format!(
+                        "Can't write {}: unsupported format {:?}.",
+                        output.display(),
+                        other
+                    )))
+                }
+            }
+        }
+        Commands::Pen {
+            action:
+                PenAction::Calibrate {
+                    up,
+                    down,
+                    raise_rate,
+                    lower_rate,
+                    up_delay,
+                    down_delay,
+                    cycles,
+                    save,
+                },
+        } => {
+            let current = machine.map(|machine| machine.pen).unwrap_or_default();
+            let pen = PenSettings {
+                up_position: up.unwrap_or(current.up_position),
+                down_position: down.unwrap_or(current.down_position),
+                raise_rate: raise_rate.unwrap_or(current.raise_rate),
+                lower_rate: lower_rate.unwrap_or(current.lower_rate),
+                up_delay: up_delay.unwrap_or(current.up_delay),
+                down_delay: down_delay.unwrap_or(current.down_delay),
+            };
+
+            let mut driver = open_ebb(&cli, machine)?;
+            driver.set_pen_settings(pen)?;
+            driver.calibrate_pen(*cycles)?;
+
+            if *save {
+                save_pen_settings(&cli, config, pen)?;
+            }
+        }
+        Commands::Pen {
+            action: PenAction::Up,
+        } => open_plotter(&cli, machine)?.pen_up()?,
+        Commands::Pen {
+            action: PenAction::Down,
+        } => open_plotter(&cli, machine)?.pen_down()?,
+        Commands::Pen {
+            action: PenAction::Toggle,
+        } => open_plotter(&cli, machine)?.toggle_pen()?,
+        Commands::Motors {
+            action: MotorsAction::Off,
+        } => open_plotter(&cli, machine)?.disable_motors()?,
+        Commands::Home => open_plotter(&cli, machine)?.home()?,
+        Commands::Jog { steps } => {
+            let mut plotter = open_plotter(&cli, machine)?;
+            Jog::new(plotter.as_mut(), steps.clone()).run()?;
+        }
+        Commands::Version => println!("{}", open_plotter(&cli, machine)?.version()?),
+        Commands::Devices => {
+            for device in discover()? {
+                println!(
+                    "{}: {:?} {} (firmware: {}, nickname: {})",
+                    device.path.display(),
+                    device.backend,
+                    device.model,
+                    device.firmware.as_deref().unwrap_or("unknown"),
+                    device.nickname.as_deref().unwrap_or("none"),
+                );
+            }
+        }
+        Commands::Serve {
+            listen,
+            socket,
+            machines,
+            input_options,
+        } => serve(
+            &cli,
+            input_options,
+            &config,
+            machine,
+            listen,
+            socket.as_deref(),
+            machines,
+        )?,
+    }
+
+    Ok(())
+}
+
+/// Plot the jobs submitted over HTTP with the given machines, or with the selected plotter. Maps
+/// are projected for the machine that plots them, or for the default settings of the backend
+/// without a selected machine.
+fn serve(
+    cli: &Cli,
+    input: &InputOptions,
+    config: &Config,
+    machine: Option<&Machine>,
+    listen: &str,
+    socket: Option<&Path>,
+    machines: &[String],
+) -> Result<(), Error> {
+    let server = Server::new(input.scale);
+    if machines.is_empty() {
+        let name = cli
+            .machine
+            .clone()
+            .or(config.default.clone())
+            .unwrap_or_else(|| "default".to_string());
+        server.add_plotter_with(
+            &name,
+            open_plotter(cli, machine)?,
+            map_options(input, &simulated_machine(cli, machine)),
+        );
+    }
+    for name in machines {
+        let machine = config
+            .machine(Some(name))?
+            .ok_or_else(|| Error::ConfigError(format!("No machine {:?}.", name)))?;
+        server.add_plotter_with(
+            name,
+            open_plotter(cli, Some(machine))?,
+            map_options(input, machine),
+        );
+    }
+
+    match socket {
+        Some(path) => {
+            // Replace the socket of a previous run.
+            if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+                fs::remove_file(path)?;
+            }
+            let listener = UnixListener::bind(path)?;
+            info!("Listening on {}", path.display());
+            server.serve(listener.incoming())
+        }
+        None => {
+            let listener = TcpListener::bind(listen)?;
+            info!("Listening on http://{}", listen);
+            server.serve(listener.incoming())
+        }
+    }
+}
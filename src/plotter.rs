@@ -0,0 +1,137 @@
+//! A device-agnostic interface to plotters.
+//!
+//! The EBB `Driver`, the Roland DXY `Driver` and the GRBL `Driver` implement `Plotter`. Use
+//! `open()` to create the driver for a `Backend`, for example one found by
+//! `discovery::discover()`.
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::discovery::Device;
+use crate::point::{Absolute, Coordinate, Relative};
+use crate::traffic::{TrafficLog, Transport};
+use crate::{gcode, roland_dxy, Backend, Driver, Error, Plot};
+
+/// Position and pen state reported by a plotter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Status {
+    /// Position of the pen relative to home, in the unit of the device.
+    pub position: Coordinate<Absolute>,
+    pub pen_down: bool,
+}
+
+/// Operations that every plotter supports.
+///
+/// Distances are in the unit of the device: steps for the EBB and plotter units for HP-GL
+/// plotters.
+pub trait Plotter: Send {
+    /// Plot all paths of the `Plot`.
+    fn plot(&mut self, plot: &Plot) -> Result<(), Error> {
+        self.plot_with_progress(plot, &mut |_, _| Ok(()))
+    }
+
+    /// Plot all paths of the `Plot`. After every stroke, with the pen raised, `progress` is called
+    /// with the number of strokes plotted and the total number of strokes. Plotting stops when
+    /// `progress` returns an error.
+    fn plot_with_progress(
+        &mut self,
+        plot: &Plot,
+        progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+    ) -> Result<(), Error>;
+
+    /// Write all bytes exchanged with the plotter to `log`.
+    fn log_traffic(&mut self, log: TrafficLog);
+
+    fn pen_up(&mut self) -> Result<(), Error>;
+
+    fn pen_down(&mut self) -> Result<(), Error>;
+
+    /// Raise the pen when it's down, lower it when it's up.
+    fn toggle_pen(&mut self) -> Result<(), Error> {
+        if self.status()?.pen_down {
+            self.pen_up()
+        } else {
+            self.pen_down()
+        }
+    }
+
+    /// Move the pen relative to its current position.
+    fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error>;
+
+    /// Raise the pen and move to home.
+    fn home(&mut self) -> Result<(), Error>;
+
+    /// Make the current position the new home.
+    fn set_origin(&mut self) -> Result<(), Error>;
+
+    /// Query the position and pen state of the plotter.
+    fn status(&mut self) -> Result<Status, Error>;
+
+    /// Disable the motors, so the carriage can be moved by hand.
+    fn disable_motors(&mut self) -> Result<(), Error>;
+
+    /// Query the model and firmware version of the plotter.
+    fn version(&mut self) -> Result<String, Error>;
+
+    /// Whether the y-axis of the plotter points up, away from the user, like on HP-GL and GRBL
+    /// plotters. On the EBB it points down.
+    fn y_up(&self) -> bool {
+        false
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ebb" => Ok(Backend::Ebb),
+            "roland-dxy" => Ok(Backend::RolandDxy),
+            "grbl" => Ok(Backend::Grbl),
+            _ => Err(format!(
+                "Unknown backend {:?}, expected \"ebb\", \"roland-dxy\" or \"grbl\".",
+                s
+            )),
+        }
+    }
+}
+
+/// Open the plotter at the given location using the driver for `backend`.
+pub fn open(backend: Backend, path: &Path) -> Result<Box<dyn Plotter>, Error> {
+    match backend {
+        Backend::Ebb => Ok(Box::new(Driver::open(path)?)),
+        Backend::RolandDxy => Ok(Box::new(roland_dxy::Driver::open(
+            path,
+            roland_dxy::default_port_settings(),
+        )?)),
+        Backend::Grbl => Ok(Box::new(gcode::Driver::open(path, Default::default())?)),
+    }
+}
+
+/// Create the driver for `backend` that talks to the plotter through `transport`.
+pub fn from_transport(backend: Backend, transport: impl Transport + 'static) -> Box<dyn Plotter> {
+    match backend {
+        Backend::Ebb => Box::new(Driver::from_transport(transport)),
+        Backend::RolandDxy => Box::new(roland_dxy::Driver::from_transport(transport)),
+        Backend::Grbl => Box::new(gcode::Driver::from_transport(transport, Default::default())),
+    }
+}
+
+impl Device {
+    /// Open the discovered plotter.
+    pub fn open(&self) -> Result<Box<dyn Plotter>, Error> {
+        open(self.backend, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_backend() {
+        assert_eq!("ebb".parse(), Ok(Backend::Ebb));
+        assert_eq!("roland-dxy".parse(), Ok(Backend::RolandDxy));
+        assert_eq!("grbl".parse(), Ok(Backend::Grbl));
+        assert!("hpgl".parse::<Backend>().is_err());
+    }
+}
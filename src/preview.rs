@@ -0,0 +1,740 @@
+//! Previews that are built by simulating the commands a driver sends to a device.
+//!
+//! `Plot::preview()` draws the `Paths` of a `Plot` directly. A `Simulation` instead replays the
+//! series of commands that `Driver::plot()` would execute, so the preview shows what the device
+//! actually draws: including pen-up travel and mistakes made while converting a `Plot` into
+//! commands.
+use std::time::Duration;
+use svg::node::element::path::Data;
+use svg::node::element::{Animate, Circle, Path as SVG_Path, Text};
+use svg::node::Text as TextNode;
+use svg::Document;
+
+use crate::point::Coordinate;
+use crate::{gcode, roland_dxy};
+use crate::{Backend, Command, Error, Path, Paths, Plot};
+
+/// Size of the paper used by EBB based plotters in steps.
+pub const EBB_PAPER: (f64, f64) = (30_300.0, 21_000.0);
+
+/// Resolution of EBB based plotters in steps per inch.
+pub const EBB_STEPS_PER_INCH: f64 = 2032.0;
+
+/// Plotting area of the Roland DXY in plotter units of 0.025 mm.
+pub const ROLAND_DXY_PLOTTING_AREA: (f64, f64) = (16_640.0, 11_040.0);
+
+/// Resolution of the Roland DXY in plotter units per inch.
+pub const ROLAND_DXY_UNITS_PER_INCH: f64 = 1016.0;
+
+/// Speed of the Roland DXY in plotter units per millisecond. That is 200 mm/s.
+pub const ROLAND_DXY_SPEED: f64 = 8.0;
+
+/// Plotting area of GRBL based plotters in millimeters: A3 paper.
+pub const GRBL_PLOTTING_AREA_MM: (f64, f64) = (420.0, 297.0);
+
+/// A straight movement of the pen between 2 positions.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Move {
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+    pub pen_down: bool,
+
+    /// Time it takes to make the move in milliseconds.
+    pub duration: f64,
+}
+
+/// The result of replaying a series of commands.
+#[derive(Debug, PartialEq)]
+pub struct Simulation {
+    /// All moves in the order the device executes them.
+    pub moves: Vec<Move>,
+
+    /// Width and height of the machine's drawing area, in the same unit as `moves`.
+    pub outline: (f64, f64),
+
+    /// Resolution of the device in units per inch.
+    pub units_per_inch: f64,
+
+    /// Whether the y-axis of the device points up. SVG's y-axis points down.
+    pub y_up: bool,
+}
+
+/// Options to tweak the SVG rendered by `Simulation::render()`.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Colour of moves made while the pen is down.
+    pub pen_colour: String,
+
+    /// Width of moves made while the pen is down, in the unit of the device.
+    pub pen_width: f64,
+
+    /// Draw moves made while the pen is up as thin dashed lines.
+    pub show_travel: bool,
+
+    /// Label every stroke with its position in the plotting order.
+    pub number_strokes: bool,
+
+    /// Mark the start of every stroke with a circle.
+    pub start_markers: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            pen_colour: "black".to_string(),
+            pen_width: 10.0,
+            show_travel: false,
+            number_strokes: false,
+            start_markers: false,
+        }
+    }
+}
+
+/// Position and pen state of a simulated device.
+#[derive(Default)]
+struct State {
+    position: (f64, f64),
+    pen_down: bool,
+    moves: Vec<Move>,
+}
+
+impl State {
+    fn move_to(&mut self, to: (f64, f64), duration: f64) {
+        if to == self.position {
+            return;
+        }
+
+        self.moves.push(Move {
+            from: self.position,
+            to,
+            pen_down: self.pen_down,
+            duration,
+        });
+        self.position = to;
+    }
+}
+
+impl Simulation {
+    /// Simulate the commands that the EBB `Driver` sends to plot the given `Plot`.
+    pub fn ebb(plot: &Plot) -> Result<Self, Error> {
+        Ok(Self::from_ebb_commands(&crate::plot_to_commands(plot)?))
+    }
+
+    /// Simulate the commands that the Roland DXY `Driver` sends to plot the given `Plot`.
+    pub fn roland_dxy(plot: &Plot) -> Result<Self, Error> {
+        Ok(Self::from_hpgl_commands(&roland_dxy::plot_to_commands(
+            plot,
+        )?))
+    }
+
+    /// Simulate the commands that the driver for `backend` sends to plot the given `Plot`.
+    pub fn for_backend(backend: Backend, plot: &Plot) -> Result<Self, Error> {
+        match backend {
+            Backend::Ebb => Self::ebb(plot),
+            Backend::RolandDxy => Self::roland_dxy(plot),
+            Backend::Grbl => Self::grbl(plot, &Default::default()),
+        }
+    }
+
+    /// Simulate the G-code that the GRBL `Driver` sends to plot the given `Plot`.
+    pub fn grbl(plot: &Plot, options: &gcode::Options) -> Result<Self, Error> {
+        Ok(Self::from_gcode(&gcode::to_gcode(plot, options)?, options))
+    }
+
+    /// Replay a series of EBB commands.
+    ///
+    /// Steps of `SM` are converted to movements on the x and y axis using the mixed-axis
+    /// geometry of the AxiDraw, with y down: motor 1 moves `x - y` and motor 2 moves `-x - y`.
+    /// `SP,0` lowers the pen, `SP,1` raises it. Commands that don't move the pen are ignored.
+    pub fn from_ebb_commands(commands: &[Command]) -> Self {
+        let mut state = State::default();
+
+        for command in commands {
+            match command {
+                Command::SM {
+                    duration,
+                    axis_step_1,
+                    axis_step_2,
+                } => {
+                    let step_1 = *axis_step_1 as f64;
+                    let step_2 = axis_step_2.unwrap_or(0) as f64;
+                    // Inverse of `From<&Coordinate<Relative>> for Command`.
+                    let (x, y) = state.position;
+                    state.move_to(
+                        (x + (step_1 - step_2) / 2.0, y - (step_1 + step_2) / 2.0),
+                        *duration as f64,
+                    );
+                }
+                Command::HM { step_frequency, .. } => {
+                    // The motor that has to make the most steps moves at `step_frequency`.
+                    let (x, y) = state.position;
+                    let steps = (x + y).abs().max((x - y).abs());
+                    state.move_to((0.0, 0.0), steps / *step_frequency as f64 * 1000.0);
+                }
+                Command::TP { .. } => state.pen_down = !state.pen_down,
+                Command::SP { value, .. } => state.pen_down = *value == 0,
+                _ => {}
+            }
+        }
+
+        Self {
+            moves: state.moves,
+            outline: EBB_PAPER,
+            units_per_inch: EBB_STEPS_PER_INCH,
+            y_up: false,
+        }
+    }
+
+    /// Replay a series of HPGL commands.
+    ///
+    /// Coordinates are given in plotter units. Coordinates of `PU` and `PD` are absolute or
+    /// relative depending on whether `PA` or `PR` was executed last. When a scale is set using
+    /// `SC`, user units are mapped onto the area between the scaling points set with `IP`.
+    pub fn from_hpgl_commands(commands: &[roland_dxy::Command]) -> Self {
+        use roland_dxy::Command::*;
+
+        let (width, height) = ROLAND_DXY_PLOTTING_AREA;
+        let mut state = State::default();
+        let mut relative = false;
+        let mut p1 = (0.0, 0.0);
+        let mut p2 = (width, height);
+        let mut scale: Option<(f64, f64, f64, f64)> = None;
+
+        for command in commands {
+            let position = state.position;
+            let to_plotter_units = move |x: i32, y: i32, relative: bool| -> (f64, f64) {
+                let (mut x, mut y) = (x as f64, y as f64);
+                if let Some((x_min, x_max, y_min, y_max)) = scale {
+                    let x_factor = (p2.0 - p1.0) / (x_max - x_min);
+                    let y_factor = (p2.1 - p1.1) / (y_max - y_min);
+                    if relative {
+                        x *= x_factor;
+                        y *= y_factor;
+                    } else {
+                        x = p1.0 + (x - x_min) * x_factor;
+                        y = p1.1 + (y - y_min) * y_factor;
+                    }
+                }
+
+                if relative {
+                    (position.0 + x, position.1 + y)
+                } else {
+                    (x, y)
+                }
+            };
+
+            let to = match command {
+                IN => {
+                    state.pen_down = false;
+                    relative = false;
+                    p1 = (0.0, 0.0);
+                    p2 = (width, height);
+                    scale = None;
+                    None
+                }
+                IP(p1_x, p1_y, p2_x, p2_y) => {
+                    p1 = (*p1_x as f64, *p1_y as f64);
+                    p2 = (*p2_x as f64, *p2_y as f64);
+                    None
+                }
+                SC(x_min, x_max, y_min, y_max) => {
+                    scale = Some((*x_min as f64, *x_max as f64, *y_min as f64, *y_max as f64));
+                    None
+                }
+                PA(coordinate) => {
+                    relative = false;
+                    coordinate.map(|c| to_plotter_units(c.x, c.y, false))
+                }
+                PR(coordinate) => {
+                    relative = true;
+                    coordinate.map(|c| to_plotter_units(c.x, c.y, true))
+                }
+                PU(coordinate) => {
+                    state.pen_down = false;
+                    coordinate.map(|c| to_plotter_units(c.x, c.y, relative))
+                }
+                PD(coordinate) => {
+                    state.pen_down = true;
+                    coordinate.map(|c| to_plotter_units(c.x, c.y, relative))
+                }
+                SP(_) => {
+                    state.pen_down = false;
+                    None
+                }
+                EA(..) => None,
+            };
+
+            if let Some(to) = to {
+                let (x, y) = state.position;
+                let distance = (to.0 - x).hypot(to.1 - y);
+                state.move_to(to, distance / ROLAND_DXY_SPEED);
+            }
+        }
+
+        Self {
+            moves: state.moves,
+            outline: ROLAND_DXY_PLOTTING_AREA,
+            units_per_inch: ROLAND_DXY_UNITS_PER_INCH,
+            y_up: true,
+        }
+    }
+
+    /// Replay G-code.
+    ///
+    /// Coordinates of `G0` and `G1` are absolute or relative depending on whether `G90` or `G91`
+    /// was executed last, in the unit selected by `G20` or `G21`. They are converted into units
+    /// of the `Plot` using `options.units_per_mm`. The lines of `options.pen_down` lower the pen,
+    /// the lines of `options.pen_up` raise it. `G0` moves at `options.travel_rate`.
+    pub fn from_gcode(gcode: &str, options: &gcode::Options) -> Self {
+        let pen_up: Vec<&str> = options.pen_up.lines().map(str::trim).collect();
+        let pen_down: Vec<&str> = options.pen_down.lines().map(str::trim).collect();
+
+        let mut state = State::default();
+        let mut relative = false;
+        let mut rapid = true;
+        let mut units_per_mm = options.units.per_mm();
+        let mut feed_rate = options.feed_rate;
+
+        for line in gcode.lines().map(str::trim) {
+            if pen_down.contains(&line) {
+                state.pen_down = true;
+                continue;
+            }
+            if pen_up.contains(&line) {
+                state.pen_down = false;
+                continue;
+            }
+
+            // Everything after a semicolon is a comment.
+            let line = line.split(';').next().unwrap_or_default();
+            let (mut x, mut y) = (None, None);
+            for word in line.split_whitespace() {
+                let (letter, value) = word.split_at(1);
+                let Ok(value) = value.parse::<f64>() else {
+                    continue;
+                };
+
+                match (letter.to_ascii_uppercase().as_str(), value) {
+                    ("G", 0.0) => rapid = true,
+                    ("G", 1.0) => rapid = false,
+                    ("G", 20.0) => units_per_mm = gcode::Units::Inches.per_mm(),
+                    ("G", 21.0) => units_per_mm = gcode::Units::Millimeters.per_mm(),
+                    ("G", 90.0) => relative = false,
+                    ("G", 91.0) => relative = true,
+                    ("F", _) => feed_rate = value,
+                    ("X", _) => x = Some(value / units_per_mm * options.units_per_mm),
+                    ("Y", _) => y = Some(value / units_per_mm * options.units_per_mm),
+                    _ => {}
+                }
+            }
+
+            if x.is_none() && y.is_none() {
+                continue;
+            }
+            let (from_x, from_y) = state.position;
+            let to = if relative {
+                (from_x + x.unwrap_or(0.0), from_y + y.unwrap_or(0.0))
+            } else {
+                (x.unwrap_or(from_x), y.unwrap_or(from_y))
+            };
+
+            // Rates are in the units of the G-code per minute.
+            let rate = if rapid {
+                options.travel_rate
+            } else {
+                feed_rate
+            };
+            let mm_per_minute = rate / units_per_mm;
+            let mm = (to.0 - from_x).hypot(to.1 - from_y) / options.units_per_mm;
+            state.move_to(to, mm / mm_per_minute * 60_000.0);
+        }
+
+        Self {
+            moves: state.moves,
+            outline: (
+                GRBL_PLOTTING_AREA_MM.0 * options.units_per_mm,
+                GRBL_PLOTTING_AREA_MM.1 * options.units_per_mm,
+            ),
+            units_per_inch: options.units_per_mm * 25.4,
+            y_up: true,
+        }
+    }
+
+    /// Total time it takes to make all moves.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.moves.iter().map(|m| m.duration).sum::<f64>() / 1000.0)
+    }
+
+    /// Group consecutive moves made with the pen down into strokes.
+    pub fn strokes(&self) -> Vec<Vec<Move>> {
+        let mut strokes: Vec<Vec<Move>> = vec![];
+        let mut previous_pen_down = false;
+
+        for m in &self.moves {
+            if !m.pen_down {
+                previous_pen_down = false;
+                continue;
+            }
+
+            match strokes.last_mut() {
+                Some(stroke) if previous_pen_down => stroke.push(*m),
+                _ => strokes.push(vec![*m]),
+            }
+            previous_pen_down = true;
+        }
+
+        strokes
+    }
+
+    /// Convert the strokes into `Paths`, rounding every position to the nearest unit of the
+    /// device.
+    pub fn paths(&self) -> Result<Paths, Error> {
+        let paths: Vec<Path> = self
+            .strokes()
+            .iter()
+            .map(|stroke| {
+                let start = stroke[0].from;
+                std::iter::once(start)
+                    .chain(stroke.iter().map(|m| m.to))
+                    .map(|(x, y)| Coordinate::new(x.round() as i32, y.round() as i32))
+                    .collect()
+            })
+            .collect();
+
+        Paths::new(paths)
+    }
+
+    /// Convert a position of the device into a position in the SVG.
+    pub(crate) fn to_svg(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        if self.y_up {
+            (x, self.outline.1 - y)
+        } else {
+            (x, y)
+        }
+    }
+
+    /// Create a SVG `Document` that fits all moves and the outline of the machine. The document
+    /// contains only the outline.
+    fn document(&self, options: &Options) -> Document {
+        let (width, height) = self.outline;
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (0.0_f64, 0.0_f64, width, height);
+        for m in &self.moves {
+            for (x, y) in [self.to_svg(m.from), self.to_svg(m.to)] {
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+
+        let margin = options.pen_width * 10.0;
+        let doc = Document::new().set(
+            "viewBox",
+            (
+                min_x - margin,
+                min_y - margin,
+                max_x - min_x + 2.0 * margin,
+                max_y - min_y + 2.0 * margin,
+            ),
+        );
+
+        let outline = SVG_Path::new()
+            .set("fill", "none")
+            .set("stroke", "grey")
+            .set("stroke-dasharray", "100,100")
+            .set("stroke-width", options.pen_width)
+            .set(
+                "d",
+                Data::new()
+                    .move_to(self.to_svg((0.0, 0.0)))
+                    .line_to(self.to_svg((0.0, height)))
+                    .line_to(self.to_svg((width, height)))
+                    .line_to(self.to_svg((width, 0.0)))
+                    .close(),
+            );
+        doc.add(outline)
+    }
+
+    /// Render the simulation as SVG.
+    pub fn render(&self, options: &Options) -> Document {
+        let mut doc = self.document(options);
+
+        if options.show_travel {
+            for m in self.moves.iter().filter(|m| !m.pen_down) {
+                let travel = SVG_Path::new()
+                    .set("fill", "none")
+                    .set("stroke", "grey")
+                    .set("stroke-dasharray", "50,50")
+                    .set("stroke-width", options.pen_width / 4.0)
+                    .set(
+                        "d",
+                        Data::new()
+                            .move_to(self.to_svg(m.from))
+                            .line_to(self.to_svg(m.to)),
+                    );
+                doc = doc.add(travel);
+            }
+        }
+
+        for (number, stroke) in self.strokes().iter().enumerate() {
+            let start = self.to_svg(stroke[0].from);
+
+            let mut data = Data::new().move_to(start);
+            for m in stroke {
+                data = data.line_to(self.to_svg(m.to));
+            }
+
+            let path = SVG_Path::new()
+                .set("fill", "none")
+                .set("stroke", options.pen_colour.as_str())
+                .set("stroke-width", options.pen_width)
+                .set("d", data);
+            doc = doc.add(path);
+
+            if options.start_markers {
+                let marker = Circle::new()
+                    .set("cx", start.0)
+                    .set("cy", start.1)
+                    .set("r", options.pen_width * 3.0)
+                    .set("fill", "none")
+                    .set("stroke", "green")
+                    .set("stroke-width", options.pen_width / 2.0);
+                doc = doc.add(marker);
+            }
+
+            if options.number_strokes {
+                let label = Text::new()
+                    .set("x", start.0 + options.pen_width * 4.0)
+                    .set("y", start.1 - options.pen_width * 4.0)
+                    .set("font-size", options.pen_width * 20.0)
+                    .set("fill", "red")
+                    .add(TextNode::new((number + 1).to_string()));
+                doc = doc.add(label);
+            }
+        }
+
+        doc
+    }
+
+    /// Render the simulation as an animated SVG that draws every move in the order and at the
+    /// pace the device executes them. The animation runs `speed_up` times faster than the
+    /// device.
+    pub fn animate(&self, options: &Options, speed_up: f64) -> Document {
+        let mut doc = self.document(options);
+        let mut begin = 0.0;
+
+        for m in &self.moves {
+            let duration = m.duration / speed_up;
+            let (from, to) = (self.to_svg(m.from), self.to_svg(m.to));
+            let data = Data::new().move_to(from).line_to(to);
+
+            if m.pen_down {
+                // Reveal the line by shrinking the offset of a dash as long as the line itself.
+                let length = (to.0 - from.0).hypot(to.1 - from.1);
+                let path = SVG_Path::new()
+                    .set("fill", "none")
+                    .set("stroke", options.pen_colour.as_str())
+                    .set("stroke-width", options.pen_width)
+                    .set("stroke-dasharray", length)
+                    .set("stroke-dashoffset", length)
+                    .set("d", data)
+                    .add(
+                        Animate::new()
+                            .set("attributeName", "stroke-dashoffset")
+                            .set("from", length)
+                            .set("to", 0)
+                            .set("begin", format!("{:.3}ms", begin))
+                            .set("dur", format!("{:.3}ms", duration.max(0.001)))
+                            .set("fill", "freeze"),
+                    );
+                doc = doc.add(path);
+            } else if options.show_travel {
+                let travel = SVG_Path::new()
+                    .set("fill", "none")
+                    .set("stroke", "grey")
+                    .set("stroke-dasharray", "50,50")
+                    .set("stroke-width", options.pen_width / 4.0)
+                    .set("visibility", "hidden")
+                    .set("d", data)
+                    .add(
+                        Animate::new()
+                            .set("attributeName", "visibility")
+                            .set("to", "visible")
+                            .set("begin", format!("{:.3}ms", begin))
+                            .set("dur", "1ms")
+                            .set("fill", "freeze"),
+                    );
+                doc = doc.add(travel);
+            }
+
+            begin += duration;
+        }
+
+        doc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Plot {
+        Plot::from_path(vec![
+            Coordinate::new(100, 100),
+            Coordinate::new(200, 100),
+            Coordinate::new(200, 200),
+            Coordinate::new(100, 200),
+            Coordinate::new(100, 100),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn simulate_ebb_mixed_axis_steps() {
+        let moves = Simulation::from_ebb_commands(&[
+            Command::SM {
+                duration: 1000,
+                axis_step_1: 100,
+                axis_step_2: Some(100),
+            },
+            Command::SP {
+                value: 0,
+                duration: None,
+            },
+            Command::SM {
+                duration: 1000,
+                axis_step_1: 100,
+                axis_step_2: Some(-100),
+            },
+        ])
+        .moves;
+
+        assert_eq!(
+            moves,
+            vec![
+                Move {
+                    from: (0.0, 0.0),
+                    to: (0.0, -100.0),
+                    pen_down: false,
+                    duration: 1000.0,
+                },
+                Move {
+                    from: (0.0, -100.0),
+                    to: (100.0, -100.0),
+                    pen_down: true,
+                    duration: 1000.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn simulate_ebb_plot() {
+        let square = square();
+        let strokes = Simulation::ebb(&square).unwrap().strokes();
+
+        // Only the square is drawn, travel to and from it is made with the pen up.
+        assert_eq!(strokes.len(), 1);
+        let drawn: Vec<(f64, f64)> = std::iter::once(strokes[0][0].from)
+            .chain(strokes[0].iter().map(|m| m.to))
+            .collect();
+        let path: Vec<(f64, f64)> = square.paths().paths[0]
+            .iter()
+            .map(|point| (point.x as f64, point.y as f64))
+            .collect();
+        assert_eq!(drawn, path);
+    }
+
+    #[test]
+    fn simulate_roland_dxy_plot() {
+        let simulation = Simulation::roland_dxy(&square()).unwrap();
+        let strokes = simulation.strokes();
+
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes[0].len(), 4);
+        assert_eq!(strokes[0][0].from, strokes[0][3].to);
+    }
+
+    #[test]
+    fn simulate_ebb_duration() {
+        let simulation = Simulation::ebb(&square()).unwrap();
+
+        // Every `SM` takes 1 second. The stroke takes 4 moves, moving to the start of the stroke
+        // and back to home take 1 move each.
+        assert_eq!(simulation.duration(), Duration::from_secs(6));
+
+        // The first move goes to the start of the square, the next one to its right.
+        assert_eq!(simulation.moves[0].to, (100.0, 100.0));
+        assert_eq!(simulation.moves[1].to, (200.0, 100.0));
+
+        // Only the moves along the square are made with the pen down.
+        assert_eq!(
+            simulation
+                .moves
+                .iter()
+                .map(|m| m.pen_down)
+                .collect::<Vec<_>>(),
+            vec![false, true, true, true, true, false]
+        );
+    }
+
+    #[test]
+    fn animate_ebb_plot() {
+        let options = Options {
+            show_travel: true,
+            ..Default::default()
+        };
+        let document = Simulation::ebb(&square())
+            .unwrap()
+            .animate(&options, 10.0)
+            .to_string();
+
+        // The attribute, begin and duration of every animation, in order.
+        let attribute = |tag: &str, name: &str| -> String {
+            let start = tag.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+            tag[start..].split('"').next().unwrap().to_string()
+        };
+        let animations: Vec<(String, String, String)> = document
+            .split("<animate ")
+            .skip(1)
+            .map(|tag| {
+                let tag = format!(" {}", tag.split('>').next().unwrap());
+                (
+                    attribute(&tag, "attributeName"),
+                    attribute(&tag, "begin"),
+                    attribute(&tag, "dur"),
+                )
+            })
+            .collect();
+
+        // Every `SM` takes 1 second, 100 ms when sped up. Travel is revealed at once, the sides
+        // of the square are drawn one after the other.
+        let animation = |name: &str, begin: &str, duration: &str| {
+            (name.to_string(), begin.to_string(), duration.to_string())
+        };
+        assert_eq!(
+            animations,
+            vec![
+                animation("visibility", "0.000ms", "1ms"),
+                animation("stroke-dashoffset", "100.000ms", "100.000ms"),
+                animation("stroke-dashoffset", "200.000ms", "100.000ms"),
+                animation("stroke-dashoffset", "300.000ms", "100.000ms"),
+                animation("stroke-dashoffset", "400.000ms", "100.000ms"),
+                animation("visibility", "500.000ms", "1ms"),
+            ]
+        );
+    }
+
+    #[test]
+    fn simulate_grbl_plot() {
+        let simulation = Simulation::grbl(&square(), &Default::default()).unwrap();
+        let strokes = simulation.strokes();
+
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes[0].len(), 4);
+        assert_eq!(strokes[0][0].from, (100.0, 100.0));
+
+        // 100 mm at 1000 mm per minute.
+        assert_eq!(strokes[0][0].duration, 6000.0);
+    }
+}
@@ -0,0 +1,388 @@
+//! An elevation profile of GPX tracks: elevation against distance, with axes, labels, the lowest
+//! and highest point and the total ascent and descent.
+//!
+//! The profile is drawn on the `Plot` of a map, below or beside it, in layers of its own.
+use std::str::FromStr;
+
+use crate::cartography::{self, TEXT_HEIGHT_MM};
+use crate::geo;
+use crate::gpx::Track;
+use crate::projection;
+use crate::text::{self, Point};
+use crate::{Error, Plot};
+
+/// Changes of elevation smaller than this are noise and don't count as ascent or descent, in
+/// meters.
+const HYSTERESIS: f64 = 5.0;
+
+/// Part of the page taken by the profile.
+const SHARE: f64 = 0.25;
+
+/// Least space between the map and the profile, in millimeters.
+const GAP_MM: f64 = 5.0;
+
+/// Length of the ticks on the axes, in millimeters.
+const TICK_MM: f64 = 1.5;
+
+/// An area of the page: left, top, right and bottom in units of the plot.
+pub type Area = (f64, f64, f64, f64);
+
+/// Where the profile goes on the page.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Placement {
+    #[default]
+    Below,
+    Beside,
+}
+
+impl FromStr for Placement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "below" => Ok(Self::Below),
+            "beside" => Ok(Self::Beside),
+            _ => Err(format!(
+                "Invalid placement {:?}, expected \"below\" or \"beside\".",
+                s
+            )),
+        }
+    }
+}
+
+/// Elevation against distance along tracks.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Profile {
+    /// Distance and elevation, in meters, of the points with an elevation, per track segment.
+    pub segments: Vec<Vec<(f64, f64)>>,
+
+    /// Length of all tracks in meters. Gaps between segments don't count.
+    pub distance: f64,
+
+    /// Distance and elevation of the lowest and highest point.
+    pub min: (f64, f64),
+    pub max: (f64, f64),
+
+    /// Total ascent and descent in meters.
+    pub ascent: f64,
+    pub descent: f64,
+}
+
+impl Profile {
+    /// Build the profile of the tracks, one after another. Fails without at least two points with
+    /// an elevation.
+    pub fn new(tracks: &[Track]) -> Result<Self, Error> {
+        let mut distance = 0.0;
+        let mut segments = vec![];
+        for segment in tracks.iter().flat_map(|track| &track.segments) {
+            let mut points = vec![];
+            for (index, point) in segment.iter().enumerate() {
+                if index > 0 {
+                    distance += geo::distance(segment[index - 1].position, point.position);
+                }
+                if let Some(elevation) = point.elevation {
+                    points.push((distance, f64::from(elevation)));
+                }
+            }
+            segments.push(points);
+        }
+
+        let points = || segments.iter().flatten();
+        if points().count() < 2 {
+            return Err(Error::GpxError(
+                "The tracks need at least two points with an elevation for a profile.".to_string(),
+            ));
+        }
+        let min = *points().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        let max = *points().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+
+        let (mut ascent, mut descent) = (0.0, 0.0);
+        for segment in segments.iter().filter(|segment| !segment.is_empty()) {
+            let mut reference = segment[0].1;
+            for (_, elevation) in segment {
+                if elevation - reference >= HYSTERESIS {
+                    ascent += elevation - reference;
+                    reference = *elevation;
+                } else if reference - elevation >= HYSTERESIS {
+                    descent += reference - elevation;
+                    reference = *elevation;
+                }
+            }
+        }
+
+        Ok(Self {
+            segments,
+            distance,
+            min,
+            max,
+            ascent,
+            descent,
+        })
+    }
+}
+
+/// Split a page of `width` and `height` with a margin around, all in units of the plot, between a
+/// map and a profile. Returns the page left for the map, which starts at the top left corner, and
+/// the area of the profile: left, top, right and bottom.
+pub fn split(
+    (width, height): (f64, f64),
+    margin: f64,
+    units_per_mm: f64,
+    placement: Placement,
+) -> ((f64, f64), Area) {
+    let gap = margin.max(GAP_MM * units_per_mm);
+    match placement {
+        Placement::Below => {
+            let size = (height - 2.0 * margin) * SHARE;
+            let top = height - margin - size;
+            (
+                (width, top - gap + margin),
+                (margin, top, width - margin, height - margin),
+            )
+        }
+        Placement::Beside => {
+            let size = (width - 2.0 * margin) * SHARE;
+            let left = width - margin - size;
+            // Half as high as wide, at the middle of the page.
+            let half = (size / 4.0).min((height - 2.0 * margin) / 2.0);
+            (
+                (left - gap + margin, height),
+                (
+                    left,
+                    height / 2.0 - half,
+                    width - margin,
+                    height / 2.0 + half,
+                ),
+            )
+        }
+    }
+}
+
+/// Round ticks from `min` to at least `max`, at most `count` steps apart.
+fn ticks(min: f64, max: f64, count: f64) -> Vec<f64> {
+    let mut step = projection::round_distance((max - min) / count).max(1.0);
+    while (max / step).ceil() - (min / step).floor() > count {
+        // The next of 1, 2 and 5 times a power of 10.
+        step = projection::round_distance(step * 2.5);
+    }
+    let first = (min / step).floor() as i64;
+    let last = ((max / step).ceil() as i64).max(first + 1);
+    (first..=last).map(|tick| tick as f64 * step).collect()
+}
+
+/// A distance as label, in kilometers from 2 km.
+fn distance_label(meters: f64, step: f64) -> String {
+    if step >= 1000.0 || meters >= 2000.0 && step >= 100.0 {
+        format!("{} km", text::number(meters / 1000.0))
+    } else {
+        format!("{} m", text::number(meters))
+    }
+}
+
+/// Draw the profile into `area` of the plot: left, top, right and bottom in units of the plot.
+/// The labels of the axes and the ascent and descent are inside the area.
+pub fn add(plot: Plot, profile: &Profile, area: Area, units_per_mm: f64) -> Plot {
+    let mm = units_per_mm;
+    let height = TEXT_HEIGHT_MM * mm;
+    let (left, top, right, bottom) = area;
+
+    let elevations = ticks(profile.min.1, profile.max.1, 4.0);
+    let elevation_labels: Vec<String> = elevations
+        .iter()
+        .map(|elevation| format!("{} m", text::number(*elevation)))
+        .collect();
+    let label_width = elevation_labels
+        .iter()
+        .map(|label| text::width(label, height))
+        .fold(0.0, f64::max);
+
+    // The chart, with room for the labels around it.
+    let chart_left = left + label_width + TICK_MM * mm + 1.0 * mm;
+    let chart_top = top + height + 2.0 * mm;
+    let chart_bottom = bottom - 2.0 * height - 3.0 * mm - TICK_MM * mm;
+    // Room for the circle around the last point.
+    let chart_right = right - 1.0 * mm;
+    let (low, high) = (elevations[0], elevations[elevations.len() - 1]);
+    let to_chart = |(distance, elevation): (f64, f64)| -> Point {
+        (
+            chart_left + distance / profile.distance.max(1.0) * (chart_right - chart_left),
+            chart_bottom - (elevation - low) / (high - low) * (chart_bottom - chart_top),
+        )
+    };
+
+    let line: Vec<Vec<Point>> = profile
+        .segments
+        .iter()
+        .map(|segment| segment.iter().map(|point| to_chart(*point)).collect())
+        .collect();
+
+    let mut axes = vec![vec![
+        (chart_left, chart_top),
+        (chart_left, chart_bottom),
+        (chart_right, chart_bottom),
+    ]];
+    let mut labels = vec![];
+    for (elevation, label) in elevations.iter().zip(&elevation_labels) {
+        let (_, y) = to_chart((0.0, *elevation));
+        axes.push(vec![(chart_left - TICK_MM * mm, y), (chart_left, y)]);
+        labels.extend(text::strokes(
+            label,
+            (
+                chart_left - TICK_MM * mm - 1.0 * mm - text::width(label, height),
+                y - height / 2.0,
+            ),
+            height,
+        ));
+    }
+
+    // As many ticks as there is room for their labels, at most 5.
+    let longest = (profile.distance / 100.0).round() * 100.0;
+    let widest = text::width(&distance_label(longest, longest), height);
+    let room = ((chart_right - chart_left) / (widest + 3.0 * mm)).floor();
+    let distances = ticks(0.0, profile.distance, room.clamp(1.0, 5.0));
+    let step = distances[1] - distances[0];
+    for distance in distances
+        .into_iter()
+        .filter(|distance| *distance <= profile.distance)
+    {
+        let (x, _) = to_chart((distance, low));
+        axes.push(vec![(x, chart_bottom), (x, chart_bottom + TICK_MM * mm)]);
+        let label = distance_label(distance, step);
+        let label_width = text::width(&label, height);
+        labels.extend(text::strokes(
+            &label,
+            (
+                (x - label_width / 2.0).min(right - label_width),
+                chart_bottom + TICK_MM * mm + 1.0 * mm,
+            ),
+            height,
+        ));
+    }
+
+    // The lowest and highest point, with a circle and their elevation below and above.
+    for ((distance, elevation), above) in [(profile.min, false), (profile.max, true)] {
+        let point = to_chart((distance, elevation));
+        axes.push(cartography::polygon(point, 0.75 * mm, 12, 0.0));
+        let label = format!("{:.0} m", elevation);
+        let label_width = text::width(&label, height);
+        let x = (point.0 - label_width / 2.0).clamp(chart_left, right - label_width);
+        let y = if above {
+            point.1 - 1.5 * mm - height
+        } else {
+            point.1 + 1.5 * mm
+        };
+        labels.extend(text::strokes(&label, (x, y), height));
+    }
+
+    let summary = format!(
+        "{:.1} km +{:.0} m -{:.0} m",
+        profile.distance / 1000.0,
+        profile.ascent,
+        profile.descent
+    );
+    labels.extend(text::strokes(
+        &summary,
+        (chart_left, bottom - height),
+        height,
+    ));
+
+    let mut paths = plot.into_paths();
+    paths.add_layer(cartography::layer("profile"), cartography::to_paths(line));
+    paths.add_layer(
+        cartography::layer("profile axes"),
+        cartography::to_paths(axes),
+    );
+    paths.add_layer(
+        cartography::layer("profile labels"),
+        cartography::to_paths(labels),
+    );
+
+    Plot::new(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::Feature;
+    use crate::gpx::TrackPoint;
+
+    fn track(points: &[(f32, Option<f32>)]) -> Track {
+        Track {
+            segments: vec![points
+                .iter()
+                .map(|(lon, elevation)| TrackPoint {
+                    position: (0.0, *lon),
+                    elevation: *elevation,
+                    time: None,
+                })
+                .collect()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn build_profile() {
+        let profile = Profile::new(&[track(&[
+            (0.0, Some(100.0)),
+            (0.01, Some(103.0)),
+            (0.02, None),
+            (0.03, Some(150.0)),
+            (0.04, Some(90.0)),
+        ])])
+        .unwrap();
+
+        assert_eq!(profile.segments[0].len(), 4);
+        assert!((profile.distance - 4447.8).abs() < 1.0);
+        assert_eq!(profile.min.1, 90.0);
+        assert_eq!(profile.max, (profile.segments[0][2].0, 150.0));
+        // The first 3 m are below the hysteresis, but count towards the climb.
+        assert_eq!(profile.ascent, 50.0);
+        assert_eq!(profile.descent, 60.0);
+
+        assert!(Profile::new(&[track(&[(0.0, Some(1.0)), (0.1, None)])]).is_err());
+    }
+
+    #[test]
+    fn split_page() {
+        let (page, area) = split((1000.0, 800.0), 40.0, 10.0, Placement::Below);
+        assert_eq!(page, (1000.0, 570.0));
+        assert_eq!(area, (40.0, 580.0, 960.0, 760.0));
+
+        let (page, area) = split((1000.0, 800.0), 0.0, 10.0, Placement::Beside);
+        assert_eq!(page, (700.0, 800.0));
+        assert_eq!(area, (750.0, 337.5, 1000.0, 462.5));
+    }
+
+    #[test]
+    fn draw_profile() {
+        let track = track(&[(0.0, Some(480.0)), (0.05, Some(1020.0))]);
+        let profile = Profile::new(std::slice::from_ref(&track)).unwrap();
+        let feature = Feature {
+            lines: vec![track.segments[0]
+                .iter()
+                .map(|point| point.position)
+                .collect()],
+            ..Default::default()
+        };
+        let map = geo::project(vec![feature], &geo::Options::default()).unwrap();
+        let area = (0.0, 1000.0, 4000.0, 3000.0);
+        let plot = add(map.plot, &profile, area, 40.0);
+
+        let paths = plot.paths();
+        let names: Vec<_> = paths
+            .layers
+            .iter()
+            .filter_map(|layer| layer.name.as_deref())
+            .collect();
+        assert_eq!(names, ["profile", "profile axes", "profile labels"]);
+        assert!(paths.paths[1..]
+            .iter()
+            .flatten()
+            .all(|point| { (0..=4000).contains(&point.x) && (1000..=3000).contains(&point.y) }));
+
+        // The line goes from the bottom left to the top right of the chart.
+        let line = &paths.paths[1];
+        assert!(line[0].y > line[1].y);
+        assert_eq!(line[1].x, 3960);
+    }
+}
@@ -0,0 +1,320 @@
+//! Map projections from latitude and longitude on the WGS 84 ellipsoid onto a plane in meters,
+//! and the scale of a map.
+//!
+//! Projected coordinates are easting and northing. False eastings and northings are left out,
+//! because maps are moved to the origin of the plot anyway.
+use std::f64::consts::FRAC_PI_4;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::geo::LatLon;
+
+/// Semi-major axis of WGS 84 in meters.
+const A: f64 = 6_378_137.0;
+
+/// Flattening of WGS 84.
+const F: f64 = 1.0 / 298.257_223_563;
+
+/// Scale factor on the central meridian of UTM zones.
+const UTM_K0: f64 = 0.9996;
+
+/// Web Mercator can't show the poles, it's cut off at this latitude.
+const WEB_MERCATOR_MAX_LATITUDE: f64 = 85.051_128_78;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Projection {
+    /// The spherical Mercator of web maps. Distorts area away from the equator.
+    WebMercator,
+
+    /// Universal Transverse Mercator. The zone is derived from the centre of the map when not
+    /// given. The exceptions around Norway and Svalbard are not applied.
+    Utm { zone: Option<u8> },
+
+    /// Transverse Mercator with a scale factor of 1 on the given central meridian, in degrees.
+    TransverseMercator { central_meridian: f64 },
+
+    /// Plate carrée, with longitude scaled by the cosine of the standard parallel. The parallel
+    /// defaults to the centre of the map.
+    Equirectangular { standard_parallel: Option<f64> },
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Projection::Utm { zone: None }
+    }
+}
+
+/// The UTM zone of a longitude in degrees.
+pub fn utm_zone(lon: f64) -> u8 {
+    (((lon + 180.0) / 6.0).floor() as i32).rem_euclid(60) as u8 + 1
+}
+
+/// The central meridian of a UTM zone in degrees.
+fn utm_central_meridian(zone: u8) -> f64 {
+    f64::from(zone) * 6.0 - 183.0
+}
+
+/// Transverse Mercator on the ellipsoid, after "Map Projections: A Working Manual" by Snyder.
+/// Returns the easting, the northing and the scale factor at the point.
+fn transverse_mercator(lat: f64, lon: f64, central_meridian: f64, k0: f64) -> (f64, f64, f64) {
+    let e2 = F * (2.0 - F);
+    let e4 = e2 * e2;
+    let e6 = e4 * e2;
+    let ep2 = e2 / (1.0 - e2);
+
+    let phi = lat.to_radians();
+    let (sin, cos) = phi.sin_cos();
+    let n = A / (1.0 - e2 * sin * sin).sqrt();
+    let t = phi.tan().powi(2);
+    let c = ep2 * cos * cos;
+    let a = (lon - central_meridian).to_radians() * cos;
+    let m = A
+        * ((1.0 - e2 / 4.0 - 3.0 * e4 / 64.0 - 5.0 * e6 / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e4 / 32.0 + 45.0 * e6 / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e4 / 256.0 + 45.0 * e6 / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e6 / 3072.0) * (6.0 * phi).sin());
+
+    let x = k0
+        * n
+        * (a + (1.0 - t + c) * a.powi(3) / 6.0
+            + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * a.powi(5) / 120.0);
+    let y = k0
+        * (m + n
+            * phi.tan()
+            * (a * a / 2.0
+                + (5.0 - t + 9.0 * c + 4.0 * c * c) * a.powi(4) / 24.0
+                + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * a.powi(6) / 720.0));
+    let k = k0
+        * (1.0
+            + (1.0 + c) * a * a / 2.0
+            + (5.0 - 4.0 * t + 42.0 * c + 13.0 * c * c - 28.0 * ep2) * a.powi(4) / 24.0
+            + (61.0 - 148.0 * t + 16.0 * t * t) * a.powi(6) / 720.0);
+
+    (x, y, k)
+}
+
+impl Projection {
+    /// Fill in the UTM zone or standard parallel that depend on the map, from its centre.
+    pub fn resolve(&self, centre: LatLon) -> Self {
+        let (lat, lon) = (f64::from(centre.0), f64::from(centre.1));
+        match *self {
+            Projection::Utm { zone: None } => Projection::Utm {
+                zone: Some(utm_zone(lon)),
+            },
+            Projection::Equirectangular {
+                standard_parallel: None,
+            } => Projection::Equirectangular {
+                standard_parallel: Some(lat),
+            },
+            projection => projection,
+        }
+    }
+
+    /// Project a point. Returns the easting and northing in meters, and the scale factor: the
+    /// ratio between a short distance on the projection and on the ground.
+    ///
+    /// A missing UTM zone is taken from the point, a missing standard parallel is the equator.
+    /// Use `resolve()` first so all points of a map are projected alike.
+    pub fn project_with_scale(&self, point: LatLon) -> (f64, f64, f64) {
+        let (lat, lon) = (f64::from(point.0), f64::from(point.1));
+        match *self {
+            Projection::WebMercator => {
+                let lat = lat.clamp(-WEB_MERCATOR_MAX_LATITUDE, WEB_MERCATOR_MAX_LATITUDE);
+                let phi = lat.to_radians();
+                (
+                    A * lon.to_radians(),
+                    A * (FRAC_PI_4 + phi / 2.0).tan().ln(),
+                    1.0 / phi.cos(),
+                )
+            }
+            Projection::Utm { zone } => {
+                let zone = zone.unwrap_or_else(|| utm_zone(lon));
+                transverse_mercator(lat, lon, utm_central_meridian(zone), UTM_K0)
+            }
+            Projection::TransverseMercator { central_meridian } => {
+                transverse_mercator(lat, lon, central_meridian, 1.0)
+            }
+            Projection::Equirectangular { standard_parallel } => {
+                let parallel = standard_parallel.unwrap_or_default().to_radians().cos();
+                // Along the parallels the scale is off by cos(parallel) / cos(lat), along the
+                // meridians it's exact. Use the mean.
+                let k = (1.0 + parallel / lat.to_radians().cos().max(f64::EPSILON)) / 2.0;
+                (A * lon.to_radians() * parallel, A * lat.to_radians(), k)
+            }
+        }
+    }
+
+    /// Project a point to easting and northing in meters.
+    pub fn project(&self, point: LatLon) -> (f64, f64) {
+        let (x, y, _) = self.project_with_scale(point);
+        (x, y)
+    }
+}
+
+impl fmt::Display for Projection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Projection::WebMercator => write!(f, "web-mercator"),
+            Projection::Utm { zone: None } => write!(f, "utm"),
+            Projection::Utm { zone: Some(zone) } => write!(f, "utm:{}", zone),
+            Projection::TransverseMercator { central_meridian } => {
+                write!(f, "transverse-mercator:{}", central_meridian)
+            }
+            Projection::Equirectangular {
+                standard_parallel: None,
+            } => write!(f, "equirectangular"),
+            Projection::Equirectangular {
+                standard_parallel: Some(parallel),
+            } => write!(f, "equirectangular:{}", parallel),
+        }
+    }
+}
+
+impl FromStr for Projection {
+    type Err = String;
+
+    /// Parse "web-mercator", "utm", "utm:<zone>", "transverse-mercator:<central meridian>",
+    /// "equirectangular" or "equirectangular:<standard parallel>".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, parameter) = match s.split_once(':') {
+            Some((name, parameter)) => (name, Some(parameter)),
+            None => (s, None),
+        };
+        let degrees = |parameter: &str| {
+            parameter
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid degrees {:?} in projection {:?}.", parameter, s))
+        };
+
+        match (name, parameter) {
+            ("web-mercator", None) => Ok(Projection::WebMercator),
+            ("utm", None) => Ok(Projection::Utm { zone: None }),
+            ("utm", Some(zone)) => match zone.parse() {
+                Ok(zone @ 1..=60) => Ok(Projection::Utm { zone: Some(zone) }),
+                _ => Err(format!("Invalid UTM zone {:?}, expected 1 to 60.", zone)),
+            },
+            ("transverse-mercator", Some(meridian)) => Ok(Projection::TransverseMercator {
+                central_meridian: degrees(meridian)?,
+            }),
+            ("equirectangular", parallel) => Ok(Projection::Equirectangular {
+                standard_parallel: parallel.map(degrees).transpose()?,
+            }),
+            _ => Err(format!(
+                "Unknown projection {:?}, expected \"web-mercator\", \"utm\", \"utm:<zone>\", \
+                 \"transverse-mercator:<central meridian>\" or \"equirectangular\".",
+                s
+            )),
+        }
+    }
+}
+
+/// The scale of a map.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Scale {
+    /// A cartographic scale, like 25 000 for 1:25 000.
+    Ratio(f64),
+
+    /// As large as fits the page.
+    #[default]
+    Fit,
+}
+
+impl FromStr for Scale {
+    type Err = String;
+
+    /// Parse "fit", "1:25000" or "25000". Spaces, underscores and commas in the number are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "fit" {
+            return Ok(Scale::Fit);
+        }
+
+        let number: String = s
+            .strip_prefix("1:")
+            .unwrap_or(s)
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | ','))
+            .collect();
+        match number.parse::<f64>() {
+            Ok(ratio) if ratio > 0.0 => Ok(Scale::Ratio(ratio)),
+            _ => Err(format!(
+                "Invalid scale {:?}, expected \"fit\" or like \"1:25000\".",
+                s
+            )),
+        }
+    }
+}
+
+/// The longest round distance, 1, 2 or 5 times a power of 10, in meters, not longer than
+/// `meters`.
+pub fn round_distance(meters: f64) -> f64 {
+    if meters <= 0.0 || !meters.is_finite() {
+        return 0.0;
+    }
+
+    let power = 10f64.powf(meters.log10().floor());
+    [5.0, 2.0, 1.0]
+        .into_iter()
+        .map(|factor| factor * power)
+        .find(|distance| *distance <= meters * (1.0 + 1e-9))
+        .unwrap_or(power)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn project_points() {
+        let (x, y) = Projection::WebMercator.project((0.0, 180.0));
+        assert_close(x, 20_037_508.34, 0.01);
+        assert_close(y, 0.0, 1e-9);
+
+        // On the central meridian, only the scale factor of UTM applies.
+        let (x, _, k) = Projection::Utm { zone: Some(32) }.project_with_scale((47.0, 9.0));
+        assert_close(x, 0.0, 1e-6);
+        assert_close(k, UTM_K0, 1e-12);
+
+        // The meridian arc from the equator to 45° is 4 984 944 m.
+        let (_, y) = Projection::TransverseMercator {
+            central_meridian: 0.0,
+        }
+        .project((45.0, 0.0));
+        assert_close(y, 4_984_944.4, 1.0);
+
+        let projection = Projection::Utm { zone: None }.resolve((47.0, 11.0));
+        assert_eq!(projection, Projection::Utm { zone: Some(32) });
+        assert_eq!(utm_zone(-180.0), 1);
+        assert_eq!(utm_zone(179.9), 60);
+    }
+
+    #[test]
+    fn parse_projections_and_scales() {
+        for text in ["web-mercator", "utm", "utm:33", "transverse-mercator:9.5"] {
+            assert_eq!(text.parse::<Projection>().unwrap().to_string(), text);
+        }
+        assert!("utm:61".parse::<Projection>().is_err());
+        assert!("mercator".parse::<Projection>().is_err());
+
+        assert_eq!("1:25 000".parse(), Ok(Scale::Ratio(25_000.0)));
+        assert_eq!("50000".parse(), Ok(Scale::Ratio(50_000.0)));
+        assert_eq!("fit".parse(), Ok(Scale::Fit));
+        assert!("1:0".parse::<Scale>().is_err());
+    }
+
+    #[test]
+    fn round_distances() {
+        assert_eq!(round_distance(740.0), 500.0);
+        assert_eq!(round_distance(2000.0), 2000.0);
+        assert_eq!(round_distance(1.9), 1.0);
+        assert_eq!(round_distance(0.0), 0.0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Rasterise a `Simulation` into a PNG image.
+//!
+//! The image covers the paper of the device. Lines are drawn as round capped segments. The
+//! coverage of every pixel is derived from its distance to a segment, which gives antialiased
+//! edges without the need for supersampling.
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path;
+
+use crate::preview::Simulation;
+use crate::Error;
+
+/// Colour of a pixel as red, green and blue.
+pub type Rgb = [u8; 3];
+
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+/// Options to tweak the image created by `Simulation::rasterize()`.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Resolution of the image in dots per inch.
+    pub dpi: f64,
+
+    /// Width of the pen in millimeters.
+    pub pen_width: f64,
+
+    /// Colour of the pen.
+    pub pen_colour: Rgb,
+
+    /// Colour of the paper.
+    pub background: Rgb,
+
+    /// Smooth the edges of lines.
+    pub antialias: bool,
+
+    /// Draw dashed guides at the given distance in millimeters from the edges of the paper.
+    pub margin_guides: Option<f64>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            dpi: 96.0,
+            pen_width: 0.5,
+            pen_colour: [0, 0, 0],
+            background: [255, 255, 255],
+            antialias: true,
+            margin_guides: None,
+        }
+    }
+}
+
+/// An RGB image.
+#[derive(Debug, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+
+    /// Pixels row by row, 3 bytes per pixel.
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    fn new(width: u32, height: u32, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: background.repeat((width * height) as usize),
+        }
+    }
+
+    /// Get the colour of the pixel at the given position.
+    pub fn pixel(&self, x: u32, y: u32) -> Rgb {
+        let i = ((y * self.width + x) * 3) as usize;
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]]
+    }
+
+    /// Blend `colour` into the pixel at the given position. `alpha` ranges from 0 to 1.
+    fn blend(&mut self, x: u32, y: u32, colour: Rgb, alpha: f64) {
+        let i = ((y * self.width + x) * 3) as usize;
+        for (channel, value) in colour.iter().enumerate() {
+            let current = self.pixels[i + channel] as f64;
+            self.pixels[i + channel] = (current + (*value as f64 - current) * alpha).round() as u8;
+        }
+    }
+
+    /// Draw a line with round caps from `from` to `to`. Positions are in pixels.
+    fn draw_line(
+        &mut self,
+        from: (f64, f64),
+        to: (f64, f64),
+        width: f64,
+        colour: Rgb,
+        antialias: bool,
+    ) {
+        let radius = width / 2.0;
+        let padding = radius + 1.0;
+
+        let clamp_x = |x: f64| x.max(0.0).min(self.width as f64) as u32;
+        let clamp_y = |y: f64| y.max(0.0).min(self.height as f64) as u32;
+        let (x_start, x_end) = (
+            clamp_x(from.0.min(to.0) - padding),
+            clamp_x(from.0.max(to.0) + padding),
+        );
+        let (y_start, y_end) = (
+            clamp_y(from.1.min(to.1) - padding),
+            clamp_y(from.1.max(to.1) + padding),
+        );
+
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                let distance = distance_to_segment((x as f64 + 0.5, y as f64 + 0.5), from, to);
+                let coverage = if antialias {
+                    (radius + 0.5 - distance).clamp(0.0, 1.0)
+                } else if distance <= radius {
+                    1.0
+                } else {
+                    0.0
+                };
+
+                if coverage > 0.0 {
+                    self.blend(x, y, colour, coverage);
+                }
+            }
+        }
+    }
+
+    /// Encode the image as PNG.
+    pub fn write_png<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Save the image as PNG at the given location.
+    pub fn save(&self, path: &path::Path) -> Result<(), Error> {
+        self.write_png(BufWriter::new(File::create(path)?))
+    }
+}
+
+/// Distance between a point and the segment from `a` to `b`.
+fn distance_to_segment(point: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    (point.0 - (a.0 + t * dx)).hypot(point.1 - (a.1 + t * dy))
+}
+
+impl Simulation {
+    /// Rasterise all moves made with the pen down on an image the size of the paper.
+    pub fn rasterize(&self, options: &Options) -> Image {
+        let pixels_per_unit = options.dpi / self.units_per_inch;
+        let pixels_per_millimeter = options.dpi / MILLIMETERS_PER_INCH;
+        let to_pixels = |position: (f64, f64)| {
+            let (x, y) = self.to_svg(position);
+            (x * pixels_per_unit, y * pixels_per_unit)
+        };
+
+        let width = (self.outline.0 * pixels_per_unit).ceil().max(1.0) as u32;
+        let height = (self.outline.1 * pixels_per_unit).ceil().max(1.0) as u32;
+        let mut image = Image::new(width, height, options.background);
+
+        if let Some(margin) = options.margin_guides {
+            let margin = margin * pixels_per_millimeter;
+            let dash = 2.0 * pixels_per_millimeter;
+            let (right, bottom) = (width as f64 - margin, height as f64 - margin);
+            let corners = [
+                (margin, margin),
+                (right, margin),
+                (right, bottom),
+                (margin, bottom),
+                (margin, margin),
+            ];
+
+            for side in corners.windows(2) {
+                let (from, to) = (side[0], side[1]);
+                let length = (to.0 - from.0).hypot(to.1 - from.1);
+                let dashes = (length / (2.0 * dash)).ceil() as usize;
+                for i in 0..dashes {
+                    let start = (2 * i) as f64 * dash / length;
+                    let end = (((2 * i + 1) as f64 * dash) / length).min(1.0);
+                    let at = |t: f64| (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t);
+                    image.draw_line(at(start), at(end), 1.0, [160, 160, 160], options.antialias);
+                }
+            }
+        }
+
+        let pen_width = (options.pen_width * pixels_per_millimeter).max(1.0);
+        for m in self.moves.iter().filter(|m| m.pen_down) {
+            image.draw_line(
+                to_pixels(m.from),
+                to_pixels(m.to),
+                pen_width,
+                options.pen_colour,
+                options.antialias,
+            );
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preview::Move;
+
+    #[test]
+    fn rasterize_line() {
+        let simulation = Simulation {
+            moves: vec![Move {
+                from: (0.0, 50.0),
+                to: (100.0, 50.0),
+                pen_down: true,
+                duration: 0.0,
+            }],
+            outline: (100.0, 100.0),
+            units_per_inch: 10.0,
+            y_up: false,
+        };
+
+        let options = Options {
+            dpi: 1.0,
+            pen_width: 25.4,
+            antialias: false,
+            ..Default::default()
+        };
+        let image = simulation.rasterize(&options);
+
+        assert_eq!((image.width, image.height), (10, 10));
+        assert_eq!(image.pixel(4, 4), [0, 0, 0]);
+        assert_eq!(image.pixel(4, 0), [255, 255, 255]);
+    }
+}
@@ -1,11 +1,16 @@
-use std::io::{Cursor, Write};
+use std::io::{Read, Write};
 use std::path;
 use std::time::Duration;
 
 use serial_core::{BaudRate, CharSize, FlowControl, Parity, PortSettings, SerialPort, StopBits};
 
+use crate::plotter::{Plotter, Status};
 use crate::point::{Absolute, Coordinate, Relative};
-use crate::{Error, Plot, Strokes};
+use crate::preview::ROLAND_DXY_PLOTTING_AREA;
+use tracing::{debug, info, info_span, trace_span};
+
+use crate::traffic::{Connection, TrafficLog, Transport};
+use crate::{Error, Plot, Strokes, DEFAULT_TIMEOUT};
 
 #[derive(Debug)]
 pub enum Command {
@@ -75,42 +80,226 @@ impl std::fmt::Display for Command {
     }
 }
 
-fn to_hp_gl(strokes: &Strokes) -> Cursor<Vec<u8>> {
-    let mut hpgl = Cursor::new(Vec::new());
+/// Parse HP-GL into a series of `Command`s.
+///
+/// An instruction is a 2 letter mnemonic followed by parameters separated by commas, optionally
+/// terminated by a semicolon. Instructions that take multiple coordinates, like `PD1,2,3,4;`, are
+/// split into a command per coordinate. Unsupported instructions are skipped, like the text of
+/// `LB` up to its terminator: ETX, unless changed with `DT`.
+pub fn parse(text: &str) -> Result<Vec<Command>, Error> {
+    let mut commands = vec![];
+    let mut chars = text.chars().peekable();
+    let mut terminator = '\u{3}';
+
+    while let Some(c) = chars.next() {
+        if !c.is_ascii_alphabetic() {
+            continue;
+        }
+
+        let mnemonic: String = match chars.next() {
+            Some(second) if second.is_ascii_alphabetic() => [c, second].iter().collect(),
+            _ => return Err(Error::HpglError(format!("Invalid instruction {:?}.", c))),
+        };
+        let mnemonic = mnemonic.to_ascii_uppercase();
+
+        match mnemonic.as_str() {
+            "LB" => {
+                chars.find(|c| *c == terminator);
+                continue;
+            }
+            "DT" => {
+                terminator = match chars.next() {
+                    Some(';') | None => '\u{3}',
+                    Some(c) => c,
+                };
+                continue;
+            }
+            _ => {}
+        }
+
+        let mut parameters = String::new();
+        while let Some(&next) = chars.peek() {
+            // An exponent, like in `1e3`, is followed by a digit or sign. A mnemonic isn't.
+            let exponent = next.eq_ignore_ascii_case(&'e')
+                && parameters.ends_with(|c: char| c.is_ascii_digit() || c == '.')
+                && chars
+                    .clone()
+                    .nth(1)
+                    .is_some_and(|c| c.is_ascii_digit() || c == '+' || c == '-');
+            if (next.is_ascii_alphabetic() && !exponent) || next == ';' {
+                break;
+            }
+            parameters.push(next);
+            chars.next();
+        }
+
+        let numbers: Vec<i32> = parameters
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<f64>().map(|number| number.round() as i32))
+            .collect::<Result<_, _>>()
+            .map_err(|_| {
+                Error::HpglError(format!(
+                    "Failed to parse parameters {:?} of {}.",
+                    parameters, mnemonic
+                ))
+            })?;
+
+        if ["PA", "PD", "PU", "PR"].contains(&mnemonic.as_str()) && numbers.len() % 2 == 1 {
+            return Err(Error::HpglError(format!(
+                "Odd number of coordinates {:?} of {}.",
+                parameters, mnemonic
+            )));
+        }
+        let unsigned = |value: &i32| {
+            usize::try_from(*value).map_err(|_| {
+                Error::HpglError(format!("Negative parameter {} of {}.", value, mnemonic))
+            })
+        };
+
+        let coordinates = |absolute: fn(Option<Coordinate<Absolute>>) -> Command| {
+            if numbers.is_empty() {
+                return vec![absolute(None)];
+            }
+            numbers
+                .chunks_exact(2)
+                .map(|xy| absolute(Some(Coordinate::new(xy[0], xy[1]))))
+                .collect()
+        };
+
+        match (mnemonic.as_str(), &numbers[..]) {
+            ("IN", _) => commands.push(Command::IN),
+            ("IP", [p1_x, p1_y, p2_x, p2_y]) => commands.push(Command::IP(
+                unsigned(p1_x)?,
+                unsigned(p1_y)?,
+                unsigned(p2_x)?,
+                unsigned(p2_y)?,
+            )),
+            ("SC", [x_min, x_max, y_min, y_max]) => {
+                commands.push(Command::SC(*x_min, *x_max, *y_min, *y_max))
+            }
+            ("SP", []) => commands.push(Command::SP(0)),
+            ("SP", [pen, ..]) => commands.push(Command::SP(*pen as u8)),
+            ("PA", _) => commands.extend(coordinates(Command::PA)),
+            ("PD", _) => commands.extend(coordinates(Command::PD)),
+            ("PU", _) => commands.extend(coordinates(Command::PU)),
+            ("PR", []) => commands.push(Command::PR(None)),
+            ("PR", _) => commands.extend(
+                numbers
+                    .chunks_exact(2)
+                    .map(|xy| Command::PR(Some(Coordinate::new(xy[0], xy[1])))),
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(commands)
+}
 
-    hpgl.write(Command::SP(1).to_string().as_bytes());
+fn to_hp_gl(strokes: &Strokes) -> Vec<Command> {
+    let mut hpgl = vec![Command::SP(1)];
 
     strokes.0.iter().for_each(|stroke| {
         //Raise pen, just to be sure.
-        hpgl.write(&Command::PU(None).to_string().as_bytes());
+        hpgl.push(Command::PU(None));
 
         //Move to to absolute start of the stroke.
-        hpgl.write(&Command::PA(Some(stroke.start)).to_string().as_bytes());
+        hpgl.push(Command::PA(Some(stroke.start)));
 
         // Lower the pen.
-        hpgl.write(&Command::PD(None).to_string().as_bytes());
+        hpgl.push(Command::PD(None));
 
         stroke.path.iter().for_each(|point| {
             // Move to each coordinate relative to current position.
-            hpgl.write(&Command::PR(Some(*point)).to_string().as_bytes());
+            hpgl.push(Command::PR(Some(*point)));
         });
     });
 
     // Raise pen and move to home
-    hpgl.write(
-        &Command::PU(Some(Coordinate::new(0, 0)))
-            .to_string()
-            .as_bytes(),
-    );
+    hpgl.push(Command::PU(Some(Coordinate::new(0, 0))));
 
     // Return pen to slot and go home.
-    hpgl.write(&Command::SP(0).to_string().as_bytes());
+    hpgl.push(Command::SP(0));
 
     hpgl
 }
 
+/// Width and height of the plotting area of the Roland DXY in plotter units.
+pub(crate) fn default_travel() -> (i32, i32) {
+    (
+        ROLAND_DXY_PLOTTING_AREA.0 as i32,
+        ROLAND_DXY_PLOTTING_AREA.1 as i32,
+    )
+}
+
+/// Build the series of commands that `Driver::plot()` sends to a plotter with the default
+/// plotting area.
+pub fn plot_to_commands(plot: &Plot) -> Result<Vec<Command>, Error> {
+    plot_to_commands_with(plot, default_travel())
+}
+
+/// Build the series of commands that `Driver::plot()` sends to the plotter. The plot is scaled to
+/// fit `travel`, the width and height of the area the pen can reach, keeping its aspect ratio.
+pub fn plot_to_commands_with(plot: &Plot, travel: (i32, i32)) -> Result<Vec<Command>, Error> {
+    let strokes: Strokes = Strokes::try_from(&plot.paths)?;
+    let (length, height) = plot.dimensions();
+    let (travel_x, travel_y) = travel;
+
+    let x_ratio = travel_x as f32 / length as f32;
+    let y_ratio = travel_y as f32 / height as f32;
+
+    let mut commands = vec![
+        Command::IN,
+        Command::IP(0, 0, travel_x as usize, travel_y as usize),
+    ];
+    // Scale the user units of both axes alike, so the axis that fills the area sets the scale.
+    if x_ratio < y_ratio {
+        commands.push(Command::SC(
+            0,
+            length,
+            0,
+            (length as f32 * travel_y as f32 / travel_x as f32) as i32,
+        ));
+    } else {
+        commands.push(Command::SC(
+            0,
+            (height as f32 * travel_x as f32 / travel_y as f32) as i32,
+            0,
+            height,
+        ));
+    }
+
+    commands.extend(to_hp_gl(&strokes));
+
+    Ok(commands)
+}
+
+/// Build a `Status` from the response to `OA;`.
+pub(crate) fn parse_status(response: &str) -> Result<Status, Error> {
+    // The response looks like "1000,2000,1": the position and 1 when the pen is down.
+    let values: Vec<i32> = response
+        .split(',')
+        .map(|value| value.trim().parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| {
+            Error::ConversionError(format!("Failed to parse {:?} as status.", response))
+        })?;
+
+    match values[..] {
+        [x, y, pen] => Ok(Status {
+            position: Coordinate::new(x, y),
+            pen_down: pen == 1,
+        }),
+        _ => Err(Error::ConversionError(format!(
+            "Failed to parse {:?} as status.",
+            response
+        ))),
+    }
+}
+
 pub struct Driver {
-    file: serial_unix::TTYPort,
+    file: Connection,
+    travel: (i32, i32),
 }
 
 pub fn default_port_settings() -> PortSettings {
@@ -125,42 +314,216 @@ pub fn default_port_settings() -> PortSettings {
 
 impl Driver {
     pub fn open(path: &path::Path, settings: PortSettings) -> Result<Self, Error> {
+        Self::open_with_timeout(path, settings, DEFAULT_TIMEOUT)
+    }
+
+    /// Open the plotter and fail reads and writes that take longer than `timeout`.
+    pub fn open_with_timeout(
+        path: &path::Path,
+        settings: PortSettings,
+        timeout: Duration,
+    ) -> Result<Self, Error> {
         let mut port = serial::open(path)?;
-        port.set_timeout(Duration::from_millis(10000))?;
-        port.configure(&settings)
-            .expect("Failed to configure serial port.");
+        port.set_timeout(timeout)?;
+        port.configure(&settings)?;
 
-        Ok(Self { file: port })
+        Ok(Self::from_transport(port))
     }
 
-    pub fn plot(&mut self, plot: &Plot) -> Result<(), Error> {
-        let strokes: Strokes = Strokes::try_from(&plot.paths)?;
-        let (length, height) = plot.dimensions();
+    /// Create a driver that talks to the plotter through `transport`, for example a `Replay`.
+    pub fn from_transport(transport: impl Transport + 'static) -> Self {
+        Self {
+            file: Connection::new(transport),
+            travel: default_travel(),
+        }
+    }
+
+    /// Scale plots to fit `travel`, the width and height of the area the pen can reach, instead of
+    /// the default plotting area.
+    pub fn set_travel(&mut self, travel: (i32, i32)) {
+        self.travel = travel;
+    }
 
-        let aspect_ratio = 10_000.0 / 7_000.0;
-        let x_ratio = 10_000.0 / length as f32;
-        let y_ratio = 7_000.0 / height as f32;
+    /// Send an output instruction, like `OI;`, and return the response of the plotter. The
+    /// plotter terminates the response with a carriage return.
+    pub fn query(&mut self, instruction: &str) -> Result<String, Error> {
+        let _span = trace_span!("query", instruction).entered();
+        self.write_bytes(instruction)?;
 
-        self.file.write(Command::IN.to_string().as_bytes());
-        self.file
-            .write(Command::IP(0, 0, 10_000, 7000).to_string().as_bytes());
-        if x_ratio < y_ratio {
-            self.file.write(
-                Command::SC(0, length, 0, (height as f32 * aspect_ratio) as i32)
-                    .to_string()
-                    .as_bytes(),
-            );
-        } else {
-            self.file.write(
-                Command::SC(0, (length as f32 * aspect_ratio) as i32, 0, height)
-                    .to_string()
-                    .as_bytes(),
-            );
+        let mut response = String::new();
+        loop {
+            let mut buffer = [0; 1];
+            self.file
+                .read_exact(&mut buffer)
+                .map_err(|err| Error::ResponseError(instruction.to_string(), err))?;
+
+            if buffer[0] == b'\r' {
+                break;
+            }
+            response.push(buffer[0] as char);
         }
 
-        let hpgl = to_hp_gl(&strokes);
-        self.file.write(&hpgl.into_inner());
+        debug!("Response {:?}", response);
+
+        Ok(response)
+    }
+
+    fn write_bytes(&mut self, instruction: &str) -> Result<(), Error> {
+        debug!("Writing instruction: {:?}", instruction);
+
+        self.file
+            .write_all(instruction.as_bytes())
+            .map_err(|err| Error::CommandError(instruction.to_string(), err))
+    }
+
+    fn write(&mut self, command: &Command) -> Result<(), Error> {
+        let _span = trace_span!("command", %command).entered();
+        self.write_bytes(&command.to_string())
+    }
+}
+
+impl Plotter for Driver {
+    fn plot_with_progress(
+        &mut self,
+        plot: &Plot,
+        progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let commands = plot_to_commands_with(plot, self.travel)?;
+        let _span = info_span!("plot", commands = commands.len()).entered();
+
+        // Every stroke starts by raising the pen.
+        let starts_stroke = |command: &Command| matches!(command, Command::PU(None));
+        let total = commands
+            .iter()
+            .filter(|command| starts_stroke(command))
+            .count();
+
+        let mut stroke = None;
+        let mut index = 0;
+        for command in commands {
+            self.write(&command)?;
+
+            if starts_stroke(&command) {
+                // Close the previous stroke first, so the new one isn't nested in it.
+                drop(stroke.take());
+                if index > 0 {
+                    progress(index, total)?;
+                }
+                stroke = Some(info_span!("stroke", index).entered());
+                index += 1;
+                info!("Plotting stroke {}", index);
+            }
+        }
+        drop(stroke);
+        progress(total, total)?;
 
         Ok(())
     }
+
+    fn log_traffic(&mut self, log: TrafficLog) {
+        self.file.log_traffic(log);
+    }
+
+    fn pen_up(&mut self) -> Result<(), Error> {
+        self.write(&Command::PU(None))
+    }
+
+    fn pen_down(&mut self) -> Result<(), Error> {
+        self.write(&Command::PD(None))
+    }
+
+    fn move_by(&mut self, delta: Coordinate<Relative>) -> Result<(), Error> {
+        self.write(&Command::PR(Some(delta)))
+    }
+
+    fn home(&mut self) -> Result<(), Error> {
+        self.pen_up()?;
+        self.write(&Command::PA(Some(Coordinate::new(0, 0))))
+    }
+
+    fn set_origin(&mut self) -> Result<(), Error> {
+        Err(Error::UnsupportedError(
+            "The Roland DXY can't move its origin.".to_string(),
+        ))
+    }
+
+    fn status(&mut self) -> Result<Status, Error> {
+        let response = self.query("OA;")?;
+        parse_status(&response)
+    }
+
+    fn disable_motors(&mut self) -> Result<(), Error> {
+        Err(Error::UnsupportedError(
+            "The Roland DXY can't disable its motors.".to_string(),
+        ))
+    }
+
+    fn version(&mut self) -> Result<String, Error> {
+        self.query("OI;")
+    }
+
+    fn y_up(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_plot_to_travel() {
+        let plot = Plot::from_path(vec![
+            Coordinate::new(0, 0),
+            Coordinate::new(1000, 0),
+            Coordinate::new(1000, 500),
+        ])
+        .unwrap();
+
+        // The plot is twice as wide as high, like the area, so it fills the area.
+        let commands = plot_to_commands_with(&plot, (4000, 2000)).unwrap();
+        assert_eq!(commands[1].to_string(), "IP0,0,4000,2000");
+        assert_eq!(commands[2].to_string(), "SC0,1000,0,500");
+
+        // In a square area, the width limits the scale.
+        let commands = plot_to_commands_with(&plot, (2000, 2000)).unwrap();
+        assert_eq!(commands[2].to_string(), "SC0,1000,0,1000");
+    }
+
+    #[test]
+    fn parse_hpgl() {
+        let commands = parse("IN;IP0,0,10000,7000SC0,100,0,70SP1;PU;PA0,0;PD10,0,10,10;PR-5,0;")
+            .unwrap()
+            .iter()
+            .map(|command| command.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            commands,
+            vec![
+                "IN;",
+                "IP0,0,10000,7000",
+                "SC0,100,0,70",
+                "SP1;",
+                "PU;",
+                "PA0,0;",
+                "PD10,0;",
+                "PD10,10;",
+                "PR-5,0;"
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_hpgl_labels_and_exponents() {
+        let commands = parse("PA1e3,2.5E2;LBHello, world!\u{3}DT*;LBPD*PU;PD1,2EA3,4;")
+            .unwrap()
+            .iter()
+            .map(|command| command.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(commands, vec!["PA1000,250;", "PU;", "PD1,2;"]);
+
+        assert!(parse("PD1,2,3;").is_err());
+        assert!(parse("IP0,-10,100,100;").is_err());
+    }
 }
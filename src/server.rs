@@ -0,0 +1,1002 @@
+//! A server that owns one or more plotters and plots the jobs submitted to it, one at a time per
+//! plotter.
+//!
+//! Jobs are submitted over a small HTTP API, served on a TCP or a Unix socket. All responses are
+//! JSON:
+//!
+//! | Request                  | Effect                                                   |
+//! |--------------------------|----------------------------------------------------------|
+//! | `GET /plotters`          | List the plotters and the job each is plotting.          |
+//! | `GET /jobs`              | List all jobs, including finished ones.                  |
+//! | `POST /jobs`             | Queue the file in the body. See below.                   |
+//! | `GET /jobs/{id}`         | State and progress of a job.                             |
+//! | `POST /jobs/{id}/pause`  | Pause a running job after the current stroke.            |
+//! | `POST /jobs/{id}/resume` | Resume a paused job.                                     |
+//! | `POST /jobs/{id}/cancel` | Cancel a job. A running job stops after the current      |
+//! |                          | stroke and the plotter returns home.                     |
+//!
+//! `POST /jobs` takes the query parameters `name`, `format` ("svg", "gpx", "geojson", "kml",
+//! "hpgl", "dxf" or "paths") and `plotter`. The format defaults to the extension of the name.
+//! Without `plotter`, the first idle plotter plots the job. Maps are projected for the plotter
+//! that plots them. Bodies larger than 64 MiB are refused.
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::geo::{self, Feature};
+use crate::plotter::Plotter;
+use crate::point::Coordinate;
+use crate::preview::Simulation;
+use crate::{dxf, geojson, gpx, kml, roland_dxy, svg_file, Error, Paths, Plot};
+
+/// Largest body of a request in bytes.
+const MAX_BODY: u64 = 64 * 1024 * 1024;
+
+/// The formats of files that can be plotted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Svg,
+    Gpx,
+    GeoJson,
+    Kml,
+    Hpgl,
+    Dxf,
+
+    /// `Paths` as JSON: an array of paths, each an array of `[x, y]` points.
+    Paths,
+}
+
+impl Format {
+    /// The format of a file with the given extension.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "svg" => Some(Format::Svg),
+            "gpx" => Some(Format::Gpx),
+            "geojson" => Some(Format::GeoJson),
+            "kml" => Some(Format::Kml),
+            "hpgl" | "hpg" | "plt" => Some(Format::Hpgl),
+            "dxf" => Some(Format::Dxf),
+            "json" => Some(Format::Paths),
+            _ => None,
+        }
+    }
+
+    /// Whether this is a format of maps, with coordinates in latitude and longitude.
+    pub fn is_map(&self) -> bool {
+        matches!(self, Format::Gpx | Format::GeoJson | Format::Kml)
+    }
+
+    /// Read the features of a map.
+    pub fn features(&self, text: &str) -> Result<Vec<Feature>, Error> {
+        match self {
+            Format::Gpx => gpx::features(text),
+            Format::GeoJson => geojson::features(text),
+            Format::Kml => kml::features(text),
+            _ => Err(Error::UnsupportedError(format!("{:?} is not a map.", self))),
+        }
+    }
+
+    /// Parse a file of this format. `scale` is the number of units of the plot per user unit of
+    /// SVG and DXF. Maps are projected with the default `geo::Options`.
+    pub fn parse(&self, text: &str, scale: f64) -> Result<Plot, Error> {
+        self.parse_with(text, scale, &geo::Options::default())
+    }
+
+    /// Parse a file of this format. Maps are projected with `map`.
+    pub fn parse_with(&self, text: &str, scale: f64, map: &geo::Options) -> Result<Plot, Error> {
+        match self {
+            Format::Gpx | Format::GeoJson | Format::Kml => {
+                Ok(geo::project(self.features(text)?, map)?.plot)
+            }
+            Format::Svg => svg_file::parse(text, scale),
+            Format::Dxf => dxf::parse(text, scale),
+            Format::Hpgl => {
+                let commands = roland_dxy::parse(text)?;
+                Ok(Plot::new(
+                    Simulation::from_hpgl_commands(&commands).paths()?,
+                ))
+            }
+            Format::Paths => {
+                let paths: Vec<Vec<(i32, i32)>> = serde_json::from_str(text)
+                    .map_err(|err| Error::InvalidPathError(format!("Invalid paths: {}.", err)))?;
+                let paths = paths
+                    .into_iter()
+                    .map(|path| {
+                        path.into_iter()
+                            .map(|(x, y)| Coordinate::new(x, y))
+                            .collect()
+                    })
+                    .collect();
+                Ok(Plot::new(Paths::new(paths)?))
+            }
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "svg" => Ok(Format::Svg),
+            "gpx" => Ok(Format::Gpx),
+            "geojson" => Ok(Format::GeoJson),
+            "kml" => Ok(Format::Kml),
+            "hpgl" => Ok(Format::Hpgl),
+            "dxf" => Ok(Format::Dxf),
+            "paths" => Ok(Format::Paths),
+            _ => Err(format!(
+                "Unknown format {:?}, expected \"svg\", \"gpx\", \"geojson\", \"kml\", \"hpgl\", \
+                 \"dxf\" or \"paths\".",
+                s
+            )),
+        }
+    }
+}
+
+/// The state of a job.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum State {
+    Queued,
+    Running,
+    Paused,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+impl State {
+    /// Whether the job is finished, successfully or not.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, State::Done | State::Failed | State::Cancelled)
+    }
+}
+
+/// A plot submitted to the server.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct Job {
+    pub id: u64,
+    pub name: String,
+    pub state: State,
+
+    /// The plotter that plots the job. Until the job runs, it's the plotter that was requested,
+    /// if any.
+    pub plotter: Option<String>,
+
+    /// Number of strokes plotted.
+    pub progress: usize,
+    pub strokes: usize,
+
+    /// Seconds since the Unix epoch.
+    pub submitted: u64,
+    pub started: Option<u64>,
+    pub finished: Option<u64>,
+
+    /// Why the job failed.
+    pub error: Option<String>,
+}
+
+/// A plotter of the server and the job it's plotting.
+#[derive(Debug, PartialEq, Clone, Serialize)]
+pub struct PlotterInfo {
+    pub name: String,
+    pub job: Option<u64>,
+}
+
+/// What a job plots.
+enum Source {
+    Plot(Plot),
+
+    /// Features of a map, projected once a plotter takes the job.
+    Map(Vec<Feature>),
+}
+
+#[derive(Default)]
+struct Queue {
+    jobs: Vec<Job>,
+
+    /// What the jobs that haven't run yet plot.
+    sources: HashMap<u64, Source>,
+
+    plotters: Vec<PlotterInfo>,
+
+    /// How maps are projected for every plotter.
+    maps: HashMap<String, geo::Options>,
+
+    next_id: u64,
+    shutdown: bool,
+}
+
+impl Queue {
+    fn job_mut(&mut self, id: u64) -> Result<&mut Job, Error> {
+        self.jobs
+            .iter_mut()
+            .find(|job| job.id == id)
+            .ok_or_else(|| Error::JobError(format!("No job {}.", id)))
+    }
+
+    /// The oldest queued job that `plotter` may plot.
+    fn next_job(&self, plotter: &str) -> Option<u64> {
+        self.jobs
+            .iter()
+            .find(|job| {
+                job.state == State::Queued
+                    && job.plotter.as_deref().is_none_or(|name| name == plotter)
+            })
+            .map(|job| job.id)
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    queue: Mutex<Queue>,
+
+    /// Notified whenever the queue changes.
+    changed: Condvar,
+}
+
+/// Queues jobs and plots them. Cloning a `Server` gives another handle to the same queue.
+#[derive(Clone)]
+pub struct Server {
+    shared: Arc<Shared>,
+
+    /// Number of units of the plot per user unit when reading SVG.
+    scale: f64,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+impl Server {
+    /// Create a server without plotters. `scale` is used to read SVG.
+    pub fn new(scale: f64) -> Self {
+        Self {
+            shared: Arc::default(),
+            scale,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.shared
+            .queue
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Let `plotter` plot jobs, on a thread of its own. Maps are projected with the default
+    /// `geo::Options`.
+    pub fn add_plotter(&self, name: &str, plotter: Box<dyn Plotter>) {
+        self.add_plotter_with(name, plotter, geo::Options::default());
+    }
+
+    /// Let `plotter` plot jobs, on a thread of its own. Maps are projected with `map`.
+    pub fn add_plotter_with(&self, name: &str, plotter: Box<dyn Plotter>, map: geo::Options) {
+        let mut queue = self.lock();
+        queue.plotters.push(PlotterInfo {
+            name: name.to_string(),
+            job: None,
+        });
+        queue.maps.insert(name.to_string(), map);
+        drop(queue);
+
+        let server = self.clone();
+        let name = name.to_string();
+        thread::spawn(move || server.work(&name, plotter));
+    }
+
+    /// How maps are projected for `plotter`, or for the first plotter when it's `None`.
+    fn map_options(&self, plotter: Option<&str>) -> geo::Options {
+        let queue = self.lock();
+        plotter
+            .or(queue.plotters.first().map(|info| info.name.as_str()))
+            .and_then(|name| queue.maps.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Plot jobs until the server shuts down.
+    fn work(&self, name: &str, mut plotter: Box<dyn Plotter>) {
+        while let Some((id, source)) = self.take_job(name) {
+            info!("Plotting job {} on {}", id, name);
+            let result = match source {
+                Source::Plot(plot) => Ok(plot),
+                Source::Map(features) => {
+                    geo::project(features, &self.map_options(Some(name))).map(|map| map.plot)
+                }
+            }
+            .and_then(|plot| {
+                plotter.plot_with_progress(&plot, &mut |done, total| {
+                    self.report_progress(id, done, total)
+                })
+            });
+
+            let cancelled = self.finish_job(name, id, result);
+            if cancelled {
+                if let Err(err) = plotter.home() {
+                    warn!("Failed to return {} home: {}", name, err);
+                }
+            }
+        }
+    }
+
+    /// Wait for a job that `plotter` may plot and mark it running.
+    fn take_job(&self, plotter: &str) -> Option<(u64, Source)> {
+        let mut queue = self.lock();
+        loop {
+            if queue.shutdown {
+                return None;
+            }
+            if let Some(id) = queue.next_job(plotter) {
+                let source = queue.sources.remove(&id)?;
+                let job = queue.job_mut(id).ok()?;
+                job.state = State::Running;
+                job.plotter = Some(plotter.to_string());
+                if let Source::Plot(plot) = &source {
+                    job.strokes = plot.paths().paths.len();
+                }
+                job.started = Some(now());
+                if let Some(info) = queue.plotters.iter_mut().find(|p| p.name == plotter) {
+                    info.job = Some(id);
+                }
+                self.shared.changed.notify_all();
+                return Some((id, source));
+            }
+            queue = self
+                .shared
+                .changed
+                .wait(queue)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    /// Update the progress of a job. Blocks while the job is paused and fails when the job is
+    /// cancelled, which stops the plot.
+    fn report_progress(&self, id: u64, done: usize, total: usize) -> Result<(), Error> {
+        let mut queue = self.lock();
+        let job = queue.job_mut(id)?;
+        job.progress = done;
+        job.strokes = total;
+        self.shared.changed.notify_all();
+
+        loop {
+            match queue.job_mut(id)?.state {
+                State::Paused => {
+                    queue = self
+                        .shared
+                        .changed
+                        .wait(queue)
+                        .unwrap_or_else(|err| err.into_inner())
+                }
+                State::Cancelled => return Err(Error::JobError(format!("Job {} cancelled.", id))),
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Record the result of a job. Returns whether the job was cancelled.
+    fn finish_job(&self, plotter: &str, id: u64, result: Result<(), Error>) -> bool {
+        let mut queue = self.lock();
+        if let Some(info) = queue.plotters.iter_mut().find(|p| p.name == plotter) {
+            info.job = None;
+        }
+
+        let Ok(job) = queue.job_mut(id) else {
+            return false;
+        };
+        job.finished = Some(now());
+        let cancelled = job.state == State::Cancelled;
+        match result {
+            _ if cancelled => info!("Cancelled job {}", id),
+            Ok(()) => {
+                info!("Finished job {}", id);
+                job.state = State::Done;
+            }
+            Err(err) => {
+                warn!("Job {} failed: {}", id, err);
+                job.state = State::Failed;
+                job.error = Some(err.to_string());
+            }
+        }
+        self.shared.changed.notify_all();
+
+        cancelled
+    }
+
+    /// Queue a plot. When `plotter` is given, only that plotter plots it.
+    pub fn submit(&self, name: &str, plotter: Option<&str>, plot: Plot) -> Result<Job, Error> {
+        let strokes = plot.paths().paths.len();
+        self.queue(name, plotter, Source::Plot(plot), strokes)
+    }
+
+    /// Queue a map, projected for the plotter that plots it. When `plotter` is given, only that
+    /// plotter plots it.
+    pub fn submit_map(
+        &self,
+        name: &str,
+        plotter: Option<&str>,
+        features: Vec<Feature>,
+    ) -> Result<Job, Error> {
+        // Project the map once to reject maps without lines, and to count the strokes.
+        let map = geo::project(features.clone(), &self.map_options(plotter))?;
+        let strokes = map.plot.paths().paths.len();
+        self.queue(name, plotter, Source::Map(features), strokes)
+    }
+
+    fn queue(
+        &self,
+        name: &str,
+        plotter: Option<&str>,
+        source: Source,
+        strokes: usize,
+    ) -> Result<Job, Error> {
+        let mut queue = self.lock();
+        if let Some(plotter) = plotter {
+            if !queue.plotters.iter().any(|info| info.name == plotter) {
+                return Err(Error::JobError(format!("No plotter {:?}.", plotter)));
+            }
+        }
+
+        queue.next_id += 1;
+        let job = Job {
+            id: queue.next_id,
+            name: name.to_string(),
+            state: State::Queued,
+            plotter: plotter.map(str::to_string),
+            progress: 0,
+            strokes,
+            submitted: now(),
+            started: None,
+            finished: None,
+            error: None,
+        };
+        queue.sources.insert(job.id, source);
+        queue.jobs.push(job.clone());
+        self.shared.changed.notify_all();
+
+        Ok(job)
+    }
+
+    /// All jobs, oldest first.
+    pub fn jobs(&self) -> Vec<Job> {
+        self.lock().jobs.clone()
+    }
+
+    pub fn job(&self, id: u64) -> Result<Job, Error> {
+        self.lock().job_mut(id).cloned()
+    }
+
+    /// The plotters and the jobs they're plotting.
+    pub fn plotters(&self) -> Vec<PlotterInfo> {
+        self.lock().plotters.clone()
+    }
+
+    /// Change the state of a job from one of `from` to `to`.
+    fn transition(&self, id: u64, from: &[State], to: State) -> Result<Job, Error> {
+        let mut queue = self.lock();
+        let job = queue.job_mut(id)?;
+        if !from.contains(&job.state) {
+            return Err(Error::JobError(format!(
+                "Job {} is {:?}, can't change it to {:?}.",
+                id, job.state, to
+            )));
+        }
+
+        job.state = to;
+        if to == State::Cancelled && job.started.is_none() {
+            job.finished = Some(now());
+        }
+        let job = job.clone();
+        queue.sources.remove(&id);
+        self.shared.changed.notify_all();
+
+        Ok(job)
+    }
+
+    /// Pause a running job after the current stroke, with the pen raised.
+    pub fn pause(&self, id: u64) -> Result<Job, Error> {
+        self.transition(id, &[State::Running], State::Paused)
+    }
+
+    pub fn resume(&self, id: u64) -> Result<Job, Error> {
+        self.transition(id, &[State::Paused], State::Running)
+    }
+
+    /// Cancel a job. A running job stops after the current stroke and the plotter returns home.
+    pub fn cancel(&self, id: u64) -> Result<Job, Error> {
+        self.transition(
+            id,
+            &[State::Queued, State::Running, State::Paused],
+            State::Cancelled,
+        )
+    }
+
+    /// Block until a job is finished.
+    pub fn wait(&self, id: u64) -> Result<Job, Error> {
+        let mut queue = self.lock();
+        loop {
+            let job = queue.job_mut(id)?;
+            if job.state.is_finished() {
+                return Ok(job.clone());
+            }
+            queue = self
+                .shared
+                .changed
+                .wait(queue)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+
+    /// Stop the plotters once their current jobs are finished.
+    pub fn shutdown(&self) {
+        self.lock().shutdown = true;
+        self.shared.changed.notify_all();
+    }
+
+    /// Handle the connections of a listener, each on a thread of its own. Works for both
+    /// `TcpListener::incoming()` and `UnixListener::incoming()`.
+    pub fn serve<S>(&self, incoming: impl Iterator<Item = io::Result<S>>) -> Result<(), Error>
+    where
+        S: Read + Write + Send + 'static,
+    {
+        for stream in incoming {
+            let stream = stream?;
+            let server = self.clone();
+            thread::spawn(move || {
+                if let Err(err) = server.handle_connection(stream) {
+                    warn!("Failed to handle request: {}", err);
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    fn handle_connection(&self, stream: impl Read + Write) -> io::Result<()> {
+        let mut stream = BufReader::new(stream);
+        let response = match Request::read(&mut stream) {
+            Ok(request) => self.handle(&request),
+            Err(err) if err.kind() == io::ErrorKind::FileTooLarge => {
+                Response::error(413, &err.to_string())
+            }
+            Err(err) => Response::error(400, &err.to_string()),
+        };
+
+        response.write(stream.get_mut())
+    }
+
+    /// Answer a request of the HTTP API.
+    pub fn handle(&self, request: &Request) -> Response {
+        let segments: Vec<&str> = request
+            .path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        let result = match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["plotters"]) => Ok(Response::json(200, &self.plotters())),
+            ("GET", ["jobs"]) => Ok(Response::json(200, &self.jobs())),
+            ("POST", ["jobs"]) => self.submit_request(request),
+            ("GET", ["jobs", id]) => parse_id(id).and_then(|id| self.job(id)).map(Response::job),
+            ("POST", ["jobs", id, action]) => parse_id(id)
+                .and_then(|id| match *action {
+                    "pause" => self.pause(id),
+                    "resume" => self.resume(id),
+                    "cancel" => self.cancel(id),
+                    _ => Err(Error::UnsupportedError(format!(
+                        "Unknown action {:?}.",
+                        action
+                    ))),
+                })
+                .map(Response::job),
+            _ => Err(Error::UnsupportedError(format!(
+                "No route for {} {}.",
+                request.method, request.path
+            ))),
+        };
+
+        result.unwrap_or_else(|err| {
+            let status = match &err {
+                Error::JobError(message) if message.starts_with("No ") => 404,
+                Error::JobError(_) => 409,
+                Error::UnsupportedError(_) => 404,
+                _ => 400,
+            };
+            Response::error(status, &err.to_string())
+        })
+    }
+
+    fn submit_request(&self, request: &Request) -> Result<Response, Error> {
+        let name = request
+            .query
+            .get("name")
+            .cloned()
+            .unwrap_or_else(|| "untitled".to_string());
+        let format = match request.query.get("format") {
+            Some(format) => format.parse().map_err(Error::ConversionError)?,
+            None => name
+                .rsplit_once('.')
+                .and_then(|(_, extension)| Format::from_extension(extension))
+                .ok_or_else(|| Error::ConversionError("Set the format of the job.".to_string()))?,
+        };
+        let text = String::from_utf8(request.body.clone())
+            .map_err(|_| Error::ConversionError("The job is not valid UTF-8.".to_string()))?;
+
+        let plotter = request.query.get("plotter").map(String::as_str);
+        let job = if format.is_map() {
+            self.submit_map(&name, plotter, format.features(&text)?)?
+        } else {
+            self.submit(&name, plotter, format.parse(&text, self.scale)?)?
+        };
+        Ok(Response::json(201, &job))
+    }
+}
+
+fn parse_id(id: &str) -> Result<u64, Error> {
+    id.parse()
+        .map_err(|_| Error::JobError(format!("No job {:?}.", id)))
+}
+
+/// A HTTP request.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Read a HTTP/1.1 request. Only the `Content-Length` header is used. Fails with
+    /// `ErrorKind::FileTooLarge` when the body is larger than `MAX_BODY`.
+    pub fn read(reader: &mut impl BufRead) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let mut parts = line.split_whitespace();
+        let method = parts.next().ok_or_else(|| invalid("empty request"))?;
+        let target = parts
+            .next()
+            .ok_or_else(|| invalid("request without path"))?;
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+        let mut request = Request {
+            method: method.to_string(),
+            path: decode(path),
+            query: query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (decode(key), decode(value))
+                })
+                .collect(),
+            body: vec![],
+        };
+
+        let mut length = 0;
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    length = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| invalid("invalid Content-Length"))?;
+                }
+            }
+        }
+
+        if length > MAX_BODY {
+            return Err(io::Error::new(
+                io::ErrorKind::FileTooLarge,
+                format!("body larger than {} bytes", MAX_BODY),
+            ));
+        }
+        reader.take(length).read_to_end(&mut request.body)?;
+        if request.body.len() as u64 != length {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "body shorter than Content-Length",
+            ));
+        }
+
+        Ok(request)
+    }
+}
+
+/// Decode a percent-encoded part of a URL.
+fn decode(text: &str) -> String {
+    let mut bytes = vec![];
+    let mut iter = text.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = iter.by_ref().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => bytes.push(byte),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(hex);
+                    }
+                }
+            }
+            _ => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// A HTTP response with a JSON body.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, value: &impl Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string_pretty(value).unwrap_or_default(),
+        }
+    }
+
+    fn job(job: Job) -> Self {
+        Self::json(200, &job)
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &HashMap::from([("error", message)]))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            201 => "Created",
+            400 => "Bad Request",
+            404 => "Not Found",
+            409 => "Conflict",
+            413 => "Content Too Large",
+            _ => "",
+        }
+    }
+
+    /// Write the response and close the connection.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plotter::{self, Status};
+    use crate::point::{Absolute, Relative};
+    use crate::traffic::{Replay, TrafficLog};
+    use crate::Backend;
+
+    /// A plotter that records the dimensions of the plots it plots.
+    struct Recorder(Arc<Mutex<Vec<(i32, i32)>>>);
+
+    impl Plotter for Recorder {
+        fn plot_with_progress(
+            &mut self,
+            plot: &Plot,
+            progress: &mut dyn FnMut(usize, usize) -> Result<(), Error>,
+        ) -> Result<(), Error> {
+            self.0.lock().unwrap().push(plot.dimensions());
+            let strokes = plot.paths().paths.len();
+            progress(strokes, strokes)
+        }
+
+        fn log_traffic(&mut self, _: TrafficLog) {}
+
+        fn pen_up(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn pen_down(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn move_by(&mut self, _: Coordinate<Relative>) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn home(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn set_origin(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn status(&mut self) -> Result<Status, Error> {
+            Ok(Status {
+                position: Coordinate::<Absolute>::new(0, 0),
+                pen_down: false,
+            })
+        }
+
+        fn disable_motors(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn version(&mut self) -> Result<String, Error> {
+            Ok("Recorder".to_string())
+        }
+    }
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        let text = format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        );
+        Request::read(&mut text.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn read_request() {
+        let request = request("POST", "/jobs?name=my%20plot.json&plotter=a", "[]");
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/jobs");
+        assert_eq!(request.query["name"], "my plot.json");
+        assert_eq!(request.query["plotter"], "a");
+        assert_eq!(request.body, b"[]");
+    }
+
+    /// A connection that reads a request and records the response.
+    struct Connection<'a> {
+        request: &'a [u8],
+        response: Vec<u8>,
+    }
+
+    impl Read for Connection<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.request.read(buf)
+        }
+    }
+
+    impl Write for Connection<'_> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.response.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn refuse_large_body() {
+        let server = Server::new(10.0);
+        let mut connection = Connection {
+            request: b"POST /jobs HTTP/1.1\r\nContent-Length: 100000000000\r\n\r\n[]",
+            response: vec![],
+        };
+        server.handle_connection(&mut connection).unwrap();
+        let response = String::from_utf8(connection.response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 413 Content Too Large\r\n"));
+
+        let truncated = b"POST /jobs HTTP/1.1\r\nContent-Length: 10\r\n\r\n[]";
+        assert!(Request::read(&mut &truncated[..]).is_err());
+    }
+
+    #[test]
+    fn plot_job() {
+        let server = Server::new(10.0);
+        let session = Replay::parse(include_str!("../tests/sessions/square.ebb.session")).unwrap();
+        server.add_plotter("axidraw", plotter::from_transport(Backend::Ebb, session));
+
+        let square = "[[[0, 0], [1000, 0], [1000, 1000], [0, 1000], [0, 0]]]";
+        let response = server.handle(&request("POST", "/jobs?name=square.json", square));
+        assert_eq!(response.status, 201);
+
+        let job = server.wait(1).unwrap();
+        assert_eq!(job.state, State::Done, "{:?}", job.error);
+        assert_eq!(job.plotter.as_deref(), Some("axidraw"));
+        assert_eq!((job.progress, job.strokes), (1, 1));
+        assert_eq!(
+            server.handle(&request("POST", "/jobs/1/pause", "")).status,
+            409
+        );
+        server.shutdown();
+    }
+
+    #[test]
+    fn project_maps_for_plotter() {
+        let server = Server::new(10.0);
+        let small = Arc::new(Mutex::new(vec![]));
+        let large = Arc::new(Mutex::new(vec![]));
+        let page = |size: f64| geo::Options {
+            page: (size, size),
+            ..Default::default()
+        };
+        server.add_plotter_with("small", Box::new(Recorder(small.clone())), page(1000.0));
+        server.add_plotter_with("large", Box::new(Recorder(large.clone())), page(10000.0));
+
+        let map = r#"{"type": "Feature", "properties": {}, "geometry": {"type": "LineString",
+            "coordinates": [[11.0, 47.0], [11.01, 47.0], [11.01, 47.01]]}}"#;
+        for (id, plotter) in [(1, "small"), (2, "large")] {
+            let path = format!("/jobs?name=map.geojson&plotter={}", plotter);
+            assert_eq!(server.handle(&request("POST", &path, map)).status, 201);
+            assert_eq!(server.wait(id).unwrap().state, State::Done);
+        }
+
+        // The map is fit to the page of the plotter that plots it.
+        let (width, height) = small.lock().unwrap()[0];
+        assert_eq!(width.max(height), 1000, "{} x {}", width, height);
+        let (width, height) = large.lock().unwrap()[0];
+        assert_eq!(width.max(height), 10000, "{} x {}", width, height);
+        server.shutdown();
+    }
+
+    #[test]
+    fn cancel_queued_job() {
+        // Without plotters, jobs stay queued.
+        let server = Server::new(10.0);
+        let path = "[[[0, 0], [10, 10]]]";
+
+        assert_eq!(
+            server
+                .handle(&request("POST", "/jobs?format=paths&plotter=a", path))
+                .status,
+            404
+        );
+        assert_eq!(
+            server
+                .handle(&request("POST", "/jobs?name=a.txt", path))
+                .status,
+            400
+        );
+        assert_eq!(
+            server
+                .handle(&request("POST", "/jobs?format=paths", path))
+                .status,
+            201
+        );
+        assert_eq!(
+            server.handle(&request("POST", "/jobs/1/pause", "")).status,
+            409
+        );
+        assert_eq!(
+            server.handle(&request("POST", "/jobs/1/cancel", "")).status,
+            200
+        );
+        assert_eq!(server.job(1).unwrap().state, State::Cancelled);
+        assert_eq!(server.handle(&request("GET", "/jobs/2", "")).status, 404);
+    }
+}
@@ -0,0 +1,437 @@
+//! Read a `Plot` from an SVG file and write a `Plot` as SVG.
+//!
+//! Only the outlines of shapes are read: `path`, `line`, `polyline`, `polygon`, `rect`, `circle`
+//! and `ellipse`. Curves and arcs are flattened into straight segments. Transformations and
+//! styles are ignored.
+use std::f64::consts::PI;
+use std::fs;
+use std::path;
+
+use svg::node::element::path::{Command as PathCommand, Data, Position};
+use svg::node::element::tag::Type;
+use svg::node::element::Path as SVG_Path;
+use svg::node::Attributes;
+use svg::parser::Event;
+use svg::Document;
+
+use crate::point::Coordinate;
+use crate::{get_boundaries, Error, Path, Paths, Plot};
+
+/// Number of straight segments used to approximate a curve.
+const CURVE_SEGMENTS: usize = 16;
+
+/// Number of straight segments used to approximate a full circle.
+const CIRCLE_SEGMENTS: usize = 64;
+
+type Point = (f64, f64);
+
+/// Read an attribute as number. Missing attributes are 0, like SVG does.
+fn number(attributes: &Attributes, name: &str) -> Result<f64, Error> {
+    match attributes.get(name) {
+        None => Ok(0.0),
+        Some(value) => value.trim().trim_end_matches("px").parse().map_err(|_| {
+            Error::SvgError(format!("Failed to parse {}={:?}.", name, value.to_string()))
+        }),
+    }
+}
+
+/// Parse the "points" attribute of a `polyline` or `polygon`.
+fn points(attributes: &Attributes) -> Result<Vec<Point>, Error> {
+    let value = attributes
+        .get("points")
+        .map(|v| v.to_string())
+        .unwrap_or_default();
+    let numbers: Vec<f64> = value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::SvgError(format!("Failed to parse points {:?}.", value)))?;
+
+    Ok(numbers.chunks_exact(2).map(|p| (p[0], p[1])).collect())
+}
+
+/// Approximate an ellipse centered at `center`, starting at angle 0.
+fn ellipse(center: Point, rx: f64, ry: f64) -> Vec<Point> {
+    (0..=CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / CIRCLE_SEGMENTS as f64;
+            (center.0 + rx * angle.cos(), center.1 + ry * angle.sin())
+        })
+        .collect()
+}
+
+/// Approximate a cubic Bézier curve. The start point is not included.
+fn cubic(p0: Point, p1: Point, p2: Point, p3: Point) -> Vec<Point> {
+    (1..=CURVE_SEGMENTS)
+        .map(|i| {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            (
+                a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+                a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+            )
+        })
+        .collect()
+}
+
+/// Approximate a quadratic Bézier curve. The start point is not included.
+fn quadratic(p0: Point, p1: Point, p2: Point) -> Vec<Point> {
+    (1..=CURVE_SEGMENTS)
+        .map(|i| {
+            let t = i as f64 / CURVE_SEGMENTS as f64;
+            let u = 1.0 - t;
+            let (a, b, c) = (u * u, 2.0 * u * t, t * t);
+            (
+                a * p0.0 + b * p1.0 + c * p2.0,
+                a * p0.1 + b * p1.1 + c * p2.1,
+            )
+        })
+        .collect()
+}
+
+/// Approximate an elliptical arc given in endpoint parameterization. The start point is not
+/// included. See https://www.w3.org/TR/SVG/implnote.html#ArcConversionEndpointToCenter.
+fn arc(
+    from: Point,
+    (mut rx, mut ry): (f64, f64),
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+) -> Vec<Point> {
+    if rx == 0.0 || ry == 0.0 || from == to {
+        return vec![to];
+    }
+    rx = rx.abs();
+    ry = ry.abs();
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+    let (x1, y1) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+
+    // Scale up radii that are too small to span both points.
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut factor = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        factor = -factor;
+    }
+    let (cx1, cy1) = (factor * rx * y1 / ry, -factor * ry * x1 / rx);
+    let center = (
+        cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0,
+        sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0,
+    );
+
+    let angle = |ux: f64, uy: f64| uy.atan2(ux);
+    let start = angle((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle((-x1 - cx1) / rx, (-y1 - cy1) / ry) - start;
+    if sweep && delta < 0.0 {
+        delta += 2.0 * PI;
+    } else if !sweep && delta > 0.0 {
+        delta -= 2.0 * PI;
+    }
+
+    (1..=CURVE_SEGMENTS)
+        .map(|i| {
+            let theta = start + delta * i as f64 / CURVE_SEGMENTS as f64;
+            let (x, y) = (rx * theta.cos(), ry * theta.sin());
+            (cos * x - sin * y + center.0, sin * x + cos * y + center.1)
+        })
+        .collect()
+}
+
+/// Convert the "d" attribute of a `path` into a series of polylines, one per subpath.
+fn path_data(d: &str) -> Result<Vec<Vec<Point>>, Error> {
+    let data = Data::parse(d).map_err(|err| Error::SvgError(err.to_string()))?;
+
+    let mut polylines: Vec<Vec<Point>> = vec![];
+    let mut current: Point = (0.0, 0.0);
+    let mut subpath_start: Point = (0.0, 0.0);
+    // Second control point of the previous curve, used by the smooth curve commands.
+    let mut last_control: Option<Point> = None;
+
+    for command in data.iter() {
+        let offset = |position: &Position, current: Point| match position {
+            Position::Absolute => (0.0, 0.0),
+            Position::Relative => current,
+        };
+
+        let mut control = None;
+        match command {
+            PathCommand::Move(position, parameters) => {
+                for (i, p) in parameters.chunks_exact(2).enumerate() {
+                    let o = offset(position, current);
+                    current = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    // Additional pairs are implicit line commands.
+                    if i == 0 {
+                        subpath_start = current;
+                        polylines.push(vec![current]);
+                    } else if let Some(polyline) = polylines.last_mut() {
+                        polyline.push(current);
+                    }
+                }
+            }
+            PathCommand::Line(position, parameters) => {
+                for p in parameters.chunks_exact(2) {
+                    let o = offset(position, current);
+                    let to = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    push(&mut polylines, current, vec![to]);
+                    current = to;
+                }
+            }
+            PathCommand::HorizontalLine(position, parameters) => {
+                for x in parameters.iter() {
+                    let o = offset(position, current);
+                    let to = (o.0 + *x as f64, current.1);
+                    push(&mut polylines, current, vec![to]);
+                    current = to;
+                }
+            }
+            PathCommand::VerticalLine(position, parameters) => {
+                for y in parameters.iter() {
+                    let o = offset(position, current);
+                    let to = (current.0, o.1 + *y as f64);
+                    push(&mut polylines, current, vec![to]);
+                    current = to;
+                }
+            }
+            PathCommand::CubicCurve(position, parameters) => {
+                for p in parameters.chunks_exact(6) {
+                    let o = offset(position, current);
+                    let p1 = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    let p2 = (o.0 + p[2] as f64, o.1 + p[3] as f64);
+                    let p3 = (o.0 + p[4] as f64, o.1 + p[5] as f64);
+                    push(&mut polylines, current, cubic(current, p1, p2, p3));
+                    current = p3;
+                    control = Some(p2);
+                }
+            }
+            PathCommand::SmoothCubicCurve(position, parameters) => {
+                for p in parameters.chunks_exact(4) {
+                    let o = offset(position, current);
+                    let p1 = match control.or(last_control) {
+                        Some(c) => (2.0 * current.0 - c.0, 2.0 * current.1 - c.1),
+                        None => current,
+                    };
+                    let p2 = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    let p3 = (o.0 + p[2] as f64, o.1 + p[3] as f64);
+                    push(&mut polylines, current, cubic(current, p1, p2, p3));
+                    current = p3;
+                    control = Some(p2);
+                }
+            }
+            PathCommand::QuadraticCurve(position, parameters) => {
+                for p in parameters.chunks_exact(4) {
+                    let o = offset(position, current);
+                    let p1 = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    let p2 = (o.0 + p[2] as f64, o.1 + p[3] as f64);
+                    push(&mut polylines, current, quadratic(current, p1, p2));
+                    current = p2;
+                    control = Some(p1);
+                }
+            }
+            PathCommand::SmoothQuadraticCurve(position, parameters) => {
+                for p in parameters.chunks_exact(2) {
+                    let o = offset(position, current);
+                    let p1 = match control.or(last_control) {
+                        Some(c) => (2.0 * current.0 - c.0, 2.0 * current.1 - c.1),
+                        None => current,
+                    };
+                    let p2 = (o.0 + p[0] as f64, o.1 + p[1] as f64);
+                    push(&mut polylines, current, quadratic(current, p1, p2));
+                    current = p2;
+                    control = Some(p1);
+                }
+            }
+            PathCommand::EllipticalArc(position, parameters) => {
+                for p in parameters.chunks_exact(7) {
+                    let o = offset(position, current);
+                    let to = (o.0 + p[5] as f64, o.1 + p[6] as f64);
+                    let points = arc(
+                        current,
+                        (p[0] as f64, p[1] as f64),
+                        p[2] as f64,
+                        p[3] != 0.0,
+                        p[4] != 0.0,
+                        to,
+                    );
+                    push(&mut polylines, current, points);
+                    current = to;
+                }
+            }
+            PathCommand::Close => {
+                push(&mut polylines, current, vec![subpath_start]);
+                current = subpath_start;
+            }
+        }
+        last_control = control;
+    }
+
+    Ok(polylines)
+}
+
+/// Append points to the last polyline. Start a new polyline at `current` if there is none.
+fn push(polylines: &mut Vec<Vec<Point>>, current: Point, points: Vec<Point>) {
+    if polylines.is_empty() {
+        polylines.push(vec![current]);
+    }
+
+    if let Some(polyline) = polylines.last_mut() {
+        polyline.extend(points);
+    }
+}
+
+/// Convert an element into polylines. Unsupported elements yield no polylines.
+fn element(name: &str, attributes: &Attributes) -> Result<Vec<Vec<Point>>, Error> {
+    let n = |name: &str| number(attributes, name);
+
+    let polylines = match name {
+        "path" => path_data(
+            &attributes
+                .get("d")
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+        )?,
+        "line" => vec![vec![(n("x1")?, n("y1")?), (n("x2")?, n("y2")?)]],
+        "polyline" => vec![points(attributes)?],
+        "polygon" => {
+            let mut points = points(attributes)?;
+            if let Some(first) = points.first().copied() {
+                points.push(first);
+            }
+            vec![points]
+        }
+        "rect" => {
+            let (x, y, width, height) = (n("x")?, n("y")?, n("width")?, n("height")?);
+            vec![vec![
+                (x, y),
+                (x + width, y),
+                (x + width, y + height),
+                (x, y + height),
+                (x, y),
+            ]]
+        }
+        "circle" => vec![ellipse((n("cx")?, n("cy")?), n("r")?, n("r")?)],
+        "ellipse" => vec![ellipse((n("cx")?, n("cy")?), n("rx")?, n("ry")?)],
+        _ => vec![],
+    };
+
+    Ok(polylines)
+}
+
+/// Build a `Plot` from the shapes in an SVG document. Every user unit is multiplied by `scale`
+/// before it's rounded to the unit of the `Plot`.
+pub fn parse(text: &str, scale: f64) -> Result<Plot, Error> {
+    let mut paths: Vec<Path> = vec![];
+
+    for event in svg::read(text)? {
+        match event {
+            Event::Tag(name, Type::Start | Type::Empty, attributes) => {
+                for polyline in element(name, &attributes)? {
+                    paths.push(
+                        polyline
+                            .iter()
+                            .map(|(x, y)| {
+                                Coordinate::new(
+                                    (x * scale).round() as i32,
+                                    (y * scale).round() as i32,
+                                )
+                            })
+                            .collect(),
+                    );
+                }
+            }
+            Event::Error(err) => return Err(Error::SvgError(err.to_string())),
+            _ => {}
+        }
+    }
+
+    Ok(Plot::new(Paths::new(paths)?))
+}
+
+/// Build a `Plot` from the shapes in the SVG file at the given location.
+pub fn read(path: &path::Path, scale: f64) -> Result<Plot, Error> {
+    parse(&fs::read_to_string(path)?, scale)
+}
+
+/// Create an SVG document containing every `Path` of the `Plot`, in the colour of its layer.
+pub fn to_document(plot: &Plot) -> Document {
+    let (min_x, min_y, max_x, max_y) = get_boundaries(plot.paths());
+    let mut doc = Document::new().set("viewBox", (min_x, min_y, max_x - min_x, max_y - min_y));
+
+    for (index, path) in plot.paths().paths.iter().enumerate() {
+        let colour = plot
+            .paths()
+            .layer_of(index)
+            .and_then(|layer| layer.colour.as_deref())
+            .unwrap_or("black");
+        let mut data = Data::new();
+        for (i, point) in path.iter().enumerate() {
+            data = if i == 0 {
+                data.move_to((point.x, point.y))
+            } else {
+                data.line_to((point.x, point.y))
+            };
+        }
+
+        doc = doc.add(
+            SVG_Path::new()
+                .set("fill", "none")
+                .set("stroke", colour)
+                .set("d", data),
+        );
+    }
+
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_shapes() {
+        let text = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <path d="M 0 0 h 10 v 10 H 0 Z"/>
+            <rect x="20" y="20" width="5" height="5"/>
+            <line x1="0" y1="0" x2="3" y2="4"/>
+        </svg>"#;
+
+        let plot = parse(text, 1.0).unwrap();
+        let paths = &plot.paths().paths;
+
+        assert_eq!(paths.len(), 3);
+        assert_eq!(
+            paths[0],
+            vec![
+                Coordinate::new(0, 0),
+                Coordinate::new(10, 0),
+                Coordinate::new(10, 10),
+                Coordinate::new(0, 10),
+                Coordinate::new(0, 0),
+            ]
+        );
+        assert_eq!(paths[1].len(), 5);
+        assert_eq!(paths[2], vec![Coordinate::new(0, 0), Coordinate::new(3, 4)]);
+    }
+
+    #[test]
+    fn flatten_arc() {
+        let points = arc((0.0, 0.0), (5.0, 5.0), 0.0, false, true, (10.0, 0.0));
+        let (x, y) = points[CURVE_SEGMENTS / 2 - 1];
+        assert!((x - 5.0).abs() < 1e-9);
+        assert!((y.abs() - 5.0).abs() < 1e-9);
+
+        let (x, y) = points[CURVE_SEGMENTS - 1];
+        assert!((x - 10.0).abs() < 1e-9);
+        assert!(y.abs() < 1e-9);
+    }
+}
@@ -0,0 +1,85 @@
+//! Single-stroke text to label plots, like the scale bar of a map or the axes of an elevation
+//! profile. Only digits, a few letters and signs are available, other characters are left blank.
+
+/// A point of a stroke, before rounding.
+pub type Point = (f64, f64);
+
+/// Strokes of a character on a grid 4 wide and 6 high, with y down.
+fn glyph(c: char) -> &'static [&'static [(f64, f64)]] {
+    match c {
+        '0' => &[&[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)]],
+        '1' => &[&[(1., 1.), (2., 0.), (2., 6.)]],
+        '2' => &[&[(0., 0.), (4., 0.), (4., 3.), (0., 3.), (0., 6.), (4., 6.)]],
+        '3' => &[
+            &[(0., 0.), (4., 0.), (4., 6.), (0., 6.)],
+            &[(0., 3.), (4., 3.)],
+        ],
+        '4' => &[&[(0., 0.), (0., 3.), (4., 3.)], &[(4., 0.), (4., 6.)]],
+        '5' => &[&[(4., 0.), (0., 0.), (0., 3.), (4., 3.), (4., 6.), (0., 6.)]],
+        '6' => &[&[(4., 0.), (0., 0.), (0., 6.), (4., 6.), (4., 3.), (0., 3.)]],
+        '7' => &[&[(0., 0.), (4., 0.), (4., 6.)]],
+        '8' => &[
+            &[(0., 0.), (4., 0.), (4., 6.), (0., 6.), (0., 0.)],
+            &[(0., 3.), (4., 3.)],
+        ],
+        '9' => &[&[(4., 3.), (0., 3.), (0., 0.), (4., 0.), (4., 6.), (0., 6.)]],
+        '.' => &[&[(2., 5.5), (2., 6.)]],
+        '+' => &[&[(0., 3.5), (4., 3.5)], &[(2., 1.5), (2., 5.5)]],
+        '-' => &[&[(0., 3.5), (4., 3.5)]],
+        'a' => &[&[(0., 2.), (4., 2.), (4., 6.), (0., 6.), (0., 4.), (4., 4.)]],
+        'i' => &[&[(2., 2.), (2., 6.)], &[(2., 0.), (2., 0.5)]],
+        'k' => &[&[(0., 0.), (0., 6.)], &[(3., 2.), (0., 4.), (3., 6.)]],
+        'm' => &[
+            &[(0., 6.), (0., 2.), (4., 2.), (4., 6.)],
+            &[(2., 2.), (2., 6.)],
+        ],
+        'n' => &[&[(0., 6.), (0., 2.), (4., 2.), (4., 6.)]],
+        'x' => &[&[(0., 2.), (4., 6.)], &[(0., 6.), (4., 2.)]],
+        'N' => &[&[(0., 6.), (0., 0.), (4., 6.), (4., 0.)]],
+        _ => &[],
+    }
+}
+
+/// Strokes of `text`, starting at the top left corner `origin`, `height` high.
+pub fn strokes(text: &str, origin: Point, height: f64) -> Vec<Vec<Point>> {
+    let unit = height / 6.0;
+    text.chars()
+        .enumerate()
+        .flat_map(|(index, c)| {
+            let left = origin.0 + index as f64 * 6.0 * unit;
+            glyph(c).iter().map(move |stroke| {
+                stroke
+                    .iter()
+                    .map(|(x, y)| (left + x * unit, origin.1 + y * unit))
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+/// Width of `text` written by `strokes()`.
+pub fn width(text: &str, height: f64) -> f64 {
+    let count = text.chars().count() as f64;
+    (count * 6.0 - 2.0).max(0.0) * height / 6.0
+}
+
+/// Format a number with at most 3 decimals and without trailing zeros.
+pub fn number(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_text() {
+        let lines = strokes("1 m", (10.0, 20.0), 6.0);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], vec![(11.0, 21.0), (12.0, 20.0), (12.0, 26.0)]);
+        assert_eq!(width("1 m", 6.0), 16.0);
+        assert_eq!(number(2.50), "2.5");
+        assert_eq!(number(1000.0), "1000");
+    }
+}
@@ -0,0 +1,394 @@
+//! Recording and replaying the bytes exchanged with a device.
+//!
+//! Drivers talk to a device through a `Connection`, which writes the traffic to a `TrafficLog`
+//! when one is set. Every line of the log holds the time since the log was created in seconds,
+//! the direction and the bytes, escaped like a Rust string:
+//!
+//! ```text
+//! 0.001203 > "SM,1000,1,-1\r"
+//! 0.004518 < "OK\r\n"
+//! ```
+//!
+//! A `Replay` feeds such a session back to a driver, so a plot can be reproduced without the
+//! device.
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::Error;
+
+/// Anything a driver can talk to: a serial port, or a `Replay` of a session.
+pub trait Transport: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Transport for T {}
+
+/// Whether bytes were sent to or received from the device.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    Write,
+    Read,
+}
+
+impl Direction {
+    fn symbol(&self) -> char {
+        match self {
+            Direction::Write => '>',
+            Direction::Read => '<',
+        }
+    }
+}
+
+/// Escape bytes so they can be written on a single line. Printable ASCII is kept as is.
+pub fn escape(bytes: &[u8]) -> String {
+    let mut escaped = String::new();
+    for byte in bytes {
+        match byte {
+            b'\r' => escaped.push_str("\\r"),
+            b'\n' => escaped.push_str("\\n"),
+            b'\\' => escaped.push_str("\\\\"),
+            b'"' => escaped.push_str("\\\""),
+            0x20..=0x7e => escaped.push(*byte as char),
+            _ => escaped.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+
+    escaped
+}
+
+/// Reverse `escape()`.
+pub fn unescape(escaped: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![];
+    let mut chars = escaped.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            let mut buffer = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            continue;
+        }
+
+        match chars.next()? {
+            'r' => bytes.push(b'\r'),
+            'n' => bytes.push(b'\n'),
+            '\\' => bytes.push(b'\\'),
+            '"' => bytes.push(b'"'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                bytes.push(u8::from_str_radix(&hex, 16).ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Writes the traffic of a device to a file. Every write gets its own line. Drivers read byte by
+/// byte, so consecutive reads are written as a single line.
+pub struct TrafficLog {
+    writer: Box<dyn Write + Send>,
+    start: Instant,
+
+    /// Bytes that are not written yet, with their direction and the time of the first byte.
+    pending: Option<(Direction, Duration, Vec<u8>)>,
+}
+
+impl TrafficLog {
+    /// Create a log that writes to `writer`.
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Box::new(writer),
+            start: Instant::now(),
+            pending: None,
+        }
+    }
+
+    /// Create a log file at `path`, replacing an existing file.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Append the bytes written to or read from the device.
+    pub fn record(&mut self, direction: Direction, bytes: &[u8]) -> io::Result<()> {
+        match &mut self.pending {
+            Some((Direction::Read, _, buffer)) if direction == Direction::Read => {
+                buffer.extend_from_slice(bytes)
+            }
+            _ => {
+                self.flush()?;
+                self.pending = Some((direction, self.start.elapsed(), bytes.to_vec()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the pending bytes.
+    pub fn flush(&mut self) -> io::Result<()> {
+        if let Some((direction, time, bytes)) = self.pending.take() {
+            writeln!(
+                self.writer,
+                "{:.6} {} \"{}\"",
+                time.as_secs_f64(),
+                direction.symbol(),
+                escape(&bytes)
+            )?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+impl Drop for TrafficLog {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+/// The transport of a driver, which records all traffic when a `TrafficLog` is set.
+pub struct Connection {
+    transport: Box<dyn Transport>,
+    log: Option<TrafficLog>,
+}
+
+impl Connection {
+    pub fn new(transport: impl Transport + 'static) -> Self {
+        Self {
+            transport: Box::new(transport),
+            log: None,
+        }
+    }
+
+    /// Write all traffic from now on to `log`.
+    pub fn log_traffic(&mut self, log: TrafficLog) {
+        self.log = Some(log);
+    }
+
+    /// Record traffic in the log, if any. Failing to log doesn't fail the transfer.
+    fn record(&mut self, direction: Direction, bytes: &[u8]) {
+        if let Some(log) = &mut self.log {
+            if let Err(err) = log.record(direction, bytes) {
+                tracing::warn!("Failed to log traffic: {}", err);
+            }
+        }
+    }
+}
+
+impl Read for Connection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.transport.read(buf)?;
+        self.record(Direction::Read, &buf[..length]);
+
+        Ok(length)
+    }
+}
+
+impl Write for Connection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let length = self.transport.write(buf)?;
+        self.record(Direction::Write, &buf[..length]);
+
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.transport.flush()
+    }
+}
+
+/// A transport that plays the device side of a recorded session.
+///
+/// Reads return the bytes the device sent. Writes must match the bytes that were sent to the
+/// device, otherwise they fail. The bytes a device sent can only be read once everything that was
+/// written before them in the session is written, but writes don't have to wait for reads. So a
+/// driver may send commands ahead of their responses. The timing of the session is ignored, so
+/// replays are deterministic. Reading past the end of the session fails like a timeout.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Replay {
+    /// Bytes that must be written, in order.
+    writes: VecDeque<Vec<u8>>,
+
+    /// Bytes that will be read, in order, with the number of writes that preceded them.
+    reads: VecDeque<(usize, Vec<u8>)>,
+
+    /// Number of entries of `writes` that are written completely.
+    written: usize,
+}
+
+impl Replay {
+    /// Parse a session written by `TrafficLog`.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let mut replay = Self {
+            writes: VecDeque::new(),
+            reads: VecDeque::new(),
+            written: 0,
+        };
+
+        for (number, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match Self::parse_line(line.trim()) {
+                Some((Direction::Write, bytes)) => replay.writes.push_back(bytes),
+                Some((Direction::Read, bytes)) => {
+                    replay.reads.push_back((replay.writes.len(), bytes))
+                }
+                None => {
+                    return Err(Error::ReplayError(format!(
+                        "Invalid line {}: {:?}.",
+                        number + 1,
+                        line
+                    )))
+                }
+            }
+        }
+
+        Ok(replay)
+    }
+
+    /// Read a session file.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    fn parse_line(line: &str) -> Option<(Direction, Vec<u8>)> {
+        let mut parts = line.splitn(3, ' ');
+        let _time: f64 = parts.next()?.parse().ok()?;
+        let direction = match parts.next()? {
+            ">" => Direction::Write,
+            "<" => Direction::Read,
+            _ => return None,
+        };
+        let bytes = parts.next()?.strip_prefix('"')?.strip_suffix('"')?;
+
+        Some((direction, unescape(bytes)?))
+    }
+
+    /// Whether all recorded traffic has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.writes.is_empty() && self.reads.is_empty()
+    }
+}
+
+impl Read for Replay {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes = match self.reads.front_mut() {
+            Some((writes, bytes)) if *writes <= self.written => bytes,
+            Some(_) => {
+                let expected = self.writes.front().map(|bytes| escape(bytes));
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!(
+                        "the device expects \"{}\" first",
+                        expected.unwrap_or_default()
+                    ),
+                ));
+            }
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "the session has ended",
+                ))
+            }
+        };
+
+        let length = buf.len().min(bytes.len());
+        buf[..length].copy_from_slice(&bytes[..length]);
+        bytes.drain(..length);
+        if bytes.is_empty() {
+            self.reads.pop_front();
+        }
+
+        Ok(length)
+    }
+}
+
+impl Write for Replay {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(bytes) = self.writes.front_mut() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unexpected write \"{}\"", escape(buf)),
+            ));
+        };
+
+        let length = buf.len().min(bytes.len());
+        if buf[..length] != bytes[..length] {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "expected \"{}\", got \"{}\"",
+                    escape(&bytes[..length]),
+                    escape(&buf[..length])
+                ),
+            ));
+        }
+
+        bytes.drain(..length);
+        if bytes.is_empty() {
+            self.writes.pop_front();
+            self.written += 1;
+        }
+
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// A writer whose contents can be read after it's moved into a `TrafficLog`.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn escape_bytes() {
+        assert_eq!(escape(b"SM,10,1\r"), "SM,10,1\\r");
+        assert_eq!(escape(b"\x1b.A\"\\"), "\\x1b.A\\\"\\\\");
+        assert_eq!(unescape("\\x1b.A\\\"\\\\").unwrap(), b"\x1b.A\"\\");
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let shared = Shared::default();
+        let mut connection =
+            Connection::new(Replay::parse("0.0 > \"V\\r\"\n0.1 < \"EBB\\r\\n\"").unwrap());
+        connection.log_traffic(TrafficLog::new(shared.clone()));
+
+        connection.write_all(b"V\r").unwrap();
+        let mut response = [0; 5];
+        for byte in response.iter_mut() {
+            connection.read_exact(std::slice::from_mut(byte)).unwrap();
+        }
+        assert_eq!(&response, b"EBB\r\n");
+        assert!(connection.read(&mut response).is_err());
+        drop(connection);
+
+        // The log is a session that can be replayed again.
+        let log = String::from_utf8(shared.0.lock().unwrap().clone()).unwrap();
+        let mut replay = Replay::parse(&log).unwrap();
+        assert!(replay.write_all(b"X\r").is_err());
+        replay.write_all(b"V\r").unwrap();
+        replay.read_exact(&mut response).unwrap();
+        assert!(replay.is_finished());
+    }
+}
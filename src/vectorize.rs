@@ -0,0 +1,369 @@
+//! Trace line art, like scanned sketches, into paths.
+//!
+//! The image is thresholded into ink and paper. With `Trace::Centreline` the ink is thinned to a
+//! skeleton one pixel wide by the algorithm of Zhang and Suen, and every stroke is plotted once,
+//! along its centre. With `Trace::Outline` the edges of the ink are traced instead. The lines are
+//! simplified by the algorithm of Ramer, Douglas and Peucker.
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::cartography;
+use crate::contour;
+use crate::dem::Grid;
+use crate::halftone::Image;
+use crate::preview::ROLAND_DXY_PLOTTING_AREA;
+use crate::text::Point;
+use crate::{Error, Paths, Plot};
+
+/// Neighbours of a pixel clockwise from the top, as Zhang and Suen number them: P2 to P9.
+const NEIGHBOURS: [(i64, i64); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// What is traced of the ink.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Trace {
+    /// The centre of the strokes, to plot every stroke once.
+    #[default]
+    Centreline,
+
+    /// The edges of the strokes.
+    Outline,
+}
+
+impl FromStr for Trace {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "centreline" | "centerline" => Ok(Self::Centreline),
+            "outline" => Ok(Self::Outline),
+            _ => Err(format!(
+                "Unknown trace {:?}, expected \"centreline\" or \"outline\".",
+                s
+            )),
+        }
+    }
+}
+
+/// How line art is traced.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Options {
+    pub trace: Trace,
+
+    /// Pixels darker than this, from 0 for white to 1 for black, are ink.
+    pub threshold: f64,
+
+    /// Largest distance in pixels between a simplified line and the traced one.
+    pub tolerance: f64,
+
+    /// Largest width and height of the plot, in units of the plot.
+    pub size: (f64, f64),
+}
+
+impl Default for Options {
+    /// Trace the centre of the strokes to fit the plotting area of a Roland DXY.
+    fn default() -> Self {
+        Self {
+            trace: Trace::default(),
+            threshold: 0.5,
+            tolerance: 1.0,
+            size: ROLAND_DXY_PLOTTING_AREA,
+        }
+    }
+}
+
+/// Ink and paper of an image.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+
+    /// Whether every pixel is ink, row by row.
+    pub pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    /// The pixels of `image` darker than `threshold` are ink.
+    pub fn threshold(image: &Image, threshold: f64) -> Self {
+        Self {
+            width: image.width,
+            height: image.height,
+            pixels: image
+                .darkness
+                .iter()
+                .map(|darkness| *darkness > threshold)
+                .collect(),
+        }
+    }
+
+    /// Whether the pixel is ink. Pixels outside are paper.
+    pub fn get(&self, x: i64, y: i64) -> bool {
+        x >= 0
+            && y >= 0
+            && (x as usize) < self.width
+            && (y as usize) < self.height
+            && self.pixels[y as usize * self.width + x as usize]
+    }
+
+    /// Whether a pixel can be removed in the first or second pass of thinning.
+    fn removable(&self, x: i64, y: i64, pass: usize) -> bool {
+        let p: Vec<bool> = NEIGHBOURS
+            .iter()
+            .map(|(dx, dy)| self.get(x + dx, y + dy))
+            .collect();
+        let count = p.iter().filter(|ink| **ink).count();
+        let transitions = (0..8).filter(|i| !p[*i] && p[(i + 1) % 8]).count();
+        // P2, P4, P6 and P8 are the neighbours above, right, below and left.
+        let (up, right, down, left) = (p[0], p[2], p[4], p[6]);
+        // The first pass removes pixels on south east edges, the second on north west ones.
+        let side = if pass == 0 {
+            !right || !down || !up && !left
+        } else {
+            !up || !left || !right && !down
+        };
+        (2..=6).contains(&count) && transitions == 1 && side
+    }
+
+    /// Thin the ink to lines one pixel wide, by the algorithm of Zhang and Suen.
+    pub fn thin(&mut self) {
+        loop {
+            let mut changed = false;
+            for pass in 0..2 {
+                let candidates: Vec<(i64, i64)> = (0..self.height as i64)
+                    .flat_map(|y| (0..self.width as i64).map(move |x| (x, y)))
+                    .filter(|(x, y)| self.get(*x, *y) && self.removable(*x, *y, pass))
+                    .collect();
+                // Check again while removing, or lines two pixels wide disappear.
+                for (x, y) in candidates {
+                    if self.removable(x, y, pass) {
+                        self.pixels[y as usize * self.width + x as usize] = false;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Neighbouring ink of a pixel. Diagonal neighbours that are also next to a horizontal or
+    /// vertical neighbour are left out, so a staircase of pixels is a single line.
+    fn neighbours(&self, (x, y): (i64, i64)) -> Vec<(i64, i64)> {
+        NEIGHBOURS
+            .iter()
+            .filter(|(dx, dy)| {
+                self.get(x + dx, y + dy)
+                    && (*dx == 0 || *dy == 0 || !(self.get(x + dx, y) || self.get(x, y + dy)))
+            })
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .collect()
+    }
+
+    /// Lines through the centres of the pixels of a skeleton, from ends and junctions to ends
+    /// and junctions, and closed loops.
+    pub fn skeleton_lines(&self) -> Vec<Vec<Point>> {
+        let pixels: Vec<(i64, i64)> = (0..self.height as i64)
+            .flat_map(|y| (0..self.width as i64).map(move |x| (x, y)))
+            .filter(|(x, y)| self.get(*x, *y))
+            .collect();
+        let mut visited = HashSet::new();
+        let edge = |a: (i64, i64), b: (i64, i64)| if a < b { (a, b) } else { (b, a) };
+
+        let mut lines = vec![];
+        // From ends and junctions first, then what is left are loops.
+        for loops in [false, true] {
+            for start in &pixels {
+                if (self.neighbours(*start).len() == 2) != loops {
+                    continue;
+                }
+                for next in self.neighbours(*start) {
+                    if !visited.insert(edge(*start, next)) {
+                        continue;
+                    }
+                    let mut line = vec![*start, next];
+                    let (mut previous, mut current) = (*start, next);
+                    while current != *start {
+                        let neighbours = self.neighbours(current);
+                        if neighbours.len() != 2 {
+                            break;
+                        }
+                        let following = neighbours[usize::from(neighbours[0] == previous)];
+                        if !visited.insert(edge(current, following)) {
+                            break;
+                        }
+                        line.push(following);
+                        (previous, current) = (current, following);
+                    }
+                    lines.push(
+                        line.into_iter()
+                            .map(|(x, y)| (x as f64 + 0.5, y as f64 + 0.5))
+                            .collect(),
+                    );
+                }
+            }
+        }
+
+        lines
+    }
+
+    /// Lines along the edges of the ink, halfway between ink and paper.
+    pub fn outlines(&self) -> Vec<Vec<Point>> {
+        // A border of paper closes the outlines of ink at the edges of the image.
+        let (columns, rows) = (self.width + 2, self.height + 2);
+        let values = (0..rows as i64)
+            .flat_map(|y| (0..columns as i64).map(move |x| (x, y)))
+            .map(|(x, y)| Some(if self.get(x - 1, y - 1) { 1.0 } else { 0.0 }))
+            .collect();
+        let grid = Grid {
+            columns,
+            rows,
+            origin: (0.0, 0.0),
+            cell_size: 1.0,
+            values,
+            projected: true,
+        };
+
+        contour::trace(&grid, 0.5)
+            .into_iter()
+            .map(|line| line.into_iter().map(|(x, y)| (x - 0.5, y - 0.5)).collect())
+            .collect()
+    }
+}
+
+/// Distance between a point and the segment from `a` to `b`.
+fn distance_to_segment(point: Point, a: Point, b: Point) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - a.0) * dx + (point.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+    (point.0 - (a.0 + t * dx)).hypot(point.1 - (a.1 + t * dy))
+}
+
+/// Leave out points of a line that are within `tolerance` of the simplified line, by the
+/// algorithm of Ramer, Douglas and Peucker.
+pub fn simplify(line: &[Point], tolerance: f64) -> Vec<Point> {
+    if line.len() < 3 {
+        return line.to_vec();
+    }
+    let (first, last) = (line[0], line[line.len() - 1]);
+    let (index, distance) = line[1..line.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(index, point)| (index + 1, distance_to_segment(*point, first, last)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+
+    if distance <= tolerance {
+        return vec![first, last];
+    }
+    let mut simplified = simplify(&line[..=index], tolerance);
+    simplified.pop();
+    simplified.extend(simplify(&line[index..], tolerance));
+    simplified
+}
+
+/// Trace the line art of `image` into a `Plot`, scaled to fit `options.size`.
+pub fn vectorize(image: &Image, options: &Options) -> Result<Plot, Error> {
+    let mut bitmap = Bitmap::threshold(image, options.threshold);
+    let lines = match options.trace {
+        Trace::Centreline => {
+            bitmap.thin();
+            bitmap.skeleton_lines()
+        }
+        Trace::Outline => bitmap.outlines(),
+    };
+
+    let scale = (options.size.0 / image.width.max(1) as f64)
+        .min(options.size.1 / image.height.max(1) as f64);
+    let lines = lines
+        .iter()
+        .map(|line| {
+            simplify(line, options.tolerance)
+                .into_iter()
+                .map(|(x, y)| (x * scale, y * scale))
+                .collect()
+        })
+        .collect();
+
+    Ok(Plot::new(Paths::new(cartography::to_paths(lines))?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bitmap from rows of "#" for ink and "." for paper.
+    fn bitmap(rows: &[&str]) -> Bitmap {
+        Bitmap {
+            width: rows[0].len(),
+            height: rows.len(),
+            pixels: rows
+                .iter()
+                .flat_map(|row| row.chars().map(|c| c == '#'))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn simplify_lines() {
+        let line = [(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)];
+        assert_eq!(
+            simplify(&line, 0.5),
+            vec![(0.0, 0.0), (2.0, -0.1), (3.0, 5.0), (4.0, 6.0)]
+        );
+        assert_eq!(simplify(&line, 10.0), vec![(0.0, 0.0), (4.0, 6.0)]);
+    }
+
+    #[test]
+    fn trace_centrelines() {
+        // A cross of strokes three pixels wide, centred at (8, 8).
+        let mut cross = Bitmap {
+            width: 17,
+            height: 17,
+            pixels: (0..17 * 17)
+                .map(|index: usize| {
+                    let (x, y) = (index % 17, index / 17);
+                    let (across, along) = (x.abs_diff(8).min(y.abs_diff(8)), x.max(y));
+                    across <= 1 && (1..16).contains(&along) && x.min(y) >= 1
+                })
+                .collect(),
+        };
+        cross.thin();
+        let lines: Vec<Vec<Point>> = cross
+            .skeleton_lines()
+            .iter()
+            .map(|line| simplify(line, 1.0))
+            .collect();
+
+        // Four arms from the centre.
+        assert_eq!(lines.len(), 4, "{:?}", lines);
+        assert!(lines
+            .iter()
+            .all(|line| line.first() == Some(&(8.5, 8.5)) || line.last() == Some(&(8.5, 8.5))));
+
+        // Lines two pixels wide don't disappear.
+        let block = bitmap(&["....", ".##.", ".##.", "...."]);
+        let mut thinned = block.clone();
+        thinned.thin();
+        assert_eq!(thinned.skeleton_lines().len(), 1);
+
+        let outlines = block.outlines();
+        assert_eq!(outlines.len(), 1);
+        assert_eq!(outlines[0].first(), outlines[0].last());
+        assert!(outlines[0]
+            .iter()
+            .all(|(x, y)| (1.0..=3.0).contains(x) && (1.0..=3.0).contains(y)));
+    }
+}
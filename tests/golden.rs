@@ -0,0 +1,126 @@
+//! Compare the commands generated for the example plots against golden files in `tests/golden`.
+//!
+//! Run with `UPDATE_GOLDEN=1` to (re)write the golden files instead:
+//!
+//! ```bash
+//! $ UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use loempia::point::Coordinate;
+use loempia::{gcode, gpx, roland_dxy, Plot};
+
+fn square() -> Plot {
+    let path = vec![
+        Coordinate::new(0, 0),
+        Coordinate::new(1000, 0),
+        Coordinate::new(1000, 1000),
+        Coordinate::new(0, 1000),
+        Coordinate::new(0, 0),
+    ];
+    Plot::from_path(path).unwrap()
+}
+
+fn triangle() -> Plot {
+    let path = vec![
+        Coordinate::new(1000, 1000),
+        Coordinate::new(2000, 0000),
+        Coordinate::new(3000, 1000),
+        Coordinate::new(4000, 0000),
+        Coordinate::new(0000, 0000),
+        Coordinate::new(2000, 2000),
+        Coordinate::new(3000, 1000),
+        Coordinate::new(1000, 1000),
+    ];
+    Plot::from_path(path).unwrap()
+}
+
+/// Write every command on a separate line.
+fn to_text<T: Display>(commands: &[T]) -> String {
+    commands
+        .iter()
+        .map(|command| format!("{}\n", command))
+        .collect()
+}
+
+/// Compare `actual` with the content of the golden file `name`. Or overwrite the golden file when
+/// the environment variable `UPDATE_GOLDEN` is set.
+fn assert_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Failed to read golden file {}. Run with `UPDATE_GOLDEN=1` to create it.",
+            path.display()
+        )
+    });
+
+    if let Some((line, (expected, actual))) = expected
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+    {
+        panic!(
+            "{} differs at line {}: expected {:?}, got {:?}. Run with `UPDATE_GOLDEN=1` to update it.",
+            path.display(),
+            line + 1,
+            expected,
+            actual
+        );
+    }
+
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "{} has a different number of lines. Run with `UPDATE_GOLDEN=1` to update it.",
+        path.display()
+    );
+}
+
+fn assert_plot(name: &str, plot: &Plot) {
+    let ebb = loempia::plot_to_commands(plot).unwrap();
+    assert_golden(&format!("{}.ebb", name), &to_text(&ebb));
+
+    let hpgl = roland_dxy::plot_to_commands(plot).unwrap();
+    assert_golden(&format!("{}.hpgl", name), &to_text(&hpgl));
+
+    let gcode = gcode::to_gcode(plot, &Default::default()).unwrap();
+    assert_golden(&format!("{}.gcode", name), &gcode);
+}
+
+#[test]
+fn square_commands() {
+    assert_plot("square", &square());
+}
+
+#[test]
+fn triangle_commands() {
+    assert_plot("triangle", &triangle());
+}
+
+#[test]
+fn gpx_commands() {
+    let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/data");
+    let mut files: Vec<PathBuf> = fs::read_dir(data)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "gpx"))
+        .collect();
+    files.sort();
+
+    for file in files {
+        let name = file.file_stem().unwrap().to_str().unwrap();
+        assert_plot(name, &gpx::read(&file).unwrap());
+    }
+}
@@ -0,0 +1,33 @@
+G21
+G90
+M5
+G0 X11682.000 Y0.000
+M3 S1000
+G1 X8950.000 Y684.000 F1000
+G1 X8822.000 Y720.000
+G1 X6359.000 Y1346.000
+G1 X5795.000 Y1603.000
+G1 X4937.000 Y1714.000
+G1 X2466.000 Y1915.000
+G1 X0.000 Y2321.000
+M5
+G0 X5795.000 Y1603.000
+M3 S1000
+G1 X5825.000 Y4154.000 F1000
+G1 X5798.000 Y5651.000
+G1 X5769.000 Y6502.000
+G1 X5828.000 Y7445.000
+G1 X5580.000 Y8714.000
+G1 X4739.000 Y10446.000
+G1 X4619.000 Y10655.000
+G1 X4443.000 Y11040.000
+M5
+G0 X4937.000 Y1714.000
+M3 S1000
+G1 X4902.000 Y266.000 F1000
+M5
+G0 X6359.000 Y1346.000
+M3 S1000
+G1 X6403.000 Y22.000 F1000
+M5
+G0 X0 Y0
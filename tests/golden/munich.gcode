@@ -0,0 +1,8199 @@
+G21
+G90
+M5
+G0 X4544.000 Y6377.000
+M3 S1000
+G1 X4661.000 Y6366.000 F1000
+G1 X4763.000 Y6355.000
+G1 X4875.000 Y6344.000
+G1 X4947.000 Y6335.000
+G1 X5062.000 Y6323.000
+G1 X5129.000 Y6315.000
+G1 X5146.000 Y6316.000
+G1 X5164.000 Y6325.000
+G1 X5167.000 Y6328.000
+M5
+G0 X4510.000 Y6486.000
+M3 S1000
+G1 X4555.000 Y6480.000 F1000
+G1 X4672.000 Y6466.000
+M5
+G0 X5247.000 Y6263.000
+M3 S1000
+G1 X5267.000 Y6257.000 F1000
+G1 X5279.000 Y6249.000
+G1 X5285.000 Y6236.000
+M5
+G0 X4705.000 Y6852.000
+M3 S1000
+G1 X4703.000 Y6834.000 F1000
+G1 X4693.000 Y6732.000
+G1 X4691.000 Y6699.000
+G1 X4695.000 Y6692.000
+G1 X4745.000 Y6667.000
+G1 X4795.000 Y6645.000
+G1 X4879.000 Y6638.000
+G1 X4893.000 Y6630.000
+M5
+G0 X4893.000 Y6630.000
+M3 S1000
+G1 X4916.000 Y6617.000 F1000
+G1 X4921.000 Y6612.000
+G1 X4920.000 Y6601.000
+G1 X4911.000 Y6507.000
+G1 X4904.000 Y6484.000
+G1 X4888.000 Y6447.000
+G1 X4875.000 Y6344.000
+M5
+G0 X4947.000 Y6335.000
+M3 S1000
+G1 X4935.000 Y6225.000 F1000
+M5
+G0 X4651.000 Y6261.000
+M3 S1000
+G1 X4647.000 Y6221.000 F1000
+G1 X4651.000 Y6218.000
+G1 X4690.000 Y6213.000
+M5
+G0 X5689.000 Y6470.000
+M3 S1000
+G1 X5697.000 Y6497.000 F1000
+G1 X5706.000 Y6506.000
+M5
+G0 X4875.000 Y6728.000
+M3 S1000
+G1 X4899.000 Y6726.000 F1000
+G1 X4897.000 Y6697.000
+G1 X4899.000 Y6687.000
+G1 X4893.000 Y6630.000
+M5
+G0 X5415.000 Y6714.000
+M3 S1000
+G1 X5381.000 Y6733.000 F1000
+G1 X5367.000 Y6730.000
+G1 X5359.000 Y6707.000
+G1 X5343.000 Y6680.000
+G1 X5334.000 Y6620.000
+M5
+G0 X4880.000 Y6231.000
+M3 S1000
+G1 X4892.000 Y6230.000 F1000
+G1 X4935.000 Y6225.000
+G1 X5050.000 Y6213.000
+M5
+G0 X4514.000 Y6592.000
+M3 S1000
+G1 X4561.000 Y6588.000 F1000
+G1 X4683.000 Y6576.000
+G1 X4784.000 Y6564.000
+M5
+G0 X4661.000 Y6366.000
+M3 S1000
+G1 X4651.000 Y6261.000 F1000
+G1 X4529.000 Y6274.000
+G1 X4525.000 Y6249.000
+G1 X4519.000 Y6192.000
+G1 X4514.000 Y6135.000
+M5
+G0 X5017.000 Y6576.000
+M3 S1000
+G1 X5027.000 Y6574.000 F1000
+M5
+G0 X5186.000 Y6457.000
+M3 S1000
+G1 X5199.000 Y6444.000 F1000
+G1 X5238.000 Y6409.000
+M5
+G0 X5685.000 Y6424.000
+M3 S1000
+G1 X5667.000 Y6346.000 F1000
+G1 X5654.000 Y6295.000
+G1 X5653.000 Y6287.000
+G1 X5654.000 Y6279.000
+M5
+G0 X5418.000 Y6544.000
+M3 S1000
+G1 X5354.000 Y6468.000 F1000
+M5
+G0 X4683.000 Y6576.000
+M3 S1000
+G1 X4672.000 Y6466.000 F1000
+G1 X4669.000 Y6432.000
+G1 X4674.000 Y6427.000
+G1 X4709.000 Y6422.000
+M5
+G0 X5238.000 Y6409.000
+M3 S1000
+G1 X5259.000 Y6392.000 F1000
+G1 X5288.000 Y6381.000
+G1 X5305.000 Y6381.000
+M5
+G0 X5732.000 Y6621.000
+M3 S1000
+G1 X5725.000 Y6592.000 F1000
+G1 X5708.000 Y6512.000
+G1 X5706.000 Y6506.000
+M5
+G0 X5418.000 Y6544.000
+M3 S1000
+G1 X5428.000 Y6537.000 F1000
+G1 X5490.000 Y6520.000
+G1 X5566.000 Y6503.000
+G1 X5570.000 Y6502.000
+G1 X5574.000 Y6501.000
+G1 X5577.000 Y6498.000
+G1 X5579.000 Y6495.000
+G1 X5580.000 Y6490.000
+G1 X5580.000 Y6485.000
+G1 X5553.000 Y6373.000
+M5
+G0 X4738.000 Y6130.000
+M3 S1000
+G1 X4612.000 Y6124.000 F1000
+G1 X4600.000 Y6125.000
+G1 X4514.000 Y6135.000
+G1 X4486.000 Y6137.000
+G1 X4470.000 Y6138.000
+G1 X4418.000 Y6154.000
+M5
+G0 X5538.000 Y6152.000
+M3 S1000
+G1 X5549.000 Y6150.000 F1000
+G1 X5575.000 Y6144.000
+G1 X5678.000 Y6119.000
+M5
+G0 X5335.000 Y6242.000
+M3 S1000
+G1 X5351.000 Y6238.000 F1000
+M5
+G0 X5706.000 Y6506.000
+M3 S1000
+G1 X5706.000 Y6486.000 F1000
+G1 X5696.000 Y6443.000
+G1 X5685.000 Y6424.000
+M5
+G0 X4866.000 Y6131.000
+M3 S1000
+G1 X4865.000 Y6121.000 F1000
+G1 X4863.000 Y6107.000
+G1 X4862.000 Y6101.000
+G1 X4828.000 Y6105.000
+G1 X4820.000 Y6040.000
+G1 X4820.000 Y6034.000
+G1 X4808.000 Y5923.000
+G1 X4806.000 Y5876.000
+G1 X4807.000 Y5843.000
+G1 X4809.000 Y5828.000
+M5
+G0 X5667.000 Y6346.000
+M3 S1000
+G1 X5787.000 Y6315.000 F1000
+M5
+G0 X4955.000 Y6122.000
+M3 S1000
+G1 X4945.000 Y6021.000 F1000
+M5
+G0 X5315.000 Y6501.000
+M3 S1000
+G1 X5238.000 Y6409.000 F1000
+G1 X5205.000 Y6372.000
+M5
+G0 X5062.000 Y6323.000
+M3 S1000
+G1 X5059.000 Y6310.000 F1000
+G1 X5050.000 Y6213.000
+G1 X5045.000 Y6162.000
+M5
+G0 X5282.000 Y6789.000
+M3 S1000
+G1 X5264.000 Y6704.000 F1000
+G1 X5265.000 Y6684.000
+G1 X5269.000 Y6676.000
+G1 X5280.000 Y6662.000
+G1 X5334.000 Y6620.000
+G1 X5408.000 Y6552.000
+G1 X5418.000 Y6544.000
+M5
+G0 X5349.000 Y6311.000
+M3 S1000
+G1 X5346.000 Y6298.000 F1000
+G1 X5335.000 Y6242.000
+G1 X5329.000 Y6232.000
+G1 X5285.000 Y6236.000
+G1 X5281.000 Y6221.000
+G1 X5268.000 Y6211.000
+G1 X5261.000 Y6180.000
+G1 X5258.000 Y6155.000
+G1 X5261.000 Y6151.000
+G1 X5268.000 Y6143.000
+G1 X5285.000 Y6121.000
+G1 X5303.000 Y6105.000
+M5
+G0 X5654.000 Y6279.000
+M3 S1000
+G1 X5662.000 Y6271.000 F1000
+G1 X5704.000 Y6249.000
+G1 X5709.000 Y6236.000
+G1 X5678.000 Y6119.000
+G1 X5653.000 Y6028.000
+G1 X5617.000 Y5881.000
+G1 X5612.000 Y5864.000
+G1 X5624.000 Y5759.000
+G1 X5631.000 Y5666.000
+M5
+G0 X5425.000 Y6403.000
+M3 S1000
+G1 X5404.000 Y6315.000 F1000
+G1 X5401.000 Y6302.000
+M5
+G0 X5280.000 Y6662.000
+M3 S1000
+G1 X5238.000 Y6616.000 F1000
+G1 X5222.000 Y6597.000
+G1 X5220.000 Y6593.000
+G1 X5220.000 Y6583.000
+G1 X5239.000 Y6566.000
+G1 X5315.000 Y6501.000
+G1 X5354.000 Y6468.000
+G1 X5425.000 Y6403.000
+M5
+G0 X5002.000 Y6166.000
+M3 S1000
+G1 X4997.000 Y6117.000 F1000
+G1 X4955.000 Y6122.000
+G1 X4866.000 Y6131.000
+M5
+G0 X5685.000 Y6424.000
+M3 S1000
+G1 X5805.000 Y6398.000 F1000
+M5
+G0 X5725.000 Y6592.000
+M3 S1000
+G1 X5850.000 Y6564.000 F1000
+M5
+G0 X4795.000 Y6645.000
+M3 S1000
+G1 X4784.000 Y6564.000 F1000
+G1 X4763.000 Y6355.000
+G1 X4738.000 Y6130.000
+M5
+G0 X5425.000 Y6403.000
+M3 S1000
+G1 X5461.000 Y6395.000 F1000
+G1 X5536.000 Y6377.000
+G1 X5553.000 Y6373.000
+G1 X5605.000 Y6361.000
+G1 X5667.000 Y6346.000
+M5
+G0 X5415.000 Y6714.000
+M3 S1000
+G1 X5439.000 Y6699.000 F1000
+G1 X5445.000 Y6690.000
+G1 X5442.000 Y6662.000
+G1 X5427.000 Y6586.000
+G1 X5408.000 Y6552.000
+M5
+G0 X4880.000 Y6231.000
+M3 S1000
+G1 X4876.000 Y6220.000 F1000
+G1 X4866.000 Y6131.000
+M5
+G0 X5685.000 Y6424.000
+M3 S1000
+G1 X5684.000 Y6446.000 F1000
+G1 X5689.000 Y6470.000
+M5
+G0 X5030.000 Y6513.000
+M3 S1000
+G1 X5024.000 Y6506.000 F1000
+G1 X5004.000 Y6483.000
+G1 X5002.000 Y6463.000
+G1 X4998.000 Y6456.000
+G1 X4997.000 Y6447.000
+G1 X4996.000 Y6435.000
+M5
+G0 X4738.000 Y6130.000
+M3 S1000
+G1 X4740.000 Y6113.000 F1000
+G1 X4748.000 Y6112.000
+G1 X4768.000 Y6110.000
+M5
+G0 X5708.000 Y6512.000
+M3 S1000
+G1 X5827.000 Y6481.000 F1000
+M5
+G0 X4820.000 Y6034.000
+M3 S1000
+G1 X4875.000 Y6028.000 F1000
+G1 X4945.000 Y6021.000
+M5
+G0 X5490.000 Y6520.000
+M3 S1000
+G1 X5461.000 Y6395.000 F1000
+M5
+G0 X7407.000 Y7229.000
+M3 S1000
+G1 X7609.000 Y7131.000 F1000
+G1 X7624.000 Y7125.000
+G1 X7740.000 Y7091.000
+M5
+G0 X4321.000 Y9755.000
+M3 S1000
+G1 X4398.000 Y9765.000 F1000
+G1 X4467.000 Y9758.000
+G1 X4527.000 Y9741.000
+G1 X4552.000 Y9732.000
+G1 X4591.000 Y9705.000
+G1 X4652.000 Y9645.000
+G1 X4686.000 Y9628.000
+G1 X4695.000 Y9624.000
+G1 X4750.000 Y9602.000
+G1 X4851.000 Y9570.000
+G1 X4945.000 Y9549.000
+G1 X4951.000 Y9547.000
+M5
+G0 X6243.000 Y9902.000
+M3 S1000
+G1 X6103.000 Y9245.000 F1000
+M5
+G0 X7243.000 Y6799.000
+M3 S1000
+G1 X6790.000 Y5607.000 F1000
+G1 X6788.000 Y5604.000
+M5
+G0 X4951.000 Y9547.000
+M3 S1000
+G1 X5043.000 Y9536.000 F1000
+G1 X5131.000 Y9519.000
+G1 X5311.000 Y9511.000
+G1 X5345.000 Y9506.000
+G1 X5369.000 Y9496.000
+G1 X5379.000 Y9486.000
+G1 X5399.000 Y9459.000
+G1 X5412.000 Y9445.000
+G1 X5424.000 Y9437.000
+G1 X5446.000 Y9429.000
+G1 X5532.000 Y9413.000
+G1 X5561.000 Y9403.000
+G1 X5586.000 Y9388.000
+G1 X5608.000 Y9371.000
+G1 X5627.000 Y9360.000
+G1 X5685.000 Y9338.000
+G1 X5746.000 Y9313.000
+G1 X5774.000 Y9306.000
+G1 X5860.000 Y9308.000
+G1 X5883.000 Y9306.000
+G1 X5911.000 Y9300.000
+G1 X5952.000 Y9293.000
+G1 X5975.000 Y9291.000
+G1 X5994.000 Y9287.000
+G1 X6079.000 Y9250.000
+G1 X6103.000 Y9245.000
+G1 X6276.000 Y9203.000
+G1 X6358.000 Y9189.000
+G1 X6410.000 Y9184.000
+G1 X6504.000 Y9184.000
+G1 X6538.000 Y9179.000
+G1 X6614.000 Y9163.000
+G1 X6688.000 Y9147.000
+G1 X6707.000 Y9140.000
+G1 X6720.000 Y9129.000
+G1 X6741.000 Y9104.000
+G1 X6759.000 Y9088.000
+G1 X6780.000 Y9072.000
+G1 X6824.000 Y9041.000
+G1 X6852.000 Y9011.000
+G1 X6918.000 Y8930.000
+G1 X6970.000 Y8859.000
+G1 X6989.000 Y8818.000
+G1 X7016.000 Y8750.000
+G1 X7054.000 Y8686.000
+G1 X7197.000 Y8450.000
+G1 X7212.000 Y8421.000
+G1 X7218.000 Y8408.000
+G1 X7233.000 Y8362.000
+G1 X7344.000 Y8158.000
+G1 X7362.000 Y8133.000
+G1 X7377.000 Y8121.000
+G1 X7389.000 Y8114.000
+G1 X7416.000 Y8101.000
+G1 X7447.000 Y8086.000
+G1 X7477.000 Y8061.000
+G1 X7490.000 Y8046.000
+G1 X7504.000 Y8023.000
+G1 X7516.000 Y7996.000
+G1 X7523.000 Y7974.000
+G1 X7533.000 Y7955.000
+G1 X7633.000 Y7829.000
+G1 X7640.000 Y7812.000
+G1 X7643.000 Y7794.000
+G1 X7640.000 Y7780.000
+G1 X7623.000 Y7739.000
+G1 X7475.000 Y7359.000
+G1 X7460.000 Y7325.000
+G1 X7443.000 Y7291.000
+G1 X7407.000 Y7229.000
+G1 X7391.000 Y7199.000
+G1 X7243.000 Y6799.000
+M5
+G0 X8877.000 Y6958.000
+M3 S1000
+G1 X8503.000 Y6809.000 F1000
+G1 X8469.000 Y6798.000
+G1 X8444.000 Y6791.000
+G1 X8422.000 Y6789.000
+G1 X8395.000 Y6789.000
+G1 X8366.000 Y6794.000
+G1 X8331.000 Y6802.000
+G1 X8262.000 Y6820.000
+G1 X8242.000 Y6824.000
+G1 X8218.000 Y6824.000
+G1 X8197.000 Y6820.000
+G1 X8152.000 Y6809.000
+G1 X8135.000 Y6808.000
+G1 X8120.000 Y6810.000
+G1 X8102.000 Y6813.000
+G1 X8072.000 Y6823.000
+G1 X8011.000 Y6846.000
+G1 X7981.000 Y6856.000
+G1 X7950.000 Y6861.000
+G1 X7914.000 Y6863.000
+G1 X7849.000 Y6864.000
+G1 X7777.000 Y6860.000
+G1 X7654.000 Y6829.000
+G1 X7525.000 Y6820.000
+G1 X7243.000 Y6799.000
+M5
+G0 X5095.000 Y6456.000
+M3 S1000
+G1 X5100.000 Y6451.000 F1000
+G1 X5180.000 Y6379.000
+G1 X5198.000 Y6364.000
+M5
+G0 X5538.000 Y6152.000
+M3 S1000
+G1 X5528.000 Y6112.000 F1000
+G1 X5526.000 Y6103.000
+G1 X5523.000 Y6095.000
+G1 X5518.000 Y6087.000
+G1 X5513.000 Y6080.000
+G1 X5508.000 Y6074.000
+G1 X5502.000 Y6068.000
+G1 X5496.000 Y6063.000
+G1 X5419.000 Y6020.000
+G1 X5404.000 Y6009.000
+G1 X5390.000 Y5993.000
+M5
+G0 X5401.000 Y6302.000
+M3 S1000
+G1 X5437.000 Y6294.000 F1000
+G1 X5455.000 Y6288.000
+G1 X5472.000 Y6281.000
+G1 X5488.000 Y6273.000
+G1 X5505.000 Y6264.000
+G1 X5521.000 Y6254.000
+G1 X5536.000 Y6243.000
+G1 X5545.000 Y6234.000
+G1 X5548.000 Y6228.000
+G1 X5550.000 Y6222.000
+G1 X5551.000 Y6217.000
+G1 X5551.000 Y6211.000
+G1 X5551.000 Y6206.000
+G1 X5538.000 Y6152.000
+M5
+G0 X5349.000 Y6311.000
+M3 S1000
+G1 X5401.000 Y6302.000 F1000
+M5
+G0 X5198.000 Y6364.000
+M3 S1000
+G1 X5220.000 Y6345.000 F1000
+G1 X5255.000 Y6329.000
+G1 X5275.000 Y6322.000
+G1 X5349.000 Y6311.000
+M5
+G0 X4485.000 Y6877.000
+M3 S1000
+G1 X4705.000 Y6852.000 F1000
+G1 X4963.000 Y6824.000
+G1 X4972.000 Y6823.000
+G1 X4985.000 Y6819.000
+G1 X4994.000 Y6816.000
+G1 X5006.000 Y6808.000
+G1 X5014.000 Y6800.000
+G1 X5019.000 Y6794.000
+G1 X5023.000 Y6787.000
+G1 X5030.000 Y6774.000
+G1 X5032.000 Y6766.000
+G1 X5033.000 Y6758.000
+G1 X5034.000 Y6746.000
+G1 X5019.000 Y6606.000
+G1 X5017.000 Y6576.000
+G1 X5015.000 Y6548.000
+G1 X5019.000 Y6534.000
+G1 X5023.000 Y6524.000
+G1 X5030.000 Y6513.000
+G1 X5095.000 Y6456.000
+M5
+G0 X4725.000 Y5820.000
+M3 S1000
+G1 X4725.000 Y5793.000 F1000
+G1 X4725.000 Y5785.000
+G1 X4724.000 Y5766.000
+G1 X4726.000 Y5755.000
+G1 X4726.000 Y5750.000
+G1 X4729.000 Y5739.000
+G1 X4734.000 Y5733.000
+G1 X4747.000 Y5730.000
+G1 X4760.000 Y5728.000
+G1 X4767.000 Y5722.000
+G1 X4769.000 Y5715.000
+G1 X4758.000 Y5685.000
+G1 X4754.000 Y5668.000
+M5
+G0 X4609.000 Y5495.000
+M3 S1000
+G1 X4613.000 Y5498.000 F1000
+G1 X4620.000 Y5499.000
+G1 X4633.000 Y5498.000
+G1 X4688.000 Y5491.000
+G1 X4720.000 Y5486.000
+G1 X4767.000 Y5472.000
+G1 X4775.000 Y5470.000
+M5
+G0 X5612.000 Y5864.000
+M3 S1000
+G1 X5512.000 Y5850.000 F1000
+G1 X5462.000 Y5846.000
+G1 X5418.000 Y5846.000
+G1 X5356.000 Y5848.000
+M5
+G0 X5193.000 Y5950.000
+M3 S1000
+G1 X5183.000 Y5898.000 F1000
+G1 X5177.000 Y5870.000
+G1 X5190.000 Y5867.000
+G1 X5187.000 Y5851.000
+G1 X5175.000 Y5795.000
+M5
+G0 X4808.000 Y5923.000
+M3 S1000
+G1 X4863.000 Y5917.000 F1000
+G1 X4937.000 Y5910.000
+G1 X4984.000 Y5906.000
+M5
+G0 X5653.000 Y6028.000
+M3 S1000
+G1 X5577.000 Y6046.000 F1000
+G1 X5570.000 Y6048.000
+G1 X5566.000 Y6048.000
+G1 X5562.000 Y6047.000
+G1 X5559.000 Y6045.000
+G1 X5557.000 Y6043.000
+G1 X5556.000 Y6040.000
+G1 X5555.000 Y6038.000
+G1 X5547.000 Y6000.000
+G1 X5512.000 Y5850.000
+M5
+G0 X4783.000 Y5184.000
+M3 S1000
+G1 X4798.000 Y5152.000 F1000
+G1 X4808.000 Y5130.000
+G1 X4812.000 Y5105.000
+G1 X4812.000 Y5094.000
+G1 X4811.000 Y5068.000
+G1 X4811.000 Y5057.000
+G1 X4810.000 Y5040.000
+G1 X4806.000 Y4991.000
+M5
+G0 X5538.000 Y5374.000
+M3 S1000
+G1 X5510.000 Y5249.000 F1000
+M5
+G0 X4849.000 Y5403.000
+M3 S1000
+G1 X4814.000 Y5389.000 F1000
+G1 X4742.000 Y5374.000
+G1 X4735.000 Y5370.000
+G1 X4731.000 Y5365.000
+G1 X4775.000 Y5287.000
+G1 X4821.000 Y5202.000
+M5
+G0 X5477.000 Y5399.000
+M3 S1000
+G1 X5453.000 Y5278.000 F1000
+G1 X5456.000 Y5259.000
+G1 X5510.000 Y5249.000
+M5
+G0 X5146.000 Y6002.000
+M3 S1000
+G1 X5139.000 Y5969.000 F1000
+G1 X5148.000 Y5967.000
+G1 X5147.000 Y5960.000
+G1 X5193.000 Y5950.000
+G1 X5238.000 Y5942.000
+G1 X5243.000 Y5945.000
+G1 X5248.000 Y5946.000
+G1 X5287.000 Y5939.000
+G1 X5292.000 Y5944.000
+G1 X5296.000 Y5956.000
+G1 X5301.000 Y5982.000
+G1 X5258.000 Y6034.000
+G1 X5254.000 Y6042.000
+M5
+G0 X5510.000 Y5249.000
+M3 S1000
+G1 X5496.000 Y5195.000 F1000
+G1 X5495.000 Y5192.000
+G1 X5493.000 Y5189.000
+G1 X5491.000 Y5188.000
+G1 X5488.000 Y5186.000
+G1 X5485.000 Y5186.000
+G1 X5482.000 Y5186.000
+G1 X5332.000 Y5221.000
+M5
+G0 X4811.000 Y5068.000
+M3 S1000
+G1 X4883.000 Y5067.000 F1000
+M5
+G0 X4849.000 Y5403.000
+M3 S1000
+G1 X4942.000 Y5258.000 F1000
+M5
+G0 X5070.000 Y5473.000
+M3 S1000
+G1 X5130.000 Y5458.000 F1000
+G1 X5153.000 Y5453.000
+G1 X5172.000 Y5452.000
+G1 X5208.000 Y5462.000
+G1 X5278.000 Y5463.000
+G1 X5366.000 Y5455.000
+G1 X5385.000 Y5451.000
+M5
+G0 X5407.000 Y5547.000
+M3 S1000
+G1 X5393.000 Y5550.000 F1000
+G1 X5372.000 Y5553.000
+G1 X5308.000 Y5560.000
+G1 X5254.000 Y5561.000
+G1 X5154.000 Y5554.000
+G1 X5089.000 Y5547.000
+G1 X5070.000 Y5473.000
+M5
+G0 X5378.000 Y5420.000
+M3 S1000
+G1 X5477.000 Y5399.000 F1000
+M5
+G0 X4754.000 Y5668.000
+M3 S1000
+G1 X4764.000 Y5656.000 F1000
+G1 X4826.000 Y5659.000
+G1 X4843.000 Y5666.000
+G1 X4897.000 Y5668.000
+G1 X4911.000 Y5666.000
+M5
+G0 X4865.000 Y5413.000
+M3 S1000
+G1 X4849.000 Y5403.000 F1000
+M5
+G0 X5547.000 Y6000.000
+M3 S1000
+G1 X5522.000 Y6005.000 F1000
+G1 X5515.000 Y6004.000
+G1 X5448.000 Y5968.000
+G1 X5440.000 Y5955.000
+G1 X5418.000 Y5846.000
+M5
+G0 X5624.000 Y5759.000
+M3 S1000
+G1 X5439.000 Y5738.000 F1000
+M5
+G0 X4694.000 Y5707.000
+M3 S1000
+G1 X4685.000 Y5643.000 F1000
+M5
+G0 X5070.000 Y5473.000
+M3 S1000
+G1 X5046.000 Y5370.000 F1000
+G1 X5027.000 Y5294.000
+M5
+G0 X5383.000 Y5605.000
+M3 S1000
+G1 X5369.000 Y5604.000 F1000
+M5
+G0 X5477.000 Y5399.000
+M3 S1000
+G1 X5531.000 Y5385.000 F1000
+G1 X5535.000 Y5384.000
+G1 X5538.000 Y5382.000
+G1 X5539.000 Y5379.000
+G1 X5538.000 Y5374.000
+M5
+G0 X5462.000 Y5846.000
+M3 S1000
+G1 X5442.000 Y5754.000 F1000
+G1 X5439.000 Y5738.000
+G1 X5438.000 Y5729.000
+G1 X5438.000 Y5721.000
+G1 X5439.000 Y5714.000
+G1 X5442.000 Y5707.000
+G1 X5447.000 Y5700.000
+G1 X5452.000 Y5695.000
+G1 X5458.000 Y5690.000
+G1 X5464.000 Y5687.000
+G1 X5477.000 Y5684.000
+G1 X5480.000 Y5685.000
+G1 X5483.000 Y5683.000
+G1 X5486.000 Y5681.000
+G1 X5487.000 Y5678.000
+G1 X5489.000 Y5675.000
+G1 X5489.000 Y5672.000
+G1 X5490.000 Y5660.000
+G1 X5491.000 Y5657.000
+G1 X5493.000 Y5654.000
+G1 X5495.000 Y5653.000
+G1 X5497.000 Y5652.000
+G1 X5500.000 Y5652.000
+G1 X5619.000 Y5663.000
+G1 X5631.000 Y5666.000
+M5
+G0 X5251.000 Y5346.000
+M3 S1000
+G1 X5355.000 Y5322.000 F1000
+M5
+G0 X5097.000 Y5134.000
+M3 S1000
+G1 X4995.000 Y5158.000 F1000
+G1 X4989.000 Y5129.000
+G1 X4975.000 Y5066.000
+G1 X4973.000 Y5058.000
+G1 X4951.000 Y4969.000
+M5
+G0 X5331.000 Y4965.000
+M3 S1000
+G1 X5276.000 Y4978.000 F1000
+M5
+G0 X5075.000 Y5031.000
+M3 S1000
+G1 X5062.000 Y5035.000 F1000
+G1 X4983.000 Y5055.000
+G1 X4973.000 Y5058.000
+M5
+G0 X5075.000 Y5031.000
+M3 S1000
+G1 X5048.000 Y4918.000 F1000
+G1 X5019.000 Y4796.000
+M5
+G0 X5331.000 Y4965.000
+M3 S1000
+G1 X5330.000 Y4963.000 F1000
+G1 X5310.000 Y4869.000
+M5
+G0 X5373.000 Y5104.000
+M3 S1000
+G1 X5342.000 Y4976.000 F1000
+M5
+G0 X5278.000 Y4990.000
+M3 S1000
+G1 X5342.000 Y4976.000 F1000
+M5
+G0 X5172.000 Y5452.000
+M3 S1000
+G1 X5133.000 Y5275.000 F1000
+M5
+G0 X5342.000 Y4976.000
+M3 S1000
+G1 X5336.000 Y4970.000 F1000
+G1 X5331.000 Y4965.000
+M5
+G0 X5048.000 Y4918.000
+M3 S1000
+G1 X5086.000 Y4909.000 F1000
+G1 X5208.000 Y4886.000
+G1 X5263.000 Y4877.000
+G1 X5295.000 Y4871.000
+G1 X5310.000 Y4869.000
+M5
+G0 X4951.000 Y4969.000
+M3 S1000
+G1 X4949.000 Y4955.000 F1000
+G1 X4949.000 Y4951.000
+G1 X4958.000 Y4946.000
+G1 X4968.000 Y4940.000
+G1 X4989.000 Y4931.000
+G1 X5014.000 Y4923.000
+G1 X5048.000 Y4918.000
+M5
+G0 X5308.000 Y5118.000
+M3 S1000
+G1 X5373.000 Y5104.000 F1000
+G1 X5463.000 Y5083.000
+G1 X5466.000 Y5081.000
+G1 X5469.000 Y5079.000
+G1 X5470.000 Y5076.000
+G1 X5470.000 Y5074.000
+G1 X5469.000 Y5070.000
+G1 X5466.000 Y5057.000
+G1 X5441.000 Y4946.000
+G1 X5403.000 Y4794.000
+G1 X5372.000 Y4662.000
+M5
+G0 X5097.000 Y5134.000
+M3 S1000
+G1 X5300.000 Y5084.000 F1000
+M5
+G0 X5075.000 Y5031.000
+M3 S1000
+G1 X5095.000 Y5033.000 F1000
+G1 X5278.000 Y4990.000
+M5
+G0 X4810.000 Y5040.000
+M3 S1000
+G1 X4876.000 Y5037.000 F1000
+M5
+G0 X5278.000 Y5463.000
+M3 S1000
+G1 X5251.000 Y5346.000 F1000
+G1 X5242.000 Y5304.000
+M5
+G0 X5097.000 Y5134.000
+M3 S1000
+G1 X5075.000 Y5031.000 F1000
+M5
+G0 X4610.000 Y5318.000
+M3 S1000
+G1 X4631.000 Y5277.000 F1000
+G1 X4635.000 Y5271.000
+G1 X4700.000 Y5144.000
+G1 X4704.000 Y5134.000
+G1 X4716.000 Y5105.000
+G1 X4720.000 Y5087.000
+G1 X4721.000 Y5075.000
+G1 X4720.000 Y5062.000
+G1 X4719.000 Y5050.000
+G1 X4711.000 Y5006.000
+G1 X4708.000 Y4991.000
+G1 X4708.000 Y4971.000
+G1 X4710.000 Y4944.000
+G1 X4710.000 Y4936.000
+M5
+G0 X7003.000 Y3414.000
+M3 S1000
+G1 X7007.000 Y3411.000 F1000
+G1 X7011.000 Y3407.000
+G1 X7015.000 Y3402.000
+G1 X7019.000 Y3396.000
+G1 X7032.000 Y3371.000
+G1 X7036.000 Y3365.000
+G1 X7038.000 Y3358.000
+G1 X7039.000 Y3351.000
+G1 X7039.000 Y3342.000
+G1 X7038.000 Y3333.000
+G1 X7032.000 Y3316.000
+G1 X6962.000 Y3163.000
+G1 X6960.000 Y3157.000
+G1 X6960.000 Y3151.000
+G1 X6960.000 Y3144.000
+G1 X6962.000 Y3138.000
+G1 X6964.000 Y3133.000
+G1 X6968.000 Y3127.000
+G1 X7214.000 Y2825.000
+G1 X7219.000 Y2817.000
+G1 X7224.000 Y2809.000
+G1 X7227.000 Y2800.000
+G1 X7229.000 Y2791.000
+G1 X7229.000 Y2781.000
+G1 X7229.000 Y2771.000
+G1 X7226.000 Y2761.000
+G1 X7219.000 Y2745.000
+G1 X7102.000 Y2485.000
+M5
+G0 X7399.000 Y5517.000
+M3 S1000
+G1 X7396.000 Y5500.000 F1000
+G1 X7055.000 Y4662.000
+G1 X7049.000 Y4648.000
+G1 X7045.000 Y4640.000
+M5
+G0 X6065.000 Y4096.000
+M3 S1000
+G1 X5979.000 Y3850.000 F1000
+M5
+G0 X5027.000 Y5294.000
+M3 S1000
+G1 X5019.000 Y5294.000 F1000
+G1 X5006.000 Y5288.000
+G1 X4979.000 Y5273.000
+M5
+G0 X4764.000 Y3384.000
+M3 S1000
+G1 X4997.000 Y3393.000 F1000
+G1 X5106.000 Y3395.000
+G1 X5269.000 Y3405.000
+G1 X5408.000 Y3411.000
+G1 X5437.000 Y3411.000
+G1 X5469.000 Y3411.000
+G1 X5500.000 Y3410.000
+G1 X5559.000 Y3408.000
+G1 X5699.000 Y3403.000
+G1 X5824.000 Y3393.000
+G1 X5949.000 Y3385.000
+G1 X5999.000 Y3385.000
+G1 X6047.000 Y3389.000
+G1 X6234.000 Y3396.000
+G1 X6296.000 Y3399.000
+M5
+G0 X5407.000 Y5589.000
+M3 S1000
+G1 X5410.000 Y5577.000 F1000
+G1 X5410.000 Y5571.000
+G1 X5410.000 Y5565.000
+G1 X5409.000 Y5557.000
+G1 X5407.000 Y5547.000
+M5
+G0 X4809.000 Y5828.000
+M3 S1000
+G1 X4849.000 Y5831.000 F1000
+G1 X4870.000 Y5832.000
+G1 X4911.000 Y5833.000
+G1 X4940.000 Y5832.000
+G1 X4967.000 Y5831.000
+G1 X4991.000 Y5829.000
+G1 X5014.000 Y5827.000
+G1 X5049.000 Y5822.000
+G1 X5082.000 Y5816.000
+G1 X5127.000 Y5806.000
+G1 X5175.000 Y5795.000
+G1 X5190.000 Y5792.000
+G1 X5238.000 Y5777.000
+G1 X5265.000 Y5768.000
+G1 X5282.000 Y5759.000
+G1 X5300.000 Y5749.000
+G1 X5308.000 Y5746.000
+G1 X5314.000 Y5744.000
+G1 X5322.000 Y5744.000
+G1 X5348.000 Y5745.000
+M5
+G0 X5027.000 Y5294.000
+M3 S1000
+G1 X5038.000 Y5298.000 F1000
+G1 X5133.000 Y5275.000
+G1 X5316.000 Y5232.000
+G1 X5332.000 Y5221.000
+M5
+G0 X9090.000 Y5628.000
+M3 S1000
+G1 X9093.000 Y5616.000 F1000
+G1 X9122.000 Y5470.000
+G1 X9127.000 Y5406.000
+G1 X9319.000 Y4454.000
+G1 X9316.000 Y4441.000
+M5
+G0 X9843.000 Y4578.000
+M3 S1000
+G1 X9625.000 Y4474.000 F1000
+G1 X9562.000 Y4448.000
+G1 X9535.000 Y4442.000
+G1 X9506.000 Y4435.000
+G1 X9455.000 Y4429.000
+G1 X9419.000 Y4427.000
+G1 X9385.000 Y4431.000
+G1 X9316.000 Y4441.000
+M5
+G0 X5476.000 Y5614.000
+M3 S1000
+G1 X5549.000 Y5621.000 F1000
+G1 X5637.000 Y5629.000
+G1 X5651.000 Y5630.000
+G1 X5695.000 Y5634.000
+G1 X5750.000 Y5638.000
+M5
+G0 X5390.000 Y5993.000
+M3 S1000
+G1 X5382.000 Y5976.000 F1000
+G1 X5356.000 Y5848.000
+M5
+G0 X5393.000 Y5627.000
+M3 S1000
+G1 X5401.000 Y5628.000 F1000
+G1 X5407.000 Y5628.000
+G1 X5413.000 Y5624.000
+G1 X5418.000 Y5620.000
+G1 X5421.000 Y5615.000
+G1 X5422.000 Y5608.000
+G1 X5421.000 Y5602.000
+G1 X5418.000 Y5596.000
+G1 X5413.000 Y5592.000
+G1 X5407.000 Y5589.000
+G1 X5399.000 Y5588.000
+G1 X5391.000 Y5592.000
+G1 X5385.000 Y5597.000
+G1 X5383.000 Y5605.000
+G1 X5383.000 Y5612.000
+G1 X5385.000 Y5618.000
+G1 X5389.000 Y5623.000
+G1 X5393.000 Y5627.000
+M5
+G0 X5348.000 Y5745.000
+M3 S1000
+G1 X5353.000 Y5729.000 F1000
+G1 X5359.000 Y5713.000
+G1 X5371.000 Y5682.000
+M5
+G0 X9022.000 Y5609.000
+M3 S1000
+G1 X8969.000 Y5595.000 F1000
+G1 X8906.000 Y5583.000
+G1 X8562.000 Y5536.000
+G1 X8502.000 Y5527.000
+G1 X8403.000 Y5521.000
+G1 X7996.000 Y5493.000
+G1 X7942.000 Y5473.000
+G1 X7922.000 Y5461.000
+M5
+G0 X5356.000 Y5848.000
+M3 S1000
+G1 X5353.000 Y5831.000 F1000
+G1 X5349.000 Y5814.000
+G1 X5347.000 Y5798.000
+G1 X5346.000 Y5782.000
+G1 X5346.000 Y5766.000
+G1 X5348.000 Y5745.000
+M5
+G0 X3912.000 Y6892.000
+M3 S1000
+G1 X3910.000 Y6875.000 F1000
+G1 X3885.000 Y6636.000
+G1 X3859.000 Y6439.000
+G1 X3841.000 Y6325.000
+G1 X3828.000 Y6232.000
+G1 X3796.000 Y5905.000
+M5
+G0 X3374.000 Y6226.000
+M3 S1000
+G1 X3332.000 Y6184.000 F1000
+G1 X3301.000 Y6142.000
+G1 X3283.000 Y6107.000
+G1 X3270.000 Y6084.000
+G1 X3198.000 Y5928.000
+G1 X3178.000 Y5881.000
+G1 X3174.000 Y5868.000
+G1 X3168.000 Y5785.000
+G1 X3167.000 Y5779.000
+G1 X3164.000 Y5767.000
+G1 X3165.000 Y5746.000
+G1 X3164.000 Y5726.000
+G1 X3162.000 Y5696.000
+G1 X3160.000 Y5659.000
+G1 X3153.000 Y5548.000
+M5
+G0 X3557.000 Y6021.000
+M3 S1000
+G1 X3512.000 Y5985.000 F1000
+G1 X3485.000 Y5954.000
+G1 X3478.000 Y5943.000
+G1 X3477.000 Y5937.000
+G1 X3477.000 Y5934.000
+G1 X3478.000 Y5930.000
+G1 X3479.000 Y5926.000
+G1 X3478.000 Y5922.000
+G1 X3476.000 Y5918.000
+G1 X3474.000 Y5913.000
+G1 X3472.000 Y5911.000
+G1 X3469.000 Y5909.000
+G1 X3466.000 Y5908.000
+G1 X3462.000 Y5908.000
+G1 X3454.000 Y5907.000
+G1 X3448.000 Y5907.000
+G1 X3443.000 Y5907.000
+G1 X3433.000 Y5905.000
+G1 X3427.000 Y5902.000
+G1 X3421.000 Y5897.000
+G1 X3416.000 Y5889.000
+G1 X3414.000 Y5882.000
+G1 X3405.000 Y5772.000
+M5
+G0 X3482.000 Y6107.000
+M3 S1000
+G1 X3446.000 Y6078.000 F1000
+G1 X3421.000 Y6051.000
+G1 X3388.000 Y5986.000
+G1 X3371.000 Y5956.000
+G1 X3341.000 Y5901.000
+G1 X3321.000 Y5864.000
+G1 X3315.000 Y5777.000
+G1 X3313.000 Y5738.000
+G1 X3310.000 Y5671.000
+G1 X3302.000 Y5537.000
+M5
+G0 X4012.000 Y5927.000
+M3 S1000
+G1 X4054.000 Y6196.000 F1000
+M5
+G0 X4612.000 Y6124.000
+M3 S1000
+G1 X4592.000 Y5930.000 F1000
+G1 X4588.000 Y5891.000
+G1 X4582.000 Y5841.000
+M5
+G0 X4179.000 Y6187.000
+M3 S1000
+G1 X4131.000 Y5909.000 F1000
+M5
+G0 X3693.000 Y5086.000
+M3 S1000
+G1 X3675.000 Y5082.000 F1000
+G1 X3641.000 Y4817.000
+G1 X3628.000 Y4726.000
+G1 X3620.000 Y4667.000
+G1 X3604.000 Y4601.000
+M5
+G0 X3315.000 Y5777.000
+M3 S1000
+G1 X3328.000 Y5776.000 F1000
+G1 X3405.000 Y5772.000
+G1 X3427.000 Y5771.000
+G1 X3601.000 Y5763.000
+G1 X3689.000 Y5760.000
+G1 X3704.000 Y5759.000
+M5
+G0 X4288.000 Y5420.000
+M3 S1000
+G1 X4298.000 Y5400.000 F1000
+G1 X4360.000 Y5275.000
+G1 X4375.000 Y5240.000
+G1 X4379.000 Y5226.000
+G1 X4381.000 Y5214.000
+G1 X4382.000 Y5202.000
+G1 X4381.000 Y5189.000
+G1 X4380.000 Y5177.000
+G1 X4371.000 Y5123.000
+G1 X4362.000 Y5070.000
+M5
+G0 X3297.000 Y5385.000
+M3 S1000
+G1 X3264.000 Y5146.000 F1000
+M5
+G0 X2340.000 Y5211.000
+M3 S1000
+G1 X2336.000 Y5217.000 F1000
+G1 X2345.000 Y5314.000
+G1 X2352.000 Y5319.000
+G1 X2426.000 Y5310.000
+G1 X2535.000 Y5299.000
+G1 X2624.000 Y5292.000
+M5
+G0 X2988.000 Y5053.000
+M3 S1000
+G1 X2996.000 Y5053.000 F1000
+G1 X3080.000 Y5044.000
+G1 X3125.000 Y5039.000
+G1 X3138.000 Y5035.000
+G1 X3149.000 Y5024.000
+G1 X3153.000 Y5020.000
+G1 X3154.000 Y5008.000
+G1 X3154.000 Y4980.000
+G1 X3153.000 Y4963.000
+G1 X3152.000 Y4953.000
+G1 X3152.000 Y4945.000
+G1 X3151.000 Y4926.000
+G1 X3152.000 Y4922.000
+G1 X3166.000 Y4905.000
+G1 X3176.000 Y4902.000
+G1 X3203.000 Y4894.000
+M5
+G0 X3601.000 Y5763.000
+M3 S1000
+G1 X3592.000 Y5658.000 F1000
+G1 X3591.000 Y5649.000
+G1 X3600.000 Y5642.000
+G1 X3603.000 Y5635.000
+G1 X3602.000 Y5619.000
+G1 X3599.000 Y5613.000
+G1 X3587.000 Y5612.000
+G1 X3591.000 Y5649.000
+M5
+G0 X3604.000 Y4601.000
+M3 S1000
+G1 X3609.000 Y4596.000 F1000
+G1 X3617.000 Y4588.000
+G1 X3632.000 Y4582.000
+G1 X3729.000 Y4553.000
+M5
+G0 X4391.000 Y5846.000
+M3 S1000
+G1 X4386.000 Y5811.000 F1000
+G1 X4380.000 Y5779.000
+M5
+G0 X2422.000 Y5147.000
+M3 S1000
+G1 X2542.000 Y5132.000 F1000
+G1 X2643.000 Y5118.000
+G1 X2745.000 Y5103.000
+M5
+G0 X2639.000 Y5289.000
+M3 S1000
+G1 X2643.000 Y5118.000 F1000
+G1 X2652.000 Y4930.000
+M5
+G0 X4027.000 Y5426.000
+M3 S1000
+G1 X4023.000 Y5419.000 F1000
+G1 X4017.000 Y5414.000
+G1 X4009.000 Y5413.000
+G1 X4002.000 Y5415.000
+G1 X3996.000 Y5418.000
+G1 X3992.000 Y5424.000
+G1 X3991.000 Y5431.000
+M5
+G0 X3796.000 Y5905.000
+M3 S1000
+G1 X3863.000 Y5898.000 F1000
+G1 X3949.000 Y5913.000
+M5
+G0 X3873.000 Y4787.000
+M3 S1000
+G1 X3859.000 Y4755.000 F1000
+M5
+G0 X3724.000 Y4739.000
+M3 S1000
+G1 X3737.000 Y4647.000 F1000
+G1 X3744.000 Y4638.000
+G1 X3753.000 Y4633.000
+G1 X3932.000 Y4580.000
+G1 X3948.000 Y4574.000
+M5
+G0 X3729.000 Y4553.000
+M3 S1000
+G1 X3744.000 Y4524.000 F1000
+M5
+G0 X2422.000 Y5147.000
+M3 S1000
+G1 X2417.000 Y5142.000 F1000
+G1 X2423.000 Y5016.000
+G1 X2426.000 Y5010.000
+G1 X2430.000 Y5007.000
+G1 X2548.000 Y4964.000
+G1 X2652.000 Y4930.000
+G1 X2749.000 Y4890.000
+G1 X2751.000 Y4891.000
+G1 X2752.000 Y4894.000
+M5
+G0 X4182.000 Y4883.000
+M3 S1000
+G1 X4169.000 Y4858.000 F1000
+G1 X4141.000 Y4809.000
+G1 X4073.000 Y4723.000
+M5
+G0 X3710.000 Y5184.000
+M3 S1000
+G1 X3946.000 Y5053.000 F1000
+G1 X3968.000 Y5053.000
+G1 X3999.000 Y5036.000
+M5
+G0 X3728.000 Y5461.000
+M3 S1000
+G1 X3825.000 Y5449.000 F1000
+G1 X3835.000 Y5444.000
+G1 X3871.000 Y5440.000
+G1 X3881.000 Y5443.000
+G1 X3931.000 Y5440.000
+G1 X3941.000 Y5433.000
+G1 X3983.000 Y5431.000
+G1 X3991.000 Y5431.000
+M5
+G0 X3164.000 Y5161.000
+M3 S1000
+G1 X3172.000 Y5158.000 F1000
+G1 X3181.000 Y5156.000
+G1 X3264.000 Y5146.000
+G1 X3375.000 Y5132.000
+G1 X3456.000 Y5121.000
+G1 X3551.000 Y5108.000
+G1 X3581.000 Y5104.000
+G1 X3598.000 Y5101.000
+G1 X3624.000 Y5094.000
+G1 X3643.000 Y5089.000
+G1 X3658.000 Y5085.000
+G1 X3675.000 Y5082.000
+M5
+G0 X3181.000 Y5543.000
+M3 S1000
+G1 X3180.000 Y5535.000 F1000
+G1 X3172.000 Y5395.000
+G1 X3169.000 Y5338.000
+G1 X3161.000 Y5171.000
+G1 X3161.000 Y5165.000
+G1 X3164.000 Y5161.000
+M5
+G0 X4360.000 Y5275.000
+M3 S1000
+G1 X4316.000 Y5258.000 F1000
+G1 X4291.000 Y5254.000
+G1 X4243.000 Y5253.000
+G1 X4225.000 Y5248.000
+G1 X4142.000 Y5172.000
+G1 X4137.000 Y5168.000
+M5
+G0 X3398.000 Y5282.000
+M3 S1000
+G1 X3476.000 Y5275.000 F1000
+G1 X3572.000 Y5268.000
+G1 X3614.000 Y5266.000
+G1 X3647.000 Y5255.000
+G1 X3661.000 Y5253.000
+M5
+G0 X4609.000 Y5495.000
+M3 S1000
+G1 X4606.000 Y5492.000 F1000
+G1 X4604.000 Y5487.000
+G1 X4603.000 Y5480.000
+G1 X4603.000 Y5474.000
+G1 X4598.000 Y5376.000
+G1 X4598.000 Y5353.000
+M5
+G0 X3712.000 Y5324.000
+M3 S1000
+G1 X4001.000 Y5288.000 F1000
+G1 X4214.000 Y5259.000
+G1 X4225.000 Y5248.000
+M5
+G0 X3454.000 Y4698.000
+M3 S1000
+G1 X3442.000 Y4687.000 F1000
+G1 X3434.000 Y4675.000
+G1 X3426.000 Y4660.000
+G1 X3424.000 Y4656.000
+G1 X3426.000 Y4651.000
+M5
+G0 X3486.000 Y4687.000
+M3 S1000
+G1 X3471.000 Y4671.000 F1000
+G1 X3458.000 Y4657.000
+M5
+G0 X2633.000 Y5572.000
+M3 S1000
+G1 X2634.000 Y5565.000 F1000
+G1 X2637.000 Y5473.000
+G1 X2641.000 Y5373.000
+G1 X2641.000 Y5363.000
+G1 X2647.000 Y5287.000
+M5
+G0 X3203.000 Y4894.000
+M3 S1000
+G1 X3350.000 Y4856.000 F1000
+G1 X3386.000 Y4852.000
+G1 X3431.000 Y4844.000
+G1 X3464.000 Y4833.000
+G1 X3513.000 Y4826.000
+M5
+G0 X3994.000 Y5013.000
+M3 S1000
+G1 X4056.000 Y4975.000 F1000
+G1 X4116.000 Y4919.000
+G1 X4148.000 Y4895.000
+G1 X4182.000 Y4883.000
+M5
+G0 X2624.000 Y5292.000
+M3 S1000
+G1 X2639.000 Y5289.000 F1000
+G1 X2647.000 Y5287.000
+G1 X2729.000 Y5280.000
+G1 X2735.000 Y5276.000
+G1 X2737.000 Y5266.000
+G1 X2745.000 Y5103.000
+G1 X2753.000 Y4904.000
+G1 X2753.000 Y4899.000
+G1 X2752.000 Y4894.000
+M5
+G0 X3197.000 Y4777.000
+M3 S1000
+G1 X3230.000 Y4767.000 F1000
+G1 X3454.000 Y4698.000
+G1 X3486.000 Y4687.000
+M5
+G0 X3172.000 Y5395.000
+M3 S1000
+G1 X3297.000 Y5385.000 F1000
+G1 X3378.000 Y5379.000
+G1 X3404.000 Y5371.000
+G1 X3423.000 Y5360.000
+M5
+G0 X3426.000 Y4651.000
+M3 S1000
+G1 X3436.000 Y4645.000 F1000
+G1 X3458.000 Y4657.000
+M5
+G0 X4011.000 Y5450.000
+M3 S1000
+G1 X4019.000 Y5448.000 F1000
+G1 X4025.000 Y5442.000
+G1 X4028.000 Y5434.000
+G1 X4027.000 Y5426.000
+M5
+G0 X2535.000 Y5299.000
+M3 S1000
+G1 X2539.000 Y5191.000 F1000
+G1 X2542.000 Y5132.000
+G1 X2546.000 Y5018.000
+M5
+G0 X4404.000 Y5506.000
+M3 S1000
+G1 X4344.000 Y5502.000 F1000
+M5
+G0 X4262.000 Y5678.000
+M3 S1000
+G1 X4284.000 Y5671.000 F1000
+G1 X4294.000 Y5668.000
+G1 X4342.000 Y5665.000
+G1 X4377.000 Y5663.000
+G1 X4406.000 Y5663.000
+G1 X4428.000 Y5664.000
+G1 X4464.000 Y5666.000
+G1 X4490.000 Y5665.000
+G1 X4527.000 Y5663.000
+M5
+G0 X3859.000 Y4755.000
+M3 S1000
+G1 X3869.000 Y4743.000 F1000
+G1 X3997.000 Y4630.000
+G1 X4085.000 Y4490.000
+G1 X4084.000 Y4471.000
+G1 X4077.000 Y4448.000
+M5
+G0 X3873.000 Y4787.000
+M3 S1000
+G1 X3694.000 Y4811.000 F1000
+G1 X3641.000 Y4817.000
+M5
+G0 X4014.000 Y5595.000
+M3 S1000
+G1 X4007.000 Y5595.000 F1000
+G1 X3970.000 Y5598.000
+G1 X3963.000 Y5592.000
+G1 X3959.000 Y5576.000
+G1 X3957.000 Y5574.000
+G1 X3954.000 Y5572.000
+G1 X3950.000 Y5572.000
+G1 X3913.000 Y5576.000
+G1 X3911.000 Y5575.000
+G1 X3910.000 Y5573.000
+G1 X3906.000 Y5568.000
+G1 X3903.000 Y5564.000
+G1 X3900.000 Y5564.000
+G1 X3893.000 Y5563.000
+G1 X3890.000 Y5525.000
+M5
+G0 X3449.000 Y5530.000
+M3 S1000
+G1 X3437.000 Y5386.000 F1000
+G1 X3423.000 Y5360.000
+G1 X3400.000 Y5312.000
+G1 X3398.000 Y5282.000
+G1 X3375.000 Y5132.000
+M5
+G0 X3198.000 Y5928.000
+M3 S1000
+G1 X3260.000 Y5897.000 F1000
+G1 X3309.000 Y5872.000
+G1 X3321.000 Y5864.000
+M5
+G0 X4598.000 Y5353.000
+M3 S1000
+G1 X4599.000 Y5345.000 F1000
+G1 X4602.000 Y5337.000
+G1 X4605.000 Y5327.000
+G1 X4610.000 Y5318.000
+M5
+G0 X3572.000 Y5268.000
+M3 S1000
+G1 X3551.000 Y5108.000 F1000
+M5
+G0 X2340.000 Y5211.000
+M3 S1000
+G1 X2350.000 Y5211.000 F1000
+G1 X2539.000 Y5191.000
+M5
+G0 X4027.000 Y5426.000
+M3 S1000
+G1 X4075.000 Y5421.000 F1000
+G1 X4086.000 Y5426.000
+G1 X4132.000 Y5420.000
+G1 X4143.000 Y5412.000
+G1 X4192.000 Y5404.000
+G1 X4204.000 Y5407.000
+G1 X4252.000 Y5403.000
+G1 X4278.000 Y5415.000
+G1 X4288.000 Y5420.000
+M5
+G0 X4262.000 Y5678.000
+M3 S1000
+G1 X4258.000 Y5662.000 F1000
+G1 X4262.000 Y5584.000
+G1 X4270.000 Y5580.000
+G1 X4335.000 Y5570.000
+G1 X4342.000 Y5568.000
+G1 X4383.000 Y5562.000
+G1 X4402.000 Y5560.000
+G1 X4407.000 Y5559.000
+G1 X4427.000 Y5557.000
+M5
+G0 X4601.000 Y5642.000
+M3 S1000
+G1 X4594.000 Y5563.000 F1000
+G1 X4593.000 Y5548.000
+G1 X4593.000 Y5537.000
+G1 X4595.000 Y5525.000
+G1 X4599.000 Y5513.000
+G1 X4604.000 Y5505.000
+G1 X4604.000 Y5503.000
+G1 X4609.000 Y5495.000
+M5
+G0 X4077.000 Y5115.000
+M3 S1000
+G1 X4211.000 Y5040.000 F1000
+M5
+G0 X4020.000 Y5703.000
+M3 S1000
+G1 X4019.000 Y5693.000 F1000
+G1 X4017.000 Y5651.000
+G1 X4016.000 Y5632.000
+G1 X4014.000 Y5595.000
+G1 X4013.000 Y5576.000
+G1 X4012.000 Y5538.000
+G1 X4012.000 Y5536.000
+G1 X4012.000 Y5513.000
+G1 X4012.000 Y5506.000
+G1 X4011.000 Y5461.000
+M5
+G0 X4402.000 Y5560.000
+M3 S1000
+G1 X4404.000 Y5539.000 F1000
+G1 X4404.000 Y5506.000
+G1 X4408.000 Y5445.000
+G1 X4421.000 Y5447.000
+G1 X4441.000 Y5449.000
+M5
+G0 X3310.000 Y5671.000
+M3 S1000
+G1 X3324.000 Y5670.000 F1000
+G1 X3592.000 Y5658.000
+M5
+G0 X3787.000 Y5796.000
+M3 S1000
+G1 X3797.000 Y5787.000 F1000
+G1 X3995.000 Y5758.000
+G1 X4173.000 Y5707.000
+G1 X4233.000 Y5703.000
+G1 X4247.000 Y5694.000
+M5
+G0 X4406.000 Y5663.000
+M3 S1000
+G1 X4399.000 Y5638.000 F1000
+G1 X4383.000 Y5562.000
+M5
+G0 X3165.000 Y5746.000
+M3 S1000
+G1 X3179.000 Y5746.000 F1000
+G1 X3298.000 Y5740.000
+G1 X3313.000 Y5738.000
+M5
+G0 X4262.000 Y5792.000
+M3 S1000
+G1 X4309.000 Y5786.000 F1000
+M5
+G0 X3476.000 Y5275.000
+M3 S1000
+G1 X3456.000 Y5121.000 F1000
+M5
+G0 X2624.000 Y5292.000
+M3 S1000
+G1 X2624.000 Y5363.000 F1000
+G1 X2623.000 Y5372.000
+G1 X2619.000 Y5472.000
+G1 X2615.000 Y5563.000
+G1 X2615.000 Y5573.000
+M5
+G0 X4073.000 Y4723.000
+M3 S1000
+G1 X3997.000 Y4630.000 F1000
+M5
+G0 X4527.000 Y5663.000
+M3 S1000
+G1 X4541.000 Y5760.000 F1000
+M5
+G0 X1483.000 Y5198.000
+M3 S1000
+G1 X1481.000 Y5180.000 F1000
+G1 X1480.000 Y5165.000
+G1 X1479.000 Y5151.000
+G1 X1480.000 Y5134.000
+G1 X1486.000 Y5002.000
+G1 X1483.000 Y4924.000
+G1 X1482.000 Y4852.000
+G1 X1498.000 Y4676.000
+G1 X1506.000 Y4543.000
+G1 X1508.000 Y4509.000
+G1 X1516.000 Y4455.000
+G1 X1522.000 Y4416.000
+G1 X1530.000 Y4378.000
+G1 X1546.000 Y4313.000
+G1 X1552.000 Y4288.000
+G1 X1560.000 Y4247.000
+G1 X1571.000 Y4159.000
+G1 X1588.000 Y4069.000
+G1 X1607.000 Y3975.000
+G1 X1617.000 Y3928.000
+G1 X1625.000 Y3909.000
+G1 X1634.000 Y3899.000
+G1 X1647.000 Y3891.000
+G1 X1670.000 Y3883.000
+G1 X1706.000 Y3875.000
+G1 X1826.000 Y3861.000
+G1 X1849.000 Y3856.000
+M5
+G0 X4285.000 Y5870.000
+M3 S1000
+G1 X4298.000 Y5865.000 F1000
+G1 X4366.000 Y5849.000
+G1 X4387.000 Y5847.000
+G1 X4391.000 Y5846.000
+G1 X4448.000 Y5840.000
+G1 X4459.000 Y5839.000
+G1 X4474.000 Y5839.000
+G1 X4511.000 Y5848.000
+G1 X4550.000 Y5843.000
+G1 X4564.000 Y5842.000
+G1 X4569.000 Y5841.000
+G1 X4582.000 Y5841.000
+G1 X4635.000 Y5834.000
+G1 X4661.000 Y5822.000
+G1 X4706.000 Y5820.000
+G1 X4725.000 Y5820.000
+G1 X4745.000 Y5821.000
+G1 X4784.000 Y5826.000
+G1 X4790.000 Y5827.000
+G1 X4809.000 Y5828.000
+M5
+G0 X3491.000 Y3518.000
+M3 S1000
+G1 X3650.000 Y3463.000 F1000
+G1 X3709.000 Y3452.000
+G1 X4036.000 Y3412.000
+G1 X4043.000 Y3410.000
+M5
+G0 X1849.000 Y3856.000
+M3 S1000
+G1 X1893.000 Y3846.000 F1000
+G1 X2623.000 Y3721.000
+G1 X2664.000 Y3711.000
+G1 X2765.000 Y3661.000
+G1 X2815.000 Y3642.000
+G1 X3033.000 Y3589.000
+G1 X3128.000 Y3573.000
+G1 X3288.000 Y3548.000
+G1 X3416.000 Y3527.000
+M5
+G0 X3584.000 Y4316.000
+M3 S1000
+G1 X3564.000 Y4150.000 F1000
+G1 X3539.000 Y3907.000
+G1 X3523.000 Y3812.000
+G1 X3491.000 Y3518.000
+M5
+G0 X1849.000 Y3856.000
+M3 S1000
+G1 X1842.000 Y3841.000 F1000
+G1 X1791.000 Y3783.000
+G1 X1782.000 Y3768.000
+G1 X1777.000 Y3752.000
+G1 X1771.000 Y3679.000
+G1 X1764.000 Y3653.000
+G1 X1752.000 Y3629.000
+G1 X1744.000 Y3614.000
+G1 X1737.000 Y3597.000
+G1 X1723.000 Y3542.000
+G1 X1720.000 Y3516.000
+G1 X1717.000 Y3466.000
+G1 X1707.000 Y3427.000
+G1 X1699.000 Y3406.000
+G1 X1687.000 Y3342.000
+G1 X1685.000 Y3331.000
+M5
+G0 X3471.000 Y3362.000
+M3 S1000
+G1 X3470.000 Y3352.000 F1000
+G1 X3455.000 Y3240.000
+G1 X3441.000 Y3101.000
+G1 X3397.000 Y2778.000
+G1 X3381.000 Y2636.000
+G1 X3369.000 Y2551.000
+G1 X3361.000 Y2479.000
+G1 X3357.000 Y2433.000
+G1 X3361.000 Y2408.000
+G1 X3367.000 Y2397.000
+G1 X3370.000 Y2383.000
+G1 X3370.000 Y2362.000
+G1 X3367.000 Y2342.000
+G1 X3353.000 Y2283.000
+M5
+G0 X4431.000 Y3385.000
+M3 S1000
+G1 X4410.000 Y3376.000 F1000
+G1 X4355.000 Y3367.000
+G1 X4346.000 Y3366.000
+G1 X4335.000 Y3367.000
+G1 X4324.000 Y3367.000
+G1 X4312.000 Y3368.000
+G1 X4228.000 Y3385.000
+G1 X4139.000 Y3401.000
+G1 X4135.000 Y3401.000
+G1 X4131.000 Y3399.000
+G1 X4126.000 Y3397.000
+G1 X4123.000 Y3395.000
+G1 X4121.000 Y3392.000
+G1 X4114.000 Y3368.000
+G1 X4097.000 Y3329.000
+G1 X4086.000 Y3302.000
+G1 X3910.000 Y2346.000
+G1 X3817.000 Y1840.000
+G1 X3766.000 Y1577.000
+G1 X3737.000 Y1354.000
+G1 X3739.000 Y1346.000
+G1 X3745.000 Y1341.000
+G1 X3866.000 Y1308.000
+G1 X4004.000 Y1285.000
+G1 X4032.000 Y1284.000
+G1 X4065.000 Y1291.000
+G1 X4090.000 Y1290.000
+G1 X4160.000 Y1276.000
+G1 X4170.000 Y1270.000
+G1 X4175.000 Y1259.000
+G1 X4159.000 Y1176.000
+G1 X4109.000 Y999.000
+G1 X4091.000 Y926.000
+G1 X4061.000 Y809.000
+G1 X4016.000 Y633.000
+G1 X3989.000 Y536.000
+G1 X3969.000 Y457.000
+G1 X3950.000 Y380.000
+G1 X3925.000 Y276.000
+G1 X3899.000 Y177.000
+G1 X3875.000 Y88.000
+G1 X3867.000 Y53.000
+G1 X3862.000 Y20.000
+G1 X3860.000 Y15.000
+G1 X3858.000 Y10.000
+G1 X3857.000 Y7.000
+G1 X3853.000 Y4.000
+G1 X3850.000 Y2.000
+G1 X3846.000 Y1.000
+G1 X3840.000 Y0.000
+M5
+G0 X3732.000 Y4488.000
+M3 S1000
+G1 X3728.000 Y4482.000 F1000
+G1 X3722.000 Y4477.000
+G1 X3714.000 Y4474.000
+G1 X3706.000 Y4471.000
+G1 X3694.000 Y4471.000
+G1 X3659.000 Y4473.000
+G1 X3635.000 Y4475.000
+G1 X3627.000 Y4475.000
+G1 X3619.000 Y4473.000
+G1 X3613.000 Y4470.000
+G1 X3609.000 Y4466.000
+G1 X3605.000 Y4461.000
+G1 X3602.000 Y4454.000
+G1 X3597.000 Y4426.000
+G1 X3584.000 Y4316.000
+M5
+G0 X401.000 Y5551.000
+M3 S1000
+G1 X420.000 Y5743.000 F1000
+G1 X423.000 Y5752.000
+G1 X428.000 Y5759.000
+G1 X436.000 Y5761.000
+G1 X506.000 Y5756.000
+G1 X639.000 Y5746.000
+G1 X1059.000 Y5719.000
+G1 X1316.000 Y5699.000
+G1 X1349.000 Y5697.000
+G1 X1360.000 Y5696.000
+G1 X1375.000 Y5696.000
+G1 X1383.000 Y5697.000
+G1 X1391.000 Y5701.000
+G1 X1397.000 Y5705.000
+G1 X1406.000 Y5710.000
+G1 X1414.000 Y5715.000
+G1 X1422.000 Y5718.000
+G1 X1429.000 Y5720.000
+G1 X1439.000 Y5720.000
+G1 X1460.000 Y5718.000
+G1 X1478.000 Y5717.000
+M5
+G0 X2615.000 Y5573.000
+M3 S1000
+G1 X2633.000 Y5572.000 F1000
+G1 X2650.000 Y5575.000
+G1 X2694.000 Y5571.000
+G1 X2904.000 Y5558.000
+G1 X3047.000 Y5551.000
+G1 X3059.000 Y5553.000
+G1 X3153.000 Y5548.000
+M5
+G0 X3772.000 Y4539.000
+M3 S1000
+G1 X3744.000 Y4524.000 F1000
+M5
+G0 X4285.000 Y5870.000
+M3 S1000
+G1 X4279.000 Y5852.000 F1000
+G1 X4264.000 Y5806.000
+G1 X4262.000 Y5792.000
+G1 X4253.000 Y5724.000
+G1 X4247.000 Y5694.000
+M5
+G0 X4236.000 Y5681.000
+M3 S1000
+G1 X4226.000 Y5670.000 F1000
+M5
+G0 X4247.000 Y5694.000
+M3 S1000
+G1 X4236.000 Y5681.000 F1000
+M5
+G0 X629.000 Y8956.000
+M3 S1000
+G1 X664.000 Y8867.000 F1000
+G1 X692.000 Y8795.000
+G1 X739.000 Y8678.000
+G1 X768.000 Y8604.000
+G1 X774.000 Y8590.000
+G1 X799.000 Y8529.000
+G1 X820.000 Y8471.000
+G1 X838.000 Y8421.000
+G1 X849.000 Y8382.000
+G1 X872.000 Y8308.000
+G1 X894.000 Y8227.000
+G1 X933.000 Y8072.000
+G1 X948.000 Y7999.000
+G1 X949.000 Y7979.000
+G1 X946.000 Y7962.000
+G1 X939.000 Y7953.000
+G1 X926.000 Y7942.000
+G1 X873.000 Y7928.000
+G1 X860.000 Y7922.000
+G1 X848.000 Y7912.000
+G1 X840.000 Y7898.000
+G1 X833.000 Y7882.000
+G1 X828.000 Y7864.000
+G1 X825.000 Y7846.000
+G1 X825.000 Y7823.000
+G1 X827.000 Y7803.000
+G1 X832.000 Y7786.000
+G1 X843.000 Y7769.000
+G1 X855.000 Y7752.000
+G1 X870.000 Y7738.000
+G1 X887.000 Y7724.000
+G1 X921.000 Y7700.000
+G1 X959.000 Y7678.000
+G1 X985.000 Y7667.000
+G1 X1024.000 Y7654.000
+G1 X1033.000 Y7643.000
+G1 X1042.000 Y7628.000
+G1 X1065.000 Y7551.000
+G1 X1069.000 Y7538.000
+G1 X1098.000 Y7433.000
+G1 X1110.000 Y7387.000
+G1 X1135.000 Y7284.000
+G1 X1163.000 Y7167.000
+G1 X1181.000 Y7096.000
+G1 X1183.000 Y7084.000
+G1 X1215.000 Y6930.000
+G1 X1245.000 Y6772.000
+G1 X1296.000 Y6542.000
+G1 X1297.000 Y6535.000
+G1 X1326.000 Y6416.000
+G1 X1425.000 Y6140.000
+G1 X1446.000 Y6071.000
+G1 X1458.000 Y6034.000
+G1 X1468.000 Y5997.000
+G1 X1471.000 Y5965.000
+G1 X1477.000 Y5769.000
+G1 X1478.000 Y5717.000
+G1 X1482.000 Y5669.000
+M5
+G0 X3302.000 Y5537.000
+M3 S1000
+G1 X3449.000 Y5530.000 F1000
+G1 X3566.000 Y5524.000
+G1 X3570.000 Y5524.000
+G1 X3670.000 Y5518.000
+G1 X3733.000 Y5514.000
+M5
+G0 X4226.000 Y5670.000
+M3 S1000
+G1 X4218.000 Y5663.000 F1000
+M5
+G0 X1482.000 Y5669.000
+M3 S1000
+G1 X1500.000 Y5669.000 F1000
+G1 X2027.000 Y5631.000
+G1 X2181.000 Y5623.000
+G1 X2232.000 Y5618.000
+G1 X2277.000 Y5609.000
+G1 X2290.000 Y5607.000
+G1 X2318.000 Y5603.000
+G1 X2385.000 Y5596.000
+G1 X2403.000 Y5594.000
+G1 X2444.000 Y5590.000
+M5
+G0 X1482.000 Y5669.000
+M3 S1000
+G1 X1484.000 Y5626.000 F1000
+G1 X1489.000 Y5410.000
+G1 X1490.000 Y5318.000
+G1 X1487.000 Y5280.000
+G1 X1487.000 Y5257.000
+G1 X1486.000 Y5240.000
+G1 X1486.000 Y5224.000
+G1 X1485.000 Y5209.000
+G1 X1483.000 Y5198.000
+M5
+G0 X4020.000 Y5703.000
+M3 S1000
+G1 X4076.000 Y5694.000 F1000
+G1 X4174.000 Y5670.000
+G1 X4204.000 Y5662.000
+G1 X4218.000 Y5663.000
+M5
+G0 X4570.000 Y4214.000
+M3 S1000
+G1 X4554.000 Y4147.000 F1000
+G1 X4525.000 Y4023.000
+G1 X4515.000 Y3979.000
+G1 X4473.000 Y3799.000
+G1 X4444.000 Y3650.000
+G1 X4441.000 Y3619.000
+G1 X4442.000 Y3591.000
+G1 X4441.000 Y3557.000
+G1 X4429.000 Y3481.000
+G1 X4422.000 Y3446.000
+G1 X4420.000 Y3424.000
+G1 X4422.000 Y3403.000
+G1 X4431.000 Y3385.000
+M5
+G0 X4229.000 Y4529.000
+M3 S1000
+G1 X4234.000 Y4519.000 F1000
+G1 X4238.000 Y4509.000
+G1 X4241.000 Y4499.000
+G1 X4242.000 Y4490.000
+G1 X4242.000 Y4482.000
+G1 X4241.000 Y4474.000
+M5
+G0 X4043.000 Y3410.000
+M3 S1000
+G1 X4029.000 Y3303.000 F1000
+G1 X4026.000 Y3276.000
+G1 X4021.000 Y3238.000
+G1 X4012.000 Y3191.000
+G1 X4010.000 Y3182.000
+M5
+G0 X4212.000 Y4331.000
+M3 S1000
+G1 X4180.000 Y4104.000 F1000
+G1 X4152.000 Y3957.000
+G1 X4150.000 Y3950.000
+G1 X4111.000 Y3744.000
+G1 X4078.000 Y3572.000
+G1 X4070.000 Y3536.000
+G1 X4043.000 Y3410.000
+M5
+G0 X3733.000 Y5514.000
+M3 S1000
+G1 X3728.000 Y5461.000 F1000
+G1 X3722.000 Y5406.000
+G1 X3712.000 Y5324.000
+G1 X3702.000 Y5249.000
+G1 X3693.000 Y5191.000
+G1 X3685.000 Y5131.000
+G1 X3688.000 Y5106.000
+G1 X3693.000 Y5086.000
+M5
+G0 X3755.000 Y5737.000
+M3 S1000
+G1 X3733.000 Y5514.000 F1000
+M5
+G0 X3693.000 Y5086.000
+M3 S1000
+G1 X3704.000 Y5063.000 F1000
+G1 X3716.000 Y5046.000
+G1 X3753.000 Y5012.000
+G1 X3913.000 Y4869.000
+G1 X4024.000 Y4773.000
+G1 X4073.000 Y4723.000
+G1 X4094.000 Y4699.000
+G1 X4195.000 Y4564.000
+G1 X4229.000 Y4529.000
+M5
+G0 X3704.000 Y5759.000
+M3 S1000
+G1 X3717.000 Y5756.000 F1000
+G1 X3729.000 Y5754.000
+M5
+G0 X3755.000 Y5750.000
+M3 S1000
+G1 X3755.000 Y5737.000 F1000
+M5
+G0 X3781.000 Y5789.000
+M3 S1000
+G1 X3770.000 Y5777.000 F1000
+M5
+G0 X3261.000 Y6611.000
+M3 S1000
+G1 X3339.000 Y6612.000 F1000
+G1 X3375.000 Y6612.000
+G1 X3423.000 Y6613.000
+M5
+G0 X3885.000 Y6636.000
+M3 S1000
+G1 X3989.000 Y6626.000 F1000
+G1 X4017.000 Y6623.000
+G1 X4025.000 Y6615.000
+G1 X4028.000 Y6606.000
+G1 X4019.000 Y6532.000
+G1 X4014.000 Y6524.000
+G1 X3987.000 Y6510.000
+G1 X3980.000 Y6506.000
+G1 X3976.000 Y6499.000
+G1 X3968.000 Y6429.000
+M5
+G0 X4269.000 Y6880.000
+M3 S1000
+G1 X4259.000 Y6779.000 F1000
+G1 X4240.000 Y6721.000
+M5
+G0 X3260.000 Y6635.000
+M3 S1000
+G1 X3185.000 Y6633.000 F1000
+M5
+G0 X3859.000 Y6439.000
+M3 S1000
+G1 X3968.000 Y6429.000 F1000
+G1 X4016.000 Y6423.000
+M5
+G0 X4375.000 Y6767.000
+M3 S1000
+G1 X4369.000 Y6701.000 F1000
+M5
+G0 X4470.000 Y6728.000
+M3 S1000
+G1 X4469.000 Y6717.000 F1000
+G1 X4472.000 Y6697.000
+G1 X4491.000 Y6655.000
+G1 X4493.000 Y6648.000
+G1 X4495.000 Y6642.000
+G1 X4496.000 Y6634.000
+G1 X4495.000 Y6627.000
+G1 X4493.000 Y6595.000
+G1 X4490.000 Y6571.000
+G1 X4481.000 Y6492.000
+G1 X4478.000 Y6458.000
+G1 X4473.000 Y6414.000
+G1 X4471.000 Y6397.000
+G1 X4470.000 Y6387.000
+G1 X4462.000 Y6328.000
+G1 X4455.000 Y6299.000
+G1 X4452.000 Y6292.000
+G1 X4433.000 Y6237.000
+G1 X4413.000 Y6180.000
+M5
+G0 X4265.000 Y6717.000
+M3 S1000
+G1 X4240.000 Y6721.000 F1000
+G1 X4136.000 Y6731.000
+G1 X4097.000 Y6735.000
+G1 X4007.000 Y6724.000
+G1 X3999.000 Y6716.000
+G1 X3989.000 Y6626.000
+M5
+G0 X3185.000 Y6633.000
+M3 S1000
+G1 X2921.000 Y6621.000 F1000
+M5
+G0 X4555.000 Y6480.000
+M3 S1000
+G1 X4544.000 Y6377.000 F1000
+M5
+G0 X4143.000 Y6796.000
+M3 S1000
+G1 X4136.000 Y6731.000 F1000
+M5
+G0 X4376.000 Y6114.000
+M3 S1000
+G1 X4337.000 Y6055.000 F1000
+G1 X4320.000 Y6030.000
+G1 X4300.000 Y5957.000
+M5
+G0 X4259.000 Y6779.000
+M3 S1000
+G1 X4375.000 Y6767.000 F1000
+G1 X4473.000 Y6757.000
+G1 X4490.000 Y6755.000
+G1 X4577.000 Y6745.000
+G1 X4693.000 Y6732.000
+M5
+G0 X3828.000 Y6232.000
+M3 S1000
+G1 X3963.000 Y6211.000 F1000
+G1 X4054.000 Y6196.000
+G1 X4160.000 Y6189.000
+G1 X4179.000 Y6187.000
+G1 X4330.000 Y6173.000
+G1 X4339.000 Y6171.000
+G1 X4347.000 Y6167.000
+G1 X4357.000 Y6162.000
+G1 X4392.000 Y6139.000
+M5
+G0 X4577.000 Y6745.000
+M3 S1000
+G1 X4561.000 Y6588.000 F1000
+M5
+G0 X3416.000 Y6803.000
+M3 S1000
+G1 X3421.000 Y6785.000 F1000
+G1 X3420.000 Y6770.000
+G1 X3423.000 Y6613.000
+G1 X3425.000 Y6603.000
+G1 X3432.000 Y6592.000
+G1 X3441.000 Y6584.000
+G1 X3452.000 Y6580.000
+G1 X3474.000 Y6579.000
+G1 X3638.000 Y6587.000
+M5
+G0 X3605.000 Y6793.000
+M3 S1000
+G1 X3493.000 Y6789.000 F1000
+M5
+G0 X3605.000 Y6793.000
+M3 S1000
+G1 X3622.000 Y6787.000 F1000
+G1 X3630.000 Y6777.000
+G1 X3633.000 Y6762.000
+G1 X3638.000 Y6587.000
+G1 X3641.000 Y6491.000
+G1 X3654.000 Y6475.000
+G1 X3727.000 Y6452.000
+G1 X3735.000 Y6441.000
+G1 X3719.000 Y6305.000
+M5
+G0 X3719.000 Y6305.000
+M3 S1000
+G1 X3547.000 Y6308.000 F1000
+G1 X3531.000 Y6308.000
+G1 X3480.000 Y6309.000
+G1 X3453.000 Y6305.000
+G1 X3429.000 Y6295.000
+G1 X3409.000 Y6280.000
+G1 X3382.000 Y6254.000
+G1 X3376.000 Y6248.000
+G1 X3373.000 Y6245.000
+G1 X3368.000 Y6244.000
+M5
+G0 X4321.000 Y9755.000
+M3 S1000
+G1 X4226.000 Y9749.000 F1000
+G1 X4214.000 Y9740.000
+M5
+G0 X985.000 Y7667.000
+M3 S1000
+G1 X969.000 Y7656.000 F1000
+G1 X883.000 Y7646.000
+G1 X458.000 Y7596.000
+M5
+G0 X3251.000 Y7053.000
+M3 S1000
+G1 X3249.000 Y7047.000 F1000
+G1 X3248.000 Y7045.000
+G1 X3247.000 Y7042.000
+G1 X3246.000 Y7039.000
+G1 X3246.000 Y7036.000
+G1 X3252.000 Y6853.000
+G1 X3253.000 Y6806.000
+G1 X3253.000 Y6806.000
+G1 X3260.000 Y6635.000
+G1 X3261.000 Y6611.000
+G1 X3267.000 Y6486.000
+G1 X3269.000 Y6435.000
+G1 X3271.000 Y6405.000
+G1 X3274.000 Y6381.000
+G1 X3282.000 Y6355.000
+G1 X3293.000 Y6330.000
+G1 X3322.000 Y6289.000
+G1 X3352.000 Y6253.000
+G1 X3359.000 Y6247.000
+G1 X3364.000 Y6244.000
+G1 X3368.000 Y6244.000
+M5
+G0 X3198.000 Y9199.000
+M3 S1000
+G1 X3201.000 Y9178.000 F1000
+G1 X3206.000 Y9148.000
+G1 X3218.000 Y9068.000
+G1 X3226.000 Y8996.000
+G1 X3228.000 Y8975.000
+G1 X3229.000 Y8955.000
+G1 X3228.000 Y8936.000
+G1 X3227.000 Y8924.000
+G1 X3225.000 Y8914.000
+G1 X3219.000 Y8894.000
+G1 X3208.000 Y8858.000
+G1 X3200.000 Y8830.000
+G1 X3197.000 Y8813.000
+G1 X3195.000 Y8796.000
+G1 X3194.000 Y8776.000
+G1 X3194.000 Y8761.000
+G1 X3195.000 Y8720.000
+G1 X3197.000 Y8667.000
+G1 X3197.000 Y8628.000
+G1 X3196.000 Y8582.000
+G1 X3195.000 Y8554.000
+G1 X3194.000 Y8539.000
+G1 X3191.000 Y8513.000
+G1 X3188.000 Y8486.000
+G1 X3188.000 Y8467.000
+G1 X3188.000 Y8450.000
+G1 X3192.000 Y8394.000
+G1 X3197.000 Y8339.000
+G1 X3207.000 Y8197.000
+G1 X3212.000 Y8118.000
+G1 X3212.000 Y8048.000
+G1 X3213.000 Y7979.000
+G1 X3220.000 Y7798.000
+G1 X3227.000 Y7669.000
+G1 X3229.000 Y7623.000
+G1 X3231.000 Y7557.000
+G1 X3233.000 Y7425.000
+G1 X3235.000 Y7348.000
+M5
+G0 X4194.000 Y9763.000
+M3 S1000
+G1 X4210.000 Y9746.000 F1000
+G1 X4214.000 Y9740.000
+M5
+G0 X4182.000 Y9772.000
+M3 S1000
+G1 X4194.000 Y9763.000 F1000
+M5
+G0 X233.000 Y2731.000
+M3 S1000
+G1 X219.000 Y2771.000 F1000
+G1 X184.000 Y2879.000
+G1 X104.000 Y3124.000
+G1 X92.000 Y3156.000
+G1 X89.000 Y3166.000
+G1 X84.000 Y3189.000
+G1 X81.000 Y3197.000
+G1 X63.000 Y3227.000
+G1 X60.000 Y3234.000
+G1 X58.000 Y3242.000
+G1 X47.000 Y3356.000
+G1 X43.000 Y3391.000
+G1 X39.000 Y3419.000
+G1 X36.000 Y3450.000
+G1 X32.000 Y3518.000
+G1 X31.000 Y3534.000
+G1 X22.000 Y3594.000
+G1 X23.000 Y3599.000
+G1 X26.000 Y3602.000
+G1 X30.000 Y3604.000
+G1 X35.000 Y3604.000
+G1 X43.000 Y3603.000
+G1 X282.000 Y3564.000
+G1 X620.000 Y3506.000
+G1 X640.000 Y3506.000
+G1 X869.000 Y3469.000
+G1 X1103.000 Y3431.000
+G1 X1239.000 Y3408.000
+G1 X1402.000 Y3380.000
+G1 X1547.000 Y3356.000
+G1 X1685.000 Y3331.000
+M5
+G0 X4536.000 Y6966.000
+M3 S1000
+G1 X4542.000 Y6980.000 F1000
+G1 X4544.000 Y6988.000
+G1 X4549.000 Y7027.000
+G1 X4557.000 Y7082.000
+M5
+G0 X3931.000 Y7162.000
+M3 S1000
+G1 X3952.000 Y7324.000 F1000
+G1 X3995.000 Y7705.000
+G1 X4035.000 Y8079.000
+G1 X4050.000 Y8163.000
+G1 X4070.000 Y8317.000
+G1 X4073.000 Y8364.000
+G1 X4076.000 Y8408.000
+G1 X4091.000 Y8599.000
+G1 X4103.000 Y8726.000
+G1 X4139.000 Y9008.000
+G1 X4149.000 Y9098.000
+G1 X4173.000 Y9382.000
+G1 X4207.000 Y9709.000
+G1 X4205.000 Y9732.000
+M5
+G0 X4205.000 Y9732.000
+M3 S1000
+G1 X4203.000 Y9734.000 F1000
+G1 X4201.000 Y9737.000
+G1 X4199.000 Y9739.000
+G1 X4197.000 Y9738.000
+M5
+G0 X4553.000 Y7087.000
+M3 S1000
+G1 X4536.000 Y6966.000 F1000
+G1 X4530.000 Y6955.000
+G1 X4527.000 Y6952.000
+G1 X4518.000 Y6944.000
+G1 X4505.000 Y6935.000
+G1 X4496.000 Y6929.000
+G1 X4490.000 Y6921.000
+G1 X4487.000 Y6907.000
+G1 X4487.000 Y6905.000
+G1 X4487.000 Y6901.000
+G1 X4486.000 Y6897.000
+G1 X4485.000 Y6877.000
+M5
+G0 X4305.000 Y10984.000
+M3 S1000
+G1 X4308.000 Y10978.000 F1000
+G1 X4312.000 Y10977.000
+G1 X4336.000 Y10978.000
+G1 X4343.000 Y10976.000
+G1 X4347.000 Y10970.000
+G1 X4350.000 Y10962.000
+G1 X4418.000 Y10664.000
+G1 X4420.000 Y10650.000
+G1 X4419.000 Y10637.000
+G1 X4338.000 Y9944.000
+G1 X4320.000 Y9869.000
+G1 X4320.000 Y9845.000
+G1 X4321.000 Y9763.000
+G1 X4321.000 Y9755.000
+M5
+G0 X4282.000 Y11040.000
+M3 S1000
+G1 X4305.000 Y10984.000 F1000
+M5
+G0 X9088.000 Y5638.000
+M3 S1000
+G1 X9186.000 Y5665.000 F1000
+G1 X9199.000 Y5669.000
+G1 X9226.000 Y5680.000
+G1 X9245.000 Y5686.000
+G1 X9296.000 Y5700.000
+G1 X9319.000 Y5706.000
+G1 X9346.000 Y5712.000
+G1 X9383.000 Y5721.000
+M5
+G0 X5371.000 Y5682.000
+M3 S1000
+G1 X5376.000 Y5680.000 F1000
+G1 X5379.000 Y5678.000
+G1 X5382.000 Y5677.000
+G1 X5384.000 Y5673.000
+G1 X5387.000 Y5669.000
+G1 X5392.000 Y5662.000
+G1 X5399.000 Y5656.000
+G1 X5408.000 Y5647.000
+M5
+G0 X4647.000 Y7019.000
+M3 S1000
+G1 X4705.000 Y7071.000 F1000
+G1 X4715.000 Y7079.000
+G1 X4728.000 Y7081.000
+G1 X4740.000 Y7079.000
+G1 X4970.000 Y7025.000
+G1 X4990.000 Y7019.000
+G1 X5027.000 Y6999.000
+G1 X5041.000 Y6993.000
+G1 X5057.000 Y6988.000
+G1 X5135.000 Y6962.000
+G1 X5148.000 Y6954.000
+G1 X5160.000 Y6938.000
+G1 X5163.000 Y6923.000
+G1 X5164.000 Y6915.000
+G1 X5161.000 Y6881.000
+G1 X5138.000 Y6762.000
+M5
+G0 X5180.000 Y6599.000
+M3 S1000
+G1 X5185.000 Y6614.000 F1000
+G1 X5194.000 Y6646.000
+G1 X5205.000 Y6686.000
+G1 X5220.000 Y6755.000
+G1 X5221.000 Y6764.000
+G1 X5217.000 Y6774.000
+G1 X5204.000 Y6802.000
+G1 X5208.000 Y6809.000
+G1 X5232.000 Y6849.000
+G1 X5239.000 Y6864.000
+G1 X5247.000 Y6885.000
+G1 X5268.000 Y6978.000
+G1 X5268.000 Y6991.000
+G1 X5265.000 Y7001.000
+G1 X5249.000 Y7024.000
+G1 X5218.000 Y7063.000
+G1 X5208.000 Y7074.000
+G1 X5205.000 Y7077.000
+G1 X5192.000 Y7080.000
+G1 X5142.000 Y7087.000
+G1 X5005.000 Y7108.000
+G1 X4970.000 Y7112.000
+G1 X4954.000 Y7113.000
+G1 X4947.000 Y7110.000
+G1 X4940.000 Y7106.000
+G1 X4936.000 Y7103.000
+G1 X4929.000 Y7102.000
+G1 X4755.000 Y7128.000
+G1 X4740.000 Y7125.000
+G1 X4729.000 Y7125.000
+G1 X4719.000 Y7122.000
+G1 X4688.000 Y7115.000
+G1 X4679.000 Y7114.000
+G1 X4671.000 Y7115.000
+G1 X4659.000 Y7118.000
+G1 X4643.000 Y7123.000
+G1 X4621.000 Y7132.000
+G1 X4597.000 Y7142.000
+G1 X4581.000 Y7158.000
+G1 X4570.000 Y7163.000
+M5
+G0 X3643.000 Y6958.000
+M3 S1000
+G1 X3643.000 Y6963.000 F1000
+G1 X3643.000 Y6969.000
+G1 X3643.000 Y6974.000
+G1 X3643.000 Y6980.000
+G1 X3643.000 Y6989.000
+G1 X3641.000 Y7082.000
+G1 X3641.000 Y7085.000
+G1 X3640.000 Y7086.000
+G1 X3639.000 Y7087.000
+G1 X3637.000 Y7087.000
+G1 X3630.000 Y7087.000
+G1 X3625.000 Y7087.000
+G1 X3620.000 Y7087.000
+G1 X3415.000 Y7077.000
+G1 X3410.000 Y7077.000
+G1 X3405.000 Y7077.000
+G1 X3258.000 Y7069.000
+G1 X3244.000 Y7069.000
+M5
+G0 X3920.000 Y6949.000
+M3 S1000
+G1 X3916.000 Y6955.000 F1000
+G1 X3910.000 Y6963.000
+G1 X3909.000 Y6965.000
+G1 X3906.000 Y6965.000
+M5
+G0 X3251.000 Y7053.000
+M3 S1000
+G1 X3258.000 Y7054.000 F1000
+G1 X3393.000 Y7060.000
+G1 X3411.000 Y7061.000
+G1 X3429.000 Y7061.000
+G1 X3608.000 Y7070.000
+G1 X3626.000 Y7071.000
+M5
+G0 X3432.000 Y6592.000
+M3 S1000
+G1 X3428.000 Y6572.000 F1000
+G1 X3428.000 Y6551.000
+G1 X3430.000 Y6510.000
+M5
+G0 X3249.000 Y7297.000
+M3 S1000
+G1 X3388.000 Y7305.000 F1000
+G1 X3404.000 Y7306.000
+G1 X3404.000 Y7289.000
+G1 X3406.000 Y7247.000
+G1 X3406.000 Y7245.000
+G1 X3406.000 Y7232.000
+G1 X3421.000 Y7233.000
+G1 X3607.000 Y7242.000
+G1 X3622.000 Y7243.000
+M5
+G0 X3770.000 Y5777.000
+M3 S1000
+G1 X3760.000 Y5768.000 F1000
+G1 X3757.000 Y5758.000
+G1 X3755.000 Y5750.000
+M5
+G0 X3767.000 Y6846.000
+M3 S1000
+G1 X3764.000 Y6959.000 F1000
+G1 X3764.000 Y6961.000
+G1 X3764.000 Y6977.000
+G1 X3764.000 Y6993.000
+G1 X3760.000 Y7165.000
+G1 X3760.000 Y7176.000
+G1 X3755.000 Y7184.000
+G1 X3752.000 Y7189.000
+G1 X3752.000 Y7192.000
+G1 X3751.000 Y7196.000
+G1 X3751.000 Y7200.000
+G1 X3751.000 Y7206.000
+G1 X3752.000 Y7212.000
+G1 X3756.000 Y7252.000
+G1 X3762.000 Y7313.000
+G1 X3763.000 Y7316.000
+G1 X3763.000 Y7319.000
+G1 X3765.000 Y7322.000
+G1 X3767.000 Y7326.000
+G1 X3769.000 Y7328.000
+G1 X3772.000 Y7331.000
+G1 X3775.000 Y7332.000
+G1 X3780.000 Y7333.000
+G1 X3785.000 Y7334.000
+G1 X3837.000 Y7331.000
+M5
+G0 X3643.000 Y6958.000
+M3 S1000
+G1 X3644.000 Y6952.000 F1000
+G1 X3645.000 Y6887.000
+G1 X3635.000 Y6853.000
+G1 X3630.000 Y6836.000
+M5
+G0 X3631.000 Y6808.000
+M3 S1000
+G1 X3643.000 Y6809.000 F1000
+G1 X3775.000 Y6810.000
+G1 X3787.000 Y6810.000
+G1 X3788.000 Y6797.000
+G1 X3787.000 Y6722.000
+M5
+G0 X3787.000 Y6722.000
+M3 S1000
+G1 X3787.000 Y6715.000 F1000
+G1 X3788.000 Y6713.000
+G1 X3790.000 Y6710.000
+G1 X3793.000 Y6709.000
+G1 X3831.000 Y6710.000
+G1 X3834.000 Y6708.000
+G1 X3836.000 Y6706.000
+G1 X3838.000 Y6701.000
+G1 X3838.000 Y6691.000
+G1 X3831.000 Y6659.000
+G1 X3826.000 Y6618.000
+G1 X3821.000 Y6595.000
+G1 X3821.000 Y6586.000
+G1 X3823.000 Y6579.000
+G1 X3821.000 Y6562.000
+G1 X3819.000 Y6552.000
+G1 X3820.000 Y6539.000
+G1 X3819.000 Y6527.000
+G1 X3816.000 Y6513.000
+G1 X3811.000 Y6501.000
+G1 X3807.000 Y6465.000
+G1 X3807.000 Y6433.000
+G1 X3810.000 Y6413.000
+G1 X3795.000 Y6317.000
+G1 X3791.000 Y6294.000
+G1 X3788.000 Y6271.000
+G1 X3783.000 Y6223.000
+G1 X3770.000 Y6095.000
+M5
+G0 X4027.000 Y6905.000
+M3 S1000
+G1 X4028.000 Y6914.000 F1000
+G1 X4033.000 Y6926.000
+G1 X4039.000 Y6936.000
+G1 X4046.000 Y6949.000
+M5
+G0 X3940.000 Y6968.000
+M3 S1000
+G1 X3934.000 Y6969.000 F1000
+G1 X3926.000 Y6969.000
+G1 X3907.000 Y6971.000
+M5
+G0 X3873.000 Y6976.000
+M3 S1000
+G1 X3847.000 Y6978.000 F1000
+G1 X3830.000 Y6979.000
+G1 X3782.000 Y6978.000
+G1 X3764.000 Y6977.000
+G1 X3748.000 Y6978.000
+G1 X3647.000 Y6974.000
+G1 X3643.000 Y6974.000
+G1 X3628.000 Y6973.000
+M5
+G0 X3872.000 Y6969.000
+M3 S1000
+G1 X3865.000 Y6970.000 F1000
+G1 X3846.000 Y6963.000
+G1 X3764.000 Y6961.000
+G1 X3649.000 Y6958.000
+G1 X3648.000 Y6958.000
+G1 X3643.000 Y6958.000
+M5
+G0 X3906.000 Y6965.000
+M3 S1000
+G1 X3872.000 Y6969.000 F1000
+M5
+G0 X3951.000 Y6986.000
+M3 S1000
+G1 X3954.000 Y7005.000 F1000
+G1 X3951.000 Y7031.000
+G1 X3938.000 Y7091.000
+G1 X3936.000 Y7118.000
+G1 X3939.000 Y7129.000
+M5
+G0 X3939.000 Y7129.000
+M3 S1000
+G1 X3944.000 Y7120.000 F1000
+G1 X3949.000 Y7094.000
+G1 X3965.000 Y7006.000
+G1 X3969.000 Y6996.000
+G1 X3973.000 Y6989.000
+M5
+G0 X3907.000 Y6971.000
+M3 S1000
+G1 X3873.000 Y6976.000 F1000
+M5
+G0 X3621.000 Y7324.000
+M3 S1000
+G1 X3622.000 Y7258.000 F1000
+G1 X3622.000 Y7243.000
+G1 X3623.000 Y7226.000
+G1 X3625.000 Y7095.000
+G1 X3625.000 Y7091.000
+G1 X3625.000 Y7087.000
+G1 X3626.000 Y7084.000
+G1 X3626.000 Y7071.000
+G1 X3626.000 Y7054.000
+G1 X3627.000 Y6991.000
+G1 X3628.000 Y6973.000
+G1 X3628.000 Y6938.000
+G1 X3630.000 Y6836.000
+M5
+G0 X4197.000 Y9738.000
+M3 S1000
+G1 X4180.000 Y9739.000 F1000
+M5
+G0 X4353.000 Y5016.000
+M3 S1000
+G1 X4357.000 Y5015.000 F1000
+G1 X4365.000 Y5014.000
+M5
+G0 X4391.000 Y5009.000
+M3 S1000
+G1 X4402.000 Y5007.000 F1000
+G1 X4405.000 Y5009.000
+G1 X4412.000 Y5018.000
+G1 X4461.000 Y5036.000
+G1 X4468.000 Y5037.000
+G1 X4474.000 Y5036.000
+G1 X4701.000 Y4992.000
+G1 X4708.000 Y4991.000
+M5
+G0 X4365.000 Y5014.000
+M3 S1000
+G1 X4374.000 Y5012.000 F1000
+M5
+G0 X4710.000 Y4944.000
+M3 S1000
+G1 X4725.000 Y4944.000 F1000
+G1 X4739.000 Y4945.000
+G1 X4754.000 Y4944.000
+G1 X4772.000 Y4943.000
+G1 X4796.000 Y4941.000
+G1 X4830.000 Y4937.000
+G1 X4851.000 Y4936.000
+G1 X4867.000 Y4935.000
+G1 X4874.000 Y4935.000
+G1 X4874.000 Y4935.000
+G1 X4881.000 Y4936.000
+G1 X4888.000 Y4938.000
+M5
+G0 X5996.000 Y4158.000
+M3 S1000
+G1 X5995.000 Y4156.000 F1000
+G1 X5995.000 Y4156.000
+G1 X5996.000 Y4154.000
+G1 X5997.000 Y4153.000
+G1 X6008.000 Y4149.000
+G1 X6009.000 Y4148.000
+G1 X6011.000 Y4147.000
+G1 X6012.000 Y4146.000
+G1 X6012.000 Y4144.000
+G1 X6017.000 Y4122.000
+G1 X6021.000 Y4116.000
+G1 X6023.000 Y4112.000
+G1 X6031.000 Y4106.000
+G1 X6040.000 Y4100.000
+G1 X6045.000 Y4099.000
+G1 X6059.000 Y4096.000
+G1 X6065.000 Y4096.000
+M5
+G0 X4427.000 Y5557.000
+M3 S1000
+G1 X4438.000 Y5637.000 F1000
+G1 X4440.000 Y5642.000
+G1 X4441.000 Y5644.000
+G1 X4566.000 Y5636.000
+G1 X4560.000 Y5538.000
+G1 X4481.000 Y5544.000
+G1 X4472.000 Y5545.000
+M5
+G0 X4560.000 Y5538.000
+M3 S1000
+G1 X4593.000 Y5537.000 F1000
+M5
+G0 X4566.000 Y5636.000
+M3 S1000
+G1 X4569.000 Y5641.000 F1000
+G1 X4574.000 Y5655.000
+M5
+G0 X4544.000 Y6377.000
+M3 S1000
+G1 X4507.000 Y6382.000 F1000
+G1 X4498.000 Y6277.000
+G1 X4529.000 Y6274.000
+M5
+G0 X4510.000 Y6486.000
+M3 S1000
+G1 X4504.000 Y6489.000 F1000
+G1 X4481.000 Y6492.000
+M5
+G0 X4514.000 Y6592.000
+M3 S1000
+G1 X4512.000 Y6593.000 F1000
+G1 X4493.000 Y6595.000
+M5
+G0 X4596.000 Y6970.000
+M3 S1000
+G1 X4588.000 Y6966.000 F1000
+G1 X4578.000 Y6964.000
+G1 X4564.000 Y6966.000
+G1 X4551.000 Y6965.000
+G1 X4536.000 Y6966.000
+G1 X4518.000 Y6968.000
+G1 X4506.000 Y6975.000
+G1 X4454.000 Y6983.000
+G1 X4365.000 Y7003.000
+G1 X4217.000 Y7023.000
+M5
+G0 X4211.000 Y6944.000
+M3 S1000
+G1 X4218.000 Y7003.000 F1000
+G1 X4219.000 Y7009.000
+G1 X4218.000 Y7013.000
+G1 X4218.000 Y7019.000
+G1 X4217.000 Y7023.000
+M5
+G0 X4207.000 Y5898.000
+M3 S1000
+G1 X4219.000 Y5953.000 F1000
+M5
+G0 X4370.000 Y5780.000
+M3 S1000
+G1 X4368.000 Y5755.000 F1000
+G1 X4436.000 Y5748.000
+G1 X4475.000 Y5745.000
+G1 X4479.000 Y5769.000
+M5
+G0 X4428.000 Y5664.000
+M3 S1000
+G1 X4429.000 Y5672.000 F1000
+G1 X4434.000 Y5733.000
+G1 X4436.000 Y5748.000
+G1 X4437.000 Y5764.000
+G1 X4438.000 Y5773.000
+M5
+G0 X4647.000 Y7019.000
+M3 S1000
+G1 X4625.000 Y7031.000 F1000
+G1 X4602.000 Y7045.000
+G1 X4573.000 Y7067.000
+G1 X4557.000 Y7082.000
+M5
+G0 X4309.000 Y5786.000
+M3 S1000
+G1 X4336.000 Y5783.000 F1000
+G1 X4370.000 Y5780.000
+G1 X4380.000 Y5779.000
+G1 X4438.000 Y5773.000
+G1 X4479.000 Y5769.000
+G1 X4541.000 Y5760.000
+M5
+G0 X4211.000 Y6944.000
+M3 S1000
+G1 X4226.000 Y6943.000 F1000
+G1 X4233.000 Y6942.000
+G1 X4276.000 Y6937.000
+G1 X4274.000 Y6923.000
+M5
+G0 X4541.000 Y5760.000
+M3 S1000
+G1 X4542.000 Y5772.000 F1000
+G1 X4545.000 Y5797.000
+G1 X4548.000 Y5819.000
+G1 X4550.000 Y5843.000
+M5
+G0 X4339.000 Y5619.000
+M3 S1000
+G1 X4338.000 Y5594.000 F1000
+G1 X4335.000 Y5570.000
+M5
+G0 X5015.000 Y5839.000
+M3 S1000
+G1 X5009.000 Y5851.000 F1000
+G1 X5006.000 Y5859.000
+G1 X5009.000 Y5892.000
+G1 X5016.000 Y5912.000
+G1 X5021.000 Y5922.000
+G1 X5028.000 Y5939.000
+G1 X5030.000 Y5942.000
+G1 X5031.000 Y5946.000
+G1 X5032.000 Y5950.000
+G1 X5031.000 Y5955.000
+G1 X5027.000 Y5973.000
+G1 X5028.000 Y5980.000
+G1 X5030.000 Y5985.000
+G1 X5031.000 Y5989.000
+G1 X5031.000 Y5994.000
+G1 X5030.000 Y5998.000
+G1 X5029.000 Y6002.000
+G1 X5027.000 Y6006.000
+G1 X5020.000 Y6020.000
+M5
+G0 X5000.000 Y6014.000
+M3 S1000
+G1 X4971.000 Y6018.000 F1000
+G1 X4950.000 Y6021.000
+G1 X4945.000 Y6021.000
+M5
+G0 X5209.000 Y6052.000
+M3 S1000
+G1 X5203.000 Y6053.000 F1000
+G1 X5196.000 Y6056.000
+G1 X5191.000 Y6060.000
+G1 X5188.000 Y6064.000
+G1 X5186.000 Y6068.000
+G1 X5184.000 Y6070.000
+G1 X5181.000 Y6072.000
+G1 X5177.000 Y6073.000
+G1 X5173.000 Y6074.000
+G1 X5167.000 Y6074.000
+M5
+G0 X5116.000 Y6108.000
+M3 S1000
+G1 X5119.000 Y6113.000 F1000
+G1 X5122.000 Y6115.000
+G1 X5125.000 Y6116.000
+G1 X5127.000 Y6115.000
+G1 X5130.000 Y6115.000
+G1 X5134.000 Y6114.000
+G1 X5138.000 Y6115.000
+G1 X5143.000 Y6117.000
+G1 X5146.000 Y6119.000
+G1 X5149.000 Y6123.000
+G1 X5156.000 Y6131.000
+G1 X5166.000 Y6136.000
+M5
+G0 X5206.000 Y6267.000
+M3 S1000
+G1 X5203.000 Y6264.000 F1000
+G1 X5201.000 Y6259.000
+G1 X5198.000 Y6231.000
+G1 X5193.000 Y6203.000
+G1 X5192.000 Y6179.000
+G1 X5189.000 Y6156.000
+G1 X5187.000 Y6148.000
+G1 X5182.000 Y6145.000
+M5
+G0 X5206.000 Y6267.000
+M3 S1000
+G1 X5205.000 Y6271.000 F1000
+G1 X5201.000 Y6276.000
+G1 X5194.000 Y6281.000
+G1 X5168.000 Y6294.000
+G1 X5158.000 Y6301.000
+G1 X5152.000 Y6306.000
+M5
+G0 X5087.000 Y6118.000
+M3 S1000
+G1 X5083.000 Y6125.000 F1000
+G1 X5056.000 Y6160.000
+G1 X5045.000 Y6162.000
+M5
+G0 X5167.000 Y6074.000
+M3 S1000
+G1 X5148.000 Y6075.000 F1000
+M5
+G0 X5182.000 Y6145.000
+M3 S1000
+G1 X5166.000 Y6136.000 F1000
+M5
+G0 X5138.000 Y6762.000
+M3 S1000
+G1 X5135.000 Y6757.000 F1000
+G1 X5132.000 Y6734.000
+G1 X5131.000 Y6728.000
+G1 X5129.000 Y6725.000
+G1 X5124.000 Y6719.000
+G1 X5123.000 Y6717.000
+G1 X5123.000 Y6713.000
+G1 X5126.000 Y6710.000
+G1 X5126.000 Y6707.000
+G1 X5123.000 Y6695.000
+G1 X5120.000 Y6687.000
+G1 X5115.000 Y6677.000
+G1 X5109.000 Y6671.000
+G1 X5101.000 Y6664.000
+G1 X5083.000 Y6653.000
+G1 X5073.000 Y6644.000
+G1 X5065.000 Y6635.000
+G1 X5058.000 Y6626.000
+M5
+G0 X4441.000 Y5644.000
+M3 S1000
+G1 X4428.000 Y5647.000 F1000
+G1 X4428.000 Y5664.000
+M5
+G0 X4687.000 Y7941.000
+M3 S1000
+G1 X4945.000 Y9549.000 F1000
+M5
+G0 X4888.000 Y4938.000
+M3 S1000
+G1 X4908.000 Y4948.000 F1000
+G1 X4916.000 Y4951.000
+G1 X4922.000 Y4952.000
+G1 X4931.000 Y4952.000
+G1 X4943.000 Y4952.000
+G1 X4949.000 Y4951.000
+M5
+G0 X4371.000 Y5123.000
+M3 S1000
+G1 X4323.000 Y5130.000 F1000
+G1 X4305.000 Y5134.000
+G1 X4293.000 Y5139.000
+G1 X4277.000 Y5147.000
+G1 X4258.000 Y5151.000
+G1 X4239.000 Y5154.000
+G1 X4216.000 Y5154.000
+G1 X4194.000 Y5152.000
+G1 X4176.000 Y5152.000
+G1 X4170.000 Y5153.000
+G1 X4167.000 Y5156.000
+G1 X4164.000 Y5161.000
+G1 X4160.000 Y5162.000
+G1 X4153.000 Y5163.000
+G1 X4148.000 Y5166.000
+G1 X4142.000 Y5172.000
+M5
+G0 X3924.000 Y7000.000
+M3 S1000
+G1 X3929.000 Y7001.000 F1000
+G1 X3949.000 Y7004.000
+G1 X3954.000 Y7005.000
+G1 X3965.000 Y7006.000
+G1 X3976.000 Y7007.000
+G1 X3984.000 Y7002.000
+G1 X4037.000 Y6952.000
+G1 X4046.000 Y6949.000
+G1 X4055.000 Y6946.000
+G1 X4067.000 Y6945.000
+G1 X4209.000 Y6929.000
+M5
+G0 X5393.000 Y5485.000
+M3 S1000
+G1 X5405.000 Y5482.000 F1000
+G1 X5447.000 Y5467.000
+G1 X5468.000 Y5468.000
+G1 X5480.000 Y5463.000
+G1 X5516.000 Y5454.000
+G1 X5525.000 Y5456.000
+G1 X5534.000 Y5457.000
+G1 X5545.000 Y5454.000
+G1 X5561.000 Y5439.000
+G1 X5565.000 Y5430.000
+G1 X5566.000 Y5416.000
+G1 X5567.000 Y5397.000
+G1 X5571.000 Y5397.000
+G1 X5575.000 Y5395.000
+G1 X5579.000 Y5393.000
+G1 X5582.000 Y5390.000
+G1 X5584.000 Y5386.000
+G1 X5586.000 Y5382.000
+G1 X5587.000 Y5377.000
+G1 X5603.000 Y5335.000
+G1 X5604.000 Y5331.000
+G1 X5604.000 Y5325.000
+G1 X5603.000 Y5320.000
+G1 X5602.000 Y5316.000
+G1 X5599.000 Y5312.000
+G1 X5594.000 Y5300.000
+G1 X5589.000 Y5292.000
+G1 X5588.000 Y5283.000
+G1 X5582.000 Y5240.000
+G1 X5572.000 Y5218.000
+G1 X5567.000 Y5206.000
+G1 X5563.000 Y5193.000
+G1 X5560.000 Y5181.000
+G1 X5559.000 Y5169.000
+G1 X5558.000 Y5152.000
+G1 X5552.000 Y5128.000
+G1 X5549.000 Y5125.000
+G1 X5545.000 Y5124.000
+G1 X5541.000 Y5122.000
+G1 X5537.000 Y5122.000
+G1 X5532.000 Y5122.000
+G1 X5528.000 Y5123.000
+G1 X5524.000 Y5124.000
+G1 X5520.000 Y5124.000
+G1 X5515.000 Y5124.000
+G1 X5511.000 Y5123.000
+G1 X5506.000 Y5122.000
+G1 X5502.000 Y5123.000
+G1 X5499.000 Y5125.000
+G1 X5497.000 Y5129.000
+G1 X5496.000 Y5132.000
+G1 X5495.000 Y5137.000
+G1 X5497.000 Y5141.000
+G1 X5499.000 Y5144.000
+G1 X5501.000 Y5148.000
+G1 X5503.000 Y5152.000
+G1 X5503.000 Y5160.000
+G1 X5501.000 Y5168.000
+M5
+G0 X5460.000 Y4753.000
+M3 S1000
+G1 X5463.000 Y4772.000 F1000
+G1 X5465.000 Y4784.000
+G1 X5468.000 Y4791.000
+G1 X5472.000 Y4803.000
+G1 X5475.000 Y4814.000
+G1 X5476.000 Y4825.000
+G1 X5477.000 Y4838.000
+G1 X5480.000 Y4847.000
+G1 X5486.000 Y4865.000
+G1 X5490.000 Y4877.000
+G1 X5493.000 Y4886.000
+G1 X5498.000 Y4906.000
+G1 X5503.000 Y4930.000
+G1 X5510.000 Y4952.000
+G1 X5511.000 Y4957.000
+G1 X5512.000 Y4963.000
+G1 X5512.000 Y4971.000
+G1 X5513.000 Y4978.000
+G1 X5514.000 Y4984.000
+G1 X5516.000 Y4996.000
+G1 X5519.000 Y5006.000
+G1 X5530.000 Y5045.000
+G1 X5536.000 Y5070.000
+G1 X5538.000 Y5079.000
+M5
+G0 X5501.000 Y5168.000
+M3 S1000
+G1 X5496.000 Y5179.000 F1000
+G1 X5491.000 Y5188.000
+M5
+G0 X5538.000 Y5382.000
+M3 S1000
+G1 X5544.000 Y5386.000 F1000
+G1 X5547.000 Y5392.000
+G1 X5550.000 Y5395.000
+G1 X5552.000 Y5396.000
+G1 X5554.000 Y5397.000
+G1 X5557.000 Y5397.000
+G1 X5567.000 Y5397.000
+M5
+G0 X5503.000 Y4930.000
+M3 S1000
+G1 X5487.000 Y4934.000 F1000
+G1 X5465.000 Y4940.000
+G1 X5441.000 Y4946.000
+M5
+G0 X5469.000 Y5079.000
+M3 S1000
+G1 X5513.000 Y5072.000 F1000
+G1 X5530.000 Y5072.000
+G1 X5536.000 Y5070.000
+M5
+G0 X5635.000 Y5639.000
+M3 S1000
+G1 X5637.000 Y5629.000 F1000
+G1 X5639.000 Y5617.000
+G1 X5641.000 Y5578.000
+G1 X5640.000 Y5568.000
+G1 X5592.000 Y5561.000
+G1 X5586.000 Y5557.000
+G1 X5569.000 Y5567.000
+G1 X5552.000 Y5594.000
+G1 X5550.000 Y5610.000
+G1 X5549.000 Y5621.000
+G1 X5548.000 Y5631.000
+M5
+G0 X5572.000 Y5469.000
+M3 S1000
+G1 X5581.000 Y5468.000 F1000
+G1 X5589.000 Y5470.000
+G1 X5594.000 Y5476.000
+G1 X5599.000 Y5495.000
+G1 X5595.000 Y5497.000
+G1 X5588.000 Y5502.000
+G1 X5585.000 Y5515.000
+G1 X5584.000 Y5544.000
+G1 X5586.000 Y5557.000
+M5
+G0 X5640.000 Y5568.000
+M3 S1000
+G1 X5646.000 Y5562.000 F1000
+G1 X5650.000 Y5551.000
+G1 X5646.000 Y5541.000
+G1 X5642.000 Y5529.000
+G1 X5641.000 Y5519.000
+G1 X5643.000 Y5507.000
+G1 X5641.000 Y5500.000
+G1 X5633.000 Y5500.000
+G1 X5610.000 Y5497.000
+G1 X5599.000 Y5495.000
+M5
+G0 X5080.000 Y5830.000
+M3 S1000
+G1 X5080.000 Y5835.000 F1000
+G1 X5079.000 Y5843.000
+G1 X5078.000 Y5850.000
+G1 X5077.000 Y5858.000
+G1 X5077.000 Y5867.000
+G1 X5078.000 Y5876.000
+G1 X5080.000 Y5885.000
+G1 X5086.000 Y5908.000
+G1 X5088.000 Y5929.000
+G1 X5087.000 Y5949.000
+G1 X5084.000 Y5969.000
+G1 X5083.000 Y5982.000
+G1 X5085.000 Y5993.000
+G1 X5090.000 Y6003.000
+G1 X5097.000 Y6008.000
+G1 X5117.000 Y6010.000
+M5
+G0 X4865.000 Y5413.000
+M3 S1000
+G1 X4878.000 Y5422.000 F1000
+M5
+G0 X4925.000 Y5452.000
+M3 S1000
+G1 X4929.000 Y5458.000 F1000
+G1 X4932.000 Y5463.000
+G1 X4935.000 Y5469.000
+G1 X4937.000 Y5476.000
+G1 X4938.000 Y5483.000
+G1 X4938.000 Y5492.000
+G1 X4938.000 Y5496.000
+G1 X4937.000 Y5503.000
+M5
+G0 X4995.000 Y5481.000
+M3 S1000
+G1 X4977.000 Y5483.000 F1000
+G1 X4969.000 Y5484.000
+G1 X4962.000 Y5486.000
+G1 X4954.000 Y5490.000
+G1 X4948.000 Y5494.000
+G1 X4944.000 Y5496.000
+G1 X4942.000 Y5498.000
+G1 X4937.000 Y5503.000
+G1 X4933.000 Y5513.000
+M5
+G0 X3197.000 Y4777.000
+M3 S1000
+G1 X3184.000 Y4780.000 F1000
+G1 X3177.000 Y4780.000
+G1 X3171.000 Y4779.000
+G1 X3152.000 Y4775.000
+G1 X3150.000 Y4774.000
+G1 X3147.000 Y4775.000
+G1 X2991.000 Y4822.000
+G1 X2937.000 Y4841.000
+G1 X2905.000 Y4848.000
+G1 X2806.000 Y4866.000
+G1 X2794.000 Y4871.000
+G1 X2785.000 Y4877.000
+G1 X2779.000 Y4896.000
+G1 X2774.000 Y5096.000
+G1 X2763.000 Y5327.000
+G1 X2762.000 Y5334.000
+G1 X2760.000 Y5342.000
+G1 X2760.000 Y5344.000
+G1 X2758.000 Y5347.000
+G1 X2756.000 Y5350.000
+G1 X2753.000 Y5352.000
+G1 X2749.000 Y5354.000
+G1 X2743.000 Y5355.000
+G1 X2737.000 Y5357.000
+G1 X2729.000 Y5359.000
+G1 X2722.000 Y5360.000
+G1 X2716.000 Y5361.000
+G1 X2711.000 Y5362.000
+M5
+G0 X2711.000 Y5362.000
+M3 S1000
+G1 X2706.000 Y5404.000 F1000
+G1 X2702.000 Y5432.000
+G1 X2701.000 Y5458.000
+G1 X2698.000 Y5492.000
+G1 X2698.000 Y5507.000
+G1 X2697.000 Y5521.000
+G1 X2695.000 Y5525.000
+G1 X2693.000 Y5528.000
+G1 X2691.000 Y5533.000
+G1 X2691.000 Y5537.000
+G1 X2691.000 Y5541.000
+G1 X2691.000 Y5544.000
+G1 X2690.000 Y5549.000
+G1 X2690.000 Y5552.000
+G1 X2690.000 Y5557.000
+G1 X2691.000 Y5562.000
+G1 X2694.000 Y5571.000
+M5
+G0 X2766.000 Y4876.000
+M3 S1000
+G1 X2747.000 Y4880.000 F1000
+G1 X2729.000 Y4883.000
+G1 X2717.000 Y4886.000
+G1 X2709.000 Y4888.000
+G1 X2701.000 Y4891.000
+G1 X2691.000 Y4895.000
+G1 X2686.000 Y4896.000
+G1 X2679.000 Y4896.000
+G1 X2671.000 Y4897.000
+G1 X2653.000 Y4897.000
+G1 X2635.000 Y4897.000
+G1 X2627.000 Y4897.000
+G1 X2617.000 Y4898.000
+G1 X2609.000 Y4899.000
+G1 X2600.000 Y4901.000
+G1 X2590.000 Y4905.000
+G1 X2584.000 Y4907.000
+G1 X2577.000 Y4912.000
+G1 X2572.000 Y4916.000
+G1 X2564.000 Y4921.000
+G1 X2557.000 Y4924.000
+G1 X2551.000 Y4927.000
+G1 X2546.000 Y4925.000
+G1 X2541.000 Y4923.000
+G1 X2528.000 Y4920.000
+G1 X2514.000 Y4918.000
+G1 X2507.000 Y4918.000
+G1 X2498.000 Y4918.000
+G1 X2493.000 Y4918.000
+G1 X2485.000 Y4919.000
+G1 X2449.000 Y4927.000
+G1 X2432.000 Y4932.000
+G1 X2413.000 Y4938.000
+G1 X2398.000 Y4943.000
+G1 X2391.000 Y4946.000
+G1 X2385.000 Y4948.000
+G1 X2379.000 Y4952.000
+G1 X2374.000 Y4955.000
+G1 X2369.000 Y4960.000
+G1 X2364.000 Y4966.000
+G1 X2361.000 Y4971.000
+G1 X2358.000 Y4976.000
+G1 X2357.000 Y4980.000
+G1 X2356.000 Y4986.000
+G1 X2356.000 Y4990.000
+G1 X2357.000 Y4996.000
+G1 X2359.000 Y5001.000
+G1 X2362.000 Y5006.000
+G1 X2366.000 Y5009.000
+G1 X2374.000 Y5013.000
+G1 X2379.000 Y5014.000
+G1 X2387.000 Y5015.000
+G1 X2392.000 Y5014.000
+G1 X2399.000 Y5014.000
+G1 X2407.000 Y5012.000
+G1 X2416.000 Y5010.000
+G1 X2426.000 Y5010.000
+M5
+G0 X2988.000 Y5053.000
+M3 S1000
+G1 X2979.000 Y5059.000 F1000
+G1 X2965.000 Y5071.000
+G1 X2864.000 Y5085.000
+G1 X2774.000 Y5096.000
+G1 X2755.000 Y5099.000
+G1 X2750.000 Y5100.000
+G1 X2745.000 Y5103.000
+M5
+G0 X2382.000 Y5411.000
+M3 S1000
+G1 X2375.000 Y5415.000 F1000
+G1 X2342.000 Y5419.000
+G1 X2334.000 Y5423.000
+G1 X2326.000 Y5432.000
+G1 X2324.000 Y5439.000
+G1 X2327.000 Y5462.000
+G1 X2330.000 Y5470.000
+G1 X2337.000 Y5486.000
+G1 X2338.000 Y5497.000
+G1 X2337.000 Y5526.000
+G1 X2337.000 Y5540.000
+G1 X2341.000 Y5549.000
+G1 X2348.000 Y5554.000
+G1 X2358.000 Y5557.000
+G1 X2371.000 Y5556.000
+G1 X2386.000 Y5551.000
+G1 X2394.000 Y5547.000
+G1 X2398.000 Y5536.000
+G1 X2400.000 Y5512.000
+G1 X2404.000 Y5494.000
+G1 X2410.000 Y5487.000
+M5
+G0 X2711.000 Y5362.000
+M3 S1000
+G1 X2693.000 Y5358.000 F1000
+G1 X2653.000 Y5363.000
+G1 X2641.000 Y5363.000
+G1 X2624.000 Y5363.000
+G1 X2616.000 Y5365.000
+G1 X2572.000 Y5369.000
+G1 X2566.000 Y5373.000
+G1 X2562.000 Y5381.000
+G1 X2560.000 Y5426.000
+G1 X2558.000 Y5432.000
+G1 X2515.000 Y5436.000
+G1 X2508.000 Y5440.000
+G1 X2505.000 Y5447.000
+G1 X2505.000 Y5539.000
+G1 X2486.000 Y5542.000
+G1 X2448.000 Y5545.000
+G1 X2444.000 Y5585.000
+G1 X2444.000 Y5590.000
+M5
+G0 X2438.000 Y5487.000
+M3 S1000
+G1 X2442.000 Y5487.000 F1000
+G1 X2441.000 Y5533.000
+G1 X2444.000 Y5541.000
+G1 X2448.000 Y5545.000
+M5
+G0 X3180.000 Y5535.000
+M3 S1000
+G1 X3158.000 Y5537.000 F1000
+G1 X3070.000 Y5542.000
+G1 X2955.000 Y5547.000
+G1 X2788.000 Y5556.000
+G1 X2691.000 Y5562.000
+G1 X2634.000 Y5565.000
+G1 X2628.000 Y5563.000
+G1 X2618.000 Y5564.000
+G1 X2615.000 Y5563.000
+G1 X2611.000 Y5564.000
+G1 X2566.000 Y5566.000
+G1 X2561.000 Y5564.000
+G1 X2557.000 Y5559.000
+G1 X2555.000 Y5544.000
+G1 X2553.000 Y5540.000
+G1 X2548.000 Y5538.000
+G1 X2505.000 Y5539.000
+M5
+G0 X4024.000 Y7799.000
+M3 S1000
+G1 X4043.000 Y7796.000 F1000
+G1 X4056.000 Y7775.000
+G1 X4054.000 Y7759.000
+M5
+G0 X3992.000 Y7556.000
+M3 S1000
+G1 X3943.000 Y7169.000 F1000
+G1 X3939.000 Y7129.000
+M5
+G0 X5959.000 Y5641.000
+M3 S1000
+G1 X6009.000 Y5640.000 F1000
+G1 X6135.000 Y5638.000
+G1 X6306.000 Y5642.000
+G1 X6331.000 Y5640.000
+G1 X6417.000 Y5623.000
+G1 X6450.000 Y5616.000
+G1 X6488.000 Y5610.000
+G1 X6534.000 Y5605.000
+G1 X6671.000 Y5593.000
+G1 X6705.000 Y5592.000
+G1 X6737.000 Y5593.000
+G1 X6788.000 Y5594.000
+G1 X6843.000 Y5595.000
+G1 X6888.000 Y5592.000
+G1 X6973.000 Y5584.000
+G1 X7051.000 Y5578.000
+G1 X7108.000 Y5568.000
+G1 X7181.000 Y5553.000
+M5
+G0 X4339.000 Y6499.000
+M3 S1000
+G1 X4338.000 Y6489.000 F1000
+M5
+G0 X5107.000 Y5182.000
+M3 S1000
+G1 X5097.000 Y5134.000 F1000
+M5
+G0 X4342.000 Y6522.000
+M3 S1000
+G1 X4339.000 Y6499.000 F1000
+M5
+G0 X5367.000 Y6235.000
+M3 S1000
+G1 X5397.000 Y6227.000 F1000
+G1 X5451.000 Y6214.000
+G1 X5461.000 Y6209.000
+G1 X5471.000 Y6193.000
+G1 X5472.000 Y6172.000
+G1 X5470.000 Y6160.000
+G1 X5458.000 Y6129.000
+G1 X5445.000 Y6117.000
+G1 X5406.000 Y6103.000
+G1 X5387.000 Y6092.000
+G1 X5362.000 Y6086.000
+G1 X5334.000 Y6065.000
+M5
+G0 X5112.000 Y5203.000
+M3 S1000
+G1 X5107.000 Y5182.000 F1000
+M5
+G0 X5538.000 Y5079.000
+M3 S1000
+G1 X5543.000 Y5100.000 F1000
+M5
+G0 X5543.000 Y5100.000
+M3 S1000
+G1 X5546.000 Y5107.000 F1000
+G1 X5550.000 Y5114.000
+G1 X5551.000 Y5116.000
+G1 X5551.000 Y5119.000
+G1 X5551.000 Y5123.000
+G1 X5549.000 Y5125.000
+M5
+G0 X4878.000 Y5422.000
+M3 S1000
+G1 X4889.000 Y5428.000 F1000
+G1 X4891.000 Y5430.000
+G1 X4907.000 Y5441.000
+G1 X4925.000 Y5452.000
+G1 X4930.000 Y5455.000
+G1 X4950.000 Y5466.000
+G1 X4960.000 Y5471.000
+G1 X4973.000 Y5475.000
+G1 X4995.000 Y5481.000
+G1 X5007.000 Y5480.000
+G1 X5043.000 Y5476.000
+G1 X5062.000 Y5474.000
+G1 X5070.000 Y5473.000
+M5
+G0 X5351.000 Y6238.000
+M3 S1000
+G1 X5367.000 Y6235.000 F1000
+M5
+G0 X4649.000 Y2940.000
+M3 S1000
+G1 X4657.000 Y2945.000 F1000
+G1 X4663.000 Y2951.000
+M5
+G0 X5393.000 Y5550.000
+M3 S1000
+G1 X5395.000 Y5559.000 F1000
+G1 X5396.000 Y5563.000
+G1 X5396.000 Y5565.000
+G1 X5395.000 Y5569.000
+G1 X5394.000 Y5571.000
+G1 X5391.000 Y5573.000
+G1 X5388.000 Y5576.000
+G1 X5382.000 Y5580.000
+G1 X5377.000 Y5583.000
+G1 X5375.000 Y5585.000
+G1 X5373.000 Y5588.000
+G1 X5371.000 Y5590.000
+G1 X5370.000 Y5594.000
+G1 X5370.000 Y5599.000
+G1 X5369.000 Y5604.000
+M5
+G0 X5408.000 Y5647.000
+M3 S1000
+G1 X5398.000 Y5643.000 F1000
+G1 X5388.000 Y5639.000
+G1 X5378.000 Y5635.000
+G1 X5366.000 Y5632.000
+M5
+G0 X3851.000 Y6440.000
+M3 S1000
+G1 X3859.000 Y6439.000 F1000
+M5
+G0 X3787.000 Y5796.000
+M3 S1000
+G1 X3789.000 Y5806.000 F1000
+G1 X3790.000 Y5816.000
+G1 X3791.000 Y5825.000
+G1 X3790.000 Y5846.000
+G1 X3792.000 Y5866.000
+G1 X3796.000 Y5905.000
+M5
+G0 X2551.000 Y4927.000
+M3 S1000
+G1 X2548.000 Y4964.000 F1000
+M5
+G0 X2747.000 Y4880.000
+M3 S1000
+G1 X2749.000 Y4890.000 F1000
+M5
+G0 X3928.000 Y6982.000
+M3 S1000
+G1 X3928.000 Y6980.000 F1000
+G1 X3926.000 Y6969.000
+G1 X3925.000 Y6959.000
+G1 X3924.000 Y6957.000
+G1 X3922.000 Y6953.000
+G1 X3920.000 Y6949.000
+G1 X3917.000 Y6945.000
+G1 X3906.000 Y6930.000
+G1 X3903.000 Y6899.000
+M5
+G0 X3841.000 Y6325.000
+M3 S1000
+G1 X3888.000 Y6318.000 F1000
+G1 X3895.000 Y6317.000
+G1 X3959.000 Y6319.000
+G1 X3971.000 Y6319.000
+G1 X3986.000 Y6317.000
+G1 X4115.000 Y6298.000
+G1 X4123.000 Y6297.000
+G1 X4119.000 Y6261.000
+G1 X4139.000 Y6260.000
+G1 X4166.000 Y6257.000
+G1 X4160.000 Y6189.000
+M5
+G0 X3833.000 Y6327.000
+M3 S1000
+G1 X3841.000 Y6325.000 F1000
+M5
+G0 X3873.000 Y6638.000
+M3 S1000
+G1 X3885.000 Y6636.000 F1000
+M5
+G0 X3820.000 Y6233.000
+M3 S1000
+G1 X3828.000 Y6232.000 F1000
+M5
+G0 X985.000 Y6055.000
+M3 S1000
+G1 X1435.000 Y6031.000 F1000
+G1 X1447.000 Y6032.000
+G1 X1458.000 Y6034.000
+M5
+G0 X1181.000 Y7096.000
+M3 S1000
+G1 X1633.000 Y7121.000 F1000
+M5
+G0 X1296.000 Y6542.000
+M3 S1000
+G1 X1544.000 Y6557.000 F1000
+G1 X1570.000 Y6544.000
+G1 X1589.000 Y6542.000
+G1 X1601.000 Y6543.000
+M5
+G0 X3425.000 Y4601.000
+M3 S1000
+G1 X3426.000 Y4604.000 F1000
+G1 X3434.000 Y4629.000
+G1 X3436.000 Y4645.000
+M5
+G0 X7218.000 Y8408.000
+M3 S1000
+G1 X7322.000 Y8573.000 F1000
+M5
+G0 X7333.000 Y7953.000
+M3 S1000
+G1 X7389.000 Y8114.000 F1000
+M5
+G0 X8072.000 Y6823.000
+M3 S1000
+G1 X8051.000 Y6768.000 F1000
+G1 X8052.000 Y6748.000
+G1 X8025.000 Y6679.000
+G1 X8023.000 Y6669.000
+G1 X8025.000 Y6664.000
+G1 X8066.000 Y6628.000
+G1 X8105.000 Y6616.000
+G1 X8123.000 Y6612.000
+G1 X8132.000 Y6617.000
+G1 X8139.000 Y6625.000
+G1 X8148.000 Y6645.000
+G1 X8157.000 Y6651.000
+G1 X8171.000 Y6649.000
+G1 X8228.000 Y6630.000
+G1 X8236.000 Y6633.000
+G1 X8242.000 Y6638.000
+G1 X8274.000 Y6758.000
+G1 X8269.000 Y6774.000
+G1 X8257.000 Y6791.000
+G1 X8216.000 Y6803.000
+G1 X8199.000 Y6803.000
+G1 X8146.000 Y6784.000
+G1 X8139.000 Y6775.000
+G1 X8130.000 Y6746.000
+M5
+G0 X6028.000 Y5898.000
+M3 S1000
+G1 X5960.000 Y5650.000 F1000
+G1 X5959.000 Y5641.000
+M5
+G0 X7631.000 Y5499.000
+M3 S1000
+G1 X7630.000 Y5510.000 F1000
+G1 X7632.000 Y5529.000
+G1 X7638.000 Y5541.000
+G1 X7652.000 Y5555.000
+G1 X7666.000 Y5572.000
+G1 X7674.000 Y5592.000
+G1 X7931.000 Y6194.000
+G1 X8095.000 Y6591.000
+G1 X8099.000 Y6599.000
+G1 X8105.000 Y6616.000
+M5
+G0 X6268.000 Y5034.000
+M3 S1000
+G1 X6312.000 Y5167.000 F1000
+G1 X6450.000 Y5616.000
+M5
+G0 X6970.000 Y8859.000
+M3 S1000
+G1 X6978.000 Y8877.000 F1000
+G1 X7057.000 Y9175.000
+M5
+G0 X7525.000 Y6820.000
+M3 S1000
+G1 X7616.000 Y7040.000 F1000
+M5
+G0 X1685.000 Y3331.000
+M3 S1000
+G1 X1686.000 Y3312.000 F1000
+G1 X1691.000 Y3297.000
+G1 X1712.000 Y3272.000
+G1 X1716.000 Y3257.000
+G1 X1717.000 Y3208.000
+M5
+G0 X4214.000 Y9740.000
+M3 S1000
+G1 X4220.000 Y9726.000 F1000
+G1 X4223.000 Y9716.000
+G1 X4224.000 Y9706.000
+G1 X4226.000 Y9686.000
+G1 X4216.000 Y9612.000
+G1 X4158.000 Y9049.000
+G1 X4104.000 Y8484.000
+G1 X4051.000 Y8036.000
+G1 X4024.000 Y7799.000
+G1 X4014.000 Y7717.000
+G1 X4012.000 Y7703.000
+G1 X3992.000 Y7556.000
+M5
+G0 X6788.000 Y5604.000
+M3 S1000
+G1 X6841.000 Y5604.000 F1000
+G1 X6888.000 Y5602.000
+G1 X6973.000 Y5594.000
+G1 X7052.000 Y5588.000
+G1 X7111.000 Y5578.000
+G1 X7268.000 Y5545.000
+G1 X7400.000 Y5525.000
+G1 X7630.000 Y5510.000
+G1 X7738.000 Y5506.000
+G1 X7910.000 Y5501.000
+G1 X7984.000 Y5503.000
+G1 X8347.000 Y5526.000
+G1 X8480.000 Y5535.000
+G1 X8606.000 Y5551.000
+G1 X8822.000 Y5581.000
+G1 X8920.000 Y5595.000
+G1 X8969.000 Y5606.000
+G1 X9042.000 Y5625.000
+G1 X9088.000 Y5638.000
+M5
+G0 X5749.000 Y5649.000
+M3 S1000
+G1 X5801.000 Y5654.000 F1000
+G1 X5960.000 Y5650.000
+G1 X6143.000 Y5645.000
+G1 X6304.000 Y5652.000
+G1 X6345.000 Y5648.000
+G1 X6420.000 Y5634.000
+G1 X6454.000 Y5627.000
+G1 X6488.000 Y5620.000
+G1 X6536.000 Y5615.000
+G1 X6671.000 Y5604.000
+G1 X6705.000 Y5602.000
+G1 X6788.000 Y5604.000
+M5
+G0 X5369.000 Y5604.000
+M3 S1000
+G1 X5368.000 Y5610.000 F1000
+G1 X5368.000 Y5617.000
+G1 X5366.000 Y5632.000
+M5
+G0 X5366.000 Y5632.000
+M3 S1000
+G1 X5363.000 Y5660.000 F1000
+G1 X5363.000 Y5664.000
+G1 X5364.000 Y5667.000
+G1 X5365.000 Y5671.000
+G1 X5367.000 Y5676.000
+G1 X5371.000 Y5682.000
+M5
+G0 X3787.000 Y5796.000
+M3 S1000
+G1 X3781.000 Y5789.000 F1000
+M5
+G0 X9088.000 Y5638.000
+M3 S1000
+G1 X9090.000 Y5628.000 F1000
+M5
+G0 X4431.000 Y3385.000
+M3 S1000
+G1 X4447.000 Y3376.000 F1000
+G1 X4463.000 Y3370.000
+G1 X4489.000 Y3367.000
+G1 X4518.000 Y3366.000
+G1 X4641.000 Y3373.000
+G1 X4764.000 Y3384.000
+M5
+G0 X3955.000 Y6943.000
+M3 S1000
+G1 X3949.000 Y6939.000 F1000
+G1 X3941.000 Y6932.000
+G1 X3926.000 Y6917.000
+G1 X3920.000 Y6910.000
+G1 X3916.000 Y6904.000
+G1 X3912.000 Y6892.000
+M5
+G0 X3912.000 Y6892.000
+M3 S1000
+G1 X3913.000 Y6910.000 F1000
+G1 X3914.000 Y6914.000
+G1 X3916.000 Y6919.000
+G1 X3918.000 Y6923.000
+G1 X3932.000 Y6939.000
+G1 X3940.000 Y6948.000
+G1 X3943.000 Y6954.000
+M5
+G0 X4271.000 Y6896.000
+M3 S1000
+G1 X4269.000 Y6880.000 F1000
+M5
+G0 X4485.000 Y6877.000
+M3 S1000
+G1 X4483.000 Y6858.000 F1000
+M5
+G0 X4271.000 Y6896.000
+M3 S1000
+G1 X4485.000 Y6877.000 F1000
+M5
+G0 X4080.000 Y6916.000
+M3 S1000
+G1 X4247.000 Y6899.000 F1000
+G1 X4271.000 Y6896.000
+M5
+G0 X4080.000 Y6916.000
+M3 S1000
+G1 X4058.000 Y6917.000 F1000
+G1 X4033.000 Y6926.000
+G1 X3993.000 Y6945.000
+G1 X3978.000 Y6944.000
+M5
+G0 X3990.000 Y6964.000
+M3 S1000
+G1 X3999.000 Y6955.000 F1000
+G1 X4010.000 Y6949.000
+G1 X4039.000 Y6936.000
+G1 X4065.000 Y6924.000
+G1 X4080.000 Y6916.000
+M5
+G0 X3973.000 Y6989.000
+M3 S1000
+G1 X3978.000 Y6987.000 F1000
+G1 X3982.000 Y6983.000
+G1 X3986.000 Y6979.000
+G1 X3988.000 Y6974.000
+G1 X3990.000 Y6970.000
+G1 X3990.000 Y6964.000
+M5
+G0 X5190.000 Y5792.000
+M3 S1000
+G1 X5167.000 Y5698.000 F1000
+G1 X5153.000 Y5691.000
+G1 X5138.000 Y5633.000
+M5
+G0 X5251.000 Y5721.000
+M3 S1000
+G1 X5253.000 Y5713.000 F1000
+G1 X5255.000 Y5707.000
+G1 X5257.000 Y5705.000
+G1 X5272.000 Y5702.000
+G1 X5277.000 Y5700.000
+G1 X5282.000 Y5697.000
+G1 X5286.000 Y5694.000
+G1 X5289.000 Y5690.000
+G1 X5293.000 Y5684.000
+G1 X5294.000 Y5680.000
+M5
+G0 X5265.000 Y5768.000
+M3 S1000
+G1 X5251.000 Y5721.000 F1000
+G1 X5237.000 Y5713.000
+G1 X5230.000 Y5684.000
+G1 X5223.000 Y5675.000
+G1 X5218.000 Y5667.000
+G1 X5215.000 Y5663.000
+G1 X5212.000 Y5653.000
+G1 X5209.000 Y5638.000
+G1 X5212.000 Y5627.000
+G1 X5235.000 Y5629.000
+M5
+G0 X4779.000 Y4629.000
+M3 S1000
+G1 X4887.000 Y4611.000 F1000
+G1 X4907.000 Y4602.000
+G1 X4914.000 Y4598.000
+G1 X4960.000 Y4589.000
+M5
+G0 X5134.000 Y4825.000
+M3 S1000
+G1 X5123.000 Y4779.000 F1000
+M5
+G0 X5263.000 Y4877.000
+M3 S1000
+G1 X5251.000 Y4829.000 F1000
+G1 X5245.000 Y4801.000
+G1 X5227.000 Y4737.000
+M5
+G0 X4753.000 Y4505.000
+M3 S1000
+G1 X4763.000 Y4548.000 F1000
+G1 X4777.000 Y4620.000
+G1 X4779.000 Y4629.000
+G1 X4791.000 Y4695.000
+G1 X4804.000 Y4768.000
+G1 X4811.000 Y4807.000
+G1 X4814.000 Y4812.000
+M5
+G0 X4966.000 Y4366.000
+M3 S1000
+G1 X4869.000 Y4394.000 F1000
+G1 X4840.000 Y4335.000
+G1 X4839.000 Y4333.000
+G1 X4837.000 Y4333.000
+G1 X4835.000 Y4332.000
+G1 X4833.000 Y4332.000
+G1 X4831.000 Y4332.000
+G1 X4830.000 Y4332.000
+G1 X4828.000 Y4334.000
+G1 X4826.000 Y4335.000
+G1 X4825.000 Y4337.000
+G1 X4824.000 Y4339.000
+G1 X4831.000 Y4416.000
+G1 X4850.000 Y4451.000
+G1 X4853.000 Y4455.000
+G1 X4856.000 Y4457.000
+G1 X4859.000 Y4459.000
+G1 X4861.000 Y4462.000
+G1 X4866.000 Y4465.000
+G1 X4866.000 Y4468.000
+M5
+G0 X5336.000 Y4970.000
+M3 S1000
+G1 X5347.000 Y4967.000 F1000
+G1 X5428.000 Y4949.000
+G1 X5441.000 Y4946.000
+M5
+G0 X5065.000 Y4790.000
+M3 S1000
+G1 X5059.000 Y4766.000 F1000
+G1 X5030.000 Y4637.000
+G1 X5003.000 Y4520.000
+M5
+G0 X5251.000 Y4829.000
+M3 S1000
+G1 X5114.000 Y4855.000 F1000
+G1 X5109.000 Y4830.000
+G1 X5134.000 Y4825.000
+G1 X5245.000 Y4801.000
+M5
+G0 X5065.000 Y4790.000
+M3 S1000
+G1 X5090.000 Y4786.000 F1000
+G1 X5123.000 Y4779.000
+G1 X5159.000 Y4767.000
+G1 X5206.000 Y4748.000
+G1 X5227.000 Y4737.000
+G1 X5272.000 Y4714.000
+G1 X5372.000 Y4662.000
+G1 X5394.000 Y4650.000
+G1 X5420.000 Y4636.000
+M5
+G0 X4938.000 Y4235.000
+M3 S1000
+G1 X4935.000 Y4223.000 F1000
+G1 X4932.000 Y4220.000
+G1 X4928.000 Y4218.000
+G1 X4924.000 Y4217.000
+G1 X4920.000 Y4217.000
+G1 X4702.000 Y4283.000
+G1 X4698.000 Y4285.000
+G1 X4695.000 Y4288.000
+G1 X4693.000 Y4292.000
+G1 X4692.000 Y4296.000
+G1 X4693.000 Y4299.000
+G1 X4695.000 Y4307.000
+M5
+G0 X4812.000 Y5094.000
+M3 S1000
+G1 X4884.000 Y5098.000 F1000
+M5
+G0 X4748.000 Y4778.000
+M3 S1000
+G1 X4804.000 Y4768.000 F1000
+M5
+G0 X4706.000 Y4558.000
+M3 S1000
+G1 X4763.000 Y4548.000 F1000
+M5
+G0 X4733.000 Y4705.000
+M3 S1000
+G1 X4791.000 Y4695.000 F1000
+M5
+G0 X4665.000 Y4370.000
+M3 S1000
+G1 X4711.000 Y4358.000 F1000
+M5
+G0 X4683.000 Y4452.000
+M3 S1000
+G1 X4736.000 Y4439.000 F1000
+M5
+G0 X4719.000 Y4630.000
+M3 S1000
+G1 X4777.000 Y4620.000 F1000
+M5
+G0 X5048.000 Y4633.000
+M3 S1000
+G1 X5138.000 Y4612.000 F1000
+M5
+G0 X5335.000 Y4314.000
+M3 S1000
+G1 X5210.000 Y4348.000 F1000
+M5
+G0 X5128.000 Y4529.000
+M3 S1000
+G1 X5136.000 Y4528.000 F1000
+G1 X5144.000 Y4527.000
+G1 X5153.000 Y4527.000
+G1 X5163.000 Y4528.000
+G1 X5173.000 Y4531.000
+G1 X5182.000 Y4534.000
+G1 X5189.000 Y4535.000
+G1 X5195.000 Y4535.000
+G1 X5202.000 Y4533.000
+G1 X5207.000 Y4531.000
+G1 X5212.000 Y4529.000
+G1 X5216.000 Y4525.000
+G1 X5230.000 Y4513.000
+G1 X5240.000 Y4507.000
+G1 X5249.000 Y4504.000
+M5
+G0 X5189.000 Y4277.000
+M3 S1000
+G1 X5193.000 Y4279.000 F1000
+G1 X5198.000 Y4282.000
+G1 X5201.000 Y4286.000
+G1 X5204.000 Y4290.000
+G1 X5207.000 Y4294.000
+G1 X5209.000 Y4299.000
+G1 X5211.000 Y4308.000
+G1 X5212.000 Y4316.000
+G1 X5212.000 Y4328.000
+G1 X5211.000 Y4338.000
+G1 X5210.000 Y4348.000
+G1 X5212.000 Y4361.000
+G1 X5214.000 Y4368.000
+G1 X5217.000 Y4375.000
+G1 X5223.000 Y4383.000
+G1 X5229.000 Y4392.000
+G1 X5235.000 Y4398.000
+G1 X5240.000 Y4405.000
+G1 X5244.000 Y4412.000
+G1 X5246.000 Y4419.000
+G1 X5247.000 Y4425.000
+G1 X5248.000 Y4432.000
+G1 X5249.000 Y4438.000
+G1 X5249.000 Y4445.000
+G1 X5248.000 Y4452.000
+G1 X5246.000 Y4459.000
+G1 X5245.000 Y4467.000
+G1 X5244.000 Y4474.000
+G1 X5244.000 Y4482.000
+G1 X5246.000 Y4492.000
+G1 X5248.000 Y4501.000
+G1 X5249.000 Y4504.000
+G1 X5251.000 Y4509.000
+G1 X5254.000 Y4514.000
+G1 X5257.000 Y4518.000
+G1 X5260.000 Y4522.000
+G1 X5263.000 Y4524.000
+G1 X5268.000 Y4531.000
+G1 X5272.000 Y4537.000
+G1 X5276.000 Y4545.000
+G1 X5278.000 Y4553.000
+G1 X5279.000 Y4561.000
+G1 X5279.000 Y4569.000
+G1 X5278.000 Y4576.000
+G1 X5277.000 Y4582.000
+G1 X5274.000 Y4589.000
+G1 X5270.000 Y4594.000
+G1 X5266.000 Y4599.000
+G1 X5261.000 Y4604.000
+G1 X5255.000 Y4607.000
+G1 X5249.000 Y4611.000
+G1 X5185.000 Y4645.000
+G1 X5179.000 Y4648.000
+G1 X5172.000 Y4650.000
+G1 X5166.000 Y4649.000
+G1 X5159.000 Y4649.000
+G1 X5153.000 Y4646.000
+G1 X5144.000 Y4640.000
+G1 X5141.000 Y4634.000
+G1 X5139.000 Y4628.000
+G1 X5138.000 Y4620.000
+G1 X5138.000 Y4612.000
+G1 X5138.000 Y4589.000
+G1 X5134.000 Y4558.000
+G1 X5128.000 Y4529.000
+G1 X5121.000 Y4517.000
+G1 X5114.000 Y4505.000
+M5
+G0 X5019.000 Y4315.000
+M3 S1000
+G1 X5066.000 Y4303.000 F1000
+G1 X5088.000 Y4305.000
+G1 X5096.000 Y4301.000
+G1 X5105.000 Y4298.000
+G1 X5118.000 Y4296.000
+G1 X5129.000 Y4293.000
+G1 X5157.000 Y4281.000
+G1 X5163.000 Y4278.000
+G1 X5169.000 Y4277.000
+G1 X5175.000 Y4276.000
+G1 X5182.000 Y4276.000
+G1 X5189.000 Y4277.000
+G1 X5202.000 Y4264.000
+G1 X5253.000 Y4251.000
+M5
+G0 X4938.000 Y4235.000
+M3 S1000
+G1 X5267.000 Y4134.000 F1000
+G1 X5271.000 Y4133.000
+G1 X5276.000 Y4132.000
+G1 X5280.000 Y4133.000
+G1 X5284.000 Y4135.000
+G1 X5287.000 Y4138.000
+G1 X5289.000 Y4141.000
+G1 X5291.000 Y4144.000
+M5
+G0 X5586.000 Y4696.000
+M3 S1000
+G1 X5576.000 Y4656.000 F1000
+G1 X5569.000 Y4627.000
+G1 X5564.000 Y4608.000
+G1 X5563.000 Y4604.000
+G1 X5559.000 Y4599.000
+G1 X5555.000 Y4595.000
+G1 X5550.000 Y4592.000
+G1 X5543.000 Y4590.000
+G1 X5537.000 Y4590.000
+G1 X5530.000 Y4592.000
+G1 X5475.000 Y4614.000
+G1 X5427.000 Y4633.000
+M5
+G0 X4694.000 Y4842.000
+M3 S1000
+G1 X4706.000 Y4840.000 F1000
+G1 X4806.000 Y4820.000
+G1 X4823.000 Y4817.000
+M5
+G0 X3151.000 Y4926.000
+M3 S1000
+G1 X3112.000 Y4930.000 F1000
+M5
+G0 X3489.000 Y5376.000
+M3 S1000
+G1 X3633.000 Y5368.000 F1000
+M5
+G0 X3075.000 Y4990.000
+M3 S1000
+G1 X3154.000 Y4980.000 F1000
+M5
+G0 X3078.000 Y5017.000
+M3 S1000
+G1 X3154.000 Y5008.000 F1000
+M5
+G0 X3671.000 Y5389.000
+M3 S1000
+G1 X3639.000 Y5392.000 F1000
+M5
+G0 X2973.000 Y4953.000
+M3 S1000
+G1 X2975.000 Y4971.000 F1000
+G1 X2983.000 Y5043.000
+G1 X2985.000 Y5048.000
+G1 X2988.000 Y5053.000
+M5
+G0 X3427.000 Y5771.000
+M3 S1000
+G1 X3428.000 Y5796.000 F1000
+G1 X3429.000 Y5809.000
+G1 X3446.000 Y5847.000
+M5
+G0 X3166.000 Y4905.000
+M3 S1000
+G1 X3153.000 Y4900.000 F1000
+G1 X3143.000 Y4900.000
+G1 X3117.000 Y4902.000
+G1 X3109.000 Y4828.000
+G1 X3134.000 Y4826.000
+G1 X3143.000 Y4900.000
+M5
+G0 X3152.000 Y4953.000
+M3 S1000
+G1 X3082.000 Y4961.000 F1000
+G1 X3076.000 Y4968.000
+G1 X3074.000 Y4979.000
+G1 X3075.000 Y4990.000
+G1 X3078.000 Y5017.000
+G1 X3080.000 Y5044.000
+M5
+G0 X3439.000 Y5796.000
+M3 S1000
+G1 X3441.000 Y5807.000 F1000
+G1 X3444.000 Y5815.000
+G1 X3447.000 Y5818.000
+G1 X3451.000 Y5820.000
+G1 X3584.000 Y5814.000
+G1 X3591.000 Y5813.000
+G1 X3595.000 Y5811.000
+G1 X3598.000 Y5806.000
+G1 X3601.000 Y5802.000
+G1 X3603.000 Y5796.000
+G1 X3603.000 Y5788.000
+M5
+G0 X3722.000 Y5406.000
+M3 S1000
+G1 X3650.000 Y5411.000 F1000
+G1 X3639.000 Y5392.000
+G1 X3633.000 Y5368.000
+G1 X3626.000 Y5342.000
+G1 X3487.000 Y5352.000
+G1 X3489.000 Y5376.000
+M5
+G0 X4009.000 Y5404.000
+M3 S1000
+G1 X4001.000 Y5288.000 F1000
+G1 X3987.000 Y5194.000
+M5
+G0 X3890.000 Y5525.000
+M3 S1000
+G1 X3888.000 Y5506.000 F1000
+M5
+G0 X3888.000 Y5506.000
+M3 S1000
+G1 X3881.000 Y5443.000 F1000
+M5
+G0 X4211.000 Y5040.000
+M3 S1000
+G1 X4221.000 Y5038.000 F1000
+G1 X4345.000 Y5018.000
+G1 X4353.000 Y5016.000
+M5
+G0 X5635.000 Y5639.000
+M3 S1000
+G1 X5635.000 Y5643.000 F1000
+G1 X5635.000 Y5645.000
+M5
+G0 X3458.000 Y4657.000
+M3 S1000
+G1 X3476.000 Y4634.000 F1000
+G1 X3590.000 Y4601.000
+G1 X3604.000 Y4601.000
+M5
+G0 X5407.000 Y5547.000
+M3 S1000
+G1 X5393.000 Y5485.000 F1000
+G1 X5385.000 Y5451.000
+G1 X5378.000 Y5420.000
+G1 X5355.000 Y5322.000
+G1 X5332.000 Y5221.000
+G1 X5314.000 Y5142.000
+G1 X5308.000 Y5118.000
+G1 X5300.000 Y5084.000
+G1 X5278.000 Y4990.000
+G1 X5276.000 Y4978.000
+M5
+G0 X5369.000 Y5604.000
+M3 S1000
+G1 X5363.000 Y5604.000 F1000
+G1 X5208.000 Y5590.000
+G1 X5048.000 Y5575.000
+G1 X5035.000 Y5574.000
+G1 X5024.000 Y5571.000
+G1 X5013.000 Y5569.000
+G1 X5001.000 Y5565.000
+G1 X4989.000 Y5561.000
+G1 X4924.000 Y5529.000
+G1 X4838.000 Y5486.000
+G1 X4812.000 Y5474.000
+G1 X4801.000 Y5472.000
+G1 X4790.000 Y5470.000
+G1 X4775.000 Y5470.000
+M5
+G0 X4889.000 Y5428.000
+M3 S1000
+G1 X4870.000 Y5456.000 F1000
+G1 X4869.000 Y5460.000
+G1 X4868.000 Y5462.000
+G1 X4869.000 Y5465.000
+G1 X4869.000 Y5466.000
+G1 X4870.000 Y5467.000
+G1 X4873.000 Y5469.000
+G1 X4877.000 Y5472.000
+G1 X4937.000 Y5503.000
+G1 X4995.000 Y5533.000
+G1 X5005.000 Y5538.000
+G1 X5015.000 Y5541.000
+G1 X5027.000 Y5545.000
+G1 X5039.000 Y5547.000
+G1 X5050.000 Y5547.000
+G1 X5083.000 Y5548.000
+G1 X5089.000 Y5547.000
+M5
+G0 X4933.000 Y5513.000
+M3 S1000
+G1 X4927.000 Y5525.000 F1000
+M5
+G0 X4927.000 Y5525.000
+M3 S1000
+G1 X4924.000 Y5529.000 F1000
+M5
+G0 X4979.000 Y5273.000
+M3 S1000
+G1 X4956.000 Y5263.000 F1000
+M5
+G0 X5332.000 Y5221.000
+M3 S1000
+G1 X5313.000 Y5221.000 F1000
+G1 X5128.000 Y5262.000
+G1 X5035.000 Y5287.000
+G1 X5027.000 Y5294.000
+M5
+G0 X4956.000 Y5263.000
+M3 S1000
+G1 X4942.000 Y5258.000 F1000
+G1 X4939.000 Y5257.000
+G1 X4935.000 Y5255.000
+G1 X4839.000 Y5210.000
+G1 X4821.000 Y5202.000
+G1 X4783.000 Y5184.000
+G1 X4700.000 Y5144.000
+M5
+G0 X4890.000 Y5100.000
+M3 S1000
+G1 X4884.000 Y5098.000 F1000
+M5
+G0 X4958.000 Y5224.000
+M3 S1000
+G1 X4939.000 Y5257.000 F1000
+M5
+G0 X4945.000 Y5182.000
+M3 S1000
+G1 X4919.000 Y5187.000 F1000
+M5
+G0 X4876.000 Y5037.000
+M3 S1000
+G1 X4882.000 Y5036.000 F1000
+M5
+G0 X4959.000 Y5184.000
+M3 S1000
+G1 X4979.000 Y5193.000 F1000
+M5
+G0 X4889.000 Y5067.000
+M3 S1000
+G1 X4883.000 Y5067.000 F1000
+M5
+G0 X4995.000 Y5158.000
+M3 S1000
+G1 X4993.000 Y5164.000 F1000
+G1 X4966.000 Y5151.000
+G1 X4946.000 Y5142.000
+M5
+G0 X4839.000 Y5210.000
+M3 S1000
+G1 X4854.000 Y5178.000 F1000
+G1 X4883.000 Y5116.000
+G1 X4890.000 Y5100.000
+G1 X4895.000 Y5090.000
+G1 X4914.000 Y5050.000
+G1 X4919.000 Y5040.000
+G1 X4946.000 Y4979.000
+G1 X4951.000 Y4969.000
+M5
+G0 X4798.000 Y5152.000
+M3 S1000
+G1 X4854.000 Y5178.000 F1000
+G1 X4924.000 Y5211.000
+G1 X4937.000 Y5217.000
+G1 X4945.000 Y5220.000
+G1 X4951.000 Y5223.000
+G1 X4958.000 Y5224.000
+G1 X4964.000 Y5222.000
+G1 X4967.000 Y5218.000
+G1 X4979.000 Y5193.000
+G1 X4993.000 Y5164.000
+M5
+G0 X4935.000 Y5255.000
+M3 S1000
+G1 X4924.000 Y5211.000 F1000
+G1 X4919.000 Y5187.000
+G1 X4904.000 Y5123.000
+G1 X4895.000 Y5090.000
+G1 X4889.000 Y5067.000
+G1 X4882.000 Y5036.000
+G1 X4871.000 Y4987.000
+G1 X4951.000 Y4969.000
+M5
+G0 X4951.000 Y5174.000
+M3 S1000
+G1 X4947.000 Y5175.000 F1000
+G1 X4945.000 Y5178.000
+G1 X4945.000 Y5182.000
+G1 X4946.000 Y5185.000
+G1 X4948.000 Y5188.000
+G1 X4952.000 Y5188.000
+G1 X4956.000 Y5188.000
+G1 X4958.000 Y5186.000
+G1 X4959.000 Y5184.000
+G1 X4960.000 Y5180.000
+G1 X4958.000 Y5176.000
+G1 X4955.000 Y5174.000
+G1 X4953.000 Y5173.000
+G1 X4951.000 Y5174.000
+M5
+G0 X5128.000 Y5262.000
+M3 S1000
+G1 X5124.000 Y5253.000 F1000
+G1 X5112.000 Y5203.000
+M5
+G0 X5133.000 Y5275.000
+M3 S1000
+G1 X5128.000 Y5262.000 F1000
+M5
+G0 X4925.000 Y5452.000
+M3 S1000
+G1 X4943.000 Y5423.000 F1000
+G1 X4958.000 Y5401.000
+G1 X4961.000 Y5397.000
+G1 X4966.000 Y5393.000
+G1 X4973.000 Y5388.000
+G1 X4980.000 Y5386.000
+G1 X5046.000 Y5370.000
+M5
+G0 X4955.000 Y5174.000
+M3 S1000
+G1 X4966.000 Y5151.000 F1000
+G1 X4969.000 Y5145.000
+G1 X4971.000 Y5137.000
+G1 X4971.000 Y5132.000
+G1 X4967.000 Y5127.000
+G1 X4962.000 Y5122.000
+G1 X4958.000 Y5115.000
+G1 X4954.000 Y5107.000
+G1 X4952.000 Y5097.000
+G1 X4949.000 Y5091.000
+G1 X4946.000 Y5085.000
+G1 X4942.000 Y5081.000
+M5
+G0 X4942.000 Y5140.000
+M3 S1000
+G1 X4904.000 Y5123.000 F1000
+M5
+G0 X4942.000 Y5081.000
+M3 S1000
+G1 X4937.000 Y5078.000 F1000
+M5
+G0 X4919.000 Y5040.000
+M3 S1000
+G1 X4945.000 Y5052.000 F1000
+M5
+G0 X4945.000 Y5052.000
+M3 S1000
+G1 X4948.000 Y5053.000 F1000
+M5
+G0 X4946.000 Y5142.000
+M3 S1000
+G1 X4942.000 Y5140.000 F1000
+M5
+G0 X4948.000 Y5053.000
+M3 S1000
+G1 X4965.000 Y5060.000 F1000
+G1 X4973.000 Y5058.000
+M5
+G0 X4937.000 Y5078.000
+M3 S1000
+G1 X4934.000 Y5074.000 F1000
+G1 X4930.000 Y5069.000
+G1 X4927.000 Y5063.000
+G1 X4925.000 Y5059.000
+G1 X4923.000 Y5056.000
+G1 X4920.000 Y5053.000
+G1 X4917.000 Y5052.000
+G1 X4914.000 Y5050.000
+M5
+G0 X4913.000 Y5551.000
+M3 S1000
+G1 X4845.000 Y5517.000 F1000
+M5
+G0 X4973.000 Y5754.000
+M3 S1000
+G1 X4972.000 Y5744.000 F1000
+M5
+G0 X4812.000 Y5105.000
+M3 S1000
+G1 X4883.000 Y5116.000 F1000
+M5
+G0 X5014.000 Y5827.000
+M3 S1000
+G1 X5013.000 Y5821.000 F1000
+G1 X5003.000 Y5822.000
+G1 X4997.000 Y5768.000
+G1 X4973.000 Y5760.000
+G1 X4973.000 Y5754.000
+M5
+G0 X4972.000 Y5744.000
+M3 S1000
+G1 X4965.000 Y5666.000 F1000
+G1 X4932.000 Y5674.000
+G1 X4911.000 Y5666.000
+M5
+G0 X3787.000 Y6810.000
+M3 S1000
+G1 X3797.000 Y6811.000 F1000
+G1 X3802.000 Y6811.000
+G1 X3829.000 Y6811.000
+G1 X3828.000 Y6838.000
+M5
+G0 X3628.000 Y6938.000
+M3 S1000
+G1 X3508.000 Y6934.000 F1000
+G1 X3506.000 Y6988.000
+G1 X3627.000 Y6991.000
+M5
+G0 X3760.000 Y7176.000
+M3 S1000
+G1 X3766.000 Y7186.000 F1000
+G1 X3771.000 Y7196.000
+G1 X3776.000 Y7208.000
+G1 X3787.000 Y7227.000
+G1 X3794.000 Y7244.000
+G1 X3801.000 Y7308.000
+M5
+G0 X3928.000 Y6982.000
+M3 S1000
+G1 X3924.000 Y6981.000 F1000
+G1 X3909.000 Y6978.000
+G1 X3908.000 Y6978.000
+M5
+G0 X3908.000 Y6978.000
+M3 S1000
+G1 X3874.000 Y6981.000 F1000
+M5
+G0 X3874.000 Y6981.000
+M3 S1000
+G1 X3869.000 Y6982.000 F1000
+G1 X3866.000 Y6983.000
+G1 X3862.000 Y6986.000
+G1 X3858.000 Y6988.000
+G1 X3837.000 Y6994.000
+G1 X3833.000 Y6994.000
+G1 X3828.000 Y6994.000
+G1 X3769.000 Y6993.000
+M5
+G0 X4285.000 Y5884.000
+M3 S1000
+G1 X4285.000 Y5870.000 F1000
+M5
+G0 X6788.000 Y5604.000
+M3 S1000
+G1 X6788.000 Y5600.000 F1000
+G1 X6788.000 Y5594.000
+M5
+G0 X6454.000 Y5627.000
+M3 S1000
+G1 X6450.000 Y5616.000 F1000
+M5
+G0 X3744.000 Y5768.000
+M3 S1000
+G1 X3760.000 Y5768.000 F1000
+M5
+G0 X4679.000 Y6047.000
+M3 S1000
+G1 X4676.000 Y6014.000 F1000
+G1 X4710.000 Y6010.000
+G1 X4703.000 Y5933.000
+G1 X4808.000 Y5923.000
+M5
+G0 X4701.000 Y5919.000
+M3 S1000
+G1 X4703.000 Y5933.000 F1000
+M5
+G0 X4701.000 Y5919.000
+M3 S1000
+G1 X4592.000 Y5930.000 F1000
+M5
+G0 X3164.000 Y5726.000
+M3 S1000
+G1 X3095.000 Y5731.000 F1000
+G1 X3093.000 Y5707.000
+G1 X3098.000 Y5700.000
+G1 X3095.000 Y5664.000
+M5
+G0 X3676.000 Y5879.000
+M3 S1000
+G1 X3677.000 Y5868.000 F1000
+G1 X3680.000 Y5855.000
+G1 X3686.000 Y5845.000
+G1 X3703.000 Y5818.000
+G1 X3704.000 Y5782.000
+G1 X3704.000 Y5759.000
+M5
+G0 X4754.000 Y5668.000
+M3 S1000
+G1 X4736.000 Y5663.000 F1000
+G1 X4704.000 Y5648.000
+G1 X4700.000 Y5646.000
+G1 X4696.000 Y5644.000
+G1 X4685.000 Y5643.000
+G1 X4601.000 Y5642.000
+M5
+G0 X2290.000 Y5607.000
+M3 S1000
+G1 X2270.000 Y5405.000 F1000
+G1 X2270.000 Y5395.000
+G1 X2271.000 Y5386.000
+G1 X2273.000 Y5379.000
+G1 X2277.000 Y5371.000
+G1 X2281.000 Y5365.000
+G1 X2286.000 Y5359.000
+G1 X2289.000 Y5353.000
+G1 X2293.000 Y5345.000
+G1 X2295.000 Y5335.000
+G1 X2297.000 Y5321.000
+G1 X2296.000 Y5311.000
+G1 X2295.000 Y5301.000
+M5
+G0 X5749.000 Y5649.000
+M3 S1000
+G1 X5750.000 Y5638.000 F1000
+M5
+G0 X5310.000 Y4869.000
+M3 S1000
+G1 X5290.000 Y4788.000 F1000
+G1 X5272.000 Y4714.000
+M5
+G0 X5310.000 Y4869.000
+M3 S1000
+G1 X5403.000 Y4794.000 F1000
+M5
+G0 X5591.000 Y6332.000
+M3 S1000
+G1 X5594.000 Y6330.000 F1000
+G1 X5654.000 Y6295.000
+M5
+G0 X5536.000 Y6377.000
+M3 S1000
+G1 X5535.000 Y6370.000 F1000
+G1 X5535.000 Y6368.000
+G1 X5536.000 Y6365.000
+G1 X5538.000 Y6362.000
+G1 X5540.000 Y6360.000
+G1 X5591.000 Y6332.000
+M5
+G0 X7400.000 Y5525.000
+M3 S1000
+G1 X7399.000 Y5517.000 F1000
+M5
+G0 X5446.000 Y5542.000
+M3 S1000
+G1 X5407.000 Y5547.000 F1000
+M5
+G0 X4427.000 Y5557.000
+M3 S1000
+G1 X4427.000 Y5547.000 F1000
+G1 X4472.000 Y5545.000
+M5
+G0 X3770.000 Y6095.000
+M3 S1000
+G1 X3747.000 Y5857.000 F1000
+G1 X3742.000 Y5813.000
+G1 X3742.000 Y5804.000
+G1 X3741.000 Y5791.000
+G1 X3744.000 Y5768.000
+G1 X3729.000 Y5768.000
+G1 X3729.000 Y5754.000
+M5
+G0 X3995.000 Y7705.000
+M3 S1000
+G1 X4001.000 Y7704.000 F1000
+G1 X4012.000 Y7703.000
+G1 X4021.000 Y7702.000
+G1 X4027.000 Y7702.000
+G1 X4051.000 Y7718.000
+G1 X4053.000 Y7728.000
+G1 X4050.000 Y7735.000
+M5
+G0 X4226.000 Y9749.000
+M3 S1000
+G1 X4227.000 Y9760.000 F1000
+G1 X4227.000 Y9773.000
+G1 X4236.000 Y9924.000
+M5
+G0 X4010.000 Y3182.000
+M3 S1000
+G1 X3738.000 Y1740.000 F1000
+G1 X3674.000 Y1396.000
+G1 X3631.000 Y1168.000
+G1 X3608.000 Y1047.000
+G1 X3564.000 Y780.000
+G1 X3545.000 Y684.000
+G1 X3498.000 Y459.000
+G1 X3496.000 Y447.000
+G1 X3470.000 Y299.000
+M5
+G0 X3978.000 Y6944.000
+M3 S1000
+G1 X3973.000 Y6942.000 F1000
+G1 X3967.000 Y6941.000
+G1 X3961.000 Y6941.000
+G1 X3955.000 Y6943.000
+G1 X3951.000 Y6945.000
+G1 X3948.000 Y6947.000
+G1 X3945.000 Y6951.000
+G1 X3943.000 Y6954.000
+G1 X3941.000 Y6960.000
+G1 X3940.000 Y6968.000
+G1 X3941.000 Y6972.000
+G1 X3943.000 Y6976.000
+G1 X3945.000 Y6980.000
+G1 X3948.000 Y6983.000
+G1 X3951.000 Y6986.000
+M5
+G0 X3951.000 Y6986.000
+M3 S1000
+G1 X3956.000 Y6989.000 F1000
+G1 X3962.000 Y6990.000
+G1 X3968.000 Y6990.000
+G1 X3973.000 Y6989.000
+M5
+G0 X3990.000 Y6964.000
+M3 S1000
+G1 X3989.000 Y6958.000 F1000
+G1 X3987.000 Y6953.000
+G1 X3983.000 Y6948.000
+G1 X3978.000 Y6944.000
+M5
+G0 X6208.000 Y431.000
+M3 S1000
+G1 X6188.000 Y424.000 F1000
+G1 X5863.000 Y499.000
+G1 X5668.000 Y542.000
+G1 X5613.000 Y550.000
+G1 X5575.000 Y558.000
+G1 X5484.000 Y581.000
+G1 X5399.000 Y601.000
+G1 X5381.000 Y610.000
+M5
+G0 X4649.000 Y2940.000
+M3 S1000
+G1 X4618.000 Y2931.000 F1000
+G1 X4602.000 Y2922.000
+G1 X4588.000 Y2897.000
+G1 X4569.000 Y2817.000
+G1 X4513.000 Y2599.000
+G1 X4463.000 Y2409.000
+G1 X4423.000 Y2243.000
+G1 X4367.000 Y2025.000
+G1 X4329.000 Y1877.000
+G1 X4276.000 Y1662.000
+G1 X4263.000 Y1606.000
+M5
+G0 X3564.000 Y4150.000
+M3 S1000
+G1 X3547.000 Y4151.000 F1000
+G1 X3534.000 Y4148.000
+G1 X3526.000 Y4145.000
+G1 X3516.000 Y4138.000
+G1 X3504.000 Y4130.000
+G1 X3491.000 Y4122.000
+G1 X3482.000 Y4117.000
+G1 X3474.000 Y4115.000
+G1 X3463.000 Y4115.000
+G1 X3453.000 Y4116.000
+G1 X3171.000 Y4160.000
+G1 X2507.000 Y4272.000
+M5
+G0 X4515.000 Y3979.000
+M3 S1000
+G1 X4313.000 Y4007.000 F1000
+G1 X4261.000 Y4017.000
+M5
+G0 X6970.000 Y4656.000
+M3 S1000
+G1 X6930.000 Y4680.000 F1000
+G1 X6846.000 Y4719.000
+G1 X6776.000 Y4746.000
+G1 X6755.000 Y4751.000
+G1 X6735.000 Y4758.000
+M5
+G0 X6443.000 Y3710.000
+M3 S1000
+G1 X6465.000 Y3764.000 F1000
+G1 X6493.000 Y3834.000
+G1 X6521.000 Y3917.000
+G1 X6530.000 Y3928.000
+G1 X6534.000 Y3934.000
+G1 X6544.000 Y3935.000
+G1 X6570.000 Y3932.000
+G1 X6586.000 Y3935.000
+G1 X6594.000 Y3945.000
+G1 X6636.000 Y3935.000
+G1 X6645.000 Y3966.000
+G1 X6640.000 Y3979.000
+G1 X6669.000 Y4081.000
+G1 X6697.000 Y4156.000
+G1 X6724.000 Y4222.000
+M5
+G0 X5408.000 Y3411.000
+M3 S1000
+G1 X5464.000 Y3624.000 F1000
+M5
+G0 X5949.000 Y3385.000
+M3 S1000
+G1 X5945.000 Y3359.000 F1000
+M5
+G0 X4663.000 Y2951.000
+M3 S1000
+G1 X4674.000 Y2984.000 F1000
+G1 X4689.000 Y3034.000
+G1 X4719.000 Y3140.000
+G1 X4736.000 Y3205.000
+G1 X4752.000 Y3275.000
+G1 X4760.000 Y3310.000
+G1 X4764.000 Y3346.000
+G1 X4764.000 Y3378.000
+G1 X4764.000 Y3384.000
+M5
+G0 X6296.000 Y3399.000
+M3 S1000
+G1 X6298.000 Y3378.000 F1000
+G1 X6188.000 Y3118.000
+G1 X6116.000 Y2952.000
+G1 X6078.000 Y2864.000
+G1 X6073.000 Y2844.000
+G1 X6034.000 Y2750.000
+G1 X5990.000 Y2647.000
+G1 X5960.000 Y2579.000
+G1 X5915.000 Y2473.000
+G1 X5872.000 Y2367.000
+G1 X5867.000 Y2356.000
+M5
+G0 X6728.000 Y4240.000
+M3 S1000
+G1 X6724.000 Y4222.000 F1000
+M5
+G0 X7045.000 Y4640.000
+M3 S1000
+G1 X6999.000 Y4657.000 F1000
+G1 X6989.000 Y4658.000
+G1 X6970.000 Y4656.000
+G1 X6918.000 Y4507.000
+G1 X6899.000 Y4461.000
+G1 X6881.000 Y4426.000
+G1 X6865.000 Y4408.000
+G1 X6770.000 Y4303.000
+G1 X6738.000 Y4263.000
+G1 X6731.000 Y4253.000
+G1 X6728.000 Y4240.000
+M5
+G0 X5634.000 Y1796.000
+M3 S1000
+G1 X5609.000 Y1789.000 F1000
+G1 X5581.000 Y1723.000
+G1 X5560.000 Y1674.000
+G1 X5532.000 Y1624.000
+G1 X5464.000 Y1648.000
+M5
+G0 X5709.000 Y1998.000
+M3 S1000
+G1 X5723.000 Y2013.000 F1000
+G1 X5854.000 Y2329.000
+G1 X5867.000 Y2356.000
+M5
+G0 X5901.000 Y3196.000
+M3 S1000
+G1 X5853.000 Y3212.000 F1000
+G1 X5619.000 Y3240.000
+G1 X5461.000 Y2800.000
+G1 X5433.000 Y2805.000
+G1 X5194.000 Y2865.000
+M5
+G0 X5464.000 Y1648.000
+M3 S1000
+G1 X5449.000 Y1662.000 F1000
+G1 X5394.000 Y1687.000
+G1 X5325.000 Y1741.000
+G1 X5279.000 Y1622.000
+G1 X5242.000 Y1530.000
+G1 X5177.000 Y1359.000
+M5
+G0 X3270.000 Y6084.000
+M3 S1000
+G1 X3242.000 Y6096.000 F1000
+G1 X3236.000 Y6098.000
+G1 X3229.000 Y6099.000
+G1 X3004.000 Y6108.000
+G1 X2992.000 Y6107.000
+G1 X2982.000 Y6105.000
+G1 X2967.000 Y6100.000
+G1 X2957.000 Y6098.000
+G1 X2949.000 Y6097.000
+G1 X2937.000 Y6096.000
+G1 X2874.000 Y6099.000
+M5
+G0 X3518.000 Y5902.000
+M3 S1000
+G1 X3503.000 Y5904.000 F1000
+G1 X3490.000 Y5906.000
+G1 X3478.000 Y5909.000
+G1 X3475.000 Y5911.000
+G1 X3474.000 Y5913.000
+M5
+G0 X3309.000 Y5872.000
+M3 S1000
+G1 X3304.000 Y5816.000 F1000
+G1 X3298.000 Y5740.000
+M5
+G0 X3566.000 Y5524.000
+M3 S1000
+G1 X3567.000 Y5532.000 F1000
+G1 X3572.000 Y5602.000
+M5
+G0 X3178.000 Y5881.000
+M3 S1000
+G1 X3121.000 Y5887.000 F1000
+M5
+G0 X3518.000 Y5910.000
+M3 S1000
+G1 X3540.000 Y5910.000 F1000
+G1 X3564.000 Y5927.000
+G1 X3542.000 Y5959.000
+G1 X3580.000 Y5992.000
+M5
+G0 X3428.000 Y5796.000
+M3 S1000
+G1 X3439.000 Y5796.000 F1000
+M5
+G0 X3328.000 Y5776.000
+M3 S1000
+G1 X3324.000 Y5670.000 F1000
+M5
+G0 X3406.000 Y7232.000
+M3 S1000
+G1 X3407.000 Y7217.000 F1000
+G1 X3407.000 Y7213.000
+G1 X3408.000 Y7176.000
+G1 X3409.000 Y7141.000
+G1 X3409.000 Y7110.000
+G1 X3410.000 Y7081.000
+G1 X3410.000 Y7077.000
+G1 X3411.000 Y7061.000
+G1 X3411.000 Y7044.000
+G1 X3416.000 Y6812.000
+G1 X3416.000 Y6803.000
+M5
+G0 X3178.000 Y5881.000
+M3 S1000
+G1 X3235.000 Y5852.000 F1000
+G1 X3304.000 Y5816.000
+M5
+G0 X3485.000 Y5954.000
+M3 S1000
+G1 X3497.000 Y5946.000 F1000
+G1 X3504.000 Y5936.000
+G1 X3508.000 Y5930.000
+G1 X3514.000 Y5922.000
+G1 X3516.000 Y5917.000
+G1 X3517.000 Y5913.000
+G1 X3518.000 Y5910.000
+G1 X3518.000 Y5902.000
+G1 X3518.000 Y5878.000
+G1 X3543.000 Y5877.000
+M5
+G0 X3570.000 Y5524.000
+M3 S1000
+G1 X3566.000 Y5454.000 F1000
+M5
+G0 X3388.000 Y5986.000
+M3 S1000
+G1 X3439.000 Y5955.000 F1000
+G1 X3477.000 Y5937.000
+M5
+G0 X4300.000 Y5957.000
+M3 S1000
+G1 X4285.000 Y5884.000 F1000
+M5
+G0 X3491.000 Y3518.000
+M3 S1000
+G1 X3471.000 Y3362.000 F1000
+M5
+G0 X3416.000 Y3527.000
+M3 S1000
+G1 X3425.000 Y3526.000 F1000
+G1 X3491.000 Y3518.000
+M5
+G0 X7449.000 Y3483.000
+M3 S1000
+G1 X7447.000 Y3473.000 F1000
+G1 X7445.000 Y3461.000
+G1 X7444.000 Y3454.000
+G1 X7440.000 Y3447.000
+G1 X7435.000 Y3441.000
+G1 X7429.000 Y3438.000
+G1 X7419.000 Y3438.000
+G1 X7406.000 Y3438.000
+G1 X7388.000 Y3440.000
+G1 X7368.000 Y3446.000
+G1 X7336.000 Y3455.000
+G1 X7291.000 Y3469.000
+G1 X7255.000 Y3481.000
+G1 X7202.000 Y3497.000
+G1 X7162.000 Y3509.000
+G1 X7097.000 Y3529.000
+G1 X7084.000 Y3531.000
+G1 X7074.000 Y3531.000
+G1 X7068.000 Y3530.000
+G1 X7033.000 Y3530.000
+M5
+G0 X5002.000 Y6166.000
+M3 S1000
+G1 X5011.000 Y6165.000 F1000
+G1 X5033.000 Y6163.000
+G1 X5045.000 Y6162.000
+M5
+G0 X3948.000 Y4574.000
+M3 S1000
+G1 X3929.000 Y4494.000 F1000
+M5
+G0 X3997.000 Y4630.000
+M3 S1000
+G1 X3948.000 Y4574.000 F1000
+M5
+G0 X3164.000 Y5161.000
+M3 S1000
+G1 X3160.000 Y5077.000 F1000
+G1 X3160.000 Y5075.000
+M5
+G0 X3149.000 Y5024.000
+M3 S1000
+G1 X3153.000 Y5066.000 F1000
+G1 X3129.000 Y5067.000
+M5
+G0 X3153.000 Y5066.000
+M3 S1000
+G1 X3153.000 Y5078.000 F1000
+G1 X3160.000 Y5077.000
+M5
+G0 X6077.000 Y4126.000
+M3 S1000
+G1 X6065.000 Y4096.000 F1000
+M5
+G0 X3247.000 Y5873.000
+M3 S1000
+G1 X3260.000 Y5897.000 F1000
+M5
+G0 X3239.000 Y5860.000
+M3 S1000
+G1 X3235.000 Y5852.000 F1000
+G1 X3195.000 Y5777.000
+G1 X3179.000 Y5746.000
+M5
+G0 X3167.000 Y5779.000
+M3 S1000
+G1 X3195.000 Y5777.000 F1000
+G1 X3244.000 Y5773.000
+M5
+G0 X3239.000 Y5860.000
+M3 S1000
+G1 X3237.000 Y5862.000 F1000
+G1 X3236.000 Y5864.000
+G1 X3236.000 Y5866.000
+G1 X3236.000 Y5869.000
+G1 X3238.000 Y5872.000
+G1 X3240.000 Y5873.000
+G1 X3243.000 Y5873.000
+G1 X3246.000 Y5873.000
+G1 X3247.000 Y5873.000
+G1 X3248.000 Y5872.000
+G1 X3250.000 Y5870.000
+G1 X3250.000 Y5867.000
+G1 X3250.000 Y5864.000
+G1 X3249.000 Y5862.000
+G1 X3247.000 Y5860.000
+G1 X3245.000 Y5859.000
+G1 X3242.000 Y5859.000
+G1 X3239.000 Y5860.000
+M5
+G0 X3293.000 Y6330.000
+M3 S1000
+G1 X3169.000 Y6326.000 F1000
+G1 X3163.000 Y6314.000
+G1 X3155.000 Y6311.000
+G1 X3137.000 Y6310.000
+G1 X3129.000 Y6312.000
+G1 X3124.000 Y6317.000
+G1 X3122.000 Y6322.000
+G1 X3123.000 Y6327.000
+G1 X3125.000 Y6331.000
+G1 X3129.000 Y6335.000
+G1 X3152.000 Y6337.000
+G1 X3161.000 Y6334.000
+G1 X3169.000 Y6326.000
+M5
+G0 X4695.000 Y9624.000
+M3 S1000
+G1 X4657.000 Y9351.000 F1000
+M5
+G0 X5685.000 Y9338.000
+M3 S1000
+G1 X5718.000 Y9497.000 F1000
+G1 X5717.000 Y9506.000
+G1 X5713.000 Y9516.000
+G1 X5693.000 Y9547.000
+M5
+G0 X6602.000 Y9062.000
+M3 S1000
+G1 X6608.000 Y9056.000 F1000
+G1 X6616.000 Y9049.000
+G1 X6627.000 Y9044.000
+G1 X6632.000 Y9047.000
+G1 X6639.000 Y9048.000
+G1 X6665.000 Y9042.000
+G1 X6667.000 Y9036.000
+M5
+G0 X6614.000 Y9163.000
+M3 S1000
+G1 X6609.000 Y9136.000 F1000
+G1 X6598.000 Y9075.000
+G1 X6599.000 Y9068.000
+G1 X6602.000 Y9062.000
+G1 X6590.000 Y9050.000
+M5
+G0 X8702.000 Y6842.000
+M3 S1000
+G1 X8733.000 Y6855.000 F1000
+M5
+G0 X8733.000 Y6855.000
+M3 S1000
+G1 X8774.000 Y6874.000 F1000
+G1 X8789.000 Y6877.000
+M5
+G0 X8444.000 Y6791.000
+M3 S1000
+G1 X8446.000 Y6783.000 F1000
+G1 X8451.000 Y6778.000
+G1 X8461.000 Y6775.000
+G1 X8473.000 Y6774.000
+G1 X8494.000 Y6775.000
+G1 X8517.000 Y6778.000
+G1 X8540.000 Y6781.000
+G1 X8564.000 Y6787.000
+G1 X8596.000 Y6798.000
+G1 X8626.000 Y6809.000
+G1 X8702.000 Y6842.000
+M5
+G0 X5555.000 Y5460.000
+M3 S1000
+G1 X5572.000 Y5469.000 F1000
+M5
+G0 X5545.000 Y5454.000
+M3 S1000
+G1 X5548.000 Y5456.000 F1000
+G1 X5555.000 Y5460.000
+M5
+G0 X5408.000 Y5647.000
+M3 S1000
+G1 X5414.000 Y5642.000 F1000
+G1 X5420.000 Y5637.000
+G1 X5424.000 Y5634.000
+G1 X5429.000 Y5631.000
+G1 X5433.000 Y5629.000
+G1 X5438.000 Y5626.000
+G1 X5443.000 Y5624.000
+G1 X5449.000 Y5623.000
+G1 X5454.000 Y5623.000
+G1 X5463.000 Y5623.000
+G1 X5477.000 Y5624.000
+M5
+G0 X5476.000 Y5614.000
+M3 S1000
+G1 X5477.000 Y5624.000 F1000
+M5
+G0 X3404.000 Y7081.000
+M3 S1000
+G1 X3257.000 Y7074.000 F1000
+M5
+G0 X3620.000 Y7091.000
+M3 S1000
+G1 X3415.000 Y7081.000 F1000
+M5
+G0 X3630.000 Y7091.000
+M3 S1000
+G1 X3625.000 Y7091.000 F1000
+G1 X3620.000 Y7091.000
+M5
+G0 X3647.000 Y6990.000
+M3 S1000
+G1 X3645.000 Y7088.000 F1000
+G1 X3642.000 Y7092.000
+G1 X3630.000 Y7091.000
+M5
+G0 X3415.000 Y7081.000
+M3 S1000
+G1 X3410.000 Y7081.000 F1000
+G1 X3404.000 Y7081.000
+M5
+G0 X3759.000 Y6993.000
+M3 S1000
+G1 X3647.000 Y6990.000 F1000
+M5
+G0 X3769.000 Y6993.000
+M3 S1000
+G1 X3764.000 Y6993.000 F1000
+G1 X3759.000 Y6993.000
+M5
+G0 X3647.000 Y6990.000
+M3 S1000
+G1 X3647.000 Y6979.000 F1000
+M5
+G0 X3647.000 Y6969.000
+M3 S1000
+G1 X3648.000 Y6961.000 F1000
+M5
+G0 X3647.000 Y6979.000
+M3 S1000
+G1 X3647.000 Y6974.000 F1000
+G1 X3647.000 Y6969.000
+M5
+G0 X3648.000 Y6955.000
+M3 S1000
+G1 X3649.000 Y6886.000 F1000
+G1 X3637.000 Y6842.000
+G1 X3630.000 Y6836.000
+M5
+G0 X3648.000 Y6961.000
+M3 S1000
+G1 X3648.000 Y6958.000 F1000
+G1 X3648.000 Y6955.000
+M5
+G0 X2499.000 Y4942.000
+M3 S1000
+G1 X2491.000 Y4943.000 F1000
+M5
+G0 X2513.000 Y4940.000
+M3 S1000
+G1 X2499.000 Y4942.000 F1000
+M5
+G0 X2551.000 Y4927.000
+M3 S1000
+G1 X2544.000 Y4931.000 F1000
+G1 X2518.000 Y4938.000
+G1 X2513.000 Y4940.000
+M5
+G0 X2653.000 Y4897.000
+M3 S1000
+G1 X2651.000 Y4922.000 F1000
+G1 X2652.000 Y4930.000
+M5
+G0 X4040.000 Y4137.000
+M3 S1000
+G1 X4067.000 Y4233.000 F1000
+M5
+G0 X4158.000 Y4110.000
+M3 S1000
+G1 X4160.000 Y4135.000 F1000
+G1 X4167.000 Y4191.000
+G1 X4163.000 Y4213.000
+M5
+G0 X4100.000 Y3986.000
+M3 S1000
+G1 X4125.000 Y3980.000 F1000
+M5
+G0 X4095.000 Y3969.000
+M3 S1000
+G1 X4100.000 Y3986.000 F1000
+G1 X4103.000 Y3997.000
+G1 X4112.000 Y4023.000
+G1 X4119.000 Y4049.000
+G1 X4127.000 Y4076.000
+M5
+G0 X4059.000 Y4010.000
+M3 S1000
+G1 X4103.000 Y3997.000 F1000
+M5
+G0 X4067.000 Y4037.000
+M3 S1000
+G1 X4112.000 Y4023.000 F1000
+G1 X4134.000 Y4017.000
+M5
+G0 X4075.000 Y4063.000
+M3 S1000
+G1 X4119.000 Y4049.000 F1000
+G1 X4141.000 Y4043.000
+M5
+G0 X4083.000 Y4089.000
+M3 S1000
+G1 X4127.000 Y4076.000 F1000
+G1 X4152.000 Y4070.000
+M5
+G0 X4152.000 Y3957.000
+M3 S1000
+G1 X4095.000 Y3969.000 F1000
+G1 X4078.000 Y3915.000
+M5
+G0 X4035.000 Y3906.000
+M3 S1000
+G1 X4075.000 Y3898.000 F1000
+G1 X4106.000 Y3892.000
+M5
+G0 X4029.000 Y3880.000
+M3 S1000
+G1 X4070.000 Y3873.000 F1000
+G1 X4102.000 Y3867.000
+M5
+G0 X4026.000 Y3856.000
+M3 S1000
+G1 X4066.000 Y3849.000 F1000
+G1 X4097.000 Y3844.000
+M5
+G0 X4021.000 Y3829.000
+M3 S1000
+G1 X4061.000 Y3822.000 F1000
+G1 X4092.000 Y3816.000
+M5
+G0 X4078.000 Y3915.000
+M3 S1000
+G1 X4075.000 Y3898.000 F1000
+G1 X4070.000 Y3873.000
+G1 X4066.000 Y3849.000
+G1 X4061.000 Y3822.000
+M5
+G0 X4111.000 Y3744.000
+M3 S1000
+G1 X4086.000 Y3748.000 F1000
+G1 X3999.000 Y3764.000
+G1 X3920.000 Y3779.000
+G1 X3915.000 Y3777.000
+G1 X3913.000 Y3773.000
+G1 X3894.000 Y3676.000
+M5
+G0 X4083.000 Y4124.000
+M3 S1000
+G1 X4044.000 Y4003.000 F1000
+G1 X4034.000 Y3967.000
+G1 X4027.000 Y3921.000
+G1 X3999.000 Y3764.000
+M5
+G0 X4100.000 Y4125.000
+M3 S1000
+G1 X4083.000 Y4124.000 F1000
+G1 X4040.000 Y4137.000
+G1 X3892.000 Y4183.000
+M5
+G0 X4180.000 Y4104.000
+M3 S1000
+G1 X4158.000 Y4110.000 F1000
+G1 X4127.000 Y4117.000
+M5
+G0 X4100.000 Y4125.000
+M3 S1000
+G1 X4107.000 Y4126.000 F1000
+G1 X4115.000 Y4124.000
+G1 X4123.000 Y4122.000
+G1 X4127.000 Y4117.000
+M5
+G0 X4127.000 Y4117.000
+M3 S1000
+G1 X4121.000 Y4115.000 F1000
+G1 X4114.000 Y4117.000
+G1 X4104.000 Y4120.000
+G1 X4100.000 Y4125.000
+M5
+G0 X4545.000 Y5797.000
+M3 S1000
+G1 X4605.000 Y5790.000 F1000
+M5
+G0 X4548.000 Y5819.000
+M3 S1000
+G1 X4608.000 Y5813.000 F1000
+G1 X4607.000 Y5804.000
+G1 X4605.000 Y5790.000
+G1 X4602.000 Y5765.000
+G1 X4542.000 Y5772.000
+M5
+G0 X4523.000 Y5993.000
+M3 S1000
+G1 X4488.000 Y5997.000 F1000
+M5
+G0 X4586.000 Y5992.000
+M3 S1000
+G1 X4543.000 Y5997.000 F1000
+M5
+G0 X4569.000 Y5841.000
+M3 S1000
+G1 X4579.000 Y5932.000 F1000
+G1 X4586.000 Y5992.000
+G1 X4600.000 Y6125.000
+M5
+G0 X4247.000 Y5694.000
+M3 S1000
+G1 X4262.000 Y5678.000 F1000
+M5
+G0 X4527.000 Y5663.000
+M3 S1000
+G1 X4532.000 Y5662.000 F1000
+G1 X4556.000 Y5660.000
+G1 X4574.000 Y5655.000
+G1 X4585.000 Y5651.000
+G1 X4594.000 Y5647.000
+G1 X4601.000 Y5642.000
+M5
+G0 X5458.000 Y4753.000
+M3 S1000
+G1 X5458.000 Y4749.000 F1000
+G1 X5458.000 Y4744.000
+G1 X5459.000 Y4742.000
+G1 X5458.000 Y4739.000
+G1 X5450.000 Y4729.000
+G1 X5449.000 Y4725.000
+G1 X5449.000 Y4715.000
+G1 X5448.000 Y4711.000
+G1 X5446.000 Y4707.000
+G1 X5441.000 Y4701.000
+G1 X5439.000 Y4696.000
+G1 X5439.000 Y4691.000
+G1 X5440.000 Y4681.000
+G1 X5437.000 Y4658.000
+G1 X5434.000 Y4650.000
+G1 X5427.000 Y4633.000
+M5
+G0 X4524.000 Y6897.000
+M3 S1000
+G1 X4708.000 Y6878.000 F1000
+G1 X4966.000 Y6851.000
+G1 X4968.000 Y6850.000
+G1 X4978.000 Y6849.000
+G1 X4988.000 Y6846.000
+G1 X4997.000 Y6843.000
+G1 X5005.000 Y6840.000
+G1 X5011.000 Y6836.000
+G1 X5018.000 Y6831.000
+G1 X5024.000 Y6825.000
+G1 X5028.000 Y6820.000
+G1 X5036.000 Y6810.000
+G1 X5041.000 Y6799.000
+G1 X5045.000 Y6789.000
+G1 X5046.000 Y6779.000
+G1 X5048.000 Y6766.000
+G1 X5047.000 Y6754.000
+G1 X5034.000 Y6638.000
+M5
+G0 X4274.000 Y6923.000
+M3 S1000
+G1 X4487.000 Y6901.000 F1000
+G1 X4524.000 Y6897.000
+M5
+G0 X5180.000 Y6599.000
+M3 S1000
+G1 X5171.000 Y6593.000 F1000
+G1 X5158.000 Y6589.000
+G1 X5151.000 Y6588.000
+G1 X5144.000 Y6589.000
+G1 X5138.000 Y6589.000
+G1 X5126.000 Y6592.000
+G1 X5115.000 Y6595.000
+G1 X5058.000 Y6626.000
+M5
+G0 X5206.000 Y6480.000
+M3 S1000
+G1 X5186.000 Y6457.000 F1000
+M5
+G0 X5168.000 Y6540.000
+M3 S1000
+G1 X5153.000 Y6543.000 F1000
+M5
+G0 X5174.000 Y6465.000
+M3 S1000
+G1 X5181.000 Y6459.000 F1000
+G1 X5186.000 Y6457.000
+M5
+G0 X5154.000 Y6419.000
+M3 S1000
+G1 X5159.000 Y6426.000 F1000
+G1 X5163.000 Y6433.000
+G1 X5166.000 Y6442.000
+G1 X5170.000 Y6453.000
+G1 X5172.000 Y6462.000
+G1 X5174.000 Y6465.000
+G1 X5170.000 Y6470.000
+G1 X5167.000 Y6473.000
+G1 X5162.000 Y6475.000
+G1 X5157.000 Y6476.000
+G1 X5151.000 Y6475.000
+G1 X5111.000 Y6477.000
+M5
+G0 X5157.000 Y6476.000
+M3 S1000
+G1 X5168.000 Y6540.000 F1000
+G1 X5177.000 Y6587.000
+G1 X5179.000 Y6594.000
+G1 X5180.000 Y6599.000
+M5
+G0 X5205.000 Y6372.000
+M3 S1000
+G1 X5154.000 Y6419.000 F1000
+G1 X5116.000 Y6452.000
+G1 X5108.000 Y6460.000
+M5
+G0 X4728.000 Y7081.000
+M3 S1000
+G1 X4731.000 Y7084.000 F1000
+G1 X4734.000 Y7097.000
+G1 X4738.000 Y7122.000
+G1 X4740.000 Y7125.000
+M5
+G0 X4054.000 Y7759.000
+M3 S1000
+G1 X4052.000 Y7743.000 F1000
+M5
+G0 X4052.000 Y7743.000
+M3 S1000
+G1 X4050.000 Y7735.000 F1000
+G1 X4026.000 Y7717.000
+G1 X4014.000 Y7717.000
+M5
+G0 X3924.000 Y7000.000
+M3 S1000
+G1 X3922.000 Y7006.000 F1000
+G1 X3918.000 Y7018.000
+G1 X3918.000 Y7022.000
+G1 X3917.000 Y7025.000
+G1 X3917.000 Y7029.000
+G1 X3918.000 Y7034.000
+G1 X3931.000 Y7162.000
+M5
+G0 X5709.000 Y1998.000
+M3 S1000
+G1 X5709.000 Y1990.000 F1000
+G1 X5709.000 Y1985.000
+G1 X5707.000 Y1979.000
+G1 X5678.000 Y1911.000
+G1 X5653.000 Y1853.000
+G1 X5634.000 Y1811.000
+G1 X5633.000 Y1807.000
+G1 X5633.000 Y1803.000
+G1 X5633.000 Y1799.000
+G1 X5634.000 Y1796.000
+M5
+G0 X4708.000 Y6878.000
+M3 S1000
+G1 X4705.000 Y6852.000 F1000
+M5
+G0 X4274.000 Y6923.000
+M3 S1000
+G1 X4271.000 Y6896.000 F1000
+M5
+G0 X5048.000 Y6529.000
+M3 S1000
+G1 X5030.000 Y6513.000 F1000
+M5
+G0 X5027.000 Y6574.000
+M3 S1000
+G1 X5027.000 Y6562.000 F1000
+G1 X5028.000 Y6556.000
+G1 X5030.000 Y6550.000
+G1 X5033.000 Y6546.000
+G1 X5036.000 Y6541.000
+G1 X5048.000 Y6529.000
+G1 X5099.000 Y6481.000
+G1 X5102.000 Y6477.000
+G1 X5103.000 Y6475.000
+G1 X5104.000 Y6473.000
+G1 X5104.000 Y6471.000
+G1 X5103.000 Y6469.000
+G1 X5101.000 Y6463.000
+G1 X5099.000 Y6461.000
+G1 X5095.000 Y6456.000
+G1 X5086.000 Y6447.000
+G1 X5081.000 Y6437.000
+M5
+G0 X3719.000 Y6305.000
+M3 S1000
+G1 X3676.000 Y5879.000 F1000
+M5
+G0 X3368.000 Y6244.000
+M3 S1000
+G1 X3371.000 Y6235.000 F1000
+G1 X3374.000 Y6226.000
+G1 X3383.000 Y6221.000
+G1 X3392.000 Y6212.000
+G1 X3416.000 Y6188.000
+G1 X3439.000 Y6161.000
+G1 X3462.000 Y6134.000
+G1 X3476.000 Y6116.000
+G1 X3482.000 Y6107.000
+G1 X3492.000 Y6097.000
+G1 X3557.000 Y6021.000
+G1 X3580.000 Y5992.000
+G1 X3659.000 Y5885.000
+G1 X3666.000 Y5881.000
+G1 X3676.000 Y5879.000
+M5
+G0 X3603.000 Y5788.000
+M3 S1000
+G1 X3439.000 Y5796.000 F1000
+M5
+G0 X3603.000 Y5788.000
+M3 S1000
+G1 X3601.000 Y5763.000 F1000
+M5
+G0 X3153.000 Y5548.000
+M3 S1000
+G1 X3181.000 Y5543.000 F1000
+G1 X3302.000 Y5537.000
+M5
+G0 X5750.000 Y5638.000
+M3 S1000
+G1 X5798.000 Y5643.000 F1000
+G1 X5902.000 Y5643.000
+G1 X5941.000 Y5642.000
+G1 X5959.000 Y5641.000
+M5
+G0 X3409.000 Y7141.000
+M3 S1000
+G1 X3394.000 Y7140.000 F1000
+G1 X3322.000 Y7140.000
+M5
+G0 X3408.000 Y7176.000
+M3 S1000
+G1 X3393.000 Y7176.000 F1000
+G1 X3316.000 Y7173.000
+M5
+G0 X3407.000 Y7213.000
+M3 S1000
+G1 X3393.000 Y7213.000 F1000
+G1 X3313.000 Y7209.000
+M5
+G0 X3409.000 Y7110.000
+M3 S1000
+G1 X3395.000 Y7115.000 F1000
+G1 X3323.000 Y7115.000
+G1 X3322.000 Y7140.000
+G1 X3321.000 Y7153.000
+G1 X3316.000 Y7173.000
+G1 X3313.000 Y7209.000
+G1 X3311.000 Y7243.000
+G1 X3391.000 Y7245.000
+G1 X3393.000 Y7213.000
+G1 X3393.000 Y7176.000
+G1 X3394.000 Y7140.000
+G1 X3395.000 Y7115.000
+M5
+G0 X3391.000 Y7245.000
+M3 S1000
+G1 X3406.000 Y7245.000 F1000
+M5
+G0 X5036.000 Y6573.000
+M3 S1000
+G1 X5035.000 Y6567.000 F1000
+G1 X5035.000 Y6563.000
+G1 X5036.000 Y6559.000
+G1 X5037.000 Y6555.000
+G1 X5039.000 Y6550.000
+G1 X5041.000 Y6547.000
+G1 X5044.000 Y6544.000
+G1 X5047.000 Y6541.000
+G1 X5107.000 Y6484.000
+G1 X5108.000 Y6482.000
+G1 X5110.000 Y6480.000
+G1 X5111.000 Y6477.000
+G1 X5111.000 Y6474.000
+G1 X5110.000 Y6469.000
+G1 X5110.000 Y6465.000
+G1 X5109.000 Y6463.000
+G1 X5108.000 Y6460.000
+M5
+G0 X8197.000 Y6820.000
+M3 S1000
+G1 X8199.000 Y6803.000 F1000
+M5
+G0 X8262.000 Y6820.000
+M3 S1000
+G1 X8257.000 Y6791.000 F1000
+M5
+G0 X3661.000 Y5253.000
+M3 S1000
+G1 X3702.000 Y5249.000 F1000
+M5
+G0 X4174.000 Y7361.000
+M3 S1000
+G1 X4189.000 Y7499.000 F1000
+G1 X4194.000 Y7506.000
+G1 X4412.000 Y7622.000
+G1 X4508.000 Y7525.000
+G1 X4513.000 Y7509.000
+G1 X4500.000 Y7433.000
+G1 X4487.000 Y7413.000
+G1 X4447.000 Y7371.000
+G1 X4437.000 Y7355.000
+G1 X4422.000 Y7235.000
+M5
+G0 X4211.000 Y6944.000
+M3 S1000
+G1 X4209.000 Y6929.000 F1000
+M5
+G0 X4557.000 Y7082.000
+M3 S1000
+G1 X4570.000 Y7163.000 F1000
+G1 X4574.000 Y7189.000
+G1 X4656.000 Y7711.000
+G1 X4951.000 Y9547.000
+M5
+G0 X3928.000 Y6982.000
+M3 S1000
+G1 X3927.000 Y6985.000 F1000
+G1 X3925.000 Y6993.000
+G1 X3924.000 Y7000.000
+M5
+G0 X4217.000 Y7023.000
+M3 S1000
+G1 X4191.000 Y7089.000 F1000
+G1 X4175.000 Y7136.000
+G1 X4163.000 Y7186.000
+G1 X4157.000 Y7220.000
+G1 X4159.000 Y7252.000
+G1 X4172.000 Y7358.000
+G1 X4174.000 Y7361.000
+M5
+G0 X4174.000 Y7361.000
+M3 S1000
+G1 X4182.000 Y7363.000 F1000
+G1 X4190.000 Y7363.000
+G1 X4220.000 Y7358.000
+G1 X4225.000 Y7355.000
+G1 X4230.000 Y7349.000
+G1 X4294.000 Y7267.000
+G1 X4335.000 Y7208.000
+G1 X4338.000 Y7205.000
+G1 X4344.000 Y7206.000
+G1 X4349.000 Y7207.000
+G1 X4404.000 Y7235.000
+G1 X4411.000 Y7236.000
+G1 X4422.000 Y7235.000
+M5
+G0 X4422.000 Y7235.000
+M3 S1000
+G1 X4431.000 Y7232.000 F1000
+G1 X4435.000 Y7227.000
+G1 X4475.000 Y7175.000
+G1 X4540.000 Y7097.000
+G1 X4553.000 Y7087.000
+G1 X4557.000 Y7082.000
+M5
+G0 X3237.000 Y7296.000
+M3 S1000
+G1 X3249.000 Y7297.000 F1000
+M5
+G0 X3257.000 Y7074.000
+M3 S1000
+G1 X3258.000 Y7069.000 F1000
+G1 X3258.000 Y7054.000
+M5
+G0 X3249.000 Y7297.000
+M3 S1000
+G1 X3257.000 Y7074.000 F1000
+M5
+G0 X3237.000 Y7296.000
+M3 S1000
+G1 X3238.000 Y7279.000 F1000
+G1 X3244.000 Y7078.000
+G1 X3244.000 Y7069.000
+G1 X3245.000 Y7067.000
+G1 X3246.000 Y7064.000
+G1 X3247.000 Y7062.000
+G1 X3248.000 Y7060.000
+G1 X3251.000 Y7053.000
+M5
+G0 X9535.000 Y4442.000
+M3 S1000
+G1 X9543.000 Y4434.000 F1000
+G1 X9552.000 Y4431.000
+G1 X9559.000 Y4431.000
+G1 X9640.000 Y4445.000
+G1 X9702.000 Y4461.000
+G1 X9788.000 Y4492.000
+G1 X9870.000 Y4525.000
+M5
+G0 X3729.000 Y5754.000
+M3 S1000
+G1 X3755.000 Y5750.000 F1000
+M5
+G0 X3630.000 Y6836.000
+M3 S1000
+G1 X3630.000 Y6816.000 F1000
+G1 X3631.000 Y6808.000
+M5
+G0 X3631.000 Y6808.000
+M3 S1000
+G1 X3631.000 Y6804.000 F1000
+G1 X3630.000 Y6800.000
+G1 X3629.000 Y6798.000
+G1 X3626.000 Y6793.000
+G1 X3622.000 Y6787.000
+M5
+G0 X3755.000 Y5737.000
+M3 S1000
+G1 X4020.000 Y5703.000 F1000
+M5
+G0 X4911.000 Y5666.000
+M3 S1000
+G1 X4914.000 Y5659.000 F1000
+G1 X4900.000 Y5582.000
+G1 X4913.000 Y5551.000
+G1 X4924.000 Y5529.000
+M5
+G0 X5080.000 Y5830.000
+M3 S1000
+G1 X5069.000 Y5833.000 F1000
+G1 X5055.000 Y5836.000
+G1 X5036.000 Y5837.000
+G1 X5015.000 Y5839.000
+M5
+G0 X5015.000 Y5839.000
+M3 S1000
+G1 X5014.000 Y5827.000 F1000
+M5
+G0 X5015.000 Y5839.000
+M3 S1000
+G1 X4999.000 Y5844.000 F1000
+G1 X4975.000 Y5847.000
+G1 X4947.000 Y5848.000
+G1 X4925.000 Y5849.000
+G1 X4875.000 Y5848.000
+G1 X4826.000 Y5845.000
+G1 X4807.000 Y5843.000
+M5
+G0 X5117.000 Y6010.000
+M3 S1000
+G1 X5141.000 Y6016.000 F1000
+G1 X5145.000 Y6007.000
+G1 X5146.000 Y6002.000
+M5
+G0 X5056.000 Y6077.000
+M3 S1000
+G1 X5081.000 Y6114.000 F1000
+G1 X5084.000 Y6117.000
+G1 X5087.000 Y6118.000
+G1 X5091.000 Y6118.000
+G1 X5095.000 Y6118.000
+G1 X5101.000 Y6116.000
+G1 X5109.000 Y6111.000
+G1 X5113.000 Y6110.000
+G1 X5116.000 Y6108.000
+M5
+G0 X5116.000 Y6108.000
+M3 S1000
+G1 X5134.000 Y6082.000 F1000
+G1 X5136.000 Y6079.000
+G1 X5139.000 Y6078.000
+G1 X5141.000 Y6076.000
+G1 X5145.000 Y6075.000
+G1 X5148.000 Y6075.000
+M5
+G0 X5116.000 Y6014.000
+M3 S1000
+G1 X5117.000 Y6010.000 F1000
+M5
+G0 X5112.000 Y6030.000
+M3 S1000
+G1 X5116.000 Y6014.000 F1000
+M5
+G0 X5056.000 Y6077.000
+M3 S1000
+G1 X5061.000 Y6071.000 F1000
+G1 X5071.000 Y6054.000
+G1 X5078.000 Y6049.000
+G1 X5092.000 Y6040.000
+G1 X5100.000 Y6037.000
+G1 X5105.000 Y6036.000
+G1 X5109.000 Y6034.000
+G1 X5112.000 Y6030.000
+M5
+G0 X5254.000 Y6042.000
+M3 S1000
+G1 X5209.000 Y6052.000 F1000
+M5
+G0 X5180.000 Y6318.000
+M3 S1000
+G1 X5175.000 Y6313.000 F1000
+G1 X5163.000 Y6309.000
+G1 X5152.000 Y6306.000
+M5
+G0 X5108.000 Y6460.000
+M3 S1000
+G1 X5106.000 Y6457.000 F1000
+G1 X5100.000 Y6451.000
+G1 X5091.000 Y6441.000
+G1 X5081.000 Y6437.000
+M5
+G0 X5036.000 Y6573.000
+M3 S1000
+G1 X5044.000 Y6573.000 F1000
+G1 X5079.000 Y6569.000
+M5
+G0 X5138.000 Y6762.000
+M3 S1000
+G1 X5145.000 Y6766.000 F1000
+G1 X5149.000 Y6767.000
+G1 X5168.000 Y6762.000
+G1 X5175.000 Y6762.000
+G1 X5179.000 Y6764.000
+G1 X5182.000 Y6767.000
+G1 X5185.000 Y6770.000
+G1 X5204.000 Y6802.000
+M5
+G0 X4596.000 Y6970.000
+M3 S1000
+G1 X4647.000 Y7019.000 F1000
+M5
+G0 X5205.000 Y6372.000
+M3 S1000
+G1 X5198.000 Y6364.000 F1000
+M5
+G0 X5167.000 Y6328.000
+M3 S1000
+G1 X5198.000 Y6364.000 F1000
+M5
+G0 X5247.000 Y6263.000
+M3 S1000
+G1 X5220.000 Y6267.000 F1000
+G1 X5206.000 Y6267.000
+M5
+G0 X5346.000 Y6298.000
+M3 S1000
+G1 X5305.000 Y6306.000 F1000
+G1 X5227.000 Y6323.000
+G1 X5216.000 Y6325.000
+G1 X5194.000 Y6326.000
+G1 X5186.000 Y6324.000
+G1 X5180.000 Y6318.000
+M5
+G0 X5180.000 Y6318.000
+M3 S1000
+G1 X5167.000 Y6328.000 F1000
+M5
+G0 X5128.000 Y6305.000
+M3 S1000
+G1 X5129.000 Y6315.000 F1000
+M5
+G0 X5152.000 Y6306.000
+M3 S1000
+G1 X5128.000 Y6305.000 F1000
+G1 X5119.000 Y6305.000
+G1 X5113.000 Y6304.000
+G1 X5059.000 Y6310.000
+M5
+G0 X5254.000 Y6042.000
+M3 S1000
+G1 X5286.000 Y6082.000 F1000
+M5
+G0 X5327.000 Y6075.000
+M3 S1000
+G1 X5334.000 Y6065.000 F1000
+G1 X5378.000 Y6006.000
+G1 X5390.000 Y5993.000
+M5
+G0 X5477.000 Y5624.000
+M3 S1000
+G1 X5548.000 Y5631.000 F1000
+G1 X5635.000 Y5639.000
+G1 X5657.000 Y5641.000
+G1 X5749.000 Y5649.000
+M5
+G0 X2410.000 Y5487.000
+M3 S1000
+G1 X2408.000 Y5482.000 F1000
+G1 X2406.000 Y5476.000
+G1 X2407.000 Y5437.000
+G1 X2404.000 Y5423.000
+G1 X2399.000 Y5417.000
+G1 X2382.000 Y5411.000
+M5
+G0 X2426.000 Y5310.000
+M3 S1000
+G1 X2426.000 Y5325.000 F1000
+G1 X2426.000 Y5383.000
+G1 X2422.000 Y5388.000
+G1 X2418.000 Y5389.000
+G1 X2402.000 Y5388.000
+G1 X2394.000 Y5392.000
+G1 X2386.000 Y5400.000
+G1 X2382.000 Y5411.000
+M5
+G0 X3203.000 Y4894.000
+M3 S1000
+G1 X3194.000 Y4789.000 F1000
+G1 X3197.000 Y4777.000
+M5
+G0 X3486.000 Y4687.000
+M3 S1000
+G1 X3492.000 Y4687.000 F1000
+G1 X3628.000 Y4726.000
+M5
+G0 X3628.000 Y4726.000
+M3 S1000
+G1 X3724.000 Y4739.000 F1000
+G1 X3859.000 Y4755.000
+M5
+G0 X3913.000 Y4869.000
+M3 S1000
+G1 X3873.000 Y4787.000 F1000
+M5
+G0 X3994.000 Y5013.000
+M3 S1000
+G1 X3913.000 Y4869.000 F1000
+M5
+G0 X4211.000 Y5040.000
+M3 S1000
+G1 X4188.000 Y4904.000 F1000
+G1 X4182.000 Y4883.000
+M5
+G0 X4362.000 Y5070.000
+M3 S1000
+G1 X4265.000 Y5088.000 F1000
+G1 X4242.000 Y5081.000
+G1 X4222.000 Y5058.000
+G1 X4211.000 Y5040.000
+M5
+G0 X4353.000 Y5016.000
+M3 S1000
+G1 X4266.000 Y4569.000 F1000
+G1 X4264.000 Y4561.000
+G1 X4261.000 Y4555.000
+G1 X4257.000 Y4548.000
+G1 X4253.000 Y4544.000
+G1 X4248.000 Y4540.000
+G1 X4229.000 Y4529.000
+M5
+G0 X4228.000 Y5554.000
+M3 S1000
+G1 X4232.000 Y5540.000 F1000
+G1 X4236.000 Y5526.000
+G1 X4240.000 Y5517.000
+G1 X4245.000 Y5507.000
+G1 X4272.000 Y5453.000
+G1 X4288.000 Y5420.000
+M5
+G0 X3729.000 Y4553.000
+M3 S1000
+G1 X3772.000 Y4539.000 F1000
+G1 X3929.000 Y4494.000
+G1 X4077.000 Y4448.000
+G1 X4215.000 Y4405.000
+G1 X4226.000 Y4402.000
+M5
+G0 X5709.000 Y1998.000
+M3 S1000
+G1 X5706.000 Y2004.000 F1000
+G1 X5703.000 Y2008.000
+G1 X5694.000 Y2014.000
+G1 X5687.000 Y2019.000
+G1 X5538.000 Y2099.000
+G1 X5528.000 Y2106.000
+G1 X5519.000 Y2114.000
+G1 X5497.000 Y2141.000
+G1 X5474.000 Y2161.000
+G1 X5268.000 Y2289.000
+G1 X5094.000 Y2435.000
+G1 X5090.000 Y2439.000
+G1 X5086.000 Y2444.000
+G1 X5084.000 Y2449.000
+G1 X5082.000 Y2454.000
+G1 X5081.000 Y2459.000
+G1 X5081.000 Y2464.000
+G1 X5095.000 Y2511.000
+G1 X5097.000 Y2518.000
+G1 X5097.000 Y2524.000
+G1 X5096.000 Y2531.000
+G1 X5093.000 Y2537.000
+G1 X5090.000 Y2542.000
+G1 X5085.000 Y2546.000
+G1 X5079.000 Y2550.000
+G1 X4828.000 Y2670.000
+G1 X4819.000 Y2675.000
+G1 X4810.000 Y2680.000
+G1 X4802.000 Y2687.000
+G1 X4792.000 Y2695.000
+G1 X4784.000 Y2704.000
+G1 X4772.000 Y2720.000
+G1 X4760.000 Y2739.000
+G1 X4713.000 Y2824.000
+G1 X4684.000 Y2888.000
+G1 X4671.000 Y2922.000
+G1 X4666.000 Y2940.000
+G1 X4663.000 Y2951.000
+M5
+G0 X5403.000 Y4794.000
+M3 S1000
+G1 X5435.000 Y4766.000 F1000
+G1 X5442.000 Y4760.000
+G1 X5445.000 Y4758.000
+G1 X5449.000 Y4756.000
+G1 X5453.000 Y4754.000
+G1 X5455.000 Y4754.000
+G1 X5458.000 Y4753.000
+G1 X5460.000 Y4753.000
+G1 X5465.000 Y4752.000
+G1 X5468.000 Y4751.000
+G1 X5471.000 Y4749.000
+G1 X5473.000 Y4748.000
+G1 X5475.000 Y4744.000
+G1 X5476.000 Y4742.000
+G1 X5478.000 Y4738.000
+G1 X5479.000 Y4736.000
+G1 X5480.000 Y4732.000
+G1 X5482.000 Y4729.000
+G1 X5484.000 Y4728.000
+G1 X5487.000 Y4727.000
+G1 X5492.000 Y4724.000
+G1 X5530.000 Y4713.000
+G1 X5567.000 Y4702.000
+G1 X5586.000 Y4696.000
+M5
+G0 X5750.000 Y5638.000
+M3 S1000
+G1 X5750.000 Y5620.000 F1000
+G1 X5716.000 Y5497.000
+G1 X5681.000 Y5371.000
+G1 X5654.000 Y5279.000
+G1 X5649.000 Y5265.000
+G1 X5648.000 Y5260.000
+G1 X5648.000 Y5253.000
+G1 X5649.000 Y5246.000
+G1 X5652.000 Y5239.000
+G1 X5706.000 Y5165.000
+G1 X5708.000 Y5160.000
+G1 X5710.000 Y5155.000
+G1 X5711.000 Y5150.000
+G1 X5710.000 Y5144.000
+G1 X5696.000 Y5091.000
+G1 X5679.000 Y5027.000
+G1 X5672.000 Y5011.000
+G1 X5656.000 Y4955.000
+G1 X5653.000 Y4933.000
+G1 X5636.000 Y4869.000
+G1 X5618.000 Y4803.000
+G1 X5608.000 Y4778.000
+G1 X5586.000 Y4696.000
+M5
+G0 X6498.000 Y3414.000
+M3 S1000
+G1 X6722.000 Y3428.000 F1000
+G1 X6783.000 Y3428.000
+G1 X6978.000 Y3421.000
+G1 X6985.000 Y3419.000
+G1 X6992.000 Y3418.000
+G1 X6997.000 Y3417.000
+G1 X7003.000 Y3414.000
+M5
+G0 X3949.000 Y5913.000
+M3 S1000
+G1 X4012.000 Y5927.000 F1000
+G1 X4131.000 Y5909.000
+G1 X4207.000 Y5898.000
+G1 X4212.000 Y5897.000
+G1 X4285.000 Y5884.000
+M5
+G0 X3903.000 Y6899.000
+M3 S1000
+G1 X3873.000 Y6638.000 F1000
+G1 X3856.000 Y6477.000
+G1 X3851.000 Y6440.000
+G1 X3833.000 Y6327.000
+G1 X3820.000 Y6233.000
+G1 X3787.000 Y5907.000
+G1 X3796.000 Y5905.000
+M5
+G0 X3912.000 Y6892.000
+M3 S1000
+G1 X3903.000 Y6899.000 F1000
+M5
+G0 X4214.000 Y9740.000
+M3 S1000
+G1 X4205.000 Y9732.000 F1000
+M5
+G0 X1720.000 Y3516.000
+M3 S1000
+G1 X1683.000 Y3524.000 F1000
+G1 X1594.000 Y3538.000
+G1 X887.000 Y3658.000
+G1 X877.000 Y3663.000
+G1 X853.000 Y3683.000
+G1 X804.000 Y3693.000
+G1 X657.000 Y3716.000
+G1 X532.000 Y3737.000
+G1 X520.000 Y3737.000
+G1 X493.000 Y3728.000
+G1 X482.000 Y3727.000
+G1 X474.000 Y3728.000
+M5
+G0 X4241.000 Y4474.000
+M3 S1000
+G1 X4236.000 Y4446.000 F1000
+G1 X4226.000 Y4402.000
+M5
+G0 X6417.000 Y5623.000
+M3 S1000
+G1 X6420.000 Y5634.000 F1000
+G1 X6429.000 Y5661.000
+G1 X6667.000 Y6372.000
+M5
+G0 X4123.000 Y5157.000
+M3 S1000
+G1 X4077.000 Y5115.000 F1000
+G1 X4016.000 Y5060.000
+G1 X4002.000 Y5044.000
+G1 X3999.000 Y5036.000
+G1 X3994.000 Y5013.000
+M5
+G0 X4137.000 Y5168.000
+M3 S1000
+G1 X4123.000 Y5157.000 F1000
+M5
+G0 X3701.000 Y5189.000
+M3 S1000
+G1 X3710.000 Y5184.000 F1000
+M5
+G0 X3693.000 Y5191.000
+M3 S1000
+G1 X3701.000 Y5189.000 F1000
+M5
+G0 X3984.000 Y5176.000
+M3 S1000
+G1 X3968.000 Y5053.000 F1000
+M5
+G0 X3987.000 Y5194.000
+M3 S1000
+G1 X3984.000 Y5176.000 F1000
+M5
+G0 X4009.000 Y5413.000
+M3 S1000
+G1 X4009.000 Y5404.000 F1000
+M5
+G0 X3991.000 Y5431.000
+M3 S1000
+G1 X3993.000 Y5441.000 F1000
+G1 X4001.000 Y5448.000
+G1 X4011.000 Y5450.000
+M5
+G0 X4011.000 Y5461.000
+M3 S1000
+G1 X4011.000 Y5450.000 F1000
+M5
+G0 X4012.000 Y5538.000
+M3 S1000
+G1 X4046.000 Y5537.000 F1000
+G1 X4053.000 Y5535.000
+G1 X4055.000 Y5529.000
+G1 X4055.000 Y5521.000
+G1 X4052.000 Y5515.000
+G1 X4045.000 Y5512.000
+G1 X4012.000 Y5513.000
+M5
+G0 X7922.000 Y5461.000
+M3 S1000
+G1 X7885.000 Y5434.000 F1000
+G1 X7861.000 Y5405.000
+G1 X7826.000 Y5337.000
+G1 X7605.000 Y4682.000
+G1 X7546.000 Y4516.000
+G1 X7489.000 Y4381.000
+G1 X7421.000 Y4252.000
+G1 X7255.000 Y3932.000
+G1 X7219.000 Y3870.000
+G1 X7195.000 Y3836.000
+G1 X7163.000 Y3804.000
+G1 X7124.000 Y3773.000
+G1 X7087.000 Y3753.000
+G1 X7033.000 Y3726.000
+M5
+G0 X4223.000 Y4094.000
+M3 S1000
+G1 X4187.000 Y4103.000 F1000
+G1 X4180.000 Y4104.000
+M5
+G0 X5979.000 Y3850.000
+M3 S1000
+G1 X6443.000 Y3710.000 F1000
+G1 X6638.000 Y3653.000
+G1 X6677.000 Y3651.000
+G1 X6737.000 Y3657.000
+G1 X6935.000 Y3711.000
+G1 X6966.000 Y3725.000
+G1 X6987.000 Y3749.000
+G1 X7000.000 Y3755.000
+G1 X7012.000 Y3752.000
+G1 X7023.000 Y3744.000
+G1 X7033.000 Y3726.000
+G1 X7038.000 Y3705.000
+G1 X7037.000 Y3695.000
+G1 X7032.000 Y3684.000
+G1 X7006.000 Y3671.000
+G1 X6943.000 Y3650.000
+G1 X6940.000 Y3648.000
+M5
+G0 X6691.000 Y3590.000
+M3 S1000
+G1 X6663.000 Y3584.000 F1000
+G1 X6619.000 Y3579.000
+G1 X6581.000 Y3581.000
+G1 X6543.000 Y3587.000
+G1 X6509.000 Y3598.000
+G1 X6251.000 Y3697.000
+G1 X6142.000 Y3734.000
+G1 X5887.000 Y3812.000
+G1 X5007.000 Y4086.000
+G1 X4960.000 Y4099.000
+G1 X4883.000 Y4105.000
+G1 X4824.000 Y4097.000
+G1 X4769.000 Y4081.000
+G1 X4699.000 Y4047.000
+G1 X4663.000 Y4030.000
+G1 X4625.000 Y4020.000
+G1 X4564.000 Y4017.000
+G1 X4545.000 Y4019.000
+G1 X4525.000 Y4023.000
+G1 X4488.000 Y4031.000
+G1 X4223.000 Y4094.000
+M5
+G0 X7922.000 Y5461.000
+M3 S1000
+G1 X7911.000 Y5470.000 F1000
+G1 X7901.000 Y5478.000
+G1 X7883.000 Y5489.000
+G1 X7864.000 Y5495.000
+G1 X7735.000 Y5496.000
+G1 X7631.000 Y5499.000
+G1 X7399.000 Y5517.000
+G1 X7317.000 Y5523.000
+G1 X7266.000 Y5534.000
+G1 X7181.000 Y5553.000
+M5
+G0 X3744.000 Y4524.000
+M3 S1000
+G1 X3740.000 Y4507.000 F1000
+G1 X3739.000 Y4505.000
+G1 X3732.000 Y4488.000
+M5
+G0 X3098.000 Y5700.000
+M3 S1000
+G1 X3162.000 Y5696.000 F1000
+M5
+G0 X4374.000 Y5012.000
+M3 S1000
+G1 X4391.000 Y5009.000 F1000
+M5
+G0 X3572.000 Y5602.000
+M3 S1000
+G1 X3587.000 Y5612.000 F1000
+M5
+G0 X2444.000 Y5590.000
+M3 S1000
+G1 X2601.000 Y5580.000 F1000
+G1 X2615.000 Y5573.000
+M5
+G0 X5422.000 Y5608.000
+M3 S1000
+G1 X5476.000 Y5614.000 F1000
+M5
+G0 X5371.000 Y5682.000
+M3 S1000
+G1 X5388.000 Y5639.000 F1000
+G1 X5393.000 Y5627.000
+M5
+G0 X4209.000 Y6929.000
+M3 S1000
+G1 X4274.000 Y6923.000 F1000
+M5
+G0 X4524.000 Y6897.000
+M3 S1000
+G1 X4527.000 Y6915.000 F1000
+G1 X4536.000 Y6966.000
+M5
+G0 X4687.000 Y7941.000
+M3 S1000
+G1 X4627.000 Y7566.000 F1000
+G1 X4569.000 Y7189.000
+G1 X4554.000 Y7093.000
+G1 X4553.000 Y7087.000
+M5
+G0 X4966.000 Y6851.000
+M3 S1000
+G1 X4963.000 Y6824.000 F1000
+M5
+G0 X5081.000 Y6437.000
+M3 S1000
+G1 X5069.000 Y6384.000 F1000
+G1 X5068.000 Y6380.000
+G1 X5063.000 Y6328.000
+G1 X5062.000 Y6323.000
+M5
+G0 X5069.000 Y6384.000
+M3 S1000
+G1 X5061.000 Y6386.000 F1000
+G1 X5050.000 Y6390.000
+G1 X5036.000 Y6397.000
+G1 X5031.000 Y6402.000
+G1 X5034.000 Y6430.000
+G1 X4996.000 Y6435.000
+M5
+G0 X2546.000 Y5018.000
+M3 S1000
+G1 X2547.000 Y5013.000 F1000
+G1 X2548.000 Y4975.000
+G1 X2548.000 Y4964.000
+M5
+G0 X2641.000 Y5373.000
+M3 S1000
+G1 X2623.000 Y5372.000 F1000
+M5
+G0 X2637.000 Y5473.000
+M3 S1000
+G1 X2619.000 Y5472.000 F1000
+M5
+G0 X2785.000 Y4877.000
+M3 S1000
+G1 X2777.000 Y4877.000 F1000
+M5
+G0 X2766.000 Y4876.000
+M3 S1000
+G1 X2777.000 Y4877.000 F1000
+M5
+G0 X4362.000 Y5070.000
+M3 S1000
+G1 X4353.000 Y5016.000 F1000
+M5
+G0 X3262.000 Y4682.000
+M3 S1000
+G1 X3365.000 Y4649.000 F1000
+G1 X3395.000 Y4647.000
+G1 X3405.000 Y4647.000
+G1 X3415.000 Y4646.000
+G1 X3426.000 Y4651.000
+M5
+G0 X5276.000 Y4978.000
+M3 S1000
+G1 X5091.000 Y5022.000 F1000
+G1 X5075.000 Y5031.000
+M5
+G0 X4710.000 Y4936.000
+M3 S1000
+G1 X4708.000 Y4914.000 F1000
+G1 X4694.000 Y4842.000
+G1 X4680.000 Y4771.000
+G1 X4671.000 Y4724.000
+G1 X4668.000 Y4709.000
+G1 X4664.000 Y4681.000
+G1 X4658.000 Y4646.000
+G1 X4652.000 Y4609.000
+G1 X4646.000 Y4575.000
+G1 X4644.000 Y4561.000
+G1 X4638.000 Y4532.000
+G1 X4632.000 Y4505.000
+G1 X4624.000 Y4469.000
+G1 X4614.000 Y4431.000
+G1 X4613.000 Y4425.000
+G1 X4606.000 Y4383.000
+G1 X4599.000 Y4345.000
+G1 X4593.000 Y4314.000
+G1 X4584.000 Y4264.000
+G1 X4580.000 Y4250.000
+G1 X4572.000 Y4220.000
+G1 X4570.000 Y4214.000
+M5
+G0 X4593.000 Y4314.000
+M3 S1000
+G1 X4601.000 Y4312.000 F1000
+G1 X4686.000 Y4287.000
+G1 X4695.000 Y4288.000
+M5
+G0 X5634.000 Y1796.000
+M3 S1000
+G1 X5639.000 Y1785.000 F1000
+G1 X5665.000 Y1727.000
+G1 X5681.000 Y1687.000
+G1 X5705.000 Y1612.000
+G1 X5728.000 Y1567.000
+G1 X5856.000 Y1362.000
+G1 X5898.000 Y1294.000
+G1 X5941.000 Y1215.000
+G1 X5949.000 Y1199.000
+M5
+G0 X4651.000 Y4502.000
+M3 S1000
+G1 X4749.000 Y4486.000 F1000
+M5
+G0 X4639.000 Y4504.000
+M3 S1000
+G1 X4651.000 Y4502.000 F1000
+M5
+G0 X4632.000 Y4505.000
+M3 S1000
+G1 X4639.000 Y4504.000 F1000
+M5
+G0 X4218.000 Y5663.000
+M3 S1000
+G1 X4222.000 Y5656.000 F1000
+G1 X4225.000 Y5647.000
+G1 X4226.000 Y5642.000
+G1 X4226.000 Y5637.000
+G1 X4225.000 Y5591.000
+G1 X4225.000 Y5583.000
+G1 X4225.000 Y5574.000
+G1 X4227.000 Y5564.000
+G1 X4228.000 Y5554.000
+M5
+G0 X4226.000 Y4402.000
+M3 S1000
+G1 X4217.000 Y4361.000 F1000
+G1 X4212.000 Y4331.000
+M5
+G0 X5020.000 Y6020.000
+M3 S1000
+G1 X5023.000 Y6023.000 F1000
+G1 X5025.000 Y6027.000
+G1 X5032.000 Y6044.000
+G1 X5037.000 Y6053.000
+G1 X5042.000 Y6061.000
+G1 X5056.000 Y6077.000
+M5
+G0 X5419.000 Y6020.000
+M3 S1000
+G1 X5448.000 Y5968.000 F1000
+M5
+G0 X5548.000 Y6228.000
+M3 S1000
+G1 X5569.000 Y6246.000 F1000
+G1 X5591.000 Y6332.000
+M5
+G0 X5189.000 Y6599.000
+M3 S1000
+G1 X5180.000 Y6599.000 F1000
+M5
+G0 X5199.000 Y6597.000
+M3 S1000
+G1 X5189.000 Y6599.000 F1000
+M5
+G0 X5220.000 Y6593.000
+M3 S1000
+G1 X5213.000 Y6595.000 F1000
+G1 X5199.000 Y6597.000
+M5
+G0 X5617.000 Y5881.000
+M3 S1000
+G1 X5641.000 Y5875.000 F1000
+G1 X5643.000 Y5865.000
+M5
+G0 X5575.000 Y6144.000
+M3 S1000
+G1 X5555.000 Y6059.000 F1000
+G1 X5559.000 Y6045.000
+M5
+G0 X5999.000 Y4164.000
+M3 S1000
+G1 X5996.000 Y4158.000 F1000
+M5
+G0 X5942.000 Y4224.000
+M3 S1000
+G1 X5943.000 Y4221.000 F1000
+G1 X5944.000 Y4220.000
+G1 X5945.000 Y4218.000
+G1 X5946.000 Y4216.000
+G1 X5973.000 Y4189.000
+G1 X5998.000 Y4169.000
+G1 X5999.000 Y4167.000
+G1 X5999.000 Y4166.000
+G1 X5999.000 Y4165.000
+G1 X5999.000 Y4164.000
+M5
+G0 X5936.000 Y4230.000
+M3 S1000
+G1 X5942.000 Y4224.000 F1000
+M5
+G0 X5586.000 Y4696.000
+M3 S1000
+G1 X5644.000 Y4679.000 F1000
+G1 X5702.000 Y4663.000
+G1 X5727.000 Y4656.000
+G1 X5754.000 Y4648.000
+G1 X5779.000 Y4638.000
+G1 X5814.000 Y4624.000
+G1 X5816.000 Y4623.000
+G1 X5818.000 Y4622.000
+G1 X5819.000 Y4619.000
+G1 X5820.000 Y4616.000
+G1 X5819.000 Y4613.000
+G1 X5801.000 Y4558.000
+G1 X5779.000 Y4487.000
+G1 X5753.000 Y4407.000
+G1 X5742.000 Y4373.000
+G1 X5741.000 Y4370.000
+G1 X5743.000 Y4366.000
+G1 X5745.000 Y4363.000
+G1 X5748.000 Y4361.000
+G1 X5758.000 Y4354.000
+G1 X5821.000 Y4319.000
+G1 X5873.000 Y4289.000
+G1 X5883.000 Y4282.000
+G1 X5891.000 Y4275.000
+G1 X5917.000 Y4250.000
+G1 X5936.000 Y4230.000
+M5
+G0 X5420.000 Y4636.000
+M3 S1000
+G1 X5427.000 Y4633.000 F1000
+M5
+G0 X5291.000 Y4144.000
+M3 S1000
+G1 X5335.000 Y4314.000 F1000
+G1 X5420.000 Y4636.000
+M5
+G0 X5108.000 Y6562.000
+M3 S1000
+G1 X5094.000 Y6568.000 F1000
+G1 X5079.000 Y6569.000
+M5
+G0 X5058.000 Y6626.000
+M3 S1000
+G1 X5034.000 Y6638.000 F1000
+M5
+G0 X5034.000 Y6638.000
+M3 S1000
+G1 X5027.000 Y6574.000 F1000
+M5
+G0 X5027.000 Y6574.000
+M3 S1000
+G1 X5036.000 Y6573.000 F1000
+M5
+G0 X5003.000 Y6577.000
+M3 S1000
+G1 X5017.000 Y6576.000 F1000
+M5
+G0 X4920.000 Y6601.000
+M3 S1000
+G1 X5002.000 Y6592.000 F1000
+M5
+G0 X4962.000 Y6811.000
+M3 S1000
+G1 X4957.000 Y6759.000 F1000
+G1 X5018.000 Y6753.000
+G1 X5008.000 Y6649.000
+G1 X5002.000 Y6592.000
+G1 X5004.000 Y6592.000
+G1 X5003.000 Y6577.000
+G1 X4997.000 Y6530.000
+G1 X5024.000 Y6506.000
+M5
+G0 X4027.000 Y6905.000
+M3 S1000
+G1 X4269.000 Y6880.000 F1000
+G1 X4483.000 Y6858.000
+G1 X4703.000 Y6834.000
+G1 X4708.000 Y6836.000
+G1 X4789.000 Y6828.000
+G1 X4790.000 Y6834.000
+G1 X4863.000 Y6827.000
+G1 X4862.000 Y6821.000
+G1 X4962.000 Y6811.000
+G1 X4963.000 Y6824.000
+M5
+G0 X3920.000 Y6949.000
+M3 S1000
+G1 X3932.000 Y6939.000 F1000
+G1 X3941.000 Y6932.000
+G1 X3967.000 Y6908.000
+G1 X3996.000 Y6909.000
+G1 X4027.000 Y6905.000
+M5
+G0 X5296.000 Y6096.000
+M3 S1000
+G1 X5303.000 Y6105.000 F1000
+M5
+G0 X5286.000 Y6082.000
+M3 S1000
+G1 X5296.000 Y6096.000 F1000
+M5
+G0 X5317.000 Y6091.000
+M3 S1000
+G1 X5327.000 Y6075.000 F1000
+M5
+G0 X5303.000 Y6105.000
+M3 S1000
+G1 X5317.000 Y6091.000 F1000
+M5
+G0 X5119.000 Y6305.000
+M3 S1000
+G1 X5115.000 Y6226.000 F1000
+G1 X5115.000 Y6221.000
+G1 X5113.000 Y6215.000
+G1 X5089.000 Y6140.000
+G1 X5084.000 Y6132.000
+G1 X5083.000 Y6125.000
+M5
+G0 X5127.000 Y5806.000
+M3 S1000
+G1 X5130.000 Y5816.000 F1000
+G1 X5139.000 Y5861.000
+M5
+G0 X5132.000 Y5907.000
+M3 S1000
+G1 X5130.000 Y5897.000 F1000
+G1 X5135.000 Y5896.000
+G1 X5128.000 Y5864.000
+G1 X5139.000 Y5861.000
+G1 X5187.000 Y5851.000
+M5
+G0 X5183.000 Y5898.000
+M3 S1000
+G1 X5132.000 Y5907.000 F1000
+G1 X5141.000 Y5945.000
+G1 X5143.000 Y5945.000
+G1 X5147.000 Y5960.000
+M5
+G0 X5078.000 Y5910.000
+M3 S1000
+G1 X5081.000 Y5910.000 F1000
+G1 X5086.000 Y5908.000
+G1 X5095.000 Y5905.000
+G1 X5130.000 Y5897.000
+M5
+G0 X5068.000 Y5912.000
+M3 S1000
+G1 X5078.000 Y5910.000 F1000
+M5
+G0 X5021.000 Y5922.000
+M3 S1000
+G1 X5031.000 Y5919.000 F1000
+G1 X5036.000 Y5917.000
+G1 X5043.000 Y5912.000
+G1 X5047.000 Y5909.000
+G1 X5051.000 Y5906.000
+G1 X5053.000 Y5905.000
+G1 X5056.000 Y5905.000
+G1 X5058.000 Y5906.000
+G1 X5065.000 Y5911.000
+G1 X5066.000 Y5912.000
+G1 X5068.000 Y5912.000
+M5
+G0 X5016.000 Y5912.000
+M3 S1000
+G1 X5001.000 Y5916.000 F1000
+G1 X4994.000 Y5919.000
+G1 X4986.000 Y5920.000
+G1 X4984.000 Y5906.000
+M5
+G0 X5000.000 Y6014.000
+M3 S1000
+G1 X5009.000 Y6015.000 F1000
+G1 X5014.000 Y6016.000
+G1 X5018.000 Y6018.000
+G1 X5020.000 Y6020.000
+M5
+G0 X5141.000 Y6016.000
+M3 S1000
+G1 X5162.000 Y6030.000 F1000
+M5
+G0 X5173.000 Y6074.000
+M3 S1000
+G1 X5172.000 Y6059.000 F1000
+G1 X5172.000 Y6053.000
+G1 X5171.000 Y6047.000
+G1 X5170.000 Y6040.000
+G1 X5169.000 Y6034.000
+M5
+G0 X5162.000 Y6030.000
+M3 S1000
+G1 X5164.000 Y6032.000 F1000
+G1 X5166.000 Y6033.000
+G1 X5169.000 Y6034.000
+G1 X5171.000 Y6032.000
+G1 X5171.000 Y6029.000
+G1 X5170.000 Y6027.000
+G1 X5168.000 Y6025.000
+G1 X5165.000 Y6025.000
+G1 X5163.000 Y6027.000
+G1 X5162.000 Y6030.000
+M5
+G0 X5196.000 Y6056.000
+M3 S1000
+G1 X5191.000 Y6050.000 F1000
+G1 X5185.000 Y6043.000
+G1 X5176.000 Y6034.000
+G1 X5171.000 Y6032.000
+M5
+G0 X5186.000 Y6068.000
+M3 S1000
+G1 X5186.000 Y6072.000 F1000
+G1 X5186.000 Y6076.000
+G1 X5185.000 Y6078.000
+G1 X5183.000 Y6079.000
+G1 X5176.000 Y6086.000
+M5
+G0 X4875.000 Y6028.000
+M3 S1000
+G1 X4870.000 Y5981.000 F1000
+G1 X4860.000 Y5982.000
+G1 X4856.000 Y5948.000
+G1 X4866.000 Y5946.000
+G1 X4863.000 Y5917.000
+M5
+G0 X4950.000 Y6021.000
+M3 S1000
+G1 X4946.000 Y5991.000 F1000
+G1 X4956.000 Y5990.000
+G1 X4952.000 Y5957.000
+G1 X4943.000 Y5958.000
+G1 X4937.000 Y5910.000
+M5
+G0 X4579.000 Y5932.000
+M3 S1000
+G1 X4592.000 Y5930.000 F1000
+M5
+G0 X6728.000 Y3603.000
+M3 S1000
+G1 X6691.000 Y3590.000 F1000
+M5
+G0 X7033.000 Y3726.000
+M3 S1000
+G1 X6949.000 Y3695.000 F1000
+G1 X6829.000 Y3644.000
+G1 X6728.000 Y3603.000
+M5
+G0 X3513.000 Y4826.000
+M3 S1000
+G1 X3641.000 Y4817.000 F1000
+M5
+G0 X4598.000 Y5376.000
+M3 S1000
+G1 X4517.000 Y5379.000 F1000
+M5
+G0 X4598.000 Y5353.000
+M3 S1000
+G1 X4591.000 Y5348.000 F1000
+G1 X4585.000 Y5345.000
+G1 X4582.000 Y5342.000
+G1 X4554.000 Y5306.000
+G1 X4531.000 Y5273.000
+G1 X4456.000 Y5186.000
+M5
+G0 X4711.000 Y5006.000
+M3 S1000
+G1 X4505.000 Y5046.000 F1000
+G1 X4528.000 Y5165.000
+M5
+G0 X4644.000 Y5141.000
+M3 S1000
+G1 X4630.000 Y5065.000 F1000
+M5
+G0 X4704.000 Y5134.000
+M3 S1000
+G1 X4676.000 Y5119.000 F1000
+G1 X4644.000 Y5141.000
+G1 X4614.000 Y5211.000
+M5
+G0 X4434.000 Y6401.000
+M3 S1000
+G1 X4420.000 Y6402.000 F1000
+G1 X4418.000 Y6395.000
+G1 X4414.000 Y6391.000
+G1 X4406.000 Y6386.000
+G1 X4395.000 Y6385.000
+G1 X4386.000 Y6390.000
+G1 X4383.000 Y6396.000
+G1 X4382.000 Y6403.000
+G1 X4382.000 Y6409.000
+G1 X4383.000 Y6412.000
+G1 X4387.000 Y6421.000
+G1 X4396.000 Y6426.000
+G1 X4405.000 Y6426.000
+G1 X4412.000 Y6422.000
+G1 X4418.000 Y6416.000
+G1 X4420.000 Y6409.000
+G1 X4420.000 Y6402.000
+M5
+G0 X4382.000 Y6409.000
+M3 S1000
+G1 X4333.000 Y6416.000 F1000
+G1 X4278.000 Y6424.000
+G1 X4224.000 Y6433.000
+M5
+G0 X4322.000 Y6344.000
+M3 S1000
+G1 X4333.000 Y6416.000 F1000
+G1 X4345.000 Y6489.000
+M5
+G0 X4267.000 Y6352.000
+M3 S1000
+G1 X4278.000 Y6424.000 F1000
+G1 X4289.000 Y6497.000
+M5
+G0 X4405.000 Y6583.000
+M3 S1000
+G1 X4388.000 Y6601.000 F1000
+G1 X4381.000 Y6602.000
+G1 X4304.000 Y6611.000
+G1 X4290.000 Y6599.000
+G1 X4253.000 Y6569.000
+G1 X4242.000 Y6561.000
+G1 X4167.000 Y6570.000
+G1 X4154.000 Y6571.000
+G1 X4142.000 Y6481.000
+G1 X4141.000 Y6467.000
+G1 X4141.000 Y6465.000
+G1 X4142.000 Y6463.000
+G1 X4151.000 Y6452.000
+G1 X4130.000 Y6434.000
+G1 X4124.000 Y6403.000
+G1 X4126.000 Y6398.000
+G1 X4131.000 Y6396.000
+G1 X4142.000 Y6394.000
+G1 X4160.000 Y6406.000
+G1 X4163.000 Y6415.000
+G1 X4166.000 Y6440.000
+G1 X4161.000 Y6441.000
+G1 X4159.000 Y6442.000
+G1 X4157.000 Y6444.000
+G1 X4151.000 Y6452.000
+M5
+G0 X4197.000 Y6436.000
+M3 S1000
+G1 X4207.000 Y6435.000 F1000
+G1 X4205.000 Y6425.000
+G1 X4213.000 Y6424.000
+G1 X4218.000 Y6424.000
+G1 X4224.000 Y6433.000
+G1 X4221.000 Y6442.000
+G1 X4216.000 Y6443.000
+G1 X4208.000 Y6444.000
+G1 X4207.000 Y6435.000
+M5
+G0 X4213.000 Y6424.000
+M3 S1000
+G1 X4208.000 Y6419.000 F1000
+G1 X4205.000 Y6412.000
+G1 X4201.000 Y6404.000
+G1 X4199.000 Y6393.000
+G1 X4202.000 Y6383.000
+G1 X4205.000 Y6373.000
+G1 X4212.000 Y6366.000
+G1 X4218.000 Y6361.000
+G1 X4226.000 Y6358.000
+G1 X4267.000 Y6352.000
+G1 X4322.000 Y6344.000
+G1 X4401.000 Y6332.000
+G1 X4410.000 Y6330.000
+G1 X4420.000 Y6390.000
+G1 X4420.000 Y6402.000
+G1 X4422.000 Y6406.000
+G1 X4432.000 Y6474.000
+G1 X4345.000 Y6489.000
+G1 X4338.000 Y6489.000
+G1 X4289.000 Y6497.000
+G1 X4243.000 Y6504.000
+G1 X4234.000 Y6501.000
+G1 X4226.000 Y6497.000
+G1 X4220.000 Y6492.000
+G1 X4215.000 Y6484.000
+G1 X4212.000 Y6476.000
+G1 X4210.000 Y6467.000
+G1 X4210.000 Y6458.000
+G1 X4213.000 Y6450.000
+G1 X4216.000 Y6443.000
+M5
+G0 X4455.000 Y6299.000
+M3 S1000
+G1 X4446.000 Y6302.000 F1000
+G1 X4443.000 Y6303.000
+G1 X4440.000 Y6306.000
+G1 X4439.000 Y6309.000
+G1 X4439.000 Y6313.000
+G1 X4440.000 Y6322.000
+G1 X4442.000 Y6331.000
+G1 X4444.000 Y6340.000
+G1 X4447.000 Y6354.000
+G1 X4449.000 Y6369.000
+G1 X4450.000 Y6385.000
+G1 X4451.000 Y6399.000
+G1 X4452.000 Y6411.000
+G1 X4457.000 Y6448.000
+G1 X4458.000 Y6451.000
+G1 X4459.000 Y6454.000
+G1 X4461.000 Y6456.000
+G1 X4464.000 Y6457.000
+G1 X4467.000 Y6458.000
+G1 X4478.000 Y6458.000
+M5
+G0 X4471.000 Y6397.000
+M3 S1000
+G1 X4451.000 Y6399.000 F1000
+G1 X4434.000 Y6401.000
+M5
+G0 X5089.000 Y6140.000
+M3 S1000
+G1 X5091.000 Y6140.000 F1000
+G1 X5094.000 Y6141.000
+G1 X5097.000 Y6139.000
+G1 X5100.000 Y6138.000
+G1 X5103.000 Y6135.000
+G1 X5118.000 Y6125.000
+G1 X5120.000 Y6121.000
+G1 X5121.000 Y6118.000
+G1 X5122.000 Y6115.000
+M5
+G0 X5030.000 Y4637.000
+M3 S1000
+G1 X5048.000 Y4633.000 F1000
+M5
+G0 X4814.000 Y4812.000
+M3 S1000
+G1 X4823.000 Y4817.000 F1000
+G1 X4977.000 Y4798.000
+G1 X5019.000 Y4796.000
+G1 X5065.000 Y4790.000
+M5
+G0 X9157.000 Y5645.000
+M3 S1000
+G1 X9128.000 Y5629.000 F1000
+G1 X9093.000 Y5616.000
+G1 X9058.000 Y5609.000
+G1 X9022.000 Y5609.000
+M5
+G0 X9022.000 Y5609.000
+M3 S1000
+G1 X9047.000 Y5618.000 F1000
+G1 X9090.000 Y5628.000
+G1 X9129.000 Y5640.000
+G1 X9157.000 Y5645.000
+M5
+G0 X9351.000 Y5700.000
+M3 S1000
+G1 X9207.000 Y5660.000 F1000
+G1 X9157.000 Y5645.000
+M5
+G0 X4868.000 Y4484.000
+M3 S1000
+G1 X4866.000 Y4488.000 F1000
+G1 X4865.000 Y4492.000
+G1 X4864.000 Y4496.000
+G1 X4862.000 Y4499.000
+G1 X4861.000 Y4502.000
+G1 X4861.000 Y4505.000
+G1 X4867.000 Y4559.000
+G1 X4887.000 Y4611.000
+G1 X4890.000 Y4687.000
+G1 X4895.000 Y4702.000
+G1 X4904.000 Y4715.000
+G1 X4913.000 Y4718.000
+G1 X4932.000 Y4715.000
+G1 X4936.000 Y4697.000
+G1 X4935.000 Y4681.000
+G1 X4907.000 Y4602.000
+G1 X4901.000 Y4532.000
+G1 X4882.000 Y4497.000
+G1 X4881.000 Y4496.000
+G1 X4879.000 Y4495.000
+G1 X4876.000 Y4493.000
+G1 X4872.000 Y4490.000
+G1 X4870.000 Y4487.000
+G1 X4868.000 Y4484.000
+G1 X4868.000 Y4482.000
+M5
+G0 X4866.000 Y4465.000
+M3 S1000
+G1 X4867.000 Y4461.000 F1000
+G1 X4868.000 Y4458.000
+G1 X4869.000 Y4456.000
+G1 X4871.000 Y4454.000
+G1 X4872.000 Y4451.000
+G1 X4873.000 Y4449.000
+G1 X4873.000 Y4445.000
+G1 X4869.000 Y4394.000
+M5
+G0 X4647.000 Y4531.000
+M3 S1000
+G1 X4656.000 Y4529.000 F1000
+M5
+G0 X4654.000 Y4573.000
+M3 S1000
+G1 X4663.000 Y4571.000 F1000
+M5
+G0 X4660.000 Y4608.000
+M3 S1000
+G1 X4669.000 Y4606.000 F1000
+M5
+G0 X4666.000 Y4644.000
+M3 S1000
+G1 X4675.000 Y4643.000 F1000
+M5
+G0 X4672.000 Y4680.000
+M3 S1000
+G1 X4681.000 Y4678.000 F1000
+M5
+G0 X4679.000 Y4722.000
+M3 S1000
+G1 X4689.000 Y4720.000 F1000
+M5
+G0 X4689.000 Y4768.000
+M3 S1000
+G1 X4697.000 Y4767.000 F1000
+M5
+G0 X4680.000 Y4771.000
+M3 S1000
+G1 X4689.000 Y4768.000 F1000
+M5
+G0 X4671.000 Y4724.000
+M3 S1000
+G1 X4679.000 Y4722.000 F1000
+M5
+G0 X4664.000 Y4681.000
+M3 S1000
+G1 X4672.000 Y4680.000 F1000
+M5
+G0 X4658.000 Y4646.000
+M3 S1000
+G1 X4666.000 Y4644.000 F1000
+M5
+G0 X4652.000 Y4609.000
+M3 S1000
+G1 X4660.000 Y4608.000 F1000
+M5
+G0 X4646.000 Y4575.000
+M3 S1000
+G1 X4654.000 Y4573.000 F1000
+M5
+G0 X4638.000 Y4532.000
+M3 S1000
+G1 X4647.000 Y4531.000 F1000
+M5
+G0 X4631.000 Y4467.000
+M3 S1000
+G1 X4624.000 Y4469.000 F1000
+M5
+G0 X4622.000 Y4429.000
+M3 S1000
+G1 X4614.000 Y4431.000 F1000
+M5
+G0 X4613.000 Y4382.000
+M3 S1000
+G1 X4606.000 Y4383.000 F1000
+M5
+G0 X4606.000 Y4344.000
+M3 S1000
+G1 X4599.000 Y4345.000 F1000
+M5
+G0 X4640.000 Y4465.000
+M3 S1000
+G1 X4631.000 Y4467.000 F1000
+M5
+G0 X4631.000 Y4427.000
+M3 S1000
+G1 X4622.000 Y4429.000 F1000
+M5
+G0 X4623.000 Y4380.000
+M3 S1000
+G1 X4613.000 Y4382.000 F1000
+M5
+G0 X4615.000 Y4341.000
+M3 S1000
+G1 X4606.000 Y4344.000 F1000
+M5
+G0 X4896.000 Y4894.000
+M3 S1000
+G1 X4893.000 Y4900.000 F1000
+G1 X4891.000 Y4905.000
+G1 X4887.000 Y4911.000
+G1 X4880.000 Y4919.000
+G1 X4876.000 Y4925.000
+G1 X4874.000 Y4928.000
+G1 X4874.000 Y4931.000
+G1 X4874.000 Y4935.000
+M5
+G0 X4953.000 Y4902.000
+M3 S1000
+G1 X4955.000 Y4916.000 F1000
+G1 X4956.000 Y4929.000
+G1 X4956.000 Y4938.000
+G1 X4956.000 Y4942.000
+G1 X4956.000 Y4945.000
+G1 X4957.000 Y4945.000
+G1 X4958.000 Y4946.000
+M5
+G0 X5633.000 Y5657.000
+M3 S1000
+G1 X5632.000 Y5660.000 F1000
+G1 X5631.000 Y5666.000
+M5
+G0 X5635.000 Y5645.000
+M3 S1000
+G1 X5633.000 Y5657.000 F1000
+M5
+G0 X4564.000 Y5842.000
+M3 S1000
+G1 X4565.000 Y5851.000 F1000
+G1 X4565.000 Y5855.000
+G1 X4563.000 Y5859.000
+G1 X4559.000 Y5861.000
+G1 X4507.000 Y5866.000
+G1 X4502.000 Y5867.000
+G1 X4499.000 Y5867.000
+G1 X4494.000 Y5866.000
+G1 X4486.000 Y5865.000
+G1 X4482.000 Y5864.000
+G1 X4478.000 Y5863.000
+G1 X4468.000 Y5860.000
+G1 X4464.000 Y5858.000
+G1 X4461.000 Y5857.000
+G1 X4457.000 Y5854.000
+G1 X4455.000 Y5850.000
+G1 X4448.000 Y5840.000
+M5
+G0 X4467.000 Y5999.000
+M3 S1000
+G1 X4488.000 Y5997.000 F1000
+M5
+G0 X4466.000 Y5985.000
+M3 S1000
+G1 X4445.000 Y5988.000 F1000
+G1 X4448.000 Y6021.000
+G1 X4450.000 Y6048.000
+G1 X4472.000 Y6046.000
+M5
+G0 X3853.000 Y7726.000
+M3 S1000
+G1 X3227.000 Y7669.000 F1000
+M5
+G0 X7037.000 Y3484.000
+M3 S1000
+G1 X7031.000 Y3465.000 F1000
+G1 X7026.000 Y3451.000
+G1 X7023.000 Y3444.000
+G1 X7018.000 Y3435.000
+G1 X7012.000 Y3427.000
+G1 X7003.000 Y3414.000
+M5
+G0 X6306.000 Y3400.000
+M3 S1000
+G1 X6308.000 Y3422.000 F1000
+G1 X6320.000 Y3460.000
+G1 X6343.000 Y3514.000
+G1 X6359.000 Y3548.000
+G1 X6375.000 Y3570.000
+G1 X6399.000 Y3609.000
+M5
+G0 X5824.000 Y3393.000
+M3 S1000
+G1 X5962.000 Y3799.000 F1000
+M5
+G0 X5945.000 Y3359.000
+M3 S1000
+G1 X5924.000 Y3291.000 F1000
+G1 X5924.000 Y3275.000
+G1 X5901.000 Y3196.000
+G1 X5877.000 Y3137.000
+M5
+G0 X4166.000 Y6440.000
+M3 S1000
+G1 X4175.000 Y6440.000 F1000
+M5
+G0 X4175.000 Y6440.000
+M3 S1000
+G1 X4197.000 Y6436.000 F1000
+M5
+G0 X4166.000 Y6257.000
+M3 S1000
+G1 X4171.000 Y6318.000 F1000
+G1 X4144.000 Y6320.000
+G1 X4139.000 Y6260.000
+M5
+G0 X3963.000 Y6211.000
+M3 S1000
+G1 X3965.000 Y6222.000 F1000
+G1 X3956.000 Y6223.000
+G1 X3971.000 Y6319.000
+M5
+G0 X4726.000 Y5755.000
+M3 S1000
+G1 X4774.000 Y5750.000 F1000
+G1 X4777.000 Y5779.000
+G1 X4725.000 Y5785.000
+M5
+G0 X4790.000 Y5827.000
+M3 S1000
+G1 X4777.000 Y5734.000 F1000
+G1 X4767.000 Y5722.000
+M5
+G0 X4696.000 Y5728.000
+M3 S1000
+G1 X4697.000 Y5733.000 F1000
+G1 X4698.000 Y5736.000
+G1 X4700.000 Y5740.000
+G1 X4702.000 Y5744.000
+G1 X4706.000 Y5755.000
+G1 X4720.000 Y5739.000
+G1 X4729.000 Y5739.000
+M5
+G0 X4720.000 Y5739.000
+M3 S1000
+G1 X4713.000 Y5739.000 F1000
+G1 X4705.000 Y5741.000
+G1 X4700.000 Y5740.000
+M5
+G0 X4694.000 Y5707.000
+M3 S1000
+G1 X4695.000 Y5717.000 F1000
+M5
+G0 X4695.000 Y5717.000
+M3 S1000
+G1 X4696.000 Y5728.000 F1000
+M5
+G0 X4604.000 Y5503.000
+M3 S1000
+G1 X4591.000 Y5500.000 F1000
+G1 X4588.000 Y5499.000
+M5
+G0 X4588.000 Y5499.000
+M3 S1000
+G1 X4580.000 Y5498.000 F1000
+M5
+G0 X4749.000 Y4486.000
+M3 S1000
+G1 X4866.000 Y4468.000 F1000
+M5
+G0 X4866.000 Y4468.000
+M3 S1000
+G1 X4954.000 Y4462.000 F1000
+G1 X4990.000 Y4463.000
+M5
+G0 X4866.000 Y4468.000
+M3 S1000
+G1 X4868.000 Y4482.000 F1000
+M5
+G0 X4695.000 Y4307.000
+M3 S1000
+G1 X4711.000 Y4358.000 F1000
+G1 X4722.000 Y4395.000
+G1 X4736.000 Y4439.000
+G1 X4749.000 Y4486.000
+M5
+G0 X4749.000 Y4486.000
+M3 S1000
+G1 X4753.000 Y4505.000 F1000
+M5
+G0 X5000.000 Y4506.000
+M3 S1000
+G1 X4990.000 Y4463.000 F1000
+G1 X4966.000 Y4366.000
+G1 X4961.000 Y4343.000
+G1 X4938.000 Y4235.000
+M5
+G0 X5003.000 Y4520.000
+M3 S1000
+G1 X5000.000 Y4506.000 F1000
+M5
+G0 X5111.000 Y4492.000
+M3 S1000
+G1 X5106.000 Y4477.000 F1000
+G1 X5103.000 Y4463.000
+G1 X5101.000 Y4447.000
+G1 X5101.000 Y4429.000
+G1 X5099.000 Y4404.000
+G1 X5095.000 Y4388.000
+G1 X5091.000 Y4373.000
+G1 X5086.000 Y4358.000
+G1 X5081.000 Y4352.000
+G1 X5079.000 Y4345.000
+G1 X5077.000 Y4337.000
+G1 X5077.000 Y4331.000
+G1 X5077.000 Y4324.000
+G1 X5079.000 Y4318.000
+G1 X5088.000 Y4305.000
+M5
+G0 X5114.000 Y4505.000
+M3 S1000
+G1 X5111.000 Y4492.000 F1000
+M5
+G0 X6498.000 Y3414.000
+M3 S1000
+G1 X6512.000 Y3446.000 F1000
+G1 X6544.000 Y3545.000
+G1 X6546.000 Y3553.000
+G1 X6549.000 Y3558.000
+G1 X6555.000 Y3560.000
+G1 X6562.000 Y3559.000
+G1 X6589.000 Y3554.000
+G1 X6625.000 Y3553.000
+G1 X6660.000 Y3556.000
+G1 X6699.000 Y3565.000
+G1 X6739.000 Y3581.000
+G1 X6913.000 Y3657.000
+G1 X6927.000 Y3663.000
+G1 X6935.000 Y3662.000
+G1 X6938.000 Y3654.000
+G1 X6940.000 Y3648.000
+M5
+G0 X832.000 Y7786.000
+M3 S1000
+G1 X868.000 Y7794.000 F1000
+G1 X873.000 Y7799.000
+G1 X875.000 Y7804.000
+G1 X875.000 Y7815.000
+G1 X880.000 Y7826.000
+M5
+G0 X1107.000 Y7576.000
+M3 S1000
+G1 X1105.000 Y7589.000 F1000
+M5
+G0 X1110.000 Y7556.000
+M3 S1000
+G1 X1107.000 Y7576.000 F1000
+M5
+G0 X1065.000 Y7551.000
+M3 S1000
+G1 X1110.000 Y7556.000 F1000
+G1 X1149.000 Y7559.000
+G1 X1161.000 Y7563.000
+G1 X1172.000 Y7570.000
+G1 X1175.000 Y7576.000
+G1 X1175.000 Y7587.000
+G1 X1166.000 Y7612.000
+M5
+G0 X1752.000 Y3629.000
+M3 S1000
+G1 X1764.000 Y3621.000 F1000
+G1 X1776.000 Y3617.000
+G1 X1850.000 Y3602.000
+G1 X1910.000 Y3593.000
+G1 X1925.000 Y3583.000
+M5
+G0 X424.000 Y3555.000
+M3 S1000
+G1 X439.000 Y3549.000 F1000
+G1 X526.000 Y3534.000
+G1 X833.000 Y3487.000
+G1 X1250.000 Y3417.000
+G1 X1431.000 Y3387.000
+G1 X1687.000 Y3342.000
+M5
+G0 X23.000 Y3599.000
+M3 S1000
+G1 X20.000 Y3610.000 F1000
+G1 X0.000 Y3816.000
+M5
+G0 X3235.000 Y7348.000
+M3 S1000
+G1 X3237.000 Y7296.000 F1000
+M5
+G0 X3220.000 Y7798.000
+M3 S1000
+G1 X3270.000 Y7801.000 F1000
+G1 X3273.000 Y7803.000
+G1 X3275.000 Y7805.000
+G1 X3274.000 Y7814.000
+M5
+G0 X6296.000 Y3399.000
+M3 S1000
+G1 X6306.000 Y3400.000 F1000
+G1 X6498.000 Y3414.000
+M5
+G0 X6940.000 Y3648.000
+M3 S1000
+G1 X6923.000 Y3640.000 F1000
+G1 X6913.000 Y3626.000
+G1 X6909.000 Y3605.000
+G1 X6915.000 Y3578.000
+G1 X6930.000 Y3562.000
+G1 X7033.000 Y3530.000
+M5
+G0 X4768.000 Y5575.000
+M3 S1000
+G1 X4787.000 Y5572.000 F1000
+G1 X4810.000 Y5569.000
+M5
+G0 X4758.000 Y5564.000
+M3 S1000
+G1 X4785.000 Y5560.000 F1000
+G1 X4808.000 Y5557.000
+G1 X4810.000 Y5569.000
+G1 X4812.000 Y5581.000
+G1 X4789.000 Y5584.000
+G1 X4783.000 Y5585.000
+G1 X4768.000 Y5575.000
+G1 X4759.000 Y5576.000
+G1 X4758.000 Y5564.000
+M5
+G0 X4789.000 Y5584.000
+M3 S1000
+G1 X4787.000 Y5572.000 F1000
+G1 X4785.000 Y5560.000
+G1 X4784.000 Y5554.000
+G1 X4779.000 Y5529.000
+G1 X4774.000 Y5500.000
+M5
+G0 X4784.000 Y5554.000
+M3 S1000
+G1 X4756.000 Y5558.000 F1000
+G1 X4752.000 Y5534.000
+G1 X4747.000 Y5505.000
+G1 X4774.000 Y5500.000
+G1 X4801.000 Y5495.000
+G1 X4806.000 Y5517.000
+G1 X4802.000 Y5519.000
+G1 X4799.000 Y5523.000
+G1 X4799.000 Y5525.000
+G1 X4800.000 Y5529.000
+G1 X4802.000 Y5529.000
+G1 X4808.000 Y5530.000
+G1 X4811.000 Y5551.000
+G1 X4784.000 Y5554.000
+M5
+G0 X4799.000 Y5525.000
+M3 S1000
+G1 X4779.000 Y5529.000 F1000
+G1 X4752.000 Y5534.000
+G1 X4710.000 Y5542.000
+M5
+G0 X4710.000 Y5542.000
+M3 S1000
+G1 X4643.000 Y5555.000 F1000
+G1 X4621.000 Y5560.000
+G1 X4594.000 Y5563.000
+M5
+G0 X4507.000 Y5148.000
+M3 S1000
+G1 X4513.000 Y5166.000 F1000
+G1 X4519.000 Y5177.000
+G1 X4525.000 Y5189.000
+G1 X4532.000 Y5200.000
+G1 X4539.000 Y5210.000
+G1 X4549.000 Y5221.000
+G1 X4559.000 Y5232.000
+G1 X4570.000 Y5241.000
+G1 X4582.000 Y5249.000
+G1 X4595.000 Y5257.000
+G1 X4631.000 Y5277.000
+M5
+G0 X4411.000 Y5424.000
+M3 S1000
+G1 X4408.000 Y5445.000 F1000
+M5
+G0 X4466.000 Y5509.000
+M3 S1000
+G1 X4467.000 Y5523.000 F1000
+G1 X4468.000 Y5528.000
+G1 X4472.000 Y5545.000
+M5
+G0 X4481.000 Y5544.000
+M3 S1000
+G1 X4481.000 Y5562.000 F1000
+M5
+G0 X4460.000 Y5590.000
+M3 S1000
+G1 X4449.000 Y5590.000 F1000
+M5
+G0 X4450.000 Y5620.000
+M3 S1000
+G1 X4474.000 Y5621.000 F1000
+M5
+G0 X4440.000 Y5642.000
+M3 S1000
+G1 X4451.000 Y5635.000 F1000
+G1 X4461.000 Y5630.000
+G1 X4474.000 Y5621.000
+G1 X4476.000 Y5619.000
+G1 X4484.000 Y5620.000
+G1 X4528.000 Y5620.000
+G1 X4530.000 Y5620.000
+G1 X4531.000 Y5618.000
+G1 X4531.000 Y5616.000
+G1 X4531.000 Y5565.000
+G1 X4530.000 Y5562.000
+G1 X4528.000 Y5561.000
+G1 X4526.000 Y5561.000
+G1 X4481.000 Y5562.000
+G1 X4453.000 Y5563.000
+G1 X4452.000 Y5563.000
+G1 X4451.000 Y5564.000
+G1 X4450.000 Y5564.000
+G1 X4450.000 Y5565.000
+G1 X4449.000 Y5566.000
+G1 X4449.000 Y5567.000
+G1 X4449.000 Y5590.000
+G1 X4450.000 Y5620.000
+G1 X4450.000 Y5632.000
+G1 X4451.000 Y5635.000
+M5
+G0 X5008.000 Y5744.000
+M3 S1000
+G1 X5027.000 Y5739.000 F1000
+M5
+G0 X5022.000 Y5716.000
+M3 S1000
+G1 X5027.000 Y5739.000 F1000
+G1 X5032.000 Y5763.000
+G1 X5036.000 Y5778.000
+G1 X5038.000 Y5785.000
+G1 X5041.000 Y5793.000
+G1 X5045.000 Y5800.000
+G1 X5045.000 Y5800.000
+G1 X5047.000 Y5807.000
+G1 X5048.000 Y5813.000
+G1 X5049.000 Y5822.000
+M5
+G0 X5080.000 Y5830.000
+M3 S1000
+G1 X5088.000 Y5825.000 F1000
+G1 X5098.000 Y5823.000
+G1 X5130.000 Y5816.000
+M5
+G0 X5243.000 Y5945.000
+M3 S1000
+G1 X5242.000 Y5948.000 F1000
+G1 X5243.000 Y5951.000
+G1 X5248.000 Y5977.000
+G1 X5247.000 Y5980.000
+G1 X5246.000 Y5982.000
+G1 X5245.000 Y5984.000
+G1 X5243.000 Y5985.000
+G1 X5230.000 Y5988.000
+G1 X5224.000 Y5990.000
+G1 X5219.000 Y5992.000
+G1 X5213.000 Y5995.000
+G1 X5208.000 Y5999.000
+G1 X5201.000 Y6004.000
+G1 X5196.000 Y6007.000
+G1 X5188.000 Y6012.000
+G1 X5178.000 Y6020.000
+G1 X5170.000 Y6027.000
+M5
+G0 X5099.000 Y5704.000
+M3 S1000
+G1 X5153.000 Y5691.000 F1000
+M5
+G0 X4490.000 Y6571.000
+M3 S1000
+G1 X4445.000 Y6577.000 F1000
+G1 X4425.000 Y6580.000
+G1 X4405.000 Y6583.000
+M5
+G0 X3849.000 Y5604.000
+M3 S1000
+G1 X3873.000 Y5602.000 F1000
+G1 X3878.000 Y5599.000
+G1 X3882.000 Y5596.000
+G1 X3883.000 Y5587.000
+G1 X3882.000 Y5576.000
+G1 X3884.000 Y5568.000
+G1 X3887.000 Y5566.000
+G1 X3893.000 Y5563.000
+M5
+G0 X4507.000 Y6382.000
+M3 S1000
+G1 X4470.000 Y6387.000 F1000
+M5
+G0 X4475.000 Y6778.000
+M3 S1000
+G1 X4473.000 Y6757.000 F1000
+G1 X4470.000 Y6728.000
+M5
+G0 X4483.000 Y6858.000
+M3 S1000
+G1 X4475.000 Y6778.000 F1000
+M5
+G0 X4392.000 Y6139.000
+M3 S1000
+G1 X4376.000 Y6114.000 F1000
+M5
+G0 X4413.000 Y6180.000
+M3 S1000
+G1 X4405.000 Y6160.000 F1000
+M5
+G0 X4405.000 Y6160.000
+M3 S1000
+G1 X4392.000 Y6139.000 F1000
+M5
+G0 X4418.000 Y6154.000
+M3 S1000
+G1 X4405.000 Y6160.000 F1000
+M5
+G0 X4392.000 Y6242.000
+M3 S1000
+G1 X4425.000 Y6239.000 F1000
+G1 X4433.000 Y6237.000
+M5
+G0 X4374.000 Y6243.000
+M3 S1000
+G1 X4337.000 Y6247.000 F1000
+M5
+G0 X4300.000 Y6209.000
+M3 S1000
+G1 X4333.000 Y6207.000 F1000
+M5
+G0 X4302.000 Y6241.000
+M3 S1000
+G1 X4336.000 Y6238.000 F1000
+M5
+G0 X4305.000 Y6273.000
+M3 S1000
+G1 X4339.000 Y6270.000 F1000
+M5
+G0 X4330.000 Y6173.000
+M3 S1000
+G1 X4333.000 Y6207.000 F1000
+G1 X4336.000 Y6238.000
+G1 X4337.000 Y6247.000
+G1 X4339.000 Y6270.000
+G1 X4340.000 Y6280.000
+M5
+G0 X4344.000 Y6535.000
+M3 S1000
+G1 X4342.000 Y6522.000 F1000
+M5
+G0 X4169.000 Y6584.000
+M3 S1000
+G1 X4230.000 Y6576.000 F1000
+M5
+G0 X4167.000 Y6570.000
+M3 S1000
+G1 X4169.000 Y6584.000 F1000
+G1 X4202.000 Y6610.000
+G1 X4230.000 Y6576.000
+G1 X4249.000 Y6574.000
+M5
+G0 X4405.000 Y6583.000
+M3 S1000
+G1 X4402.000 Y6576.000 F1000
+G1 X4397.000 Y6538.000
+G1 X4397.000 Y6534.000
+G1 X4398.000 Y6531.000
+G1 X4400.000 Y6529.000
+G1 X4402.000 Y6528.000
+G1 X4404.000 Y6527.000
+G1 X4425.000 Y6524.000
+G1 X4428.000 Y6524.000
+G1 X4430.000 Y6525.000
+G1 X4433.000 Y6526.000
+G1 X4436.000 Y6528.000
+G1 X4437.000 Y6530.000
+M5
+G0 X4445.000 Y6577.000
+M3 S1000
+G1 X4437.000 Y6530.000 F1000
+M5
+G0 X4425.000 Y6580.000
+M3 S1000
+G1 X4429.000 Y6606.000 F1000
+M5
+G0 X4134.000 Y6628.000
+M3 S1000
+G1 X4172.000 Y6624.000 F1000
+G1 X4183.000 Y6633.000
+M5
+G0 X4329.000 Y6552.000
+M3 S1000
+G1 X4313.000 Y6539.000 F1000
+G1 X4284.000 Y6541.000
+G1 X4286.000 Y6556.000
+G1 X4290.000 Y6599.000
+M5
+G0 X4364.000 Y6533.000
+M3 S1000
+G1 X4394.000 Y6556.000 F1000
+M5
+G0 X4356.000 Y6544.000
+M3 S1000
+G1 X4364.000 Y6533.000 F1000
+G1 X4383.000 Y6531.000
+G1 X4391.000 Y6537.000
+G1 X4394.000 Y6556.000
+G1 X4384.000 Y6567.000
+M5
+G0 X4331.000 Y6572.000
+M3 S1000
+G1 X4346.000 Y6554.000 F1000
+G1 X4372.000 Y6574.000
+M5
+G0 X4376.000 Y6573.000
+M3 S1000
+G1 X4377.000 Y6587.000 F1000
+M5
+G0 X4297.000 Y6592.000
+M3 S1000
+G1 X4300.000 Y6595.000 F1000
+G1 X4377.000 Y6587.000
+G1 X4380.000 Y6586.000
+G1 X4381.000 Y6602.000
+M5
+G0 X4206.000 Y6627.000
+M3 S1000
+G1 X4274.000 Y6620.000 F1000
+G1 X4290.000 Y6599.000
+G1 X4297.000 Y6592.000
+G1 X4321.000 Y6563.000
+G1 X4329.000 Y6552.000
+G1 X4344.000 Y6535.000
+G1 X4356.000 Y6544.000
+G1 X4384.000 Y6567.000
+G1 X4405.000 Y6583.000
+M5
+G0 X4205.000 Y6629.000
+M3 S1000
+G1 X4156.000 Y6591.000 F1000
+G1 X4154.000 Y6571.000
+G1 X4128.000 Y6574.000
+G1 X4134.000 Y6628.000
+G1 X4135.000 Y6638.000
+G1 X4183.000 Y6633.000
+G1 X4202.000 Y6631.000
+G1 X4205.000 Y6629.000
+G1 X4206.000 Y6627.000
+G1 X4249.000 Y6574.000
+G1 X4253.000 Y6569.000
+G1 X4262.000 Y6558.000
+G1 X4286.000 Y6556.000
+G1 X4307.000 Y6553.000
+G1 X4321.000 Y6563.000
+G1 X4331.000 Y6572.000
+G1 X4338.000 Y6577.000
+G1 X4372.000 Y6574.000
+G1 X4376.000 Y6573.000
+G1 X4379.000 Y6573.000
+G1 X4384.000 Y6567.000
+M5
+G0 X4483.000 Y5953.000
+M3 S1000
+G1 X4488.000 Y5997.000 F1000
+G1 X4492.000 Y6042.000
+M5
+G0 X4777.000 Y6747.000
+M3 S1000
+G1 X4868.000 Y6738.000 F1000
+G1 X4875.000 Y6728.000
+M5
+G0 X6218.000 Y6922.000
+M3 S1000
+G1 X5981.000 Y6994.000 F1000
+G1 X5877.000 Y7017.000
+G1 X5869.000 Y7021.000
+G1 X5863.000 Y7026.000
+G1 X5843.000 Y7040.000
+G1 X5838.000 Y7041.000
+G1 X5829.000 Y7044.000
+G1 X5779.000 Y7059.000
+G1 X5762.000 Y7062.000
+G1 X5710.000 Y7054.000
+G1 X5671.000 Y7050.000
+G1 X5591.000 Y7045.000
+G1 X5579.000 Y7045.000
+G1 X5562.000 Y7047.000
+G1 X5075.000 Y7115.000
+G1 X4863.000 Y7146.000
+M5
+G0 X1316.000 Y5699.000
+M3 S1000
+G1 X1317.000 Y5781.000 F1000
+G1 X1326.000 Y5784.000
+G1 X1454.000 Y5771.000
+G1 X1477.000 Y5769.000
+M5
+G0 X353.000 Y5561.000
+M3 S1000
+G1 X381.000 Y5553.000 F1000
+G1 X387.000 Y5552.000
+G1 X401.000 Y5551.000
+G1 X418.000 Y5549.000
+M5
+G0 X3659.000 Y4473.000
+M3 S1000
+G1 X3660.000 Y4469.000 F1000
+G1 X3662.000 Y4465.000
+G1 X3665.000 Y4459.000
+G1 X3668.000 Y4455.000
+G1 X3672.000 Y4451.000
+M5
+G0 X3844.000 Y4370.000
+M3 S1000
+G1 X3782.000 Y4388.000 F1000
+G1 X3742.000 Y4402.000
+G1 X3731.000 Y4408.000
+G1 X3719.000 Y4413.000
+G1 X3709.000 Y4420.000
+G1 X3696.000 Y4429.000
+G1 X3683.000 Y4440.000
+G1 X3672.000 Y4451.000
+M5
+G0 X1813.000 Y4891.000
+M3 S1000
+G1 X1809.000 Y4870.000 F1000
+M5
+G0 X1864.000 Y4879.000
+M3 S1000
+G1 X1860.000 Y4873.000 F1000
+G1 X1856.000 Y4870.000
+G1 X1853.000 Y4868.000
+G1 X1849.000 Y4866.000
+G1 X1844.000 Y4865.000
+G1 X1838.000 Y4864.000
+G1 X1833.000 Y4866.000
+G1 X1809.000 Y4870.000
+G1 X1542.000 Y4916.000
+G1 X1490.000 Y4924.000
+G1 X1483.000 Y4924.000
+M5
+G0 X2426.000 Y5487.000
+M3 S1000
+G1 X2438.000 Y5487.000 F1000
+M5
+G0 X2410.000 Y5487.000
+M3 S1000
+G1 X2426.000 Y5487.000 F1000
+M5
+G0 X2295.000 Y5301.000
+M3 S1000
+G1 X2294.000 Y5298.000 F1000
+G1 X2293.000 Y5296.000
+G1 X2290.000 Y5293.000
+G1 X2286.000 Y5292.000
+G1 X2282.000 Y5290.000
+G1 X2278.000 Y5290.000
+G1 X2258.000 Y5291.000
+M5
+G0 X2281.000 Y5183.000
+M3 S1000
+G1 X2285.000 Y5202.000 F1000
+G1 X2287.000 Y5212.000
+G1 X2290.000 Y5222.000
+G1 X2293.000 Y5231.000
+G1 X2295.000 Y5301.000
+M5
+G0 X2891.000 Y5748.000
+M3 S1000
+G1 X2895.000 Y5702.000 F1000
+G1 X2898.000 Y5671.000
+G1 X2901.000 Y5657.000
+G1 X2902.000 Y5654.000
+G1 X2903.000 Y5652.000
+G1 X2905.000 Y5650.000
+G1 X2906.000 Y5649.000
+M5
+G0 X2904.000 Y5558.000
+M3 S1000
+G1 X2902.000 Y5638.000 F1000
+G1 X2902.000 Y5641.000
+G1 X2903.000 Y5644.000
+G1 X2904.000 Y5646.000
+G1 X2906.000 Y5649.000
+G1 X2906.000 Y5650.000
+G1 X2910.000 Y5653.000
+G1 X2913.000 Y5654.000
+G1 X2919.000 Y5654.000
+G1 X2934.000 Y5654.000
+M5
+G0 X2257.000 Y5575.000
+M3 S1000
+G1 X2263.000 Y5579.000 F1000
+G1 X2267.000 Y5582.000
+G1 X2271.000 Y5588.000
+G1 X2274.000 Y5597.000
+G1 X2277.000 Y5609.000
+M5
+G0 X2350.000 Y5211.000
+M3 S1000
+G1 X2347.000 Y5166.000 F1000
+G1 X2346.000 Y5163.000
+G1 X2345.000 Y5160.000
+G1 X2343.000 Y5157.000
+G1 X2340.000 Y5153.000
+G1 X2335.000 Y5150.000
+G1 X2330.000 Y5149.000
+G1 X2325.000 Y5147.000
+G1 X2321.000 Y5145.000
+G1 X2319.000 Y5143.000
+G1 X2317.000 Y5140.000
+G1 X2315.000 Y5137.000
+G1 X2313.000 Y5131.000
+M5
+G0 X4574.000 Y7189.000
+M3 S1000
+G1 X4863.000 Y7146.000 F1000
+M5
+G0 X4706.000 Y5755.000
+M3 S1000
+G1 X4703.000 Y5760.000 F1000
+G1 X4701.000 Y5765.000
+G1 X4699.000 Y5772.000
+G1 X4697.000 Y5777.000
+M5
+G0 X4712.000 Y5789.000
+M3 S1000
+G1 X4711.000 Y5791.000 F1000
+G1 X4708.000 Y5792.000
+G1 X4706.000 Y5794.000
+G1 X4703.000 Y5796.000
+M5
+G0 X4678.000 Y5787.000
+M3 S1000
+G1 X4684.000 Y5788.000 F1000
+G1 X4689.000 Y5790.000
+G1 X4693.000 Y5792.000
+G1 X4697.000 Y5793.000
+G1 X4701.000 Y5794.000
+G1 X4703.000 Y5796.000
+G1 X4705.000 Y5798.000
+G1 X4705.000 Y5806.000
+G1 X4706.000 Y5820.000
+M5
+G0 X4682.000 Y5777.000
+M3 S1000
+G1 X4687.000 Y5777.000 F1000
+G1 X4692.000 Y5776.000
+G1 X4694.000 Y5776.000
+G1 X4697.000 Y5777.000
+G1 X4701.000 Y5778.000
+G1 X4705.000 Y5782.000
+G1 X4709.000 Y5786.000
+G1 X4712.000 Y5789.000
+G1 X4715.000 Y5791.000
+G1 X4718.000 Y5792.000
+G1 X4720.000 Y5793.000
+G1 X4725.000 Y5793.000
+M5
+G0 X4671.000 Y5749.000
+M3 S1000
+G1 X4676.000 Y5756.000 F1000
+G1 X4680.000 Y5764.000
+G1 X4682.000 Y5771.000
+G1 X4682.000 Y5777.000
+G1 X4682.000 Y5780.000
+G1 X4682.000 Y5782.000
+G1 X4681.000 Y5785.000
+G1 X4678.000 Y5787.000
+G1 X4674.000 Y5787.000
+G1 X4669.000 Y5788.000
+G1 X4663.000 Y5788.000
+G1 X4657.000 Y5788.000
+G1 X4652.000 Y5788.000
+M5
+G0 X4702.000 Y5744.000
+M3 S1000
+G1 X4700.000 Y5746.000 F1000
+G1 X4698.000 Y5746.000
+G1 X4694.000 Y5746.000
+G1 X4691.000 Y5747.000
+G1 X4686.000 Y5746.000
+G1 X4681.000 Y5746.000
+G1 X4677.000 Y5746.000
+G1 X4673.000 Y5748.000
+G1 X4671.000 Y5749.000
+G1 X4665.000 Y5751.000
+G1 X4661.000 Y5753.000
+G1 X4656.000 Y5754.000
+G1 X4653.000 Y5754.000
+G1 X4649.000 Y5754.000
+M5
+G0 X4607.000 Y5804.000
+M3 S1000
+G1 X4624.000 Y5805.000 F1000
+G1 X4631.000 Y5805.000
+G1 X4634.000 Y5805.000
+G1 X4639.000 Y5805.000
+G1 X4643.000 Y5804.000
+G1 X4646.000 Y5802.000
+G1 X4650.000 Y5798.000
+G1 X4652.000 Y5794.000
+G1 X4652.000 Y5790.000
+G1 X4652.000 Y5788.000
+G1 X4649.000 Y5754.000
+G1 X4649.000 Y5750.000
+G1 X4648.000 Y5748.000
+G1 X4647.000 Y5746.000
+G1 X4645.000 Y5745.000
+G1 X4642.000 Y5743.000
+G1 X4636.000 Y5741.000
+G1 X4632.000 Y5739.000
+G1 X4628.000 Y5739.000
+G1 X4623.000 Y5738.000
+G1 X4615.000 Y5739.000
+G1 X4610.000 Y5741.000
+G1 X4608.000 Y5742.000
+G1 X4608.000 Y5743.000
+G1 X4608.000 Y5744.000
+M5
+G0 X4608.000 Y5744.000
+M3 S1000
+G1 X4609.000 Y5761.000 F1000
+G1 X4608.000 Y5763.000
+G1 X4606.000 Y5765.000
+G1 X4602.000 Y5765.000
+M5
+G0 X4409.000 Y5940.000
+M3 S1000
+G1 X4414.000 Y5939.000 F1000
+M5
+G0 X4401.000 Y5941.000
+M3 S1000
+G1 X4409.000 Y5940.000 F1000
+M5
+G0 X4442.000 Y6049.000
+M3 S1000
+G1 X4440.000 Y6022.000 F1000
+M5
+G0 X4387.000 Y5847.000
+M3 S1000
+G1 X4392.000 Y5892.000 F1000
+M5
+G0 X4448.000 Y5840.000
+M3 S1000
+G1 X4461.000 Y5945.000 F1000
+G1 X4464.000 Y5970.000
+G1 X4466.000 Y5985.000
+G1 X4467.000 Y5999.000
+G1 X4472.000 Y6046.000
+G1 X4476.000 Y6074.000
+G1 X4486.000 Y6137.000
+M5
+G0 X4450.000 Y6048.000
+M3 S1000
+G1 X4442.000 Y6049.000 F1000
+G1 X4418.000 Y6051.000
+M5
+G0 X4395.000 Y5941.000
+M3 S1000
+G1 X4401.000 Y5941.000 F1000
+M5
+G0 X4435.000 Y6022.000
+M3 S1000
+G1 X4431.000 Y5983.000 F1000
+G1 X4428.000 Y5981.000
+G1 X4420.000 Y5982.000
+G1 X4418.000 Y5980.000
+G1 X4414.000 Y5939.000
+G1 X4411.000 Y5912.000
+G1 X4393.000 Y5903.000
+G1 X4392.000 Y5892.000
+M5
+G0 X4406.000 Y6025.000
+M3 S1000
+G1 X4435.000 Y6022.000 F1000
+G1 X4440.000 Y6022.000
+G1 X4448.000 Y6021.000
+M5
+G0 X4419.000 Y5950.000
+M3 S1000
+G1 X4461.000 Y5945.000 F1000
+M5
+G0 X4421.000 Y5974.000
+M3 S1000
+G1 X4464.000 Y5970.000 F1000
+M5
+G0 X3341.000 Y5901.000
+M3 S1000
+G1 X3351.000 Y5896.000 F1000
+G1 X3416.000 Y5889.000
+M5
+G0 X3371.000 Y5956.000
+M3 S1000
+G1 X3448.000 Y5913.000 F1000
+G1 X3448.000 Y5907.000
+M5
+G0 X3339.000 Y6612.000
+M3 S1000
+G1 X3337.000 Y6657.000 F1000
+G1 X3335.000 Y6692.000
+G1 X3334.000 Y6716.000
+G1 X3332.000 Y6764.000
+G1 X3330.000 Y6808.000
+M5
+G0 X3416.000 Y6812.000
+M3 S1000
+G1 X3364.000 Y6809.000 F1000
+G1 X3330.000 Y6808.000
+G1 X3262.000 Y6806.000
+G1 X3253.000 Y6806.000
+M5
+G0 X806.000 Y4364.000
+M3 S1000
+G1 X1179.000 Y4303.000 F1000
+G1 X1532.000 Y4247.000
+G1 X1551.000 Y4246.000
+G1 X1560.000 Y4247.000
+M5
+G0 X4519.000 Y6192.000
+M3 S1000
+G1 X4572.000 Y6188.000 F1000
+G1 X4589.000 Y6193.000
+G1 X4627.000 Y6189.000
+M5
+G0 X3547.000 Y6281.000
+M3 S1000
+G1 X3547.000 Y6308.000 F1000
+M5
+G0 X7731.000 Y3194.000
+M3 S1000
+G1 X7761.000 Y3249.000 F1000
+G1 X7771.000 Y3266.000
+G1 X7774.000 Y3272.000
+G1 X7784.000 Y3295.000
+G1 X7803.000 Y3344.000
+G1 X7811.000 Y3365.000
+G1 X7824.000 Y3391.000
+G1 X7830.000 Y3404.000
+G1 X7835.000 Y3413.000
+G1 X7838.000 Y3429.000
+G1 X7839.000 Y3442.000
+M5
+G0 X0 Y0
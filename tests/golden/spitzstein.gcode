@@ -0,0 +1,3527 @@
+G21
+G90
+M5
+G0 X6068.000 Y10480.000
+M3 S1000
+G1 X6050.000 Y10476.000 F1000
+G1 X6029.000 Y10461.000
+G1 X6017.000 Y10456.000
+G1 X5998.000 Y10452.000
+G1 X5986.000 Y10446.000
+G1 X5974.000 Y10442.000
+G1 X5962.000 Y10436.000
+G1 X5956.000 Y10428.000
+G1 X5947.000 Y10413.000
+G1 X5939.000 Y10399.000
+G1 X5936.000 Y10385.000
+G1 X5937.000 Y10371.000
+G1 X5940.000 Y10357.000
+G1 X5944.000 Y10344.000
+G1 X5938.000 Y10335.000
+G1 X5938.000 Y10321.000
+G1 X5942.000 Y10304.000
+G1 X5954.000 Y10299.000
+G1 X5964.000 Y10290.000
+G1 X5979.000 Y10291.000
+G1 X5995.000 Y10292.000
+G1 X6001.000 Y10278.000
+G1 X5990.000 Y10255.000
+G1 X5972.000 Y10249.000
+G1 X5960.000 Y10240.000
+G1 X5951.000 Y10236.000
+G1 X5936.000 Y10225.000
+G1 X5924.000 Y10216.000
+G1 X5918.000 Y10202.000
+G1 X5909.000 Y10188.000
+G1 X5900.000 Y10179.000
+G1 X5888.000 Y10170.000
+G1 X5873.000 Y10160.000
+G1 X5868.000 Y10150.000
+G1 X5855.000 Y10154.000
+G1 X5840.000 Y10149.000
+G1 X5825.000 Y10140.000
+G1 X5810.000 Y10130.000
+G1 X5798.000 Y10111.000
+G1 X5796.000 Y10088.000
+G1 X5790.000 Y10079.000
+G1 X5778.000 Y10070.000
+G1 X5766.000 Y10064.000
+G1 X5754.000 Y10061.000
+G1 X5742.000 Y10050.000
+G1 X5730.000 Y10046.000
+G1 X5718.000 Y10035.000
+G1 X5705.000 Y10031.000
+G1 X5693.000 Y10025.000
+G1 X5678.000 Y10016.000
+G1 X5663.000 Y10010.000
+G1 X5651.000 Y10006.000
+G1 X5636.000 Y10001.000
+G1 X5624.000 Y9995.000
+G1 X5615.000 Y9986.000
+G1 X5603.000 Y9982.000
+G1 X5591.000 Y9971.000
+G1 X5570.000 Y9967.000
+G1 X5554.000 Y9961.000
+G1 X5545.000 Y9952.000
+G1 X5527.000 Y9946.000
+G1 X5512.000 Y9942.000
+G1 X5500.000 Y9937.000
+G1 X5487.000 Y9931.000
+G1 X5475.000 Y9927.000
+G1 X5463.000 Y9922.000
+G1 X5448.000 Y9918.000
+G1 X5433.000 Y9912.000
+G1 X5418.000 Y9906.000
+G1 X5403.000 Y9902.000
+G1 X5387.000 Y9896.000
+G1 X5378.000 Y9887.000
+G1 X5370.000 Y9868.000
+G1 X5367.000 Y9854.000
+G1 X5365.000 Y9842.000
+G1 X5359.000 Y9828.000
+G1 X5347.000 Y9827.000
+G1 X5335.000 Y9821.000
+G1 X5323.000 Y9816.000
+G1 X5310.000 Y9812.000
+G1 X5295.000 Y9811.000
+G1 X5282.000 Y9814.000
+G1 X5270.000 Y9819.000
+G1 X5258.000 Y9824.000
+G1 X5243.000 Y9813.000
+G1 X5237.000 Y9804.000
+G1 X5225.000 Y9790.000
+G1 X5213.000 Y9786.000
+G1 X5201.000 Y9780.000
+G1 X5185.000 Y9776.000
+G1 X5170.000 Y9776.000
+G1 X5157.000 Y9779.000
+G1 X5142.000 Y9775.000
+G1 X5130.000 Y9774.000
+G1 X5117.000 Y9782.000
+G1 X5102.000 Y9782.000
+G1 X5087.000 Y9776.000
+G1 X5075.000 Y9767.000
+G1 X5066.000 Y9758.000
+G1 X5060.000 Y9747.000
+G1 X5051.000 Y9735.000
+G1 X5049.000 Y9721.000
+G1 X5040.000 Y9710.000
+G1 X5028.000 Y9701.000
+G1 X5019.000 Y9692.000
+G1 X5010.000 Y9683.000
+G1 X4998.000 Y9672.000
+G1 X4989.000 Y9663.000
+G1 X4981.000 Y9649.000
+G1 X4975.000 Y9640.000
+G1 X4969.000 Y9626.000
+G1 X4964.000 Y9612.000
+G1 X4964.000 Y9600.000
+G1 X4961.000 Y9586.000
+G1 X4962.000 Y9572.000
+G1 X4962.000 Y9563.000
+G1 X4963.000 Y9544.000
+G1 X4960.000 Y9535.000
+G1 X4954.000 Y9521.000
+G1 X4952.000 Y9507.000
+G1 X4946.000 Y9493.000
+G1 X4947.000 Y9479.000
+G1 X4944.000 Y9470.000
+G1 X4944.000 Y9456.000
+G1 X4948.000 Y9443.000
+G1 X4952.000 Y9431.000
+G1 X4955.000 Y9417.000
+G1 X4965.000 Y9403.000
+G1 X4974.000 Y9398.000
+G1 X4987.000 Y9390.000
+G1 X4996.000 Y9385.000
+G1 X5009.000 Y9377.000
+G1 X5022.000 Y9369.000
+G1 X5028.000 Y9355.000
+G1 X5032.000 Y9341.000
+G1 X5029.000 Y9333.000
+G1 X5017.000 Y9318.000
+G1 X5005.000 Y9318.000
+G1 X4993.000 Y9317.000
+G1 X4980.000 Y9317.000
+G1 X4965.000 Y9316.000
+G1 X4955.000 Y9321.000
+G1 X4940.000 Y9324.000
+G1 X4927.000 Y9332.000
+G1 X4915.000 Y9337.000
+G1 X4899.000 Y9337.000
+G1 X4887.000 Y9336.000
+G1 X4875.000 Y9341.000
+G1 X4859.000 Y9344.000
+G1 X4844.000 Y9343.000
+G1 X4828.000 Y9343.000
+G1 X4816.000 Y9353.000
+G1 X4828.000 Y9357.000
+G1 X4838.000 Y9348.000
+G1 X4835.000 Y9334.000
+G1 X4823.000 Y9334.000
+G1 X4807.000 Y9333.000
+G1 X4792.000 Y9338.000
+G1 X4779.000 Y9338.000
+G1 X4764.000 Y9337.000
+G1 X4752.000 Y9331.000
+G1 X4737.000 Y9326.000
+G1 X4725.000 Y9322.000
+G1 X4716.000 Y9313.000
+G1 X4704.000 Y9307.000
+G1 X4695.000 Y9298.000
+G1 X4689.000 Y9284.000
+G1 X4683.000 Y9275.000
+G1 X4684.000 Y9261.000
+G1 X4684.000 Y9247.000
+G1 X4688.000 Y9234.000
+G1 X4691.000 Y9220.000
+G1 X4698.000 Y9211.000
+G1 X4705.000 Y9198.000
+G1 X4711.000 Y9184.000
+G1 X4721.000 Y9170.000
+G1 X4727.000 Y9162.000
+G1 X4734.000 Y9153.000
+G1 X4740.000 Y9140.000
+G1 X4744.000 Y9126.000
+G1 X4750.000 Y9112.000
+G1 X4754.000 Y9099.000
+G1 X4755.000 Y9086.000
+G1 X4761.000 Y9076.000
+G1 X4758.000 Y9062.000
+G1 X4746.000 Y9053.000
+G1 X4738.000 Y9044.000
+G1 X4735.000 Y9030.000
+G1 X4732.000 Y9016.000
+G1 X4730.000 Y9007.000
+G1 X4724.000 Y8993.000
+G1 X4721.000 Y8979.000
+G1 X4719.000 Y8965.000
+G1 X4713.000 Y8953.000
+G1 X4707.000 Y8942.000
+G1 X4705.000 Y8928.000
+G1 X4699.000 Y8916.000
+G1 X4693.000 Y8905.000
+G1 X4691.000 Y8888.000
+G1 X4688.000 Y8879.000
+G1 X4688.000 Y8865.000
+G1 X4686.000 Y8851.000
+G1 X4683.000 Y8837.000
+G1 X4671.000 Y8837.000
+G1 X4662.000 Y8831.000
+G1 X4656.000 Y8819.000
+G1 X4651.000 Y8805.000
+G1 X4639.000 Y8794.000
+G1 X4630.000 Y8785.000
+G1 X4618.000 Y8781.000
+G1 X4609.000 Y8767.000
+G1 X4606.000 Y8753.000
+G1 X4604.000 Y8744.000
+G1 X4598.000 Y8730.000
+G1 X4592.000 Y8716.000
+G1 X4583.000 Y8707.000
+G1 X4581.000 Y8693.000
+G1 X4581.000 Y8679.000
+G1 X4579.000 Y8665.000
+G1 X4573.000 Y8651.000
+G1 X4567.000 Y8637.000
+G1 X4558.000 Y8628.000
+G1 X4550.000 Y8613.000
+G1 X4541.000 Y8604.000
+G1 X4529.000 Y8599.000
+G1 X4517.000 Y8590.000
+G1 X4505.000 Y8580.000
+G1 X4486.000 Y8580.000
+G1 X4471.000 Y8579.000
+G1 X4452.000 Y8587.000
+G1 X4443.000 Y8601.000
+G1 X4433.000 Y8609.000
+G1 X4420.000 Y8623.000
+G1 X4402.000 Y8627.000
+G1 X4389.000 Y8630.000
+G1 X4374.000 Y8626.000
+G1 X4362.000 Y8626.000
+G1 X4349.000 Y8625.000
+G1 X4331.000 Y8628.000
+G1 X4319.000 Y8628.000
+G1 X4303.000 Y8627.000
+G1 X4291.000 Y8627.000
+G1 X4276.000 Y8623.000
+G1 X4263.000 Y8622.000
+G1 X4248.000 Y8617.000
+G1 X4236.000 Y8611.000
+G1 X4224.000 Y8607.000
+G1 X4215.000 Y8598.000
+G1 X4203.000 Y8587.000
+G1 X4194.000 Y8578.000
+G1 X4185.000 Y8569.000
+G1 X4173.000 Y8563.000
+G1 X4161.000 Y8554.000
+G1 X4149.000 Y8549.000
+G1 X4143.000 Y8535.000
+G1 X4135.000 Y8526.000
+G1 X4129.000 Y8511.000
+G1 X4120.000 Y8502.000
+G1 X4111.000 Y8493.000
+G1 X4105.000 Y8479.000
+G1 X4103.000 Y8465.000
+G1 X4103.000 Y8451.000
+G1 X4104.000 Y8438.000
+G1 X4101.000 Y8425.000
+G1 X4099.000 Y8411.000
+G1 X4099.000 Y8397.000
+G1 X4103.000 Y8384.000
+G1 X4106.000 Y8370.000
+G1 X4116.000 Y8362.000
+G1 X4125.000 Y8353.000
+G1 X4138.000 Y8343.000
+G1 X4147.000 Y8335.000
+G1 X4154.000 Y8326.000
+G1 X4148.000 Y8317.000
+G1 X4136.000 Y8317.000
+G1 X4127.000 Y8311.000
+G1 X4112.000 Y8306.000
+G1 X4093.000 Y8305.000
+G1 X4078.000 Y8305.000
+G1 X4066.000 Y8304.000
+G1 X4053.000 Y8300.000
+G1 X4041.000 Y8295.000
+G1 X4029.000 Y8291.000
+G1 X4023.000 Y8277.000
+G1 X4018.000 Y8262.000
+G1 X4015.000 Y8248.000
+G1 X4013.000 Y8234.000
+G1 X4013.000 Y8226.000
+G1 X4013.000 Y8212.000
+G1 X4014.000 Y8198.000
+G1 X4011.000 Y8184.000
+G1 X4003.000 Y8170.000
+G1 X3997.000 Y8157.000
+G1 X3991.000 Y8147.000
+G1 X3976.000 Y8143.000
+G1 X3964.000 Y8142.000
+G1 X3945.000 Y8145.000
+G1 X3933.000 Y8145.000
+G1 X3917.000 Y8149.000
+G1 X3902.000 Y8154.000
+G1 X3889.000 Y8154.000
+G1 X3874.000 Y8153.000
+G1 X3859.000 Y8147.000
+G1 X3846.000 Y8147.000
+G1 X3831.000 Y8141.000
+G1 X3816.000 Y8141.000
+G1 X3804.000 Y8140.000
+G1 X3791.000 Y8140.000
+G1 X3776.000 Y8139.000
+G1 X3761.000 Y8139.000
+G1 X3749.000 Y8135.000
+G1 X3736.000 Y8138.000
+G1 X3721.000 Y8137.000
+G1 X3705.000 Y8137.000
+G1 X3693.000 Y8136.000
+G1 X3684.000 Y8127.000
+G1 X3672.000 Y8127.000
+G1 X3656.000 Y8126.000
+G1 X3641.000 Y8126.000
+G1 X3629.000 Y8125.000
+G1 X3617.000 Y8125.000
+G1 X3601.000 Y8124.000
+G1 X3589.000 Y8119.000
+G1 X3574.000 Y8118.000
+G1 X3561.000 Y8114.000
+G1 X3549.000 Y8108.000
+G1 X3537.000 Y8105.000
+G1 X3522.000 Y8099.000
+G1 X3516.000 Y8090.000
+G1 X3501.000 Y8079.000
+G1 X3489.000 Y8078.000
+G1 X3473.000 Y8083.000
+G1 X3461.000 Y8091.000
+G1 X3448.000 Y8096.000
+G1 X3436.000 Y8104.000
+G1 X3417.000 Y8109.000
+G1 X3405.000 Y8109.000
+G1 X3392.000 Y8112.000
+G1 X3377.000 Y8116.000
+G1 X3364.000 Y8121.000
+G1 X3352.000 Y8124.000
+G1 X3337.000 Y8123.000
+G1 X3321.000 Y8119.000
+G1 X3306.000 Y8119.000
+G1 X3291.000 Y8113.000
+G1 X3278.000 Y8113.000
+G1 X3267.000 Y8107.000
+G1 X3254.000 Y8103.000
+G1 X3239.000 Y8103.000
+G1 X3227.000 Y8102.000
+G1 X3211.000 Y8102.000
+G1 X3199.000 Y8101.000
+G1 X3183.000 Y8101.000
+G1 X3171.000 Y8095.000
+G1 X3159.000 Y8091.000
+G1 X3144.000 Y8085.000
+G1 X3135.000 Y8080.000
+G1 X3120.000 Y8076.000
+G1 X3108.000 Y8075.000
+G1 X3095.000 Y8070.000
+G1 X3084.000 Y8055.000
+G1 X3078.000 Y8046.000
+G1 X3066.000 Y8037.000
+G1 X3054.000 Y8032.000
+G1 X3045.000 Y8023.000
+G1 X3033.000 Y8017.000
+G1 X3021.000 Y8008.000
+G1 X3009.000 Y7999.000
+G1 X3000.000 Y7993.000
+G1 X2997.000 Y7979.000
+G1 X2991.000 Y7965.000
+G1 X2983.000 Y7956.000
+G1 X2971.000 Y7947.000
+G1 X2959.000 Y7941.000
+G1 X2946.000 Y7941.000
+G1 X2943.000 Y7955.000
+G1 X2937.000 Y7946.000
+G1 X2925.000 Y7932.000
+G1 X2919.000 Y7923.000
+G1 X2910.000 Y7914.000
+G1 X2895.000 Y7903.000
+G1 X2883.000 Y7899.000
+G1 X2871.000 Y7898.000
+G1 X2856.000 Y7893.000
+G1 X2844.000 Y7883.000
+G1 X2832.000 Y7874.000
+G1 X2820.000 Y7869.000
+G1 X2811.000 Y7860.000
+G1 X2799.000 Y7849.000
+G1 X2787.000 Y7845.000
+G1 X2774.000 Y7839.000
+G1 X2762.000 Y7835.000
+G1 X2754.000 Y7825.000
+G1 X2741.000 Y7821.000
+G1 X2729.000 Y7810.000
+G1 X2717.000 Y7809.000
+G1 X2702.000 Y7805.000
+G1 X2690.000 Y7800.000
+G1 X2678.000 Y7791.000
+G1 X2666.000 Y7785.000
+G1 X2657.000 Y7776.000
+G1 X2642.000 Y7772.000
+G1 X2629.000 Y7766.000
+G1 X2617.000 Y7761.000
+G1 X2609.000 Y7752.000
+G1 X2597.000 Y7743.000
+G1 X2587.000 Y7734.000
+G1 X2579.000 Y7723.000
+G1 X2573.000 Y7714.000
+G1 X2561.000 Y7705.000
+G1 X2549.000 Y7699.000
+G1 X2537.000 Y7690.000
+G1 X2525.000 Y7681.000
+G1 X2513.000 Y7675.000
+G1 X2504.000 Y7666.000
+G1 X2498.000 Y7657.000
+G1 X2495.000 Y7643.000
+G1 X2487.000 Y7629.000
+G1 X2475.000 Y7623.000
+G1 X2462.000 Y7620.000
+G1 X2454.000 Y7605.000
+G1 X2445.000 Y7596.000
+G1 X2436.000 Y7586.000
+G1 X2427.000 Y7577.000
+G1 X2421.000 Y7568.000
+G1 X2409.000 Y7559.000
+G1 X2397.000 Y7553.000
+G1 X2385.000 Y7544.000
+G1 X2376.000 Y7538.000
+G1 X2367.000 Y7524.000
+G1 X2355.000 Y7524.000
+G1 X2343.000 Y7515.000
+G1 X2331.000 Y7509.000
+G1 X2319.000 Y7505.000
+G1 X2307.000 Y7499.000
+G1 X2298.000 Y7490.000
+G1 X2289.000 Y7476.000
+G1 X2280.000 Y7467.000
+G1 X2271.000 Y7458.000
+G1 X2262.000 Y7447.000
+G1 X2247.000 Y7443.000
+G1 X2235.000 Y7432.000
+G1 X2226.000 Y7423.000
+G1 X2217.000 Y7414.000
+G1 X2209.000 Y7405.000
+G1 X2197.000 Y7395.000
+G1 X2187.000 Y7391.000
+G1 X2179.000 Y7377.000
+G1 X2170.000 Y7368.000
+G1 X2161.000 Y7357.000
+G1 X2155.000 Y7348.000
+G1 X2146.000 Y7334.000
+G1 X2147.000 Y7320.000
+G1 X2147.000 Y7308.000
+G1 X2141.000 Y7297.000
+G1 X2133.000 Y7285.000
+G1 X2121.000 Y7274.000
+G1 X2115.000 Y7265.000
+G1 X2106.000 Y7251.000
+G1 X2101.000 Y7242.000
+G1 X2098.000 Y7228.000
+G1 X2089.000 Y7219.000
+G1 X2077.000 Y7218.000
+G1 X2064.000 Y7221.000
+G1 X2055.000 Y7212.000
+G1 X2047.000 Y7203.000
+G1 X2044.000 Y7194.000
+G1 X2032.000 Y7184.000
+G1 X2019.000 Y7183.000
+G1 X2007.000 Y7174.000
+G1 X1995.000 Y7174.000
+G1 X1990.000 Y7165.000
+G1 X1987.000 Y7151.000
+G1 X1987.000 Y7137.000
+G1 X1981.000 Y7128.000
+G1 X1976.000 Y7114.000
+G1 X1970.000 Y7105.000
+G1 X1964.000 Y7091.000
+G1 X1958.000 Y7082.000
+G1 X1950.000 Y7073.000
+G1 X1938.000 Y7062.000
+G1 X1929.000 Y7053.000
+G1 X1932.000 Y7039.000
+G1 X1936.000 Y7031.000
+G1 X1948.000 Y7036.000
+G1 X1960.000 Y7040.000
+G1 X1969.000 Y7049.000
+G1 X1984.000 Y7050.000
+G1 X1996.000 Y7055.000
+G1 X2009.000 Y7056.000
+G1 X2021.000 Y7048.000
+G1 X2028.000 Y7039.000
+G1 X2031.000 Y7025.000
+G1 X2032.000 Y7012.000
+G1 X2032.000 Y6998.000
+G1 X2030.000 Y6984.000
+G1 X2021.000 Y6969.000
+G1 X2018.000 Y6955.000
+G1 X2013.000 Y6943.000
+G1 X2004.000 Y6929.000
+G1 X1995.000 Y6918.000
+G1 X1986.000 Y6914.000
+G1 X1974.000 Y6905.000
+G1 X1962.000 Y6900.000
+G1 X1953.000 Y6894.000
+G1 X1944.000 Y6880.000
+G1 X1938.000 Y6871.000
+G1 X1933.000 Y6857.000
+G1 X1924.000 Y6848.000
+G1 X1908.000 Y6852.000
+G1 X1896.000 Y6847.000
+G1 X1884.000 Y6841.000
+G1 X1872.000 Y6832.000
+G1 X1860.000 Y6828.000
+G1 X1848.000 Y6817.000
+G1 X1836.000 Y6813.000
+G1 X1823.000 Y6813.000
+G1 X1811.000 Y6812.000
+G1 X1799.000 Y6807.000
+G1 X1784.000 Y6803.000
+G1 X1778.000 Y6789.000
+G1 X1772.000 Y6778.000
+G1 X1763.000 Y6788.000
+G1 X1766.000 Y6774.000
+G1 X1754.000 Y6769.000
+G1 X1752.000 Y6755.000
+G1 X1746.000 Y6741.000
+G1 X1743.000 Y6728.000
+G1 X1753.000 Y6718.000
+G1 X1763.000 Y6710.000
+G1 X1772.000 Y6702.000
+G1 X1773.000 Y6688.000
+G1 X1776.000 Y6674.000
+G1 X1783.000 Y6660.000
+G1 X1789.000 Y6652.000
+G1 X1799.000 Y6638.000
+G1 X1802.000 Y6630.000
+G1 X1809.000 Y6616.000
+G1 X1812.000 Y6602.000
+G1 X1819.000 Y6594.000
+G1 X1823.000 Y6580.000
+G1 X1829.000 Y6571.000
+G1 X1836.000 Y6558.000
+G1 X1839.000 Y6544.000
+G1 X1840.000 Y6530.000
+G1 X1843.000 Y6516.000
+G1 X1844.000 Y6508.000
+G1 X1844.000 Y6494.000
+G1 X1845.000 Y6480.000
+G1 X1845.000 Y6468.000
+G1 X1849.000 Y6454.000
+G1 X1852.000 Y6440.000
+G1 X1853.000 Y6426.000
+G1 X1856.000 Y6418.000
+G1 X1857.000 Y6399.000
+G1 X1857.000 Y6390.000
+G1 X1861.000 Y6376.000
+G1 X1864.000 Y6362.000
+G1 X1865.000 Y6348.000
+G1 X1865.000 Y6335.000
+G1 X1872.000 Y6326.000
+G1 X1875.000 Y6312.000
+G1 X1882.000 Y6300.000
+G1 X1879.000 Y6286.000
+G1 X1873.000 Y6272.000
+G1 X1868.000 Y6258.000
+G1 X1872.000 Y6244.000
+G1 X1875.000 Y6231.000
+G1 X1879.000 Y6217.000
+G1 X1882.000 Y6203.000
+G1 X1883.000 Y6191.000
+G1 X1883.000 Y6177.000
+G1 X1878.000 Y6158.000
+G1 X1869.000 Y6149.000
+G1 X1863.000 Y6135.000
+G1 X1857.000 Y6121.000
+G1 X1852.000 Y6106.000
+G1 X1846.000 Y6094.000
+G1 X1844.000 Y6075.000
+G1 X1847.000 Y6061.000
+G1 X1848.000 Y6047.000
+G1 X1845.000 Y6033.000
+G1 X1839.000 Y6021.000
+G1 X1831.000 Y6007.000
+G1 X1825.000 Y5992.000
+G1 X1823.000 Y5973.000
+G1 X1823.000 Y5961.000
+G1 X1824.000 Y5942.000
+G1 X1815.000 Y5928.000
+G1 X1806.000 Y5919.000
+G1 X1797.000 Y5910.000
+G1 X1785.000 Y5904.000
+G1 X1779.000 Y5890.000
+G1 X1780.000 Y5876.000
+G1 X1777.000 Y5867.000
+G1 X1768.000 Y5853.000
+G1 X1763.000 Y5839.000
+G1 X1760.000 Y5827.000
+G1 X1758.000 Y5813.000
+G1 X1764.000 Y5803.000
+G1 X1774.000 Y5794.000
+G1 X1786.000 Y5791.000
+G1 X1790.000 Y5777.000
+G1 X1787.000 Y5763.000
+G1 X1790.000 Y5755.000
+G1 X1791.000 Y5741.000
+G1 X1788.000 Y5727.000
+G1 X1795.000 Y5719.000
+G1 X1795.000 Y5705.000
+G1 X1796.000 Y5691.000
+G1 X1793.000 Y5677.000
+G1 X1806.000 Y5668.000
+G1 X1821.000 Y5669.000
+G1 X1831.000 Y5655.000
+G1 X1846.000 Y5661.000
+G1 X1850.000 Y5647.000
+G1 X1853.000 Y5634.000
+G1 X1853.000 Y5625.000
+G1 X1860.000 Y5611.000
+G1 X1867.000 Y5598.000
+G1 X1873.000 Y5589.000
+G1 X1877.000 Y5575.000
+G1 X1880.000 Y5562.000
+G1 X1887.000 Y5548.000
+G1 X1887.000 Y5539.000
+G1 X1891.000 Y5520.000
+G1 X1894.000 Y5512.000
+G1 X1898.000 Y5498.000
+G1 X1899.000 Y5484.000
+G1 X1905.000 Y5476.000
+G1 X1906.000 Y5462.000
+G1 X1912.000 Y5448.000
+G1 X1913.000 Y5434.000
+G1 X1919.000 Y5426.000
+G1 X1926.000 Y5412.000
+G1 X1932.000 Y5398.000
+G1 X1933.000 Y5386.000
+G1 X1942.000 Y5376.000
+G1 X1949.000 Y5368.000
+G1 X1955.000 Y5354.000
+G1 X1962.000 Y5346.000
+G1 X1959.000 Y5332.000
+G1 X1954.000 Y5323.000
+G1 X1951.000 Y5309.000
+G1 X1955.000 Y5295.000
+G1 X1955.000 Y5281.000
+G1 X1955.000 Y5272.000
+G1 X1953.000 Y5258.000
+G1 X1956.000 Y5245.000
+G1 X1963.000 Y5231.000
+G1 X1963.000 Y5222.000
+G1 X1954.000 Y5213.000
+G1 X1955.000 Y5199.000
+G1 X1955.000 Y5185.000
+G1 X1968.000 Y5177.000
+G1 X1974.000 Y5169.000
+G1 X1987.000 Y5164.000
+G1 X1994.000 Y5150.000
+G1 X1991.000 Y5136.000
+G1 X1991.000 Y5124.000
+G1 X2001.000 Y5111.000
+G1 X2002.000 Y5097.000
+G1 X2002.000 Y5078.000
+G1 X1993.000 Y5074.000
+G1 X1984.000 Y5082.000
+G1 X1993.000 Y5077.000
+G1 X2000.000 Y5064.000
+G1 X2006.000 Y5052.000
+G1 X2013.000 Y5041.000
+G1 X2019.000 Y5029.000
+G1 X2029.000 Y5016.000
+G1 X2033.000 Y5002.000
+G1 X2036.000 Y4994.000
+G1 X2043.000 Y4980.000
+G1 X2046.000 Y4966.000
+G1 X2046.000 Y4957.000
+G1 X2050.000 Y4944.000
+G1 X2057.000 Y4933.000
+G1 X2072.000 Y4931.000
+G1 X2082.000 Y4922.000
+G1 X2073.000 Y4908.000
+G1 X2064.000 Y4897.000
+G1 X2055.000 Y4888.000
+G1 X2059.000 Y4874.000
+G1 X2062.000 Y4862.000
+G1 X2056.000 Y4852.000
+G1 X2060.000 Y4838.000
+G1 X2072.000 Y4838.000
+G1 X2082.000 Y4830.000
+G1 X2089.000 Y4813.000
+G1 X2092.000 Y4803.000
+G1 X2102.000 Y4794.000
+G1 X2114.000 Y4791.000
+G1 X2124.000 Y4778.000
+G1 X2130.000 Y4767.000
+G1 X2128.000 Y4755.000
+G1 X2125.000 Y4745.000
+G1 X2131.000 Y4733.000
+G1 X2135.000 Y4719.000
+G1 X2144.000 Y4714.000
+G1 X2157.000 Y4709.000
+G1 X2169.000 Y4706.000
+G1 X2182.000 Y4698.000
+G1 X2182.000 Y4684.000
+G1 X2186.000 Y4670.000
+G1 X2193.000 Y4657.000
+G1 X2199.000 Y4643.000
+G1 X2203.000 Y4634.000
+G1 X2209.000 Y4621.000
+G1 X2222.000 Y4621.000
+G1 X2237.000 Y4622.000
+G1 X2249.000 Y4617.000
+G1 X2244.000 Y4603.000
+G1 X2241.000 Y4591.000
+G1 X2251.000 Y4581.000
+G1 X2257.000 Y4569.000
+G1 X2264.000 Y4555.000
+G1 X2276.000 Y4550.000
+G1 X2286.000 Y4542.000
+G1 X2286.000 Y4533.000
+G1 X2277.000 Y4522.000
+G1 X2265.000 Y4518.000
+G1 X2253.000 Y4508.000
+G1 X2241.000 Y4504.000
+G1 X2229.000 Y4495.000
+G1 X2220.000 Y4489.000
+G1 X2208.000 Y4483.000
+G1 X2193.000 Y4479.000
+G1 X2181.000 Y4469.000
+G1 X2175.000 Y4456.000
+G1 X2176.000 Y4442.000
+G1 X2173.000 Y4432.000
+G1 X2176.000 Y4420.000
+G1 X2177.000 Y4406.000
+G1 X2174.000 Y4392.000
+G1 X2175.000 Y4373.000
+G1 X2172.000 Y4360.000
+G1 X2173.000 Y4347.000
+G1 X2188.000 Y4342.000
+G1 X2198.000 Y4334.000
+G1 X2204.000 Y4325.000
+G1 X2202.000 Y4311.000
+G1 X2202.000 Y4297.000
+G1 X2215.000 Y4289.000
+G1 X2218.000 Y4275.000
+G1 X2219.000 Y4261.000
+G1 X2223.000 Y4248.000
+G1 X2229.000 Y4234.000
+G1 X2236.000 Y4225.000
+G1 X2236.000 Y4212.000
+G1 X2240.000 Y4198.000
+G1 X2243.000 Y4184.000
+G1 X2247.000 Y4170.000
+G1 X2253.000 Y4158.000
+G1 X2260.000 Y4148.000
+G1 X2266.000 Y4134.000
+G1 X2267.000 Y4122.000
+G1 X2271.000 Y4108.000
+G1 X2268.000 Y4100.000
+G1 X2268.000 Y4086.000
+G1 X2278.000 Y4076.000
+G1 X2291.000 Y4067.000
+G1 X2300.000 Y4059.000
+G1 X2304.000 Y4045.000
+G1 X2307.000 Y4032.000
+G1 X2305.000 Y4018.000
+G1 X2302.000 Y4009.000
+G1 X2302.000 Y3995.000
+G1 X2300.000 Y3981.000
+G1 X2291.000 Y3972.000
+G1 X2285.000 Y3963.000
+G1 X2285.000 Y3949.000
+G1 X2289.000 Y3935.000
+G1 X2296.000 Y3922.000
+G1 X2299.000 Y3908.000
+G1 X2300.000 Y3896.000
+G1 X2312.000 Y3891.000
+G1 X2324.000 Y3891.000
+G1 X2336.000 Y3897.000
+G1 X2352.000 Y3898.000
+G1 X2367.000 Y3893.000
+G1 X2383.000 Y3888.000
+G1 X2390.000 Y3875.000
+G1 X2390.000 Y3862.000
+G1 X2390.000 Y3852.000
+G1 X2400.000 Y3838.000
+G1 X2367.000 Y3806.000
+G1 X2395.000 Y3812.000
+G1 X2349.000 Y3788.000
+G1 X2335.000 Y3774.000
+G1 X2326.000 Y3759.000
+G1 X2317.000 Y3745.000
+G1 X2311.000 Y3736.000
+G1 X2299.000 Y3727.000
+G1 X2284.000 Y3721.000
+G1 X2272.000 Y3721.000
+G1 X2257.000 Y3720.000
+G1 X2241.000 Y3720.000
+G1 X2229.000 Y3714.000
+G1 X2217.000 Y3710.000
+G1 X2202.000 Y3704.000
+G1 X2190.000 Y3699.000
+G1 X2181.000 Y3690.000
+G1 X2178.000 Y3676.000
+G1 X2176.000 Y3662.000
+G1 X2176.000 Y3650.000
+G1 X2174.000 Y3636.000
+G1 X2168.000 Y3622.000
+G1 X2165.000 Y3608.000
+G1 X2163.000 Y3594.000
+G1 X2160.000 Y3585.000
+G1 X2160.000 Y3571.000
+G1 X2164.000 Y3557.000
+G1 X2168.000 Y3543.000
+G1 X2177.000 Y3535.000
+G1 X2190.000 Y3530.000
+G1 X2199.000 Y3518.000
+G1 X2200.000 Y3504.000
+G1 X2200.000 Y3491.000
+G1 X2198.000 Y3477.000
+G1 X2198.000 Y3463.000
+G1 X2202.000 Y3449.000
+G1 X2205.000 Y3435.000
+G1 X2215.000 Y3427.000
+G1 X2224.000 Y3418.000
+G1 X2234.000 Y3410.000
+G1 X2244.000 Y3400.000
+G1 X2256.000 Y3400.000
+G1 X2268.000 Y3397.000
+G1 X2278.000 Y3389.000
+G1 X2278.000 Y3375.000
+G1 X2282.000 Y3361.000
+G1 X2282.000 Y3347.000
+G1 X2286.000 Y3334.000
+G1 X2289.000 Y3325.000
+G1 X2293.000 Y3311.000
+G1 X2293.000 Y3297.000
+G1 X2288.000 Y3283.000
+G1 X2279.000 Y3274.000
+G1 X2266.000 Y3269.000
+G1 X2258.000 Y3256.000
+G1 X2252.000 Y3246.000
+G1 X2243.000 Y3231.000
+G1 X2234.000 Y3222.000
+G1 X2229.000 Y3213.000
+G1 X2229.000 Y3200.000
+G1 X2233.000 Y3186.000
+G1 X2236.000 Y3172.000
+G1 X2240.000 Y3158.000
+G1 X2231.000 Y3149.000
+G1 X2237.000 Y3136.000
+G1 X2247.000 Y3127.000
+G1 X2265.000 Y3124.000
+G1 X2278.000 Y3120.000
+G1 X2291.000 Y3111.000
+G1 X2303.000 Y3101.000
+G1 X2313.000 Y3093.000
+G1 X2319.000 Y3085.000
+G1 X2329.000 Y3076.000
+G1 X2338.000 Y3066.000
+G1 X2351.000 Y3058.000
+G1 X2360.000 Y3050.000
+G1 X2373.000 Y3036.000
+G1 X2380.000 Y3028.000
+G1 X2386.000 Y3014.000
+G1 X2387.000 Y3000.000
+G1 X2378.000 Y2991.000
+G1 X2366.000 Y2982.000
+G1 X2351.000 Y2976.000
+G1 X2336.000 Y2976.000
+G1 X2323.000 Y2970.000
+G1 X2308.000 Y2969.000
+G1 X2293.000 Y2969.000
+G1 X2277.000 Y2968.000
+G1 X2262.000 Y2968.000
+G1 X2247.000 Y2964.000
+G1 X2235.000 Y2963.000
+G1 X2219.000 Y2963.000
+G1 X2207.000 Y2962.000
+G1 X2194.000 Y2965.000
+G1 X2179.000 Y2970.000
+G1 X2167.000 Y2970.000
+G1 X2154.000 Y2969.000
+G1 X2145.000 Y2978.000
+G1 X2135.000 Y2986.000
+G1 X2123.000 Y2985.000
+G1 X2111.000 Y2982.000
+G1 X2102.000 Y2973.000
+G1 X2093.000 Y2962.000
+G1 X2087.000 Y2949.000
+G1 X2085.000 Y2935.000
+G1 X2085.000 Y2922.000
+G1 X2086.000 Y2908.000
+G1 X2089.000 Y2894.000
+G1 X2096.000 Y2880.000
+G1 X2102.000 Y2867.000
+G1 X2109.000 Y2853.000
+G1 X2117.000 Y2808.000
+G1 X2134.000 Y2836.000
+G1 X2140.000 Y2854.000
+G1 X2145.000 Y2879.000
+G1 X2150.000 Y2901.000
+G1 X2153.000 Y2919.000
+G1 X2156.000 Y2933.000
+G1 X2155.000 Y2947.000
+G1 X2152.000 Y2960.000
+G1 X2151.000 Y2969.000
+G1 X2148.000 Y2960.000
+M5
+G0 X2215.000 Y2893.000
+M3 S1000
+G1 X2225.000 Y2885.000 F1000
+G1 X2240.000 Y2885.000
+G1 X2253.000 Y2886.000
+G1 X2268.000 Y2886.000
+G1 X2283.000 Y2887.000
+G1 X2296.000 Y2884.000
+G1 X2311.000 Y2879.000
+G1 X2327.000 Y2871.000
+G1 X2321.000 Y2857.000
+G1 X2309.000 Y2848.000
+G1 X2297.000 Y2847.000
+G1 X2288.000 Y2837.000
+G1 X2282.000 Y2824.000
+G1 X2292.000 Y2819.000
+G1 X2308.000 Y2806.000
+G1 X2320.000 Y2801.000
+G1 X2332.000 Y2802.000
+G1 X2345.000 Y2793.000
+G1 X2357.000 Y2790.000
+G1 X2370.000 Y2780.000
+G1 X2379.000 Y2772.000
+G1 X2389.000 Y2767.000
+G1 X2398.000 Y2759.000
+G1 X2408.000 Y2745.000
+G1 X2409.000 Y2731.000
+G1 X2393.000 Y2727.000
+G1 X2381.000 Y2722.000
+G1 X2369.000 Y2721.000
+G1 X2363.000 Y2712.000
+G1 X2354.000 Y2703.000
+G1 X2342.000 Y2693.000
+G1 X2327.000 Y2689.000
+G1 X2315.000 Y2688.000
+G1 X2303.000 Y2682.000
+G1 X2291.000 Y2673.000
+G1 X2282.000 Y2664.000
+G1 X2279.000 Y2650.000
+G1 X2289.000 Y2642.000
+G1 X2295.000 Y2632.000
+G1 X2308.000 Y2629.000
+G1 X2317.000 Y2620.000
+G1 X2327.000 Y2610.000
+G1 X2331.000 Y2597.000
+G1 X2325.000 Y2584.000
+G1 X2313.000 Y2570.000
+G1 X2307.000 Y2559.000
+G1 X2298.000 Y2547.000
+G1 X2296.000 Y2536.000
+G1 X2299.000 Y2522.000
+G1 X2297.000 Y2510.000
+G1 X2294.000 Y2496.000
+G1 X2294.000 Y2482.000
+G1 X2292.000 Y2474.000
+G1 X2292.000 Y2460.000
+G1 X2296.000 Y2446.000
+G1 X2296.000 Y2432.000
+G1 X2297.000 Y2418.000
+G1 X2306.000 Y2410.000
+G1 X2319.000 Y2401.000
+G1 X2331.000 Y2405.000
+G1 X2343.000 Y2411.000
+G1 X2352.000 Y2420.000
+G1 X2364.000 Y2420.000
+G1 X2377.000 Y2426.000
+G1 X2389.000 Y2418.000
+G1 X2399.000 Y2408.000
+G1 X2411.000 Y2405.000
+G1 X2423.000 Y2405.000
+G1 X2433.000 Y2400.000
+G1 X2434.000 Y2383.000
+G1 X2440.000 Y2369.000
+G1 X2450.000 Y2359.000
+G1 X2456.000 Y2347.000
+G1 X2466.000 Y2337.000
+G1 X2475.000 Y2329.000
+G1 X2488.000 Y2329.000
+G1 X2497.000 Y2321.000
+G1 X2507.000 Y2313.000
+G1 X2516.000 Y2299.000
+G1 X2526.000 Y2291.000
+G1 X2529.000 Y2277.000
+G1 X2536.000 Y2267.000
+G1 X2546.000 Y2258.000
+G1 X2549.000 Y2245.000
+G1 X2543.000 Y2230.000
+G1 X2541.000 Y2218.000
+G1 X2541.000 Y2208.000
+G1 X2548.000 Y2196.000
+G1 X2554.000 Y2186.000
+G1 X2552.000 Y2172.000
+G1 X2543.000 Y2163.000
+G1 X2537.000 Y2154.000
+G1 X2531.000 Y2140.000
+G1 X2529.000 Y2126.000
+G1 X2517.000 Y2116.000
+G1 X2511.000 Y2108.000
+G1 X2499.000 Y2097.000
+G1 X2493.000 Y2084.000
+G1 X2494.000 Y2070.000
+G1 X2494.000 Y2057.000
+G1 X2501.000 Y2043.000
+G1 X2513.000 Y2038.000
+G1 X2523.000 Y2035.000
+G1 X2535.000 Y2027.000
+G1 X2548.000 Y2022.000
+G1 X2560.000 Y2017.000
+G1 X2570.000 Y2009.000
+G1 X2585.000 Y2004.000
+G1 X2601.000 Y2005.000
+G1 X2610.000 Y2015.000
+G1 X2618.000 Y2024.000
+G1 X2634.000 Y2025.000
+G1 X2628.000 Y2016.000
+G1 X2625.000 Y2002.000
+G1 X2626.000 Y1988.000
+G1 X2614.000 Y1979.000
+G1 X2608.000 Y1969.000
+G1 X2609.000 Y1951.000
+G1 X2618.000 Y1943.000
+G1 X2616.000 Y1932.000
+G1 X2613.000 Y1920.000
+G1 X2620.000 Y1906.000
+G1 X2620.000 Y1892.000
+G1 X2626.000 Y1884.000
+G1 X2636.000 Y1874.000
+G1 X2646.000 Y1866.000
+G1 X2652.000 Y1852.000
+G1 X2653.000 Y1838.000
+G1 X2644.000 Y1829.000
+G1 X2641.000 Y1815.000
+G1 X2651.000 Y1807.000
+G1 X2639.000 Y1798.000
+G1 X2642.000 Y1784.000
+G1 X2646.000 Y1770.000
+G1 X2643.000 Y1756.000
+G1 X2637.000 Y1747.000
+G1 X2638.000 Y1733.000
+G1 X2635.000 Y1719.000
+G1 X2648.000 Y1716.000
+G1 X2651.000 Y1702.000
+G1 X2664.000 Y1698.000
+G1 X2676.000 Y1703.000
+G1 X2683.000 Y1690.000
+G1 X2677.000 Y1675.000
+G1 X2675.000 Y1661.000
+G1 X2687.000 Y1658.000
+G1 X2696.000 Y1667.000
+G1 X2708.000 Y1677.000
+G1 X2717.000 Y1682.000
+G1 X2732.000 Y1686.000
+G1 X2744.000 Y1692.000
+G1 X2756.000 Y1701.000
+G1 X2768.000 Y1707.000
+G1 X2781.000 Y1707.000
+G1 X2793.000 Y1716.000
+G1 X2801.000 Y1722.000
+G1 X2802.000 Y1708.000
+G1 X2796.000 Y1699.000
+G1 X2784.000 Y1684.000
+G1 X2772.000 Y1674.000
+G1 X2757.000 Y1664.000
+G1 X2748.000 Y1655.000
+G1 X2743.000 Y1641.000
+G1 X2740.000 Y1627.000
+G1 X2747.000 Y1614.000
+G1 X2747.000 Y1605.000
+G1 X2744.000 Y1591.000
+G1 X2742.000 Y1577.000
+G1 X2757.000 Y1583.000
+G1 X2766.000 Y1592.000
+G1 X2775.000 Y1601.000
+G1 X2784.000 Y1615.000
+G1 X2793.000 Y1626.000
+G1 X2805.000 Y1630.000
+G1 X2814.000 Y1639.000
+G1 X2826.000 Y1644.000
+G1 X2833.000 Y1631.000
+G1 X2830.000 Y1617.000
+G1 X2821.000 Y1608.000
+G1 X2818.000 Y1594.000
+G1 X2816.000 Y1580.000
+G1 X2810.000 Y1565.000
+G1 X2807.000 Y1557.000
+G1 X2805.000 Y1543.000
+G1 X2796.000 Y1530.000
+G1 X2787.000 Y1519.000
+G1 X2784.000 Y1505.000
+G1 X2776.000 Y1496.000
+G1 X2788.000 Y1493.000
+G1 X2800.000 Y1497.000
+G1 X2812.000 Y1503.000
+G1 X2825.000 Y1507.000
+G1 X2837.000 Y1513.000
+G1 X2849.000 Y1522.000
+G1 X2857.000 Y1532.000
+G1 X2866.000 Y1536.000
+G1 X2882.000 Y1542.000
+G1 X2894.000 Y1551.000
+G1 X2906.000 Y1551.000
+G1 X2915.000 Y1560.000
+G1 X2924.000 Y1570.000
+G1 X2936.000 Y1566.000
+G1 X2945.000 Y1581.000
+G1 X2951.000 Y1590.000
+G1 X2956.000 Y1604.000
+G1 X2960.000 Y1590.000
+G1 X2957.000 Y1581.000
+G1 X2958.000 Y1567.000
+G1 X2958.000 Y1553.000
+G1 X2959.000 Y1539.000
+G1 X2953.000 Y1531.000
+G1 X2944.000 Y1516.000
+G1 X2938.000 Y1507.000
+G1 X2926.000 Y1498.000
+G1 X2921.000 Y1488.000
+G1 X2915.000 Y1479.000
+G1 X2927.000 Y1474.000
+G1 X2940.000 Y1474.000
+G1 X2952.000 Y1480.000
+G1 X2960.000 Y1489.000
+G1 X2976.000 Y1490.000
+G1 X2973.000 Y1476.000
+G1 X2970.000 Y1463.000
+G1 X2986.000 Y1464.000
+G1 X2993.000 Y1454.000
+G1 X2987.000 Y1445.000
+G1 X2978.000 Y1431.000
+G1 X2984.000 Y1422.000
+G1 X3000.000 Y1418.000
+G1 X3012.000 Y1414.000
+G1 X3010.000 Y1400.000
+G1 X3001.000 Y1391.000
+G1 X2992.000 Y1381.000
+G1 X2989.000 Y1372.000
+G1 X2984.000 Y1358.000
+G1 X2975.000 Y1349.000
+G1 X2966.000 Y1340.000
+G1 X2960.000 Y1331.000
+G1 X2948.000 Y1320.000
+G1 X2939.000 Y1311.000
+G1 X2940.000 Y1297.000
+G1 X2934.000 Y1283.000
+G1 X2919.000 Y1279.000
+G1 X2910.000 Y1268.000
+G1 X2895.000 Y1264.000
+G1 X2883.000 Y1255.000
+G1 X2880.000 Y1241.000
+G1 X2871.000 Y1227.000
+G1 X2865.000 Y1218.000
+G1 X2860.000 Y1204.000
+G1 X2854.000 Y1195.000
+G1 X2842.000 Y1184.000
+G1 X2833.000 Y1180.000
+G1 X2821.000 Y1170.000
+G1 X2809.000 Y1166.000
+G1 X2800.000 Y1157.000
+G1 X2785.000 Y1151.000
+G1 X2773.000 Y1142.000
+G1 X2767.000 Y1131.000
+G1 X2761.000 Y1122.000
+G1 X2750.000 Y1108.000
+G1 X2741.000 Y1099.000
+G1 X2732.000 Y1090.000
+G1 X2726.000 Y1076.000
+G1 X2714.000 Y1061.000
+G1 X2708.000 Y1053.000
+G1 X2703.000 Y1038.000
+G1 X2709.000 Y1030.000
+G1 X2719.000 Y1022.000
+G1 X2725.000 Y1011.000
+G1 X2741.000 Y1012.000
+G1 X2756.000 Y1023.000
+G1 X2768.000 Y1027.000
+G1 X2780.000 Y1036.000
+G1 X2788.000 Y1047.000
+G1 X2801.000 Y1051.000
+G1 X2816.000 Y1051.000
+G1 X2828.000 Y1052.000
+G1 X2844.000 Y1049.000
+G1 X2856.000 Y1049.000
+G1 X2868.000 Y1050.000
+G1 X2881.000 Y1050.000
+G1 X2893.000 Y1054.000
+G1 X2908.000 Y1060.000
+G1 X2923.000 Y1065.000
+G1 X2939.000 Y1061.000
+G1 X2951.000 Y1056.000
+G1 X2963.000 Y1062.000
+G1 X2972.000 Y1071.000
+G1 X2987.000 Y1076.000
+G1 X3000.000 Y1080.000
+G1 X3015.000 Y1086.000
+G1 X3027.000 Y1081.000
+G1 X3037.000 Y1073.000
+G1 X3049.000 Y1070.000
+G1 X3062.000 Y1070.000
+G1 X3074.000 Y1060.000
+G1 X3090.000 Y1061.000
+G1 X3102.000 Y1061.000
+G1 X3117.000 Y1067.000
+G1 X3132.000 Y1073.000
+G1 X3141.000 Y1082.000
+G1 X3156.000 Y1086.000
+G1 X3168.000 Y1092.000
+G1 X3181.000 Y1087.000
+G1 X3193.000 Y1075.000
+G1 X3206.000 Y1065.000
+G1 X3219.000 Y1062.000
+G1 X3228.000 Y1052.000
+G1 X3241.000 Y1052.000
+G1 X3253.000 Y1053.000
+G1 X3268.000 Y1053.000
+G1 X3280.000 Y1059.000
+G1 X3293.000 Y1059.000
+G1 X3308.000 Y1060.000
+G1 X3323.000 Y1061.000
+G1 X3336.000 Y1056.000
+G1 X3348.000 Y1056.000
+G1 X3360.000 Y1057.000
+G1 X3373.000 Y1057.000
+G1 X3385.000 Y1057.000
+G1 X3400.000 Y1055.000
+G1 X3409.000 Y1064.000
+G1 X3424.000 Y1069.000
+G1 X3434.000 Y1056.000
+G1 X3435.000 Y1042.000
+G1 X3432.000 Y1028.000
+G1 X3423.000 Y1019.000
+G1 X3421.000 Y1000.000
+G1 X3415.000 Y991.000
+G1 X3406.000 Y982.000
+G1 X3400.000 Y973.000
+G1 X3391.000 Y962.000
+G1 X3379.000 Y958.000
+G1 X3373.000 Y944.000
+G1 X3368.000 Y935.000
+G1 X3368.000 Y921.000
+G1 X3366.000 Y907.000
+G1 X3378.000 Y903.000
+G1 X3385.000 Y889.000
+G1 X3394.000 Y886.000
+G1 X3410.000 Y881.000
+G1 X3422.000 Y881.000
+G1 X3419.000 Y868.000
+G1 X3429.000 Y854.000
+G1 X3438.000 Y851.000
+G1 X3454.000 Y851.000
+G1 X3466.000 Y852.000
+G1 X3481.000 Y856.000
+G1 X3493.000 Y867.000
+G1 X3505.000 Y871.000
+G1 X3518.000 Y876.000
+G1 X3527.000 Y868.000
+G1 X3536.000 Y858.000
+G1 X3546.000 Y846.000
+G1 X3553.000 Y836.000
+G1 X3550.000 Y822.000
+G1 X3538.000 Y821.000
+G1 X3526.000 Y821.000
+G1 X3517.000 Y808.000
+G1 X3517.000 Y798.000
+G1 X3505.000 Y794.000
+G1 X3493.000 Y788.000
+G1 X3484.000 Y774.000
+G1 X3478.000 Y765.000
+G1 X3473.000 Y751.000
+G1 X3473.000 Y737.000
+G1 X3470.000 Y723.000
+G1 X3462.000 Y720.000
+G1 X3450.000 Y710.000
+G1 X3441.000 Y700.000
+G1 X3428.000 Y696.000
+G1 X3420.000 Y685.000
+G1 X3411.000 Y676.000
+G1 X3402.000 Y662.000
+G1 X3396.000 Y653.000
+G1 X3403.000 Y639.000
+G1 X3415.000 Y645.000
+G1 X3424.000 Y659.000
+G1 X3436.000 Y663.000
+G1 X3451.000 Y669.000
+G1 X3460.000 Y674.000
+G1 X3475.000 Y675.000
+G1 X3488.000 Y670.000
+G1 X3497.000 Y665.000
+G1 X3504.000 Y651.000
+G1 X3492.000 Y639.000
+G1 X3486.000 Y628.000
+G1 X3478.000 Y616.000
+G1 X3469.000 Y605.000
+G1 X3456.000 Y605.000
+G1 X3447.000 Y596.000
+G1 X3438.000 Y590.000
+G1 X3427.000 Y581.000
+G1 X3418.000 Y572.000
+G1 X3409.000 Y563.000
+G1 X3403.000 Y552.000
+G1 X3397.000 Y543.000
+G1 X3385.000 Y534.000
+G1 X3376.000 Y525.000
+G1 X3364.000 Y520.000
+G1 X3352.000 Y511.000
+G1 X3346.000 Y502.000
+G1 X3334.000 Y496.000
+G1 X3322.000 Y490.000
+G1 X3310.000 Y495.000
+G1 X3298.000 Y486.000
+G1 X3292.000 Y475.000
+G1 X3280.000 Y466.000
+G1 X3271.000 Y457.000
+G1 X3259.000 Y452.000
+G1 X3249.000 Y456.000
+G1 X3234.000 Y461.000
+G1 X3228.000 Y447.000
+G1 X3229.000 Y433.000
+G1 X3235.000 Y425.000
+G1 X3239.000 Y411.000
+G1 X3233.000 Y402.000
+G1 X3221.000 Y402.000
+G1 X3206.000 Y396.000
+G1 X3203.000 Y387.000
+G1 X3187.000 Y390.000
+G1 X3188.000 Y376.000
+G1 X3182.000 Y367.000
+G1 X3182.000 Y353.000
+G1 X3198.000 Y350.000
+G1 X3210.000 Y354.000
+G1 X3222.000 Y365.000
+G1 X3234.000 Y360.000
+G1 X3247.000 Y361.000
+G1 X3259.000 Y361.000
+G1 X3271.000 Y367.000
+G1 X3278.000 Y353.000
+G1 X3281.000 Y339.000
+G1 X3272.000 Y330.000
+G1 X3267.000 Y320.000
+G1 X3276.000 Y317.000
+G1 X3280.000 Y303.000
+G1 X3286.000 Y289.000
+G1 X3298.000 Y290.000
+G1 X3311.000 Y290.000
+G1 X3314.000 Y276.000
+G1 X3302.000 Y267.000
+G1 X3287.000 Y261.000
+G1 X3282.000 Y247.000
+G1 X3272.000 Y243.000
+G1 X3260.000 Y238.000
+G1 X3248.000 Y229.000
+G1 X3246.000 Y220.000
+G1 X3252.000 Y210.000
+G1 X3259.000 Y198.000
+G1 X3271.000 Y193.000
+G1 X3281.000 Y185.000
+G1 X3290.000 Y175.000
+G1 X3297.000 Y166.000
+G1 X3309.000 Y163.000
+G1 X3322.000 Y158.000
+G1 X3325.000 Y145.000
+G1 X3334.000 Y136.000
+G1 X3347.000 Y137.000
+G1 X3359.000 Y128.000
+G1 X3372.000 Y129.000
+G1 X3384.000 Y129.000
+G1 X3396.000 Y130.000
+G1 X3409.000 Y125.000
+G1 X3421.000 Y117.000
+G1 X3425.000 Y108.000
+G1 X3419.000 Y94.000
+G1 X3414.000 Y80.000
+G1 X3404.000 Y74.000
+G1 X3396.000 Y65.000
+G1 X3387.000 Y56.000
+G1 X3374.000 Y51.000
+G1 X3366.000 Y42.000
+G1 X3357.000 Y33.000
+G1 X3357.000 Y22.000
+G1 X3357.000 Y8.000
+G1 X3367.000 Y0.000
+G1 X3357.000 Y8.000
+G1 X3351.000 Y22.000
+G1 X3344.000 Y36.000
+G1 X3356.000 Y45.000
+G1 X3368.000 Y56.000
+G1 X3380.000 Y60.000
+G1 X3365.000 Y64.000
+G1 X3352.000 Y69.000
+G1 X3346.000 Y77.000
+G1 X3342.000 Y91.000
+G1 X3342.000 Y105.000
+G1 X3345.000 Y117.000
+G1 X3347.000 Y128.000
+G1 X3344.000 Y142.000
+G1 X3337.000 Y154.000
+G1 X3325.000 Y153.000
+G1 X3312.000 Y153.000
+G1 X3321.000 Y164.000
+G1 X3318.000 Y176.000
+G1 X3306.000 Y175.000
+G1 X3293.000 Y175.000
+G1 X3284.000 Y171.000
+G1 X3272.000 Y174.000
+G1 X3274.000 Y188.000
+G1 X3268.000 Y198.000
+G1 X3271.000 Y210.000
+G1 X3258.000 Y210.000
+G1 X3246.000 Y215.000
+G1 X3236.000 Y223.000
+G1 X3239.000 Y237.000
+G1 X3232.000 Y246.000
+G1 X3242.000 Y242.000
+G1 X3254.000 Y246.000
+G1 X3259.000 Y257.000
+G1 X3275.000 Y266.000
+G1 X3287.000 Y267.000
+G1 X3299.000 Y267.000
+G1 X3305.000 Y281.000
+G1 X3311.000 Y295.000
+G1 X3319.000 Y304.000
+G1 X3310.000 Y313.000
+G1 X3298.000 Y317.000
+G1 X3285.000 Y317.000
+G1 X3273.000 Y320.000
+G1 X3264.000 Y311.000
+G1 X3257.000 Y325.000
+G1 X3251.000 Y333.000
+G1 X3253.000 Y347.000
+G1 X3259.000 Y361.000
+G1 X3261.000 Y375.000
+G1 X3258.000 Y384.000
+G1 X3252.000 Y375.000
+G1 X3240.000 Y383.000
+G1 X3228.000 Y377.000
+G1 X3213.000 Y373.000
+G1 X3200.000 Y373.000
+G1 X3184.000 Y381.000
+G1 X3172.000 Y389.000
+G1 X3163.000 Y380.000
+G1 X3159.000 Y394.000
+G1 X3165.000 Y412.000
+G1 X3171.000 Y422.000
+G1 X3183.000 Y431.000
+G1 X3192.000 Y437.000
+G1 X3204.000 Y446.000
+G1 X3219.000 Y450.000
+G1 X3234.000 Y451.000
+G1 X3234.000 Y461.000
+G1 X3243.000 Y474.000
+G1 X3255.000 Y479.000
+G1 X3267.000 Y488.000
+G1 X3279.000 Y494.000
+G1 X3291.000 Y503.000
+G1 X3297.000 Y512.000
+G1 X3305.000 Y523.000
+G1 X3308.000 Y537.000
+G1 X3323.000 Y541.000
+G1 X3336.000 Y533.000
+G1 X3349.000 Y524.000
+G1 X3361.000 Y525.000
+G1 X3366.000 Y539.000
+G1 X3372.000 Y551.000
+G1 X3384.000 Y557.000
+G1 X3396.000 Y562.000
+G1 X3411.000 Y572.000
+G1 X3417.000 Y581.000
+G1 X3426.000 Y595.000
+G1 X3432.000 Y604.000
+G1 X3441.000 Y614.000
+G1 X3453.000 Y618.000
+G1 X3462.000 Y627.000
+G1 X3471.000 Y638.000
+G1 X3476.000 Y651.000
+G1 X3479.000 Y665.000
+G1 X3485.000 Y675.000
+G1 X3490.000 Y688.000
+G1 X3484.000 Y701.000
+G1 X3471.000 Y711.000
+G1 X3443.000 Y714.000
+G1 X3431.000 Y705.000
+G1 X3428.000 Y696.000
+G1 X3432.000 Y677.000
+G1 X3435.000 Y668.000
+G1 X3424.000 Y659.000
+G1 X3412.000 Y648.000
+G1 X3397.000 Y639.000
+G1 X3384.000 Y635.000
+G1 X3369.000 Y635.000
+G1 X3369.000 Y647.000
+G1 X3378.000 Y652.000
+G1 X3387.000 Y666.000
+G1 X3392.000 Y681.000
+G1 X3398.000 Y689.000
+G1 X3410.000 Y695.000
+G1 X3419.000 Y709.000
+G1 X3428.000 Y718.000
+G1 X3439.000 Y733.000
+G1 X3448.000 Y742.000
+G1 X3457.000 Y751.000
+G1 X3469.000 Y760.000
+G1 X3472.000 Y770.000
+G1 X3474.000 Y784.000
+G1 X3462.000 Y787.000
+G1 X3456.000 Y796.000
+G1 X3465.000 Y807.000
+G1 X3480.000 Y816.000
+G1 X3492.000 Y820.000
+G1 X3507.000 Y831.000
+G1 X3519.000 Y835.000
+G1 X3528.000 Y845.000
+G1 X3537.000 Y854.000
+G1 X3531.000 Y840.000
+G1 X3519.000 Y840.000
+G1 X3509.000 Y853.000
+G1 X3497.000 Y853.000
+G1 X3488.000 Y844.000
+G1 X3473.000 Y838.000
+G1 X3460.000 Y832.000
+G1 X3448.000 Y829.000
+G1 X3430.000 Y831.000
+G1 X3417.000 Y845.000
+G1 X3408.000 Y850.000
+G1 X3401.000 Y863.000
+G1 X3407.000 Y872.000
+G1 X3391.000 Y877.000
+G1 X3379.000 Y871.000
+G1 X3364.000 Y871.000
+G1 X3349.000 Y870.000
+G1 X3339.000 Y861.000
+G1 X3342.000 Y875.000
+G1 X3339.000 Y884.000
+G1 X3329.000 Y897.000
+G1 X3322.000 Y911.000
+G1 X3319.000 Y923.000
+G1 X3318.000 Y942.000
+G1 X3330.000 Y951.000
+G1 X3339.000 Y960.000
+G1 X3348.000 Y971.000
+G1 X3357.000 Y983.000
+G1 X3369.000 Y994.000
+G1 X3384.000 Y1004.000
+G1 X3396.000 Y1009.000
+G1 X3402.000 Y1022.000
+G1 X3404.000 Y1036.000
+G1 X3407.000 Y1050.000
+G1 X3409.000 Y1064.000
+G1 X3415.000 Y1072.000
+G1 X3403.000 Y1077.000
+G1 X3390.000 Y1077.000
+G1 X3375.000 Y1071.000
+G1 X3363.000 Y1067.000
+G1 X3351.000 Y1056.000
+G1 X3339.000 Y1052.000
+G1 X3324.000 Y1047.000
+G1 X3311.000 Y1046.000
+G1 X3296.000 Y1051.000
+G1 X3281.000 Y1050.000
+G1 X3268.000 Y1053.000
+G1 X3253.000 Y1053.000
+G1 X3241.000 Y1044.000
+G1 X3229.000 Y1043.000
+G1 X3216.000 Y1048.000
+G1 X3204.000 Y1051.000
+G1 X3191.000 Y1051.000
+G1 X3176.000 Y1050.000
+G1 X3164.000 Y1055.000
+G1 X3154.000 Y1063.000
+G1 X3144.000 Y1073.000
+G1 X3132.000 Y1076.000
+G1 X3116.000 Y1081.000
+G1 X3104.000 Y1075.000
+G1 X3096.000 Y1061.000
+G1 X3087.000 Y1052.000
+G1 X3075.000 Y1052.000
+G1 X3062.000 Y1051.000
+G1 X3047.000 Y1051.000
+G1 X3031.000 Y1055.000
+G1 X3016.000 Y1050.000
+G1 X3001.000 Y1044.000
+G1 X2985.000 Y1048.000
+G1 X2973.000 Y1043.000
+G1 X2961.000 Y1039.000
+G1 X2949.000 Y1025.000
+G1 X2944.000 Y1016.000
+G1 X2928.000 Y1019.000
+G1 X2915.000 Y1029.000
+G1 X2903.000 Y1032.000
+G1 X2893.000 Y1040.000
+G1 X2878.000 Y1039.000
+G1 X2866.000 Y1039.000
+G1 X2848.000 Y1035.000
+G1 X2832.000 Y1029.000
+G1 X2820.000 Y1034.000
+G1 X2804.000 Y1037.000
+G1 X2792.000 Y1033.000
+G1 X2777.000 Y1027.000
+G1 X2768.000 Y1023.000
+G1 X2753.000 Y1012.000
+G1 X2735.000 Y1003.000
+G1 X2723.000 Y997.000
+G1 X2710.000 Y1002.000
+G1 X2704.000 Y1016.000
+G1 X2706.000 Y1025.000
+G1 X2712.000 Y1039.000
+G1 X2711.000 Y1053.000
+G1 X2714.000 Y1061.000
+G1 X2720.000 Y1076.000
+G1 X2726.000 Y1090.000
+G1 X2728.000 Y1104.000
+G1 X2740.000 Y1113.000
+G1 X2752.000 Y1122.000
+G1 X2764.000 Y1128.000
+G1 X2779.000 Y1137.000
+G1 X2788.000 Y1146.000
+G1 X2797.000 Y1157.000
+G1 X2812.000 Y1161.000
+G1 X2821.000 Y1170.000
+G1 X2833.000 Y1180.000
+G1 X2839.000 Y1189.000
+G1 X2845.000 Y1203.000
+G1 X2850.000 Y1218.000
+G1 X2856.000 Y1226.000
+G1 X2862.000 Y1241.000
+G1 X2867.000 Y1255.000
+G1 X2876.000 Y1264.000
+G1 X2885.000 Y1273.000
+G1 X2900.000 Y1282.000
+G1 X2912.000 Y1288.000
+G1 X2921.000 Y1297.000
+G1 X2933.000 Y1306.000
+G1 X2942.000 Y1316.000
+G1 X2951.000 Y1325.000
+G1 X2960.000 Y1340.000
+G1 X2968.000 Y1354.000
+G1 X2974.000 Y1368.000
+G1 X2980.000 Y1380.000
+G1 X2982.000 Y1394.000
+G1 X2982.000 Y1408.000
+G1 X2981.000 Y1422.000
+G1 X2975.000 Y1436.000
+G1 X2980.000 Y1450.000
+G1 X2986.000 Y1459.000
+G1 X2989.000 Y1473.000
+G1 X2976.000 Y1476.000
+G1 X2967.000 Y1486.000
+G1 X2957.000 Y1499.000
+G1 X2945.000 Y1502.000
+G1 X2929.000 Y1507.000
+G1 X2917.000 Y1501.000
+G1 X2929.000 Y1502.000
+G1 X2917.000 Y1498.000
+G1 X2905.000 Y1492.000
+G1 X2890.000 Y1492.000
+G1 X2892.000 Y1506.000
+G1 X2901.000 Y1515.000
+G1 X2916.000 Y1521.000
+G1 X2925.000 Y1530.000
+G1 X2938.000 Y1525.000
+G1 X2946.000 Y1539.000
+G1 X2955.000 Y1548.000
+G1 X2955.000 Y1562.000
+G1 X2948.000 Y1570.000
+G1 X2948.000 Y1589.000
+G1 X2947.000 Y1598.000
+G1 X2950.000 Y1612.000
+G1 X2941.000 Y1621.000
+G1 X2925.000 Y1616.000
+G1 X2907.000 Y1605.000
+G1 X2898.000 Y1596.000
+G1 X2896.000 Y1582.000
+G1 X2896.000 Y1569.000
+G1 X2890.000 Y1556.000
+G1 X2882.000 Y1545.000
+G1 X2876.000 Y1533.000
+G1 X2882.000 Y1523.000
+G1 X2892.000 Y1514.000
+G1 X2910.000 Y1515.000
+G1 X2919.000 Y1524.000
+G1 X2920.000 Y1510.000
+G1 X2923.000 Y1498.000
+G1 X2918.000 Y1484.000
+G1 X2909.000 Y1470.000
+G1 X2897.000 Y1461.000
+G1 X2885.000 Y1460.000
+G1 X2869.000 Y1468.000
+G1 X2859.000 Y1477.000
+G1 X2850.000 Y1485.000
+G1 X2843.000 Y1499.000
+G1 X2840.000 Y1507.000
+G1 X2836.000 Y1521.000
+G1 X2833.000 Y1535.000
+G1 X2829.000 Y1549.000
+G1 X2829.000 Y1563.000
+G1 X2825.000 Y1576.000
+G1 X2825.000 Y1590.000
+G1 X2824.000 Y1602.000
+G1 X2821.000 Y1616.000
+G1 X2820.000 Y1627.000
+G1 X2814.000 Y1635.000
+G1 X2802.000 Y1629.000
+G1 X2793.000 Y1620.000
+G1 X2778.000 Y1615.000
+G1 X2766.000 Y1606.000
+G1 X2757.000 Y1591.000
+G1 X2751.000 Y1582.000
+G1 X2742.000 Y1568.000
+G1 X2733.000 Y1577.000
+G1 X2732.000 Y1591.000
+G1 X2732.000 Y1604.000
+G1 X2734.000 Y1618.000
+G1 X2737.000 Y1632.000
+G1 X2742.000 Y1646.000
+G1 X2745.000 Y1655.000
+G1 X2757.000 Y1664.000
+G1 X2763.000 Y1673.000
+G1 X2769.000 Y1687.000
+G1 X2778.000 Y1698.000
+G1 X2787.000 Y1707.000
+G1 X2780.000 Y1716.000
+G1 X2765.000 Y1715.000
+G1 X2752.000 Y1715.000
+G1 X2737.000 Y1709.000
+G1 X2725.000 Y1705.000
+G1 X2716.000 Y1699.000
+G1 X2704.000 Y1690.000
+G1 X2695.000 Y1685.000
+G1 X2680.000 Y1681.000
+G1 X2668.000 Y1675.000
+G1 X2658.000 Y1689.000
+G1 X2661.000 Y1703.000
+G1 X2660.000 Y1717.000
+G1 X2654.000 Y1725.000
+G1 X2641.000 Y1728.000
+G1 X2635.000 Y1742.000
+G1 X2637.000 Y1756.000
+G1 X2640.000 Y1765.000
+G1 X2640.000 Y1778.000
+G1 X2642.000 Y1792.000
+G1 X2639.000 Y1806.000
+G1 X2644.000 Y1820.000
+G1 X2650.000 Y1829.000
+G1 X2646.000 Y1843.000
+G1 X2643.000 Y1857.000
+G1 X2645.000 Y1871.000
+G1 X2642.000 Y1885.000
+G1 X2635.000 Y1893.000
+G1 X2635.000 Y1907.000
+G1 X2637.000 Y1921.000
+G1 X2637.000 Y1933.000
+G1 X2634.000 Y1947.000
+G1 X2627.000 Y1960.000
+G1 X2620.000 Y1974.000
+G1 X2617.000 Y1988.000
+G1 X2613.000 Y1996.000
+G1 X2613.000 Y2010.000
+G1 X2612.000 Y2024.000
+G1 X2606.000 Y2033.000
+G1 X2594.000 Y2029.000
+G1 X2585.000 Y2018.000
+G1 X2570.000 Y2014.000
+G1 X2561.000 Y2009.000
+G1 X2545.000 Y1999.000
+G1 X2533.000 Y1999.000
+G1 X2521.000 Y2007.000
+G1 X2514.000 Y2021.000
+G1 X2504.000 Y2029.000
+G1 X2495.000 Y2037.000
+G1 X2486.000 Y2042.000
+G1 X2482.000 Y2056.000
+G1 X2485.000 Y2070.000
+G1 X2484.000 Y2084.000
+G1 X2490.000 Y2102.000
+G1 X2499.000 Y2111.000
+G1 X2510.000 Y2121.000
+G1 X2522.000 Y2125.000
+G1 X2532.000 Y2131.000
+G1 X2540.000 Y2140.000
+G1 X2552.000 Y2149.000
+G1 X2555.000 Y2163.000
+G1 X2555.000 Y2172.000
+G1 X2554.000 Y2186.000
+G1 X2554.000 Y2199.000
+G1 X2550.000 Y2213.000
+G1 X2543.000 Y2227.000
+G1 X2540.000 Y2236.000
+G1 X2537.000 Y2249.000
+G1 X2536.000 Y2263.000
+G1 X2539.000 Y2277.000
+G1 X2535.000 Y2291.000
+G1 X2526.000 Y2299.000
+G1 X2516.000 Y2308.000
+G1 X2503.000 Y2321.000
+G1 X2494.000 Y2329.000
+G1 X2481.000 Y2338.000
+G1 X2469.000 Y2342.000
+G1 X2453.000 Y2356.000
+G1 X2441.000 Y2359.000
+G1 X2428.000 Y2369.000
+G1 X2424.000 Y2383.000
+G1 X2427.000 Y2395.000
+G1 X2426.000 Y2409.000
+G1 X2423.000 Y2423.000
+G1 X2407.000 Y2427.000
+G1 X2395.000 Y2427.000
+G1 X2380.000 Y2426.000
+G1 X2367.000 Y2426.000
+G1 X2355.000 Y2425.000
+G1 X2343.000 Y2420.000
+G1 X2331.000 Y2416.000
+G1 X2319.000 Y2405.000
+G1 X2307.000 Y2401.000
+G1 X2294.000 Y2404.000
+G1 X2288.000 Y2414.000
+G1 X2281.000 Y2426.000
+G1 X2278.000 Y2440.000
+G1 X2274.000 Y2454.000
+G1 X2277.000 Y2468.000
+G1 X2279.000 Y2482.000
+G1 X2288.000 Y2491.000
+G1 X2294.000 Y2505.000
+G1 X2299.000 Y2514.000
+G1 X2305.000 Y2528.000
+G1 X2308.000 Y2537.000
+G1 X2314.000 Y2551.000
+G1 X2316.000 Y2565.000
+G1 X2319.000 Y2579.000
+G1 X2321.000 Y2593.000
+G1 X2324.000 Y2607.000
+G1 X2324.000 Y2621.000
+G1 X2317.000 Y2633.000
+G1 X2313.000 Y2643.000
+G1 X2301.000 Y2651.000
+G1 X2291.000 Y2659.000
+G1 X2279.000 Y2656.000
+G1 X2279.000 Y2668.000
+G1 X2287.000 Y2678.000
+G1 X2297.000 Y2687.000
+G1 X2309.000 Y2691.000
+G1 X2321.000 Y2697.000
+G1 X2336.000 Y2703.000
+G1 X2348.000 Y2712.000
+G1 X2360.000 Y2712.000
+G1 X2375.000 Y2722.000
+G1 X2387.000 Y2731.000
+G1 X2399.000 Y2731.000
+G1 X2405.000 Y2745.000
+G1 X2396.000 Y2750.000
+G1 X2380.000 Y2755.000
+G1 X2368.000 Y2758.000
+G1 X2355.000 Y2766.000
+G1 X2346.000 Y2776.000
+G1 X2333.000 Y2779.000
+G1 X2321.000 Y2784.000
+G1 X2308.000 Y2792.000
+G1 X2299.000 Y2801.000
+G1 X2289.000 Y2814.000
+G1 X2279.000 Y2824.000
+G1 X2267.000 Y2832.000
+G1 X2279.000 Y2836.000
+G1 X2291.000 Y2833.000
+G1 X2291.000 Y2847.000
+G1 X2297.000 Y2860.000
+G1 X2309.000 Y2870.000
+G1 X2314.000 Y2885.000
+G1 X2289.000 Y2887.000
+G1 X2274.000 Y2887.000
+G1 X2259.000 Y2886.000
+G1 X2246.000 Y2891.000
+G1 X2234.000 Y2890.000
+G1 X2222.000 Y2890.000
+G1 X2212.000 Y2898.000
+G1 X2206.000 Y2907.000
+G1 X2193.000 Y2917.000
+G1 X2181.000 Y2920.000
+G1 X2171.000 Y2928.000
+G1 X2164.000 Y2938.000
+G1 X2158.000 Y2952.000
+G1 X2149.000 Y2955.000
+G1 X2152.000 Y2941.000
+G1 X2156.000 Y2928.000
+G1 X2165.000 Y2919.000
+G1 X2178.000 Y2911.000
+G1 X2193.000 Y2906.000
+G1 X2202.000 Y2917.000
+G1 X2218.000 Y2918.000
+G1 X2205.000 Y2921.000
+G1 X2193.000 Y2925.000
+G1 X2180.000 Y2934.000
+G1 X2170.000 Y2942.000
+G1 X2161.000 Y2952.000
+G1 X2152.000 Y2955.000
+G1 X2140.000 Y2951.000
+G1 X2134.000 Y2941.000
+G1 X2125.000 Y2932.000
+G1 X2119.000 Y2918.000
+G1 X2129.000 Y2909.000
+G1 X2141.000 Y2904.000
+M5
+G0 X2177.000 Y2928.000
+M3 S1000
+G1 X2178.000 Y2911.000 F1000
+G1 X2181.000 Y2897.000
+G1 X2191.000 Y2889.000
+G1 X2204.000 Y2875.000
+G1 X2216.000 Y2871.000
+G1 X2228.000 Y2881.000
+G1 X2227.000 Y2904.000
+G1 X2227.000 Y2913.000
+G1 X2223.000 Y2930.000
+G1 X2217.000 Y2944.000
+G1 X2201.000 Y2962.000
+G1 X2191.000 Y2971.000
+G1 X2181.000 Y2979.000
+G1 X2194.000 Y2979.000
+G1 X2206.000 Y2980.000
+G1 X2221.000 Y2986.000
+G1 X2237.000 Y2986.000
+G1 X2255.000 Y2990.000
+G1 X2267.000 Y2996.000
+G1 X2279.000 Y2996.000
+G1 X2295.000 Y2988.000
+G1 X2308.000 Y2975.000
+G1 X2317.000 Y2966.000
+G1 X2330.000 Y2967.000
+G1 X2345.000 Y2971.000
+G1 X2360.000 Y2982.000
+G1 X2372.000 Y2986.000
+G1 X2381.000 Y3000.000
+G1 X2383.000 Y3014.000
+G1 X2380.000 Y3031.000
+G1 X2376.000 Y3041.000
+G1 X2366.000 Y3055.000
+G1 X2354.000 Y3063.000
+G1 X2344.000 Y3072.000
+G1 X2326.000 Y3080.000
+G1 X2313.000 Y3090.000
+G1 X2304.000 Y3093.000
+G1 X2294.000 Y3101.000
+G1 X2281.000 Y3111.000
+G1 X2269.000 Y3125.000
+G1 X2253.000 Y3133.000
+G1 X2237.000 Y3141.000
+G1 X2222.000 Y3149.000
+G1 X2209.000 Y3157.000
+G1 X2193.000 Y3162.000
+G1 X2178.000 Y3170.000
+G1 X2162.000 Y3180.000
+G1 X2159.000 Y3188.000
+G1 X2143.000 Y3191.000
+G1 X2131.000 Y3191.000
+G1 X2121.000 Y3201.000
+G1 X2106.000 Y3204.000
+G1 X2093.000 Y3209.000
+G1 X2081.000 Y3213.000
+G1 X2068.000 Y3222.000
+G1 X2056.000 Y3230.000
+G1 X2046.000 Y3238.000
+G1 X2039.000 Y3252.000
+G1 X2027.000 Y3265.000
+G1 X2020.000 Y3274.000
+G1 X2011.000 Y3284.000
+G1 X1998.000 Y3292.000
+G1 X1989.000 Y3301.000
+G1 X1979.000 Y3314.000
+G1 X1963.000 Y3322.000
+G1 X1954.000 Y3331.000
+G1 X1962.000 Y3345.000
+G1 X1959.000 Y3359.000
+G1 X1955.000 Y3372.000
+G1 X1955.000 Y3386.000
+G1 X1948.000 Y3403.000
+G1 X1938.000 Y3413.000
+G1 X1920.000 Y3422.000
+G1 X1907.000 Y3430.000
+G1 X1895.000 Y3438.000
+G1 X1879.000 Y3443.000
+G1 X1863.000 Y3447.000
+G1 X1848.000 Y3450.000
+G1 X1836.000 Y3450.000
+G1 X1817.000 Y3449.000
+G1 X1802.000 Y3449.000
+G1 X1789.000 Y3453.000
+G1 X1777.000 Y3456.000
+G1 X1761.000 Y3466.000
+G1 X1752.000 Y3478.000
+G1 X1739.000 Y3488.000
+G1 X1724.000 Y3488.000
+G1 X1708.000 Y3490.000
+G1 X1696.000 Y3490.000
+G1 X1677.000 Y3489.000
+G1 X1665.000 Y3494.000
+G1 X1649.000 Y3499.000
+G1 X1634.000 Y3498.000
+G1 X1619.000 Y3498.000
+G1 X1606.000 Y3492.000
+G1 X1591.000 Y3491.000
+G1 X1579.000 Y3491.000
+G1 X1560.000 Y3496.000
+G1 X1545.000 Y3495.000
+G1 X1529.000 Y3498.000
+G1 X1511.000 Y3497.000
+G1 X1492.000 Y3497.000
+G1 X1480.000 Y3496.000
+G1 X1462.000 Y3492.000
+G1 X1450.000 Y3492.000
+G1 X1432.000 Y3486.000
+G1 X1419.000 Y3480.000
+G1 X1404.000 Y3480.000
+G1 X1385.000 Y3489.000
+G1 X1370.000 Y3492.000
+G1 X1360.000 Y3497.000
+G1 X1345.000 Y3500.000
+G1 X1329.000 Y3505.000
+G1 X1317.000 Y3513.000
+G1 X1301.000 Y3518.000
+G1 X1283.000 Y3517.000
+G1 X1267.000 Y3522.000
+G1 X1255.000 Y3530.000
+G1 X1239.000 Y3538.000
+G1 X1226.000 Y3543.000
+G1 X1211.000 Y3546.000
+G1 X1195.000 Y3556.000
+G1 X1183.000 Y3564.000
+G1 X1170.000 Y3572.000
+G1 X1154.000 Y3580.000
+G1 X1142.000 Y3594.000
+G1 X1129.000 Y3607.000
+G1 X1119.000 Y3621.000
+G1 X1109.000 Y3634.000
+G1 X1100.000 Y3648.000
+G1 X1087.000 Y3660.000
+G1 X1077.000 Y3673.000
+G1 X1064.000 Y3687.000
+G1 X1058.000 Y3695.000
+G1 X1045.000 Y3708.000
+G1 X1029.000 Y3727.000
+G1 X1016.000 Y3740.000
+G1 X1007.000 Y3749.000
+G1 X991.000 Y3762.000
+G1 X981.000 Y3770.000
+G1 X975.000 Y3784.000
+G1 X965.000 Y3792.000
+G1 X956.000 Y3801.000
+G1 X946.000 Y3806.000
+G1 X934.000 Y3819.000
+G1 X927.000 Y3828.000
+G1 X914.000 Y3841.000
+G1 X908.000 Y3850.000
+G1 X898.000 Y3868.000
+G1 X888.000 Y3877.000
+G1 X876.000 Y3890.000
+G1 X863.000 Y3904.000
+G1 X853.000 Y3912.000
+G1 X844.000 Y3925.000
+G1 X834.000 Y3934.000
+G1 X824.000 Y3947.000
+G1 X812.000 Y3956.000
+G1 X805.000 Y3964.000
+G1 X793.000 Y3978.000
+G1 X783.000 Y3986.000
+G1 X774.000 Y3996.000
+G1 X767.000 Y4004.000
+G1 X757.000 Y4013.000
+G1 X751.000 Y4026.000
+G1 X744.000 Y4035.000
+G1 X735.000 Y4048.000
+G1 X722.000 Y4053.000
+G1 X710.000 Y4061.000
+G1 X697.000 Y4066.000
+G1 X688.000 Y4078.000
+G1 X678.000 Y4088.000
+G1 X671.000 Y4102.000
+G1 X656.000 Y4113.000
+G1 X646.000 Y4118.000
+G1 X637.000 Y4127.000
+G1 X630.000 Y4140.000
+G1 X620.000 Y4154.000
+G1 X611.000 Y4162.000
+G1 X604.000 Y4172.000
+G1 X595.000 Y4184.000
+G1 X588.000 Y4194.000
+G1 X581.000 Y4206.000
+G1 X581.000 Y4220.000
+G1 X596.000 Y4221.000
+G1 X609.000 Y4218.000
+G1 X621.000 Y4222.000
+G1 X633.000 Y4233.000
+G1 X648.000 Y4242.000
+G1 X660.000 Y4246.000
+G1 X672.000 Y4251.000
+G1 X690.000 Y4252.000
+G1 X700.000 Y4261.000
+G1 X718.000 Y4262.000
+G1 X730.000 Y4259.000
+G1 X739.000 Y4268.000
+G1 X748.000 Y4277.000
+G1 X760.000 Y4286.000
+G1 X772.000 Y4291.000
+G1 X784.000 Y4297.000
+G1 X800.000 Y4292.000
+G1 X815.000 Y4293.000
+G1 X827.000 Y4293.000
+G1 X843.000 Y4294.000
+G1 X855.000 Y4300.000
+G1 X867.000 Y4304.000
+G1 X879.000 Y4314.000
+G1 X885.000 Y4323.000
+G1 X890.000 Y4337.000
+G1 X899.000 Y4346.000
+G1 X908.000 Y4361.000
+G1 X917.000 Y4370.000
+G1 X926.000 Y4379.000
+G1 X935.000 Y4388.000
+G1 X944.000 Y4398.000
+G1 X943.000 Y4416.000
+G1 X936.000 Y4429.000
+G1 X933.000 Y4443.000
+G1 X929.000 Y4452.000
+G1 X923.000 Y4465.000
+G1 X919.000 Y4479.000
+G1 X907.000 Y4487.000
+G1 X897.000 Y4501.000
+G1 X884.000 Y4506.000
+G1 X872.000 Y4519.000
+G1 X865.000 Y4528.000
+G1 X853.000 Y4536.000
+G1 X840.000 Y4544.000
+G1 X831.000 Y4553.000
+G1 X821.000 Y4563.000
+G1 X811.000 Y4576.000
+G1 X805.000 Y4588.000
+G1 X798.000 Y4598.000
+G1 X789.000 Y4612.000
+G1 X779.000 Y4620.000
+G1 X769.000 Y4629.000
+G1 X763.000 Y4642.000
+G1 X750.000 Y4647.000
+G1 X741.000 Y4655.000
+G1 X728.000 Y4664.000
+G1 X719.000 Y4672.000
+G1 X715.000 Y4686.000
+G1 X708.000 Y4694.000
+G1 X699.000 Y4704.000
+G1 X689.000 Y4716.000
+G1 X686.000 Y4730.000
+G1 X679.000 Y4744.000
+G1 X672.000 Y4757.000
+G1 X663.000 Y4766.000
+G1 X659.000 Y4779.000
+G1 X653.000 Y4793.000
+G1 X646.000 Y4801.000
+G1 X643.000 Y4815.000
+G1 X639.000 Y4829.000
+G1 X632.000 Y4843.000
+G1 X623.000 Y4851.000
+G1 X610.000 Y4865.000
+G1 X601.000 Y4873.000
+G1 X591.000 Y4881.000
+G1 X585.000 Y4895.000
+G1 X578.000 Y4909.000
+G1 X568.000 Y4917.000
+G1 X559.000 Y4930.000
+G1 X540.000 Y4940.000
+G1 X527.000 Y4943.000
+G1 X524.000 Y4952.000
+G1 X520.000 Y4971.000
+G1 X514.000 Y4984.000
+G1 X507.000 Y4993.000
+G1 X498.000 Y5007.000
+G1 X488.000 Y5015.000
+G1 X478.000 Y5028.000
+G1 X472.000 Y5042.000
+G1 X465.000 Y5051.000
+G1 X458.000 Y5064.000
+G1 X452.000 Y5078.000
+G1 X445.000 Y5086.000
+G1 X436.000 Y5095.000
+G1 X426.000 Y5108.000
+G1 X417.000 Y5113.000
+G1 X407.000 Y5121.000
+G1 X398.000 Y5130.000
+G1 X385.000 Y5140.000
+G1 X375.000 Y5152.000
+G1 X363.000 Y5162.000
+G1 X350.000 Y5165.000
+G1 X338.000 Y5169.000
+G1 X326.000 Y5169.000
+G1 X310.000 Y5177.000
+G1 X298.000 Y5177.000
+G1 X282.000 Y5181.000
+G1 X270.000 Y5181.000
+G1 X260.000 Y5189.000
+G1 X248.000 Y5194.000
+G1 X235.000 Y5202.000
+G1 X223.000 Y5205.000
+G1 X207.000 Y5210.000
+G1 X195.000 Y5218.000
+G1 X182.000 Y5228.000
+G1 X173.000 Y5231.000
+G1 X160.000 Y5240.000
+G1 X150.000 Y5250.000
+G1 X138.000 Y5258.000
+G1 X128.000 Y5266.000
+G1 X113.000 Y5271.000
+G1 X100.000 Y5274.000
+G1 X85.000 Y5279.000
+G1 X75.000 Y5287.000
+G1 X63.000 Y5297.000
+G1 X50.000 Y5300.000
+G1 X34.000 Y5305.000
+G1 X22.000 Y5308.000
+G1 X13.000 Y5318.000
+G1 X0.000 Y5326.000
+G1 X6.000 Y5335.000
+G1 X18.000 Y5344.000
+G1 X20.000 Y5358.000
+G1 X29.000 Y5372.000
+G1 X38.000 Y5381.000
+G1 X47.000 Y5392.000
+G1 X56.000 Y5401.000
+G1 X65.000 Y5415.000
+G1 X73.000 Y5424.000
+G1 X85.000 Y5433.000
+G1 X97.000 Y5442.000
+G1 X109.000 Y5448.000
+G1 X118.000 Y5462.000
+G1 X124.000 Y5476.000
+G1 X136.000 Y5486.000
+G1 X148.000 Y5491.000
+G1 X157.000 Y5504.000
+G1 X163.000 Y5514.000
+G1 X165.000 Y5528.000
+G1 X177.000 Y5541.000
+G1 X186.000 Y5552.000
+G1 X192.000 Y5561.000
+G1 X200.000 Y5575.000
+G1 X212.000 Y5579.000
+G1 X225.000 Y5584.000
+G1 X237.000 Y5593.000
+G1 X245.000 Y5604.000
+G1 X257.000 Y5608.000
+G1 X273.000 Y5617.000
+G1 X281.000 Y5628.000
+G1 X290.000 Y5641.000
+G1 X287.000 Y5654.000
+G1 X277.000 Y5664.000
+G1 X271.000 Y5676.000
+G1 X267.000 Y5695.000
+G1 X263.000 Y5709.000
+G1 X263.000 Y5726.000
+G1 X259.000 Y5737.000
+G1 X256.000 Y5749.000
+G1 X261.000 Y5768.000
+G1 X264.000 Y5782.000
+G1 X266.000 Y5796.000
+G1 X272.000 Y5814.000
+G1 X281.000 Y5823.000
+G1 X290.000 Y5833.000
+G1 X292.000 Y5846.000
+G1 X295.000 Y5856.000
+G1 X294.000 Y5870.000
+G1 X288.000 Y5882.000
+G1 X290.000 Y5893.000
+G1 X302.000 Y5907.000
+G1 X308.000 Y5916.000
+G1 X317.000 Y5930.000
+G1 X326.000 Y5939.000
+G1 X328.000 Y5953.000
+G1 X328.000 Y5967.000
+G1 X321.000 Y5984.000
+G1 X318.000 Y5993.000
+G1 X311.000 Y6006.000
+G1 X311.000 Y6020.000
+G1 X307.000 Y6034.000
+G1 X303.000 Y6048.000
+G1 X306.000 Y6062.000
+G1 X309.000 Y6076.000
+G1 X311.000 Y6088.000
+G1 X320.000 Y6099.000
+G1 X329.000 Y6113.000
+G1 X341.000 Y6122.000
+G1 X353.000 Y6131.000
+G1 X362.000 Y6140.000
+G1 X364.000 Y6159.000
+G1 X367.000 Y6173.000
+G1 X366.000 Y6186.000
+G1 X375.000 Y6200.000
+G1 X393.000 Y6206.000
+G1 X399.000 Y6215.000
+G1 X405.000 Y6229.000
+G1 X404.000 Y6237.000
+G1 X401.000 Y6251.000
+G1 X400.000 Y6265.000
+G1 X403.000 Y6279.000
+G1 X418.000 Y6283.000
+G1 X430.000 Y6283.000
+G1 X436.000 Y6298.000
+G1 X445.000 Y6308.000
+G1 X454.000 Y6312.000
+G1 X466.000 Y6321.000
+G1 X472.000 Y6332.000
+G1 X484.000 Y6341.000
+G1 X496.000 Y6347.000
+G1 X505.000 Y6356.000
+G1 X510.000 Y6370.000
+G1 X513.000 Y6379.000
+G1 X519.000 Y6393.000
+G1 X528.000 Y6402.000
+G1 X540.000 Y6407.000
+G1 X552.000 Y6397.000
+G1 X561.000 Y6412.000
+G1 X570.000 Y6422.000
+G1 X569.000 Y6434.000
+G1 X566.000 Y6445.000
+G1 X572.000 Y6462.000
+G1 X577.000 Y6476.000
+G1 X586.000 Y6485.000
+G1 X595.000 Y6494.000
+G1 X601.000 Y6505.000
+G1 X613.000 Y6509.000
+G1 X622.000 Y6523.000
+G1 X628.000 Y6532.000
+G1 X630.000 Y6546.000
+G1 X636.000 Y6560.000
+G1 X635.000 Y6569.000
+G1 X641.000 Y6583.000
+G1 X647.000 Y6594.000
+G1 X659.000 Y6603.000
+G1 X671.000 Y6607.000
+G1 X687.000 Y6599.000
+G1 X699.000 Y6590.000
+G1 X711.000 Y6599.000
+G1 X720.000 Y6608.000
+G1 X723.000 Y6622.000
+G1 X716.000 Y6636.000
+G1 X714.000 Y6622.000
+G1 X714.000 Y6608.000
+G1 X699.000 Y6604.000
+G1 X693.000 Y6590.000
+G1 X699.000 Y6604.000
+G1 X692.000 Y6618.000
+G1 X683.000 Y6626.000
+G1 X679.000 Y6643.000
+G1 X672.000 Y6657.000
+G1 X669.000 Y6671.000
+G1 X665.000 Y6679.000
+G1 X659.000 Y6693.000
+G1 X652.000 Y6707.000
+G1 X642.000 Y6720.000
+G1 X636.000 Y6729.000
+G1 X629.000 Y6743.000
+G1 X626.000 Y6756.000
+G1 X622.000 Y6770.000
+G1 X619.000 Y6784.000
+G1 X615.000 Y6801.000
+G1 X614.000 Y6810.000
+G1 X611.000 Y6829.000
+G1 X607.000 Y6843.000
+G1 X607.000 Y6851.000
+G1 X607.000 Y6865.000
+G1 X603.000 Y6879.000
+G1 X602.000 Y6896.000
+G1 X602.000 Y6910.000
+G1 X605.000 Y6924.000
+G1 X607.000 Y6938.000
+G1 X609.000 Y6952.000
+G1 X609.000 Y6961.000
+G1 X612.000 Y6978.000
+G1 X608.000 Y7001.000
+G1 X604.000 Y7015.000
+G1 X604.000 Y7028.000
+G1 X603.000 Y7042.000
+G1 X600.000 Y7056.000
+G1 X599.000 Y7070.000
+G1 X596.000 Y7084.000
+G1 X592.000 Y7101.000
+G1 X589.000 Y7110.000
+G1 X582.000 Y7128.000
+G1 X578.000 Y7142.000
+G1 X578.000 Y7156.000
+G1 X580.000 Y7168.000
+G1 X583.000 Y7182.000
+G1 X585.000 Y7196.000
+G1 X588.000 Y7210.000
+G1 X588.000 Y7224.000
+G1 X584.000 Y7242.000
+G1 X574.000 Y7260.000
+G1 X561.000 Y7277.000
+G1 X555.000 Y7287.000
+G1 X548.000 Y7296.000
+G1 X538.000 Y7309.000
+G1 X529.000 Y7323.000
+G1 X522.000 Y7331.000
+G1 X519.000 Y7345.000
+G1 X509.000 Y7359.000
+G1 X499.000 Y7371.000
+G1 X492.000 Y7384.000
+G1 X486.000 Y7398.000
+G1 X476.000 Y7411.000
+G1 X464.000 Y7420.000
+G1 X454.000 Y7425.000
+G1 X442.000 Y7433.000
+G1 X435.000 Y7447.000
+G1 X428.000 Y7460.000
+G1 X422.000 Y7469.000
+G1 X412.000 Y7482.000
+G1 X403.000 Y7491.000
+G1 X396.000 Y7499.000
+G1 X386.000 Y7513.000
+G1 X377.000 Y7523.000
+G1 X367.000 Y7535.000
+G1 X358.000 Y7545.000
+G1 X342.000 Y7553.000
+G1 X333.000 Y7556.000
+G1 X320.000 Y7566.000
+G1 X311.000 Y7574.000
+G1 X295.000 Y7582.000
+G1 X282.000 Y7587.000
+G1 X270.000 Y7590.000
+G1 X257.000 Y7600.000
+G1 X248.000 Y7603.000
+G1 X232.000 Y7613.000
+G1 X220.000 Y7621.000
+G1 X210.000 Y7630.000
+G1 X201.000 Y7638.000
+G1 X188.000 Y7643.000
+G1 X175.000 Y7651.000
+G1 X163.000 Y7659.000
+G1 X150.000 Y7669.000
+G1 X141.000 Y7678.000
+G1 X131.000 Y7691.000
+G1 X124.000 Y7700.000
+G1 X115.000 Y7708.000
+G1 X115.000 Y7722.000
+G1 X127.000 Y7731.000
+G1 X148.000 Y7737.000
+G1 X160.000 Y7737.000
+G1 X175.000 Y7738.000
+G1 X188.000 Y7733.000
+G1 X200.000 Y7725.000
+G1 X210.000 Y7720.000
+G1 X222.000 Y7717.000
+G1 X238.000 Y7718.000
+G1 X253.000 Y7718.000
+G1 X268.000 Y7719.000
+G1 X281.000 Y7719.000
+G1 X296.000 Y7720.000
+G1 X308.000 Y7720.000
+G1 X321.000 Y7715.000
+G1 X339.000 Y7716.000
+G1 X355.000 Y7717.000
+G1 X370.000 Y7714.000
+G1 X382.000 Y7718.000
+G1 X395.000 Y7718.000
+G1 X407.000 Y7718.000
+G1 X422.000 Y7716.000
+G1 X435.000 Y7711.000
+G1 X447.000 Y7706.000
+G1 X460.000 Y7703.000
+G1 X472.000 Y7695.000
+G1 X485.000 Y7685.000
+G1 X494.000 Y7682.000
+G1 X510.000 Y7677.000
+G1 X513.000 Y7668.000
+G1 X523.000 Y7660.000
+G1 X532.000 Y7650.000
+G1 X545.000 Y7642.000
+G1 X557.000 Y7637.000
+G1 X570.000 Y7634.000
+G1 X585.000 Y7634.000
+G1 X598.000 Y7630.000
+G1 X613.000 Y7627.000
+G1 X626.000 Y7622.000
+G1 X638.000 Y7617.000
+G1 X654.000 Y7614.000
+G1 X666.000 Y7615.000
+G1 X682.000 Y7610.000
+G1 X691.000 Y7605.000
+G1 X706.000 Y7602.000
+G1 X719.000 Y7603.000
+G1 X734.000 Y7598.000
+G1 X750.000 Y7590.000
+G1 X762.000 Y7585.000
+G1 X775.000 Y7582.000
+G1 X787.000 Y7582.000
+G1 X800.000 Y7578.000
+G1 X812.000 Y7573.000
+G1 X831.000 Y7570.000
+G1 X843.000 Y7565.000
+G1 X856.000 Y7562.000
+G1 X868.000 Y7558.000
+G1 X881.000 Y7549.000
+G1 X893.000 Y7545.000
+G1 X906.000 Y7542.000
+G1 X918.000 Y7537.000
+G1 X933.000 Y7532.000
+G1 X949.000 Y7529.000
+G1 X961.000 Y7519.000
+G1 X971.000 Y7516.000
+G1 X983.000 Y7511.000
+G1 X993.000 Y7503.000
+G1 X1005.000 Y7495.000
+G1 X1018.000 Y7485.000
+G1 X1031.000 Y7482.000
+G1 X1040.000 Y7477.000
+G1 X1052.000 Y7469.000
+G1 X1065.000 Y7464.000
+G1 X1075.000 Y7455.000
+G1 X1087.000 Y7447.000
+G1 X1097.000 Y7442.000
+G1 X1112.000 Y7439.000
+G1 X1124.000 Y7443.000
+G1 X1136.000 Y7444.000
+G1 X1152.000 Y7449.000
+G1 X1164.000 Y7450.000
+G1 X1179.000 Y7454.000
+G1 X1191.000 Y7460.000
+G1 X1203.000 Y7469.000
+G1 X1222.000 Y7461.000
+G1 X1247.000 Y7456.000
+G1 X1262.000 Y7462.000
+G1 X1274.000 Y7457.000
+G1 X1290.000 Y7454.000
+G1 X1302.000 Y7455.000
+G1 X1321.000 Y7459.000
+G1 X1333.000 Y7465.000
+G1 X1348.000 Y7460.000
+G1 X1348.000 Y7474.000
+G1 X1332.000 Y7487.000
+G1 X1322.000 Y7496.000
+G1 X1313.000 Y7506.000
+G1 X1300.000 Y7509.000
+G1 X1288.000 Y7519.000
+G1 X1275.000 Y7527.000
+G1 X1263.000 Y7535.000
+G1 X1250.000 Y7543.000
+G1 X1237.000 Y7553.000
+G1 X1225.000 Y7562.000
+G1 X1212.000 Y7570.000
+G1 X1193.000 Y7574.000
+G1 X1181.000 Y7577.000
+G1 X1172.000 Y7586.000
+G1 X1165.000 Y7599.000
+G1 X1158.000 Y7613.000
+G1 X1152.000 Y7622.000
+G1 X1133.000 Y7631.000
+G1 X1117.000 Y7645.000
+G1 X1107.000 Y7662.000
+G1 X1101.000 Y7675.000
+G1 X1091.000 Y7684.000
+G1 X1081.000 Y7697.000
+G1 X1072.000 Y7711.000
+G1 X1062.000 Y7719.000
+G1 X1052.000 Y7733.000
+G1 X1049.000 Y7741.000
+G1 X1039.000 Y7755.000
+G1 X1030.000 Y7763.000
+G1 X1017.000 Y7773.000
+G1 X1008.000 Y7782.000
+G1 X1001.000 Y7790.000
+G1 X994.000 Y7804.000
+G1 X988.000 Y7812.000
+G1 X981.000 Y7826.000
+G1 X975.000 Y7839.000
+G1 X971.000 Y7853.000
+G1 X965.000 Y7867.000
+G1 X961.000 Y7881.000
+G1 X958.000 Y7893.000
+G1 X957.000 Y7907.000
+G1 X959.000 Y7921.000
+G1 X962.000 Y7940.000
+G1 X968.000 Y7952.000
+G1 X973.000 Y7972.000
+G1 X979.000 Y7986.000
+G1 X981.000 Y8000.000
+G1 X987.000 Y8009.000
+G1 X990.000 Y8023.000
+G1 X996.000 Y8037.000
+G1 X1001.000 Y8051.000
+G1 X1007.000 Y8063.000
+G1 X1022.000 Y8074.000
+G1 X1024.000 Y8086.000
+G1 X1015.000 Y8100.000
+G1 X1020.000 Y8114.000
+G1 X1023.000 Y8128.000
+G1 X1025.000 Y8147.000
+G1 X1031.000 Y8160.000
+G1 X1034.000 Y8174.000
+G1 X1036.000 Y8188.000
+G1 X1042.000 Y8202.000
+G1 X1048.000 Y8216.000
+G1 X1050.000 Y8225.000
+G1 X1059.000 Y8239.000
+G1 X1065.000 Y8253.000
+G1 X1067.000 Y8267.000
+G1 X1076.000 Y8281.000
+G1 X1082.000 Y8290.000
+G1 X1088.000 Y8304.000
+G1 X1093.000 Y8318.000
+G1 X1099.000 Y8327.000
+G1 X1101.000 Y8341.000
+G1 X1107.000 Y8350.000
+G1 X1110.000 Y8364.000
+G1 X1112.000 Y8378.000
+G1 X1112.000 Y8390.000
+G1 X1114.000 Y8404.000
+G1 X1114.000 Y8418.000
+G1 X1117.000 Y8432.000
+G1 X1119.000 Y8446.000
+G1 X1125.000 Y8460.000
+G1 X1134.000 Y8469.000
+G1 X1140.000 Y8483.000
+G1 X1148.000 Y8498.000
+G1 X1154.000 Y8506.000
+G1 X1160.000 Y8521.000
+G1 X1165.000 Y8535.000
+G1 X1171.000 Y8549.000
+G1 X1174.000 Y8561.000
+G1 X1176.000 Y8575.000
+G1 X1182.000 Y8589.000
+G1 X1185.000 Y8603.000
+G1 X1184.000 Y8617.000
+G1 X1187.000 Y8631.000
+G1 X1189.000 Y8645.000
+G1 X1189.000 Y8659.000
+G1 X1191.000 Y8671.000
+G1 X1191.000 Y8685.000
+G1 X1190.000 Y8704.000
+G1 X1193.000 Y8713.000
+G1 X1198.000 Y8727.000
+G1 X1198.000 Y8741.000
+G1 X1197.000 Y8755.000
+G1 X1194.000 Y8767.000
+G1 X1191.000 Y8781.000
+G1 X1190.000 Y8795.000
+G1 X1190.000 Y8808.000
+G1 X1189.000 Y8828.000
+G1 X1188.000 Y8840.000
+G1 X1185.000 Y8853.000
+G1 X1181.000 Y8867.000
+G1 X1178.000 Y8881.000
+G1 X1174.000 Y8895.000
+G1 X1170.000 Y8909.000
+G1 X1167.000 Y8922.000
+G1 X1163.000 Y8934.000
+G1 X1160.000 Y8945.000
+G1 X1153.000 Y8958.000
+G1 X1147.000 Y8970.000
+G1 X1140.000 Y8984.000
+G1 X1134.000 Y8998.000
+G1 X1130.000 Y9006.000
+G1 X1120.000 Y9020.000
+G1 X1114.000 Y9033.000
+G1 X1107.000 Y9042.000
+G1 X1101.000 Y9056.000
+G1 X1091.000 Y9069.000
+G1 X1084.000 Y9078.000
+G1 X1078.000 Y9091.000
+G1 X1071.000 Y9105.000
+G1 X1065.000 Y9113.000
+G1 X1055.000 Y9127.000
+G1 X1042.000 Y9140.000
+G1 X1036.000 Y9149.000
+G1 X1026.000 Y9168.000
+G1 X1016.000 Y9181.000
+G1 X1007.000 Y9193.000
+G1 X997.000 Y9212.000
+G1 X987.000 Y9225.000
+G1 X980.000 Y9239.000
+G1 X970.000 Y9253.000
+G1 X961.000 Y9270.000
+G1 X948.000 Y9278.000
+G1 X935.000 Y9288.000
+G1 X922.000 Y9305.000
+G1 X919.000 Y9319.000
+G1 X931.000 Y9328.000
+G1 X940.000 Y9337.000
+G1 X949.000 Y9347.000
+G1 X961.000 Y9351.000
+G1 X976.000 Y9361.000
+G1 X988.000 Y9366.000
+G1 X1007.000 Y9367.000
+G1 X1019.000 Y9362.000
+G1 X1034.000 Y9363.000
+G1 X1050.000 Y9368.000
+G1 X1065.000 Y9369.000
+G1 X1077.000 Y9369.000
+G1 X1092.000 Y9370.000
+G1 X1105.000 Y9376.000
+G1 X1120.000 Y9380.000
+G1 X1135.000 Y9380.000
+G1 X1151.000 Y9381.000
+G1 X1166.000 Y9381.000
+G1 X1184.000 Y9382.000
+G1 X1197.000 Y9388.000
+G1 X1209.000 Y9388.000
+G1 X1224.000 Y9388.000
+G1 X1239.000 Y9394.000
+G1 X1258.000 Y9398.000
+G1 X1270.000 Y9399.000
+G1 X1285.000 Y9399.000
+G1 X1300.000 Y9405.000
+G1 X1313.000 Y9409.000
+G1 X1325.000 Y9415.000
+G1 X1337.000 Y9420.000
+G1 X1352.000 Y9424.000
+G1 X1364.000 Y9435.000
+G1 X1379.000 Y9444.000
+G1 X1388.000 Y9453.000
+G1 X1397.000 Y9462.000
+G1 X1406.000 Y9473.000
+G1 X1418.000 Y9482.000
+G1 X1430.000 Y9486.000
+G1 X1439.000 Y9497.000
+G1 X1448.000 Y9506.000
+G1 X1450.000 Y9520.000
+G1 X1456.000 Y9534.000
+G1 X1465.000 Y9543.000
+G1 X1477.000 Y9552.000
+G1 X1483.000 Y9561.000
+G1 X1491.000 Y9572.000
+G1 X1503.000 Y9581.000
+G1 X1515.000 Y9585.000
+G1 X1527.000 Y9596.000
+G1 X1543.000 Y9605.000
+G1 X1554.000 Y9619.000
+G1 X1566.000 Y9634.000
+G1 X1578.000 Y9648.000
+G1 X1590.000 Y9661.000
+G1 X1605.000 Y9666.000
+G1 X1593.000 Y9648.000
+G1 X1576.000 Y9624.000
+G1 X1567.000 Y9611.000
+G1 X1582.000 Y9612.000
+G1 X1594.000 Y9615.000
+G1 X1603.000 Y9624.000
+G1 X1619.000 Y9630.000
+G1 X1624.000 Y9644.000
+G1 X1633.000 Y9653.000
+G1 X1645.000 Y9659.000
+G1 X1654.000 Y9668.000
+G1 X1666.000 Y9674.000
+G1 X1678.000 Y9683.000
+G1 X1684.000 Y9692.000
+G1 X1686.000 Y9711.000
+G1 X1692.000 Y9723.000
+G1 X1701.000 Y9737.000
+G1 X1706.000 Y9752.000
+G1 X1706.000 Y9765.000
+G1 X1709.000 Y9779.000
+G1 X1711.000 Y9793.000
+G1 X1711.000 Y9807.000
+G1 X1707.000 Y9821.000
+G1 X1701.000 Y9833.000
+G1 X1694.000 Y9843.000
+G1 X1691.000 Y9855.000
+G1 X1684.000 Y9869.000
+G1 X1678.000 Y9879.000
+G1 X1668.000 Y9888.000
+G1 X1658.000 Y9896.000
+G1 X1652.000 Y9904.000
+G1 X1645.000 Y9918.000
+G1 X1639.000 Y9926.000
+G1 X1632.000 Y9937.000
+G1 X1622.000 Y9950.000
+G1 X1616.000 Y9962.000
+G1 X1609.000 Y9972.000
+G1 X1600.000 Y9986.000
+G1 X1590.000 Y9994.000
+G1 X1583.000 Y10008.000
+G1 X1580.000 Y10020.000
+G1 X1567.000 Y10033.000
+G1 X1555.000 Y10038.000
+G1 X1551.000 Y10052.000
+G1 X1542.000 Y10060.000
+G1 X1532.000 Y10069.000
+G1 X1523.000 Y10079.000
+G1 X1516.000 Y10091.000
+G1 X1513.000 Y10104.000
+G1 X1506.000 Y10115.000
+G1 X1502.000 Y10127.000
+G1 X1496.000 Y10140.000
+G1 X1486.000 Y10150.000
+G1 X1480.000 Y10162.000
+G1 X1470.000 Y10176.000
+G1 X1464.000 Y10184.000
+G1 X1460.000 Y10198.000
+G1 X1450.000 Y10212.000
+G1 X1444.000 Y10225.000
+G1 X1434.000 Y10234.000
+G1 X1427.000 Y10247.000
+G1 X1418.000 Y10256.000
+G1 X1408.000 Y10266.000
+G1 X1402.000 Y10280.000
+G1 X1392.000 Y10288.000
+G1 X1385.000 Y10302.000
+G1 X1382.000 Y10314.000
+G1 X1378.000 Y10327.000
+G1 X1372.000 Y10341.000
+G1 X1365.000 Y10350.000
+G1 X1359.000 Y10363.000
+G1 X1349.000 Y10373.000
+G1 X1343.000 Y10385.000
+G1 X1336.000 Y10399.000
+G1 X1329.000 Y10413.000
+G1 X1323.000 Y10426.000
+G1 X1316.000 Y10435.000
+G1 X1310.000 Y10448.000
+G1 X1303.000 Y10462.000
+G1 X1296.000 Y10476.000
+G1 X1290.000 Y10484.000
+G1 X1292.000 Y10498.000
+G1 X1305.000 Y10499.000
+G1 X1317.000 Y10499.000
+G1 X1333.000 Y10494.000
+G1 X1351.000 Y10486.000
+G1 X1364.000 Y10482.000
+G1 X1379.000 Y10479.000
+G1 X1392.000 Y10474.000
+G1 X1404.000 Y10466.000
+G1 X1414.000 Y10457.000
+G1 X1429.000 Y10453.000
+G1 X1439.000 Y10444.000
+G1 X1448.000 Y10436.000
+G1 X1461.000 Y10431.000
+G1 X1473.000 Y10423.000
+G1 X1489.000 Y10418.000
+G1 X1502.000 Y10410.000
+G1 X1514.000 Y10402.000
+G1 X1527.000 Y10397.000
+G1 X1536.000 Y10389.000
+G1 X1549.000 Y10384.000
+G1 X1561.000 Y10376.000
+G1 X1574.000 Y10367.000
+G1 X1587.000 Y10357.000
+G1 X1596.000 Y10349.000
+G1 X1612.000 Y10346.000
+G1 X1624.000 Y10341.000
+G1 X1637.000 Y10337.000
+G1 X1652.000 Y10334.000
+G1 X1664.000 Y10329.000
+G1 X1677.000 Y10321.000
+G1 X1690.000 Y10312.000
+G1 X1699.000 Y10302.000
+G1 X1712.000 Y10294.000
+G1 X1724.000 Y10286.000
+G1 X1737.000 Y10278.000
+G1 X1753.000 Y10273.000
+G1 X1768.000 Y10268.000
+G1 X1784.000 Y10265.000
+G1 X1796.000 Y10261.000
+G1 X1812.000 Y10258.000
+G1 X1824.000 Y10248.000
+G1 X1840.000 Y10248.000
+G1 X1852.000 Y10245.000
+G1 X1864.000 Y10240.000
+G1 X1880.000 Y10241.000
+G1 X1898.000 Y10242.000
+G1 X1917.000 Y10242.000
+G1 X1932.000 Y10243.000
+G1 X1951.000 Y10238.000
+G1 X1963.000 Y10244.000
+G1 X1978.000 Y10244.000
+G1 X1994.000 Y10250.000
+G1 X2009.000 Y10251.000
+G1 X2021.000 Y10251.000
+G1 X2033.000 Y10255.000
+G1 X2048.000 Y10256.000
+G1 X2061.000 Y10261.000
+G1 X2073.000 Y10267.000
+G1 X2088.000 Y10271.000
+G1 X2100.000 Y10277.000
+G1 X2118.000 Y10277.000
+G1 X2131.000 Y10278.000
+G1 X2146.000 Y10278.000
+G1 X2162.000 Y10274.000
+G1 X2174.000 Y10271.000
+G1 X2187.000 Y10261.000
+G1 X2199.000 Y10252.000
+G1 X2209.000 Y10244.000
+G1 X2221.000 Y10239.000
+G1 X2237.000 Y10231.000
+G1 X2246.000 Y10226.000
+G1 X2256.000 Y10218.000
+G1 X2272.000 Y10205.000
+G1 X2282.000 Y10196.000
+G1 X2291.000 Y10183.000
+G1 X2298.000 Y10174.000
+G1 X2310.000 Y10166.000
+G1 X2323.000 Y10156.000
+G1 X2338.000 Y10156.000
+G1 X2351.000 Y10153.000
+G1 X2366.000 Y10154.000
+G1 X2381.000 Y10149.000
+G1 X2397.000 Y10155.000
+G1 X2412.000 Y10159.000
+G1 X2424.000 Y10170.000
+G1 X2433.000 Y10179.000
+G1 X2442.000 Y10193.000
+G1 X2450.000 Y10202.000
+G1 X2459.000 Y10211.000
+G1 X2471.000 Y10220.000
+G1 X2486.000 Y10226.000
+G1 X2499.000 Y10232.000
+G1 X2508.000 Y10241.000
+G1 X2523.000 Y10246.000
+G1 X2535.000 Y10256.000
+G1 X2547.000 Y10260.000
+G1 X2562.000 Y10270.000
+G1 X2574.000 Y10280.000
+G1 X2583.000 Y10289.000
+G1 X2595.000 Y10298.000
+G1 X2607.000 Y10309.000
+G1 X2616.000 Y10318.000
+G1 X2628.000 Y10327.000
+G1 X2636.000 Y10341.000
+G1 X2645.000 Y10355.000
+G1 X2651.000 Y10369.000
+G1 X2659.000 Y10378.000
+G1 X2665.000 Y10392.000
+G1 X2671.000 Y10406.000
+G1 X2676.000 Y10421.000
+G1 X2682.000 Y10433.000
+G1 X2685.000 Y10443.000
+G1 X2687.000 Y10457.000
+G1 X2690.000 Y10470.000
+G1 X2696.000 Y10484.000
+G1 X2708.000 Y10498.000
+G1 X2720.000 Y10504.000
+G1 X2735.000 Y10508.000
+G1 X2747.000 Y10513.000
+G1 X2756.000 Y10528.000
+G1 X2765.000 Y10531.000
+G1 X2774.000 Y10542.000
+G1 X2783.000 Y10551.000
+G1 X2795.000 Y10557.000
+G1 X2810.000 Y10561.000
+G1 X2826.000 Y10558.000
+G1 X2841.000 Y10553.000
+G1 X2851.000 Y10545.000
+G1 X2866.000 Y10540.000
+G1 X2854.000 Y10535.000
+G1 X2848.000 Y10526.000
+G1 X2849.000 Y10512.000
+G1 X2852.000 Y10500.000
+G1 X2856.000 Y10481.000
+G1 X2859.000 Y10467.000
+G1 X2863.000 Y10453.000
+G1 X2864.000 Y10439.000
+G1 X2864.000 Y10427.000
+G1 X2861.000 Y10413.000
+G1 X2850.000 Y10402.000
+G1 X2844.000 Y10393.000
+G1 X2838.000 Y10379.000
+G1 X2842.000 Y10362.000
+G1 X2845.000 Y10348.000
+G1 X2849.000 Y10340.000
+G1 X2855.000 Y10326.000
+G1 X2862.000 Y10312.000
+G1 X2865.000 Y10299.000
+G1 X2869.000 Y10285.000
+G1 X2872.000 Y10276.000
+G1 X2876.000 Y10263.000
+G1 X2877.000 Y10245.000
+G1 X2880.000 Y10231.000
+G1 X2881.000 Y10217.000
+G1 X2884.000 Y10200.000
+G1 X2885.000 Y10186.000
+G1 X2882.000 Y10172.000
+G1 X2883.000 Y10158.000
+G1 X2880.000 Y10139.000
+G1 X2881.000 Y10127.000
+G1 X2878.000 Y10113.000
+G1 X2879.000 Y10099.000
+G1 X2879.000 Y10085.000
+G1 X2880.000 Y10071.000
+G1 X2877.000 Y10054.000
+G1 X2878.000 Y10040.000
+G1 X2878.000 Y10026.000
+G1 X2876.000 Y10012.000
+G1 X2876.000 Y9995.000
+G1 X2877.000 Y9981.000
+G1 X2877.000 Y9962.000
+G1 X2878.000 Y9953.000
+G1 X2878.000 Y9939.000
+G1 X2882.000 Y9926.000
+G1 X2885.000 Y9912.000
+G1 X2886.000 Y9898.000
+G1 X2889.000 Y9886.000
+G1 X2896.000 Y9872.000
+G1 X2899.000 Y9858.000
+G1 X2903.000 Y9845.000
+G1 X2910.000 Y9831.000
+G1 X2913.000 Y9817.000
+G1 X2920.000 Y9803.000
+G1 X2926.000 Y9792.000
+G1 X2930.000 Y9778.000
+G1 X2937.000 Y9764.000
+G1 X2940.000 Y9750.000
+G1 X2947.000 Y9737.000
+G1 X2954.000 Y9723.000
+G1 X2960.000 Y9714.000
+G1 X2967.000 Y9701.000
+G1 X2973.000 Y9687.000
+G1 X2986.000 Y9674.000
+G1 X2996.000 Y9665.000
+G1 X3008.000 Y9662.000
+G1 X3018.000 Y9649.000
+G1 X3031.000 Y9639.000
+G1 X3040.000 Y9630.000
+G1 X3052.000 Y9631.000
+G1 X3067.000 Y9640.000
+G1 X3076.000 Y9646.000
+G1 X3089.000 Y9641.000
+G1 X3101.000 Y9638.000
+G1 X3111.000 Y9629.000
+G1 X3123.000 Y9619.000
+G1 X3133.000 Y9611.000
+G1 X3140.000 Y9597.000
+G1 X3146.000 Y9589.000
+G1 X3159.000 Y9581.000
+G1 X3171.000 Y9573.000
+G1 X3184.000 Y9568.000
+G1 X3199.000 Y9563.000
+G1 X3209.000 Y9560.000
+G1 X3221.000 Y9555.000
+G1 X3233.000 Y9556.000
+G1 X3246.000 Y9551.000
+G1 X3261.000 Y9548.000
+G1 X3277.000 Y9548.000
+G1 X3289.000 Y9544.000
+G1 X3305.000 Y9536.000
+G1 X3317.000 Y9536.000
+G1 X3333.000 Y9531.000
+G1 X3345.000 Y9532.000
+G1 X3366.000 Y9533.000
+G1 X3379.000 Y9538.000
+G1 X3391.000 Y9544.000
+G1 X3400.000 Y9553.000
+G1 X3409.000 Y9562.000
+G1 X3421.000 Y9571.000
+G1 X3430.000 Y9582.000
+G1 X3435.000 Y9594.000
+G1 X3441.000 Y9608.000
+G1 X3440.000 Y9622.000
+G1 X3437.000 Y9636.000
+G1 X3433.000 Y9650.000
+G1 X3423.000 Y9663.000
+G1 X3426.000 Y9677.000
+G1 X3429.000 Y9686.000
+G1 X3431.000 Y9700.000
+G1 X3434.000 Y9714.000
+G1 X3436.000 Y9731.000
+G1 X3439.000 Y9745.000
+G1 X3438.000 Y9763.000
+G1 X3441.000 Y9777.000
+G1 X3444.000 Y9791.000
+G1 X3443.000 Y9810.000
+G1 X3443.000 Y9819.000
+G1 X3445.000 Y9833.000
+G1 X3444.000 Y9846.000
+G1 X3447.000 Y9859.000
+G1 X3447.000 Y9873.000
+G1 X3446.000 Y9886.000
+G1 X3446.000 Y9900.000
+G1 X3448.000 Y9914.000
+G1 X3454.000 Y9928.000
+G1 X3463.000 Y9937.000
+G1 X3469.000 Y9952.000
+G1 X3465.000 Y9965.000
+G1 X3458.000 Y9979.000
+G1 X3452.000 Y9991.000
+G1 X3451.000 Y10010.000
+G1 X3448.000 Y10024.000
+G1 X3447.000 Y10038.000
+G1 X3449.000 Y10052.000
+G1 X3452.000 Y10069.000
+G1 X3452.000 Y10083.000
+G1 X3454.000 Y10097.000
+G1 X3453.000 Y10114.000
+G1 X3453.000 Y10128.000
+G1 X3452.000 Y10142.000
+G1 X3449.000 Y10156.000
+G1 X3455.000 Y10165.000
+G1 X3460.000 Y10184.000
+G1 X3463.000 Y10196.000
+G1 X3462.000 Y10210.000
+G1 X3465.000 Y10224.000
+G1 X3465.000 Y10238.000
+G1 X3464.000 Y10252.000
+G1 X3464.000 Y10266.000
+G1 X3463.000 Y10283.000
+G1 X3462.000 Y10297.000
+G1 X3462.000 Y10316.000
+G1 X3458.000 Y10334.000
+G1 X3457.000 Y10348.000
+G1 X3454.000 Y10361.000
+G1 X3451.000 Y10370.000
+G1 X3447.000 Y10387.000
+G1 X3446.000 Y10401.000
+G1 X3446.000 Y10415.000
+G1 X3442.000 Y10429.000
+G1 X3439.000 Y10442.000
+G1 X3432.000 Y10456.000
+G1 X3426.000 Y10470.000
+G1 X3422.000 Y10484.000
+G1 X3421.000 Y10496.000
+G1 X3424.000 Y10510.000
+G1 X3420.000 Y10524.000
+G1 X3417.000 Y10537.000
+G1 X3413.000 Y10551.000
+G1 X3410.000 Y10560.000
+G1 X3403.000 Y10573.000
+G1 X3397.000 Y10587.000
+G1 X3390.000 Y10601.000
+G1 X3383.000 Y10614.000
+G1 X3374.000 Y10626.000
+G1 X3367.000 Y10636.000
+G1 X3361.000 Y10650.000
+G1 X3354.000 Y10662.000
+G1 X3344.000 Y10676.000
+G1 X3335.000 Y10689.000
+G1 X3325.000 Y10703.000
+G1 X3318.000 Y10716.000
+G1 X3315.000 Y10725.000
+G1 X3311.000 Y10739.000
+G1 X3311.000 Y10753.000
+G1 X3316.000 Y10767.000
+G1 X3325.000 Y10776.000
+G1 X3331.000 Y10785.000
+G1 X3343.000 Y10799.000
+G1 X3355.000 Y10805.000
+G1 X3370.000 Y10809.000
+G1 X3382.000 Y10814.000
+G1 X3397.000 Y10823.000
+G1 X3410.000 Y10824.000
+G1 X3422.000 Y10821.000
+G1 X3434.000 Y10821.000
+G1 X3447.000 Y10816.000
+G1 X3462.000 Y10822.000
+G1 X3475.000 Y10817.000
+G1 X3490.000 Y10818.000
+G1 X3505.000 Y10824.000
+G1 X3517.000 Y10824.000
+G1 X3539.000 Y10825.000
+G1 X3554.000 Y10829.000
+G1 X3570.000 Y10830.000
+G1 X3582.000 Y10830.000
+G1 X3597.000 Y10831.000
+G1 X3619.000 Y10831.000
+G1 X3631.000 Y10832.000
+G1 X3643.000 Y10832.000
+G1 X3662.000 Y10829.000
+G1 X3671.000 Y10824.000
+G1 X3687.000 Y10820.000
+G1 X3699.000 Y10812.000
+G1 X3712.000 Y10807.000
+G1 X3731.000 Y10799.000
+G1 X3746.000 Y10796.000
+G1 X3758.000 Y10796.000
+G1 X3777.000 Y10786.000
+G1 X3793.000 Y10778.000
+G1 X3806.000 Y10774.000
+G1 X3821.000 Y10771.000
+G1 X3836.000 Y10771.000
+G1 X3849.000 Y10766.000
+G1 X3861.000 Y10763.000
+G1 X3883.000 Y10759.000
+G1 X3898.000 Y10760.000
+G1 X3907.000 Y10769.000
+G1 X3925.000 Y10783.000
+G1 X3930.000 Y10802.000
+G1 X3933.000 Y10825.000
+G1 X3932.000 Y10839.000
+G1 X3935.000 Y10851.000
+G1 X3944.000 Y10865.000
+G1 X3956.000 Y10876.000
+G1 X3968.000 Y10885.000
+G1 X3986.000 Y10886.000
+G1 X4002.000 Y10881.000
+G1 X4017.000 Y10873.000
+G1 X4024.000 Y10860.000
+G1 X4033.000 Y10851.000
+G1 X4037.000 Y10832.000
+G1 X4041.000 Y10818.000
+G1 X4041.000 Y10801.000
+G1 X4039.000 Y10787.000
+G1 X4033.000 Y10773.000
+G1 X4022.000 Y10755.000
+G1 X4007.000 Y10741.000
+G1 X3995.000 Y10726.000
+G1 X3986.000 Y10712.000
+G1 X3981.000 Y10698.000
+G1 X3978.000 Y10684.000
+G1 X3978.000 Y10672.000
+G1 X3976.000 Y10653.000
+G1 X3973.000 Y10639.000
+G1 X3974.000 Y10621.000
+G1 X3978.000 Y10602.000
+G1 X3991.000 Y10589.000
+G1 X4006.000 Y10581.000
+G1 X4022.000 Y10581.000
+G1 X4037.000 Y10587.000
+G1 X4052.000 Y10591.000
+G1 X4061.000 Y10602.000
+G1 X4063.000 Y10614.000
+G1 X4060.000 Y10633.000
+G1 X4056.000 Y10647.000
+G1 X4059.000 Y10661.000
+G1 X4058.000 Y10675.000
+G1 X4061.000 Y10687.000
+G1 X4063.000 Y10701.000
+G1 X4066.000 Y10712.000
+G1 X4072.000 Y10724.000
+G1 X4071.000 Y10738.000
+G1 X4071.000 Y10752.000
+G1 X4073.000 Y10766.000
+G1 X4073.000 Y10783.000
+G1 X4072.000 Y10797.000
+G1 X4072.000 Y10811.000
+G1 X4078.000 Y10825.000
+G1 X4086.000 Y10834.000
+G1 X4098.000 Y10845.000
+G1 X4110.000 Y10849.000
+G1 X4123.000 Y10849.000
+G1 X4135.000 Y10846.000
+G1 X4148.000 Y10836.000
+G1 X4157.000 Y10828.000
+G1 X4164.000 Y10814.000
+G1 X4164.000 Y10800.000
+G1 X4165.000 Y10786.000
+G1 X4162.000 Y10774.000
+G1 X4163.000 Y10760.000
+G1 X4163.000 Y10746.000
+G1 X4161.000 Y10727.000
+G1 X4164.000 Y10715.000
+G1 X4168.000 Y10701.000
+G1 X4175.000 Y10688.000
+G1 X4181.000 Y10679.000
+G1 X4194.000 Y10666.000
+G1 X4204.000 Y10656.000
+G1 X4210.000 Y10644.000
+G1 X4217.000 Y10633.000
+G1 X4226.000 Y10620.000
+G1 X4233.000 Y10608.000
+G1 X4239.000 Y10598.000
+G1 X4246.000 Y10584.000
+G1 X4253.000 Y10572.000
+G1 X4253.000 Y10558.000
+G1 X4254.000 Y10544.000
+G1 X4257.000 Y10536.000
+G1 X4261.000 Y10522.000
+G1 X4264.000 Y10508.000
+G1 X4268.000 Y10495.000
+G1 X4268.000 Y10481.000
+G1 X4269.000 Y10467.000
+G1 X4272.000 Y10453.000
+G1 X4273.000 Y10441.000
+G1 X4273.000 Y10427.000
+G1 X4271.000 Y10413.000
+G1 X4258.000 Y10421.000
+G1 X4255.000 Y10435.000
+G1 X4255.000 Y10421.000
+G1 X4267.000 Y10427.000
+G1 X4270.000 Y10416.000
+G1 X4274.000 Y10404.000
+G1 X4280.000 Y10391.000
+G1 X4278.000 Y10377.000
+G1 X4276.000 Y10354.000
+G1 X4279.000 Y10345.000
+G1 X4292.000 Y10335.000
+G1 X4307.000 Y10333.000
+G1 X4323.000 Y10333.000
+G1 X4338.000 Y10337.000
+G1 X4347.000 Y10348.000
+G1 X4353.000 Y10360.000
+G1 X4358.000 Y10374.000
+G1 X4361.000 Y10388.000
+G1 X4363.000 Y10402.000
+G1 X4366.000 Y10411.000
+G1 X4375.000 Y10425.000
+G1 X4384.000 Y10434.000
+G1 X4386.000 Y10448.000
+G1 X4392.000 Y10462.000
+G1 X4401.000 Y10471.000
+G1 X4410.000 Y10482.000
+G1 X4422.000 Y10491.000
+G1 X4433.000 Y10506.000
+G1 X4442.000 Y10515.000
+G1 X4451.000 Y10524.000
+G1 X4463.000 Y10538.000
+G1 X4472.000 Y10547.000
+G1 X4484.000 Y10553.000
+G1 X4496.000 Y10562.000
+G1 X4515.000 Y10559.000
+G1 X4530.000 Y10549.000
+G1 X4540.000 Y10541.000
+G1 X4550.000 Y10524.000
+G1 X4550.000 Y10510.000
+G1 X4551.000 Y10491.000
+G1 X4548.000 Y10482.000
+G1 X4549.000 Y10463.000
+G1 X4553.000 Y10446.000
+G1 X4556.000 Y10432.000
+G1 X4566.000 Y10423.000
+G1 X4575.000 Y10415.000
+G1 X4576.000 Y10401.000
+G1 X4582.000 Y10382.000
+G1 X4589.000 Y10374.000
+G1 X4598.000 Y10360.000
+G1 X4605.000 Y10352.000
+G1 X4615.000 Y10338.000
+G1 X4624.000 Y10325.000
+G1 X4637.000 Y10316.000
+G1 X4650.000 Y10308.000
+G1 X4659.000 Y10303.000
+G1 X4669.000 Y10290.000
+G1 X4679.000 Y10276.000
+G1 X4685.000 Y10264.000
+G1 X4692.000 Y10251.000
+G1 X4695.000 Y10237.000
+G1 X4708.000 Y10223.000
+G1 X4721.000 Y10215.000
+G1 X4733.000 Y10210.000
+G1 X4749.000 Y10206.000
+G1 X4761.000 Y10206.000
+G1 X4773.000 Y10207.000
+G1 X4785.000 Y10212.000
+G1 X4797.000 Y10218.000
+G1 X4806.000 Y10232.000
+G1 X4815.000 Y10245.000
+G1 X4814.000 Y10258.000
+G1 X4814.000 Y10277.000
+G1 X4807.000 Y10286.000
+G1 X4801.000 Y10300.000
+G1 X4797.000 Y10313.000
+G1 X4791.000 Y10327.000
+G1 X4781.000 Y10335.000
+G1 X4774.000 Y10344.000
+G1 X4771.000 Y10363.000
+G1 X4764.000 Y10375.000
+G1 X4761.000 Y10385.000
+G1 X4754.000 Y10402.000
+G1 X4751.000 Y10411.000
+G1 X4747.000 Y10425.000
+G1 X4744.000 Y10438.000
+G1 X4740.000 Y10452.000
+G1 X4736.000 Y10466.000
+G1 X4730.000 Y10480.000
+G1 X4726.000 Y10493.000
+G1 X4723.000 Y10502.000
+G1 X4719.000 Y10516.000
+G1 X4722.000 Y10530.000
+G1 X4721.000 Y10542.000
+G1 X4717.000 Y10561.000
+G1 X4717.000 Y10575.000
+G1 X4714.000 Y10589.000
+G1 X4710.000 Y10601.000
+G1 X4709.000 Y10614.000
+G1 X4709.000 Y10628.000
+G1 X4708.000 Y10647.000
+G1 X4708.000 Y10661.000
+G1 X4707.000 Y10674.000
+G1 X4707.000 Y10693.000
+G1 X4703.000 Y10706.000
+G1 X4703.000 Y10724.000
+G1 X4699.000 Y10746.000
+G1 X4695.000 Y10757.000
+G1 X4689.000 Y10768.000
+G1 X4679.000 Y10782.000
+G1 X4669.000 Y10792.000
+G1 X4660.000 Y10800.000
+G1 X4647.000 Y10814.000
+G1 X4637.000 Y10831.000
+G1 X4634.000 Y10840.000
+G1 X4633.000 Y10853.000
+G1 X4639.000 Y10868.000
+G1 X4651.000 Y10877.000
+G1 X4666.000 Y10888.000
+G1 X4684.000 Y10888.000
+G1 X4697.000 Y10889.000
+G1 X4718.000 Y10884.000
+G1 X4741.000 Y10868.000
+G1 X4750.000 Y10858.000
+G1 X4760.000 Y10844.000
+G1 X4773.000 Y10836.000
+G1 X4788.000 Y10828.000
+G1 X4807.000 Y10823.000
+G1 X4819.000 Y10823.000
+G1 X4840.000 Y10824.000
+G1 X4856.000 Y10835.000
+G1 X4871.000 Y10839.000
+G1 X4889.000 Y10840.000
+G1 X4901.000 Y10846.000
+G1 X4914.000 Y10849.000
+G1 X4929.000 Y10855.000
+G1 X4941.000 Y10864.000
+G1 X4956.000 Y10870.000
+G1 X4968.000 Y10876.000
+G1 X4980.000 Y10880.000
+G1 X4989.000 Y10894.000
+G1 X4998.000 Y10903.000
+G1 X5004.000 Y10917.000
+G1 X5009.000 Y10936.000
+G1 X5014.000 Y10949.000
+G1 X5020.000 Y10963.000
+G1 X5026.000 Y10977.000
+G1 X5032.000 Y10986.000
+G1 X5041.000 Y11000.000
+G1 X5055.000 Y11014.000
+G1 X5074.000 Y11020.000
+G1 X5086.000 Y11021.000
+G1 X5101.000 Y11025.000
+G1 X5113.000 Y11030.000
+G1 X5125.000 Y11039.000
+G1 X5141.000 Y11040.000
+G1 X5156.000 Y11037.000
+G1 X5169.000 Y11027.000
+G1 X5185.000 Y11015.000
+G1 X5188.000 Y10996.000
+G1 X5195.000 Y10983.000
+G1 X5202.000 Y10969.000
+G1 X5211.000 Y10956.000
+G1 X5212.000 Y10943.000
+G1 X5212.000 Y10933.000
+G1 X5213.000 Y10921.000
+G1 X5213.000 Y10907.000
+G1 X5217.000 Y10888.000
+G1 X5224.000 Y10871.000
+G1 X5227.000 Y10861.000
+G1 X5230.000 Y10849.000
+G1 X5237.000 Y10835.000
+G1 X5244.000 Y10821.000
+G1 X5247.000 Y10807.000
+G1 X5260.000 Y10794.000
+G1 X5266.000 Y10785.000
+G1 X5276.000 Y10767.000
+G1 X5283.000 Y10755.000
+G1 X5296.000 Y10736.000
+G1 X5309.000 Y10723.000
+G1 X5316.000 Y10709.000
+G1 X5325.000 Y10701.000
+G1 X5335.000 Y10687.000
+G1 X5341.000 Y10673.000
+G1 X5351.000 Y10665.000
+G1 X5357.000 Y10651.000
+G1 X5364.000 Y10643.000
+G1 X5374.000 Y10621.000
+G1 X5378.000 Y10607.000
+G1 X5384.000 Y10590.000
+G1 X5391.000 Y10576.000
+G1 X5392.000 Y10557.000
+G1 X5395.000 Y10543.000
+G1 X5402.000 Y10526.000
+G1 X5408.000 Y10512.000
+G1 X5412.000 Y10499.000
+G1 X5415.000 Y10490.000
+G1 X5419.000 Y10476.000
+G1 X5423.000 Y10454.000
+G1 X5426.000 Y10445.000
+G1 X5433.000 Y10426.000
+G1 X5443.000 Y10413.000
+G1 X5453.000 Y10399.000
+G1 X5465.000 Y10396.000
+G1 X5477.000 Y10388.000
+G1 X5490.000 Y10383.000
+G1 X5506.000 Y10379.000
+G1 X5524.000 Y10379.000
+G1 X5539.000 Y10380.000
+G1 X5558.000 Y10380.000
+G1 X5573.000 Y10377.000
+G1 X5589.000 Y10378.000
+G1 X5607.000 Y10373.000
+G1 X5623.000 Y10369.000
+G1 X5647.000 Y10370.000
+G1 X5669.000 Y10367.000
+G1 X5685.000 Y10362.000
+G1 X5706.000 Y10360.000
+G1 X5718.000 Y10360.000
+G1 X5734.000 Y10355.000
+G1 X5753.000 Y10356.000
+G1 X5765.000 Y10351.000
+G1 X5780.000 Y10348.000
+G1 X5793.000 Y10344.000
+G1 X5808.000 Y10344.000
+G1 X5824.000 Y10339.000
+G1 X5843.000 Y10337.000
+G1 X5858.000 Y10337.000
+G1 X5870.000 Y10332.000
+G1 X5886.000 Y10333.000
+G1 X5904.000 Y10330.000
+G1 X5919.000 Y10334.000
+G1 X5932.000 Y10340.000
+G1 X5937.000 Y10357.000
+G1 X5940.000 Y10371.000
+G1 X5942.000 Y10385.000
+G1 X5948.000 Y10394.000
+G1 X5954.000 Y10408.000
+G1 X5962.000 Y10423.000
+G1 X5971.000 Y10432.000
+G1 X5980.000 Y10446.000
+G1 X5986.000 Y10460.000
+G1 X5991.000 Y10477.000
+G1 X6000.000 Y10492.000
+G1 X6009.000 Y10497.000
+G1 X6024.000 Y10506.000
+G1 X6045.000 Y10512.000
+G1 X6064.000 Y10508.000
+G1 X6073.000 Y10505.000
+G1 X6071.000 Y10491.000
+G1 X6080.000 Y10486.000
+M5
+G0 X0 Y0
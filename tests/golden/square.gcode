@@ -0,0 +1,11 @@
+G21
+G90
+M5
+G0 X0.000 Y0.000
+M3 S1000
+G1 X1000.000 Y0.000 F1000
+G1 X1000.000 Y1000.000
+G1 X0.000 Y1000.000
+G1 X0.000 Y0.000
+M5
+G0 X0 Y0
@@ -0,0 +1,14 @@
+G21
+G90
+M5
+G0 X1000.000 Y1000.000
+M3 S1000
+G1 X2000.000 Y0.000 F1000
+G1 X3000.000 Y1000.000
+G1 X4000.000 Y0.000
+G1 X0.000 Y0.000
+G1 X2000.000 Y2000.000
+G1 X3000.000 Y1000.000
+G1 X1000.000 Y1000.000
+M5
+G0 X0 Y0
@@ -0,0 +1,161 @@
+//! Replay sessions recorded with `--traffic-log` against the drivers. The sessions are in
+//! `tests/sessions`.
+use std::path::PathBuf;
+
+use loempia::plotter::Plotter;
+use loempia::point::Coordinate;
+use loempia::traffic::Replay;
+use loempia::{gcode, roland_dxy, Driver, Error, Paths, Plot};
+
+fn square() -> Plot {
+    let path = vec![
+        Coordinate::new(0, 0),
+        Coordinate::new(1000, 0),
+        Coordinate::new(1000, 1000),
+        Coordinate::new(0, 1000),
+        Coordinate::new(0, 0),
+    ];
+    Plot::from_path(path).unwrap()
+}
+
+fn session(name: &str) -> Replay {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/sessions")
+        .join(name);
+    Replay::read(&path).unwrap()
+}
+
+#[test]
+fn replay_ebb() {
+    let mut driver = Driver::from_transport(session("square.ebb.session"));
+
+    driver.plot(&square()).unwrap();
+    assert_eq!(
+        driver.version().unwrap(),
+        "EBBv13_and_above EB Firmware Version 2.8.1"
+    );
+}
+
+#[test]
+fn replay_roland_dxy() {
+    let mut driver = roland_dxy::Driver::from_transport(session("square.hpgl.session"));
+
+    driver.plot(&square()).unwrap();
+    assert_eq!(driver.version().unwrap(), "DXY-1300");
+}
+
+#[test]
+fn replay_grbl() {
+    let mut driver =
+        gcode::Driver::from_transport(session("square.grbl.session"), Default::default());
+
+    driver.plot(&square()).unwrap();
+    assert_eq!(driver.version().unwrap(), "Grbl 1.1h.20190825");
+}
+
+#[test]
+fn replay_ebb_error_response() {
+    let replay = Replay::parse(
+        r#"
+        0.0 > "QU,3\r"
+        0.1 < "1\r\nOK\r\n"
+        0.2 > "SP,1\r"
+        0.3 > "SM,1000,0,0\r"
+        0.4 < "OK\r\n"
+        0.5 < "OK\r\n"
+        0.6 > "SP,0\r"
+        0.7 > "SM,1000,1000,-1000\r"
+        0.8 < "!8 Err: Unknown command\r\n"
+        0.9 < "OK\r\n"
+        "#,
+    )
+    .unwrap();
+    let mut driver = Driver::from_transport(replay);
+
+    // Two commands are in flight, but the pen is only lowered once the travel to the square is
+    // confirmed. The first side of the square is sent before the error of lowering the pen is
+    // read. The error counts the commands of the plot from 1.
+    match driver.plot(&square()) {
+        Err(Error::PipelineError(number, command, _)) => {
+            assert_eq!(number, 3);
+            assert_eq!(command.to_string(), "SP,0");
+        }
+        other => panic!("Unexpected result {:?}", other),
+    }
+}
+
+/// Two lines below each other, plotted from left to right.
+fn two_lines() -> Plot {
+    let line = |y| vec![Coordinate::new(0, y), Coordinate::new(1000, y)];
+    Plot::new(Paths::new(vec![line(0), line(1000)]).unwrap())
+}
+
+/// The commands that plot `two_lines()`, each with its response read before the pen is lowered
+/// and when three commands are in flight.
+const TWO_LINES: &str = r#"
+    0.00 > "SP,1\r"
+    0.01 > "SM,1000,0,0\r"
+    0.02 < "OK\r\n"
+    0.03 < "OK\r\n"
+    0.04 > "SP,0\r"
+    0.05 > "SM,1000,1000,-1000\r"
+    0.06 > "SP,1\r"
+    0.07 < "OK\r\n"
+    0.08 > "SM,1000,-1000,1000\r"
+    0.09 < "OK\r\n"
+    0.10 > "SM,1000,-1000,-1000\r"
+    0.11 < "OK\r\n"
+    0.12 < "OK\r\n"
+    0.13 < "OK\r\n"
+    0.14 > "SP,0\r"
+    0.15 > "SM,1000,1000,-1000\r"
+    0.16 > "SP,1\r"
+    0.17 < "OK\r\n"
+    0.18 > "SM,1000,0,2000\r"
+    0.19 < "OK\r\n"
+    0.20 < "OK\r\n"
+    0.21 < "OK\r\n"
+"#;
+
+#[test]
+fn replay_ebb_pipeline_across_strokes() {
+    let mut driver = Driver::from_transport(Replay::parse(TWO_LINES).unwrap());
+    driver.set_pipeline_depth(3);
+
+    // The travel to the second line is sent before the first line is confirmed.
+    let mut progress = vec![];
+    driver
+        .plot_with_progress(&two_lines(), &mut |done, total| {
+            progress.push((done, total));
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(progress, vec![(1, 2), (2, 2)]);
+}
+
+#[test]
+fn replay_ebb_stop_with_pen_raised() {
+    // Stopping after the first line reads the response to the travel in flight, but doesn't lower
+    // the pen for the second line: the session ends before that.
+    let session = &TWO_LINES[..TWO_LINES.find("0.14").unwrap()];
+    let mut driver = Driver::from_transport(Replay::parse(session).unwrap());
+    driver.set_pipeline_depth(3);
+
+    let result = driver.plot_with_progress(&two_lines(), &mut |_, _| {
+        Err(Error::JobError("Stopped.".to_string()))
+    });
+    assert!(matches!(result, Err(Error::JobError(_))));
+}
+
+#[test]
+fn replay_detects_different_commands() {
+    let mut driver = Driver::from_transport(session("square.ebb.session"));
+
+    let plot = Plot::from_path(vec![
+        Coordinate::new(0, 0),
+        Coordinate::new(2000, 0),
+        Coordinate::new(0, 0),
+    ])
+    .unwrap();
+    assert!(matches!(driver.plot(&plot), Err(Error::CommandError(_, _))));
+}